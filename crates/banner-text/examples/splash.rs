@@ -0,0 +1,93 @@
+use std::{
+    collections::HashMap,
+    io,
+    time::Duration,
+};
+
+use caponata_banner_text::{
+    BannerFont,
+    BannerTextWidget,
+};
+use caponata_small_text::{
+    AnimationRepeatMode,
+    ScannerAnimationStyleBuilder,
+    SmallTextStyleBuilder,
+    Target,
+};
+use crossterm::event::{
+    Event,
+    KeyCode,
+    poll,
+    read,
+};
+use ratatui::{
+    DefaultTerminal,
+    layout::{
+        Alignment,
+        Constraint,
+        Flex,
+        Layout,
+    },
+    style::Color,
+    widgets::Paragraph,
+};
+
+pub fn main() -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+fn run(terminal: &mut DefaultTerminal) -> io::Result<()> {
+    let text_style = SmallTextStyleBuilder::default()
+        .with_text("Caponata")
+        .for_target(Target::Untouched)
+        .set_foreground_color(Color::Rgb(120, 120, 140))
+        .then()
+        .build();
+    let scanner = ScannerAnimationStyleBuilder::default()
+        .with_text_style(&text_style)
+        .with_duration(Duration::from_millis(120))
+        .with_foreground_color(Color::Rgb(255, 200, 80))
+        .with_trail_length(2u16)
+        .with_repeat_mode(AnimationRepeatMode::Infinite)
+        .build()
+        .unwrap();
+    let mut banner = BannerTextWidget::animated(
+        text_style.clone(),
+        HashMap::from([("scanner", scanner.try_into().unwrap())]),
+        BannerFont::block(),
+    )
+    .with_alignment(Alignment::Center);
+    banner.enable_animation(&"scanner");
+
+    loop {
+        terminal.draw(|frame| {
+            let [banner_area, hint_area] = Layout::vertical([
+                Constraint::Length(banner.height()),
+                Constraint::Length(1),
+            ])
+            .spacing(1)
+            .flex(Flex::Center)
+            .areas(frame.area());
+
+            frame.render_widget(&mut banner, banner_area);
+            frame.render_widget(
+                Paragraph::new("Press q to quit").centered(),
+                hint_area,
+            );
+        })?;
+
+        let timeout = banner
+            .time_until_next_frame()
+            .unwrap_or(Duration::from_millis(100));
+        if !poll(timeout)? {
+            continue;
+        }
+        if matches!(read()?, Event::Key(key) if key.code == KeyCode::Char('q'))
+        {
+            return Ok(());
+        }
+    }
+}
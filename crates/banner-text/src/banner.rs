@@ -0,0 +1,474 @@
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    hash::Hash,
+    time::Duration,
+};
+
+use caponata_common::{
+    AccessibleDescription,
+    Animated,
+    Identifiable,
+    SharedClock,
+    WidgetIdentity,
+    render_adapted,
+};
+use caponata_small_text::{
+    AnimatedSmallTextWidget,
+    AnimationStyle,
+    SmallTextStyle,
+    SmallTextWidget,
+    Symbol,
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::{
+        Alignment,
+        Position,
+        Rect,
+    },
+    style::Style,
+    widgets::Widget,
+};
+
+use super::{
+    BannerFont,
+    BannerGlyph,
+};
+
+/// A widget that displays a short text with multi-row glyphs
+/// of a [`BannerFont`], e.g. for splash screens. A bigger
+/// sibling of [`SmallTextWidget`].
+///
+/// Letters are styled and animated like the symbols of
+/// [`AnimatedSmallTextWidget`]: targets of the text style and
+/// of the animations address letters, and every cell of a
+/// glyph takes the style of its letter. Characters the font
+/// has no glyph for are displayed with the glyph of `?`, or
+/// skipped if there's none.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     style::Color,
+///     widgets::Widget,
+/// };
+/// use caponata_banner_text::{
+///     BannerFont,
+///     BannerTextWidget,
+/// };
+/// use caponata_small_text::{
+///     SmallTextStyleBuilder,
+///     Target,
+/// };
+///
+/// let style = SmallTextStyleBuilder::default()
+///     .with_text("Hi")
+///     .for_target(Target::Single(1))
+///     .set_foreground_color(Color::Red)
+///     .then()
+///     .build();
+/// let mut banner: BannerTextWidget =
+///     BannerTextWidget::new(style, BannerFont::block());
+///
+/// let area = Rect::new(0, 0, 7, 3);
+/// let mut buf = Buffer::empty(area);
+/// banner.render(area, &mut buf);
+/// assert_eq!(
+///     buf.content().iter().map(|cell| cell.symbol()).collect::<String>(),
+///     "█ █ ▀█▀█▀█  █ ▀ ▀ ▀▀▀",
+/// );
+/// assert_eq!(buf[(4, 0)].fg, Color::Red);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BannerTextWidget<K = ()>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    text: AnimatedSmallTextWidget<K>,
+
+    /// Virtual x coordinates and characters of the letters,
+    /// in the order they are displayed.
+    letters: Vec<(u16, char)>,
+    font: BannerFont,
+
+    /// Number of cells between the glyphs.
+    letter_spacing: u16,
+    alignment: Alignment,
+    identity: WidgetIdentity,
+}
+
+impl<K> Widget for &mut BannerTextWidget<K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.identity.record(area, &*self);
+        let area = area.intersection(buf.area);
+        if area.is_empty() || !self.text.is_visible() {
+            return;
+        }
+
+        render_adapted(area, buf, |buf| {
+            let glyph_areas = self.glyph_areas(area);
+            let symbols = self.text.next_symbols();
+            for (x, glyph_area) in glyph_areas {
+                let Some(symbol) = symbols.get(&x) else {
+                    continue;
+                };
+                let Some(glyph) = glyph(&self.font, symbol.value) else {
+                    continue;
+                };
+                render_glyph(glyph, symbol, glyph_area, buf);
+            }
+        });
+    }
+}
+
+impl BannerTextWidget {
+    /// Creates a widget displaying the text of provided style
+    /// with the glyphs of provided font.
+    pub fn new(text_style: SmallTextStyle, font: BannerFont) -> Self {
+        Self::animated(text_style, HashMap::new(), font)
+    }
+}
+
+impl<K> BannerTextWidget<K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    /// Creates a widget like [`BannerTextWidget::new`] with
+    /// animations that can be enabled by their keys, like
+    /// [`AnimatedSmallTextWidget::new`].
+    pub fn animated(
+        text_style: SmallTextStyle,
+        animation_styles: HashMap<K, AnimationStyle>,
+        font: BannerFont,
+    ) -> Self {
+        let mut letters: Vec<(u16, char)> =
+            SmallTextWidget::new(text_style.clone())
+                .symbols()
+                .iter()
+                .map(|(x, symbol)| (*x, symbol.value))
+                .collect();
+        letters.sort_unstable();
+
+        Self {
+            text: AnimatedSmallTextWidget::new(text_style, animation_styles),
+            letters,
+            font,
+            letter_spacing: 1,
+            alignment: Alignment::Left,
+            identity: WidgetIdentity::default(),
+        }
+    }
+
+    /// Sets the number of cells between the glyphs, `1` by
+    /// default.
+    pub fn with_letter_spacing(mut self, letter_spacing: u16) -> Self {
+        self.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Sets the horizontal alignment of the glyphs within the
+    /// area, [`Alignment::Left`] by default.
+    pub fn with_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    pub fn font(&self) -> &BannerFont {
+        &self.font
+    }
+
+    /// Returns the number of cells the glyphs, separated by
+    /// the letter spacing, occupy.
+    pub fn width(&self) -> u16 {
+        let (glyph_count, glyphs_width) = self
+            .letters
+            .iter()
+            .filter_map(|(_, letter)| glyph(&self.font, *letter))
+            .fold((0u16, 0u16), |(count, width), glyph| {
+                (count + 1, width.saturating_add(glyph.width()))
+            });
+        let spacing_count = glyph_count.saturating_sub(1);
+
+        glyphs_width.saturating_add(spacing_count * self.letter_spacing)
+    }
+
+    /// Returns the number of rows the glyphs occupy.
+    pub fn height(&self) -> u16 {
+        self.font.height()
+    }
+
+    /// Returns the underlying animated text, e.g. to hide it
+    /// or to drain its animation events. Show and hide
+    /// transitions of the text are not played.
+    pub fn text_mut(&mut self) -> &mut AnimatedSmallTextWidget<K> {
+        &mut self.text
+    }
+
+    /// Enables the animation associated with the specified key
+    /// if it exists, like
+    /// [`AnimatedSmallTextWidget::enable_animation`].
+    pub fn enable_animation(&mut self, key: &K) {
+        self.text.enable_animation(key);
+    }
+
+    /// Plays the animation associated with the specified key a
+    /// single time if it exists, like
+    /// [`AnimatedSmallTextWidget::play_once`].
+    pub fn play_once(&mut self, key: &K) {
+        self.text.play_once(key);
+    }
+
+    /// Disables the currently active animation, if any;
+    /// otherwise has no effect.
+    pub fn disable_animation(&mut self) {
+        self.text.disable_animation();
+    }
+
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.text.set_clock(clock);
+    }
+
+    /// Returns the time left until the active animation
+    /// displays its next frame, or `None` if there's none.
+    pub fn time_until_next_frame(&self) -> Option<Duration> {
+        self.text.time_until_next_frame()
+    }
+
+    /// Returns the virtual x coordinate of the letter whose
+    /// glyph, rendered in provided area, occupies provided
+    /// position; otherwise returns `None`.
+    pub fn hit_test(&self, area: Rect, position: Position) -> Option<u16> {
+        self.glyph_areas(area)
+            .into_iter()
+            .find(|(_, glyph_area)| glyph_area.contains(position))
+            .map(|(x, _)| x)
+    }
+
+    /// Returns the virtual x coordinates of the letters and
+    /// the areas of their glyphs within provided area. Glyphs
+    /// that don't fit are clipped or omitted.
+    fn glyph_areas(&self, area: Rect) -> Vec<(u16, Rect)> {
+        let free_width = area.width.saturating_sub(self.width());
+        let offset = match self.alignment {
+            Alignment::Left => 0,
+            Alignment::Center => free_width / 2,
+            Alignment::Right => free_width,
+        };
+
+        let mut glyph_x = area.x.saturating_add(offset);
+        let mut glyph_areas = Vec::new();
+        for (x, letter) in self.letters.iter() {
+            let Some(glyph) = glyph(&self.font, *letter) else {
+                continue;
+            };
+            let glyph_area =
+                Rect::new(glyph_x, area.y, glyph.width(), self.height())
+                    .intersection(area);
+            if !glyph_area.is_empty() {
+                glyph_areas.push((*x, glyph_area));
+            }
+            glyph_x = glyph_x
+                .saturating_add(glyph.width())
+                .saturating_add(self.letter_spacing);
+        }
+
+        glyph_areas
+    }
+}
+
+impl<K> Animated for BannerTextWidget<K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn time_until_next_frame(&self) -> Option<Duration> {
+        BannerTextWidget::time_until_next_frame(self)
+    }
+}
+
+impl<K> AccessibleDescription for BannerTextWidget<K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    /// Describes the banner as its text, which reads the
+    /// letters rather than the glyphs they are drawn with.
+    fn accessible_description(&self) -> String {
+        let description = self.text.accessible_description();
+        match description.strip_prefix("text") {
+            Some(rest) => format!("banner{rest}"),
+            None => description,
+        }
+    }
+}
+
+impl<K> Identifiable for BannerTextWidget<K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+/// Returns the glyph provided letter is displayed with.
+fn glyph(font: &BannerFont, letter: char) -> Option<&BannerGlyph> {
+    font.glyph(letter).or_else(|| font.glyph('?'))
+}
+
+/// Renders provided glyph in provided area with the style of
+/// provided symbol. Spaces of the glyph only take the
+/// background color.
+fn render_glyph(
+    glyph: &BannerGlyph,
+    symbol: &Symbol,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let style = Style {
+        fg: symbol.foreground_color,
+        bg: symbol.background_color,
+        ..Style::default()
+    }
+    .add_modifier(symbol.modifier);
+    buf.set_style(
+        area,
+        Style {
+            bg: symbol.background_color,
+            ..Style::default()
+        },
+    );
+
+    for (row, y) in glyph.rows().iter().zip(area.top()..area.bottom()) {
+        for (character, x) in row.chars().zip(area.left()..area.right()) {
+            if character != ' ' {
+                buf[(x, y)].set_char(character).set_style(style);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        time::Duration,
+    };
+
+    use caponata_common::{
+        AccessibleDescription,
+        AnimationGroup,
+    };
+    use caponata_small_text::{
+        AnimationAdvanceMode,
+        AnimationRepeatMode,
+        AnimationStepBuilder,
+        AnimationStyleBuilder,
+        AnimationTarget,
+        SmallTextStyleBuilder,
+    };
+    use ratatui::{
+        buffer::Buffer,
+        layout::{
+            Alignment,
+            Position,
+            Rect,
+        },
+        style::Color,
+        widgets::Widget,
+    };
+
+    use super::BannerTextWidget;
+    use crate::BannerFont;
+
+    fn font() -> BannerFont {
+        BannerFont::new(2)
+            .with_glyph('a', &["/\\", "--"])
+            .with_glyph('b', &["|>", "|>"])
+            .with_glyph('?', &["?", "."])
+    }
+
+    #[test]
+    fn glyphs_are_aligned() {
+        let style = SmallTextStyleBuilder::default().with_text("ab!").build();
+        let mut banner = BannerTextWidget::new(style, font())
+            .with_letter_spacing(0)
+            .with_alignment(Alignment::Right);
+        assert_eq!(banner.width(), 5);
+        assert_eq!(banner.height(), 2);
+
+        let area = Rect::new(0, 0, 7, 2);
+        let mut buf = Buffer::empty(area);
+        banner.render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["  /\\|>?", "  --|>."]));
+        assert_eq!(banner.hit_test(area, Position::new(3, 1)), Some(0));
+        assert_eq!(banner.hit_test(area, Position::new(6, 0)), Some(2));
+        assert_eq!(banner.hit_test(area, Position::new(1, 0)), None);
+    }
+
+    #[test]
+    fn animation_targets_letters() {
+        let step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_millis(100))
+            .for_target(AnimationTarget::Single(1))
+            .update_foreground_color(Color::Rgb(200, 0, 0))
+            .then()
+            .build()
+            .unwrap();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_advance_mode(AnimationAdvanceMode::Auto)
+            .with_repeat_mode(AnimationRepeatMode::Infinite)
+            .with_steps(vec![step])
+            .build()
+            .unwrap();
+        let style = SmallTextStyleBuilder::default().with_text("ab").build();
+        let mut banner = BannerTextWidget::animated(
+            style,
+            HashMap::from([(0, animation_style)]),
+            font(),
+        );
+        let group = AnimationGroup::new();
+        banner.set_clock(group.clock());
+        banner.enable_animation(&0);
+        group.advance(Duration::from_millis(10));
+
+        let area = Rect::new(0, 0, 5, 2);
+        let mut buf = Buffer::empty(area);
+        banner.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].fg, Color::Reset);
+        assert_eq!(buf[(3, 0)].fg, Color::Rgb(200, 0, 0));
+        assert_eq!(buf[(4, 1)].fg, Color::Rgb(200, 0, 0));
+        assert!(banner.time_until_next_frame().is_some());
+        assert_eq!(banner.accessible_description(), "banner 'ab', animated");
+    }
+
+    #[test]
+    fn figlet_font_is_parsed() {
+        let mut source = String::from("flf2a$ 2 1 4 0 1\nA comment\n");
+        for character in ' '..='~' {
+            let glyph = match character {
+                'x' => "\\/@\n/\\@@\n".to_string(),
+                _ => format!("{character}$@\n@@\n"),
+            };
+            source.push_str(&glyph);
+        }
+        source.push_str(&"@\n@@\n".repeat(7));
+        source.push_str("0x263A  SMILE\n:)#\n  ##\n");
+
+        let font = BannerFont::from_figlet(&source).unwrap();
+        assert_eq!(font.height(), 2);
+        assert_eq!(font.glyph('x').unwrap().rows(), ["\\/", "/\\"]);
+        assert_eq!(font.glyph('!').unwrap().rows(), ["! ", ""]);
+        assert_eq!(font.glyph('☺').unwrap().rows(), [":)", "  "]);
+        assert_eq!(font.glyph('ä').unwrap().width(), 0);
+    }
+}
@@ -0,0 +1,44 @@
+use std::{
+    error::Error,
+    fmt,
+};
+
+/// An error returned when a FIGlet font passed to
+/// [`BannerFont::from_figlet`] can't be parsed.
+///
+/// [`BannerFont::from_figlet`]: crate::BannerFont::from_figlet
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BannerFontError {
+    /// The first line is not a `flf2a` header with the height
+    /// of the glyphs and the number of comment lines.
+    InvalidHeader,
+
+    /// The font ends before all the rows of the glyph of
+    /// provided character.
+    MissingGlyph(char),
+
+    /// A code tag of a glyph is not a valid character code.
+    /// Includes the number of the line, starting from 1.
+    InvalidCodeTag { line: usize },
+}
+
+impl fmt::Display for BannerFontError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHeader => {
+                write!(formatter, "font has no valid `flf2a` header")
+            }
+            Self::MissingGlyph(character) => write!(
+                formatter,
+                "font ends before the glyph of {:?}",
+                character,
+            ),
+            Self::InvalidCodeTag { line } => {
+                write!(formatter, "invalid code tag at line {}", line)
+            }
+        }
+    }
+}
+
+impl Error for BannerFontError {}
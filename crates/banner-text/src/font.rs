@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+
+use ratatui::text::Span;
+
+use super::BannerFontError;
+
+/// Rows of the glyphs of [`BannerFont::block`]. Lowercase
+/// letters are displayed with the uppercase glyphs.
+const BLOCK_GLYPHS: [(char, [&str; 3]); 48] = [
+    (' ', ["  ", "  ", "  "]),
+    ('!', ["█", "▀", "▀"]),
+    ('\'', ["▀", " ", " "]),
+    (',', [" ", " ", "▄"]),
+    ('-', ["   ", "▀▀▀", "   "]),
+    ('.', [" ", " ", "▀"]),
+    ('/', ["  █", " █ ", "█  "]),
+    ('0', ["█▀█", "█ █", "▀▀▀"]),
+    ('1', ["▄█ ", " █ ", "▀▀▀"]),
+    ('2', ["▀▀█", "█▀▀", "▀▀▀"]),
+    ('3', ["▀▀█", " ▀█", "▀▀▀"]),
+    ('4', ["█ █", "▀▀█", "  ▀"]),
+    ('5', ["█▀▀", "▀▀█", "▀▀▀"]),
+    ('6', ["█▀▀", "█▀█", "▀▀▀"]),
+    ('7', ["▀▀█", "  █", "  ▀"]),
+    ('8', ["█▀█", "█▀█", "▀▀▀"]),
+    ('9', ["█▀█", "▀▀█", "▀▀▀"]),
+    (':', [" ", "▀", "▀"]),
+    ('?', ["▀▀█", " █▀", " ▀ "]),
+    ('A', ["█▀█", "█▀█", "▀ ▀"]),
+    ('B', ["█▀▄", "█▀▄", "▀▀ "]),
+    ('C', ["█▀▀", "█  ", "▀▀▀"]),
+    ('D', ["█▀▄", "█ █", "▀▀ "]),
+    ('E', ["█▀▀", "█▀▀", "▀▀▀"]),
+    ('F', ["█▀▀", "█▀▀", "▀  "]),
+    ('G', ["█▀▀", "█ █", "▀▀▀"]),
+    ('H', ["█ █", "█▀█", "▀ ▀"]),
+    ('I', ["▀█▀", " █ ", "▀▀▀"]),
+    ('J', ["  █", "▄ █", "▀▀▀"]),
+    ('K', ["█ █", "█▀▄", "▀ ▀"]),
+    ('L', ["█  ", "█  ", "▀▀▀"]),
+    ('M', ["█▄ ▄█", "█ ▀ █", "▀   ▀"]),
+    ('N', ["█▄ █", "█ ▀█", "▀  ▀"]),
+    ('O', ["█▀█", "█ █", "▀▀▀"]),
+    ('P', ["█▀█", "█▀▀", "▀  "]),
+    ('Q', ["█▀█", "█ █", "▀▀█"]),
+    ('R', ["█▀█", "█▀▄", "▀ ▀"]),
+    ('S', ["█▀▀", "▀▀█", "▀▀▀"]),
+    ('T', ["▀█▀", " █ ", " ▀ "]),
+    ('U', ["█ █", "█ █", "▀▀▀"]),
+    ('V', ["█ █", "█ █", " ▀ "]),
+    ('W', ["█   █", "█ █ █", " ▀ ▀ "]),
+    ('X', ["█ █", "▄▀▄", "▀ ▀"]),
+    ('Y', ["█ █", "▀█▀", " ▀ "]),
+    ('Z', ["▀▀█", "▄▀ ", "▀▀▀"]),
+    ('_', ["   ", "   ", "▀▀▀"]),
+    ('(', ["▄▀", "█ ", " ▀"]),
+    (')', ["▀▄", " █", "▀ "]),
+];
+
+/// Characters of the glyphs following the ASCII ones in a
+/// FIGlet font, in the order they are defined. Some fonts
+/// omit them.
+const FIGLET_GERMAN_CHARACTERS: [char; 7] =
+    ['Ä', 'Ö', 'Ü', 'ä', 'ö', 'ü', 'ß'];
+
+/// A multi-row glyph of a single character.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct BannerGlyph {
+    rows: Vec<String>,
+    width: u16,
+}
+
+impl BannerGlyph {
+    /// Creates a glyph from provided rows. The width of the
+    /// glyph is the width of its widest row.
+    pub fn new(rows: Vec<String>) -> Self {
+        let width = rows
+            .iter()
+            .map(|row| Span::raw(row.as_str()).width() as u16)
+            .max()
+            .unwrap_or_default();
+
+        Self { rows, width }
+    }
+
+    pub fn rows(&self) -> &[String] {
+        &self.rows
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+}
+
+/// A set of multi-row glyphs [`BannerTextWidget`] displays
+/// characters with, like a FIGlet font.
+///
+/// Fonts can be built glyph by glyph, parsed from FIGlet
+/// `.flf` files, or taken from the built-in ones.
+///
+/// [`BannerTextWidget`]: crate::BannerTextWidget
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_banner_text::BannerFont;
+///
+/// let font = BannerFont::new(2)
+///     .with_glyph('o', &["┌┐", "└┘"])
+///     .with_glyph('k', &["│╱", "│╲"]);
+///
+/// assert_eq!(font.height(), 2);
+/// assert_eq!(font.glyph('k').unwrap().rows(), ["│╱", "│╲"]);
+/// assert_eq!(BannerFont::block().glyph('a').unwrap().width(), 3);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BannerFont {
+    height: u16,
+    glyphs: HashMap<char, BannerGlyph>,
+}
+
+impl BannerFont {
+    /// Creates a font without glyphs, whose glyphs are
+    /// provided number of rows high.
+    pub fn new(height: u16) -> Self {
+        Self {
+            height,
+            glyphs: HashMap::new(),
+        }
+    }
+
+    /// Returns a font of blocky glyphs three rows high, e.g.
+    /// `█▀▀` `█  ` `▀▀▀` for `C`, covering digits, Latin
+    /// letters, the space and common punctuation.
+    pub fn block() -> Self {
+        BLOCK_GLYPHS
+            .iter()
+            .fold(Self::new(3), |font, (character, rows)| {
+                font.with_glyph(*character, rows)
+            })
+    }
+
+    /// Parses a font in the FIGlet `.flf` format. Hard blanks
+    /// are replaced with spaces. Glyphs are displayed at their
+    /// full width, without FIGlet kerning or smushing.
+    pub fn from_figlet(source: &str) -> Result<Self, BannerFontError> {
+        let mut lines = source.lines().enumerate();
+
+        let (_, header) =
+            lines.next().ok_or(BannerFontError::InvalidHeader)?;
+        let parameters = header
+            .strip_prefix("flf2a")
+            .ok_or(BannerFontError::InvalidHeader)?;
+        let mut characters = parameters.chars();
+        let hard_blank =
+            characters.next().ok_or(BannerFontError::InvalidHeader)?;
+        let numbers: Vec<usize> = characters
+            .as_str()
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|_| BannerFontError::InvalidHeader)?;
+        let (height, comment_line_count) = match numbers.as_slice() {
+            [height, _, _, _, comment_line_count, ..] => {
+                (*height, *comment_line_count)
+            }
+            _ => return Err(BannerFontError::InvalidHeader),
+        };
+
+        let mut font = Self::new(height as u16);
+        let mut lines = lines.skip(comment_line_count).peekable();
+        for character in ' '..='~' {
+            let glyph =
+                read_figlet_glyph(&mut lines, character, height, hard_blank)?;
+            font.glyphs.insert(character, glyph);
+        }
+        for character in FIGLET_GERMAN_CHARACTERS {
+            if lines.peek().is_none() {
+                return Ok(font);
+            }
+            let glyph =
+                read_figlet_glyph(&mut lines, character, height, hard_blank)?;
+            font.glyphs.insert(character, glyph);
+        }
+
+        while let Some((index, code_tag)) = lines.next() {
+            if code_tag.trim().is_empty() {
+                continue;
+            }
+            let character = code_tag
+                .split_whitespace()
+                .next()
+                .and_then(parse_code_tag)
+                .ok_or(BannerFontError::InvalidCodeTag { line: index + 1 })?;
+            let glyph =
+                read_figlet_glyph(&mut lines, character, height, hard_blank)?;
+            font.glyphs.insert(character, glyph);
+        }
+
+        Ok(font)
+    }
+
+    /// Adds a glyph of provided character made of provided
+    /// rows. Missing rows are left blank and extra rows are
+    /// dropped.
+    pub fn with_glyph(mut self, character: char, rows: &[&str]) -> Self {
+        let rows = (0..self.height as usize)
+            .map(|index| rows.get(index).copied().unwrap_or_default())
+            .map(String::from)
+            .collect();
+        self.glyphs.insert(character, BannerGlyph::new(rows));
+        self
+    }
+
+    /// Returns the number of rows of the glyphs.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Returns the glyph of provided character, or of its
+    /// uppercase form if the font has no glyph for it.
+    pub fn glyph(&self, character: char) -> Option<&BannerGlyph> {
+        self.glyphs.get(&character).or_else(|| {
+            let mut uppercase = character.to_uppercase();
+            match (uppercase.next(), uppercase.next()) {
+                (Some(uppercase), None) => self.glyphs.get(&uppercase),
+                _ => None,
+            }
+        })
+    }
+}
+
+/// Reads the glyph of provided character from the next
+/// `height` lines of a FIGlet font.
+fn read_figlet_glyph<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+    character: char,
+    height: usize,
+    hard_blank: char,
+) -> Result<BannerGlyph, BannerFontError> {
+    let rows = (0..height)
+        .map(|_| {
+            lines
+                .next()
+                .map(|(_, line)| figlet_row(line, hard_blank))
+                .ok_or(BannerFontError::MissingGlyph(character))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(BannerGlyph::new(rows))
+}
+
+/// Returns provided row of a FIGlet glyph without its end
+/// marks and with hard blanks replaced with spaces.
+fn figlet_row(line: &str, hard_blank: char) -> String {
+    let line = line.trim_end();
+    let row = match line.chars().last() {
+        Some(end_mark) => line.trim_end_matches(end_mark),
+        None => line,
+    };
+    row.replace(hard_blank, " ")
+}
+
+/// Parses the character code of a code-tagged FIGlet glyph,
+/// written in decimal, hexadecimal (`0x`) or octal (`0`)
+/// notation.
+fn parse_code_tag(code_tag: &str) -> Option<char> {
+    let code = if let Some(hexadecimal) = code_tag
+        .strip_prefix("0x")
+        .or_else(|| code_tag.strip_prefix("0X"))
+    {
+        u32::from_str_radix(hexadecimal, 16).ok()?
+    } else if code_tag.len() > 1
+        && let Some(octal) = code_tag.strip_prefix('0')
+    {
+        u32::from_str_radix(octal, 8).ok()?
+    } else {
+        code_tag.parse().ok()?
+    };
+
+    char::from_u32(code)
+}
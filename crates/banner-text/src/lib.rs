@@ -0,0 +1,9 @@
+#![doc = include_str!("../README.md")]
+
+pub mod banner;
+pub mod error;
+pub mod font;
+
+pub use banner::*;
+pub use error::*;
+pub use font::*;
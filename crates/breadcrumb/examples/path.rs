@@ -0,0 +1,116 @@
+use std::{
+    io,
+    time::Duration,
+};
+
+use caponata_breadcrumb::{
+    BreadcrumbEvent,
+    BreadcrumbSegment,
+    BreadcrumbStyleBuilder,
+    BreadcrumbWidget,
+};
+use caponata_small_text::SymbolStyleBuilder;
+use crossterm::{
+    event::{
+        EnableMouseCapture,
+        Event,
+        KeyCode,
+        poll,
+        read,
+    },
+    execute,
+};
+use ratatui::{
+    DefaultTerminal,
+    layout::Rect,
+    style::{
+        Color,
+        Modifier,
+    },
+};
+
+const PATH: [&str; 5] = ["home", "projects", "caponata", "crates", "src"];
+
+pub fn main() -> io::Result<()> {
+    execute!(io::stdout(), EnableMouseCapture)?;
+
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal);
+
+    ratatui::restore();
+    result
+}
+
+fn run(terminal: &mut DefaultTerminal) -> io::Result<()> {
+    let mut depth = PATH.len();
+    let mut should_exit = false;
+    let mut widget_area = Rect::default();
+
+    while !should_exit {
+        let breadcrumb = make_breadcrumb(depth);
+
+        terminal.draw(|frame| {
+            let area = frame.area();
+            widget_area = Rect::new(area.x + 1, area.y + 1, 30, 1);
+            frame.render_widget(&breadcrumb, widget_area);
+        })?;
+
+        let timeout = Duration::from_millis(100);
+        if !poll(timeout)? {
+            continue;
+        }
+        match read()? {
+            Event::Key(key) => match key.code {
+                KeyCode::Char('q') => should_exit = true,
+                KeyCode::Char('r') => depth = PATH.len(),
+                _ => {}
+            },
+            event => {
+                if let Some(BreadcrumbEvent::SegmentClicked(index)) =
+                    breadcrumb.handle_event(event, widget_area)
+                {
+                    depth = index + 1;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn make_breadcrumb(depth: usize) -> BreadcrumbWidget<'static> {
+    let segment_style = SymbolStyleBuilder::default()
+        .with_foreground_color(Color::Blue)
+        .with_modifier(Modifier::UNDERLINED)
+        .build()
+        .unwrap();
+    let current_segment_style = SymbolStyleBuilder::default()
+        .with_foreground_color(Color::White)
+        .with_modifier(Modifier::BOLD)
+        .build()
+        .unwrap();
+    let separator_style = SymbolStyleBuilder::default()
+        .with_foreground_color(Color::DarkGray)
+        .build()
+        .unwrap();
+
+    let segments = PATH[..depth]
+        .iter()
+        .enumerate()
+        .map(|(i, text)| {
+            if i + 1 == depth {
+                BreadcrumbSegment::new(text, current_segment_style)
+            } else {
+                BreadcrumbSegment::new(text, segment_style)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let style = BreadcrumbStyleBuilder::default()
+        .with_segments(segments)
+        .with_separator_style(separator_style)
+        .with_ellipsis_style(separator_style)
+        .build()
+        .unwrap();
+    BreadcrumbWidget::new(style)
+}
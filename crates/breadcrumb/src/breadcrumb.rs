@@ -0,0 +1,378 @@
+#[cfg(feature = "crossterm")]
+use caponata_common::HandlesEvent;
+use caponata_common::{
+    AccessibleDescription,
+    Identifiable,
+    WidgetIdentity,
+    describe_widget,
+    render_adapted,
+};
+#[cfg(feature = "crossterm")]
+use crossterm::event::{
+    Event,
+    MouseButton,
+    MouseEventKind,
+};
+#[cfg(feature = "crossterm")]
+use ratatui::layout::Position;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    text::Span,
+    widgets::Widget,
+};
+
+#[cfg(feature = "crossterm")]
+use super::BreadcrumbEvent;
+use super::BreadcrumbStyle;
+
+/// An element of the line rendered by [`BreadcrumbWidget`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BreadcrumbItem {
+    /// A segment with the provided index.
+    Segment(usize),
+    Separator,
+    Ellipsis,
+}
+
+/// A widget that displays a segmented path in a single line.
+///
+/// When the area is too narrow, the segments in the middle
+/// are replaced with an ellipsis, while the first and the
+/// last segments are kept visible as long as possible.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     widgets::Widget,
+/// };
+/// use caponata_breadcrumb::{
+///     BreadcrumbSegment,
+///     BreadcrumbStyleBuilder,
+///     BreadcrumbWidget,
+/// };
+///
+/// let style = BreadcrumbStyleBuilder::default()
+///     .with_segments(vec![
+///         BreadcrumbSegment::from("usr"),
+///         BreadcrumbSegment::from("local"),
+///         BreadcrumbSegment::from("bin"),
+///     ])
+///     .with_separator("/")
+///     .build()
+///     .unwrap();
+/// let breadcrumb = BreadcrumbWidget::new(style);
+///
+/// let area = Rect::new(0, 0, 13, 1);
+/// let mut buf = Buffer::empty(area);
+/// breadcrumb.render(area, &mut buf);
+///
+/// assert_eq!(buf, Buffer::with_lines(vec!["usr/local/bin"]));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BreadcrumbWidget<'a> {
+    style: BreadcrumbStyle<'a>,
+    identity: WidgetIdentity,
+}
+
+impl<'a> Widget for &BreadcrumbWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.identity.record(area, self);
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+
+        render_adapted(area, buf, |buf| {
+            for (item, x, width) in self.layout(area) {
+                let (text, style) = self.item_text_and_style(item);
+                buf.set_stringn(x, area.y, text, width as usize, style);
+            }
+        });
+    }
+}
+
+impl<'a> BreadcrumbWidget<'a> {
+    pub fn new(style: BreadcrumbStyle<'a>) -> Self {
+        Self {
+            style,
+            identity: WidgetIdentity::default(),
+        }
+    }
+
+    /// Returns the visible items along with their real x
+    /// coordinates and widths, clipped to provided area.
+    fn layout(&self, area: Rect) -> Vec<(BreadcrumbItem, u16, u16)> {
+        let mut x = area.x;
+        let right = area.x + area.width;
+
+        let mut layout = Vec::new();
+        for item in self.visible_items(area.width) {
+            if x >= right {
+                break;
+            }
+            let width = self.item_width(item).min(right - x);
+            layout.push((item, x, width));
+            x += width;
+        }
+
+        layout
+    }
+
+    /// Returns items that should be rendered to fit into
+    /// provided width.
+    fn visible_items(&self, width: u16) -> Vec<BreadcrumbItem> {
+        let segment_count = self.style.segments.len();
+        if segment_count == 0 {
+            return Vec::new();
+        }
+
+        let all_items = self.join_segments(None, 0..segment_count);
+        if self.items_width(&all_items) <= width as usize || segment_count == 1
+        {
+            return all_items;
+        }
+
+        // Keep the first segment and as many of the last
+        // segments as possible.
+        for first_tail_index in 2..segment_count {
+            let items =
+                self.join_segments(Some(0), first_tail_index..segment_count);
+            if self.items_width(&items) <= width as usize {
+                return items;
+            }
+        }
+
+        self.join_segments(None, segment_count - 1..segment_count)
+    }
+
+    /// Joins provided head segment, an ellipsis (if some
+    /// segments are skipped) and provided tail segments with
+    /// separators.
+    fn join_segments(
+        &self,
+        head: Option<usize>,
+        tail: std::ops::Range<usize>,
+    ) -> Vec<BreadcrumbItem> {
+        let is_truncated = tail.start > head.map_or(0, |head| head + 1);
+
+        let mut items = Vec::new();
+        if let Some(head) = head {
+            items.push(BreadcrumbItem::Segment(head));
+        }
+        if is_truncated {
+            items.push(BreadcrumbItem::Ellipsis);
+        }
+        items.extend(tail.map(BreadcrumbItem::Segment));
+
+        let item_count = items.len();
+        items
+            .into_iter()
+            .enumerate()
+            .flat_map(|(i, item)| {
+                if i + 1 < item_count {
+                    vec![item, BreadcrumbItem::Separator]
+                } else {
+                    vec![item]
+                }
+            })
+            .collect()
+    }
+
+    fn items_width(&self, items: &[BreadcrumbItem]) -> usize {
+        items
+            .iter()
+            .map(|item| self.item_width(*item) as usize)
+            .sum()
+    }
+
+    fn item_width(&self, item: BreadcrumbItem) -> u16 {
+        let (text, _) = self.item_text_and_style(item);
+        Span::raw(text).width() as u16
+    }
+
+    fn item_text_and_style(&self, item: BreadcrumbItem) -> (&'a str, Style) {
+        let (text, symbol_style) = match item {
+            BreadcrumbItem::Segment(index) => {
+                let segment = self.style.segments[index];
+                (segment.text, segment.style)
+            }
+            BreadcrumbItem::Separator => {
+                (self.style.separator, self.style.separator_style)
+            }
+            BreadcrumbItem::Ellipsis => {
+                (self.style.ellipsis, self.style.ellipsis_style)
+            }
+        };
+        (text, symbol_style.into())
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl<'a> BreadcrumbWidget<'a> {
+    pub fn handle_event(
+        &self,
+        event: Event,
+        area: Rect,
+    ) -> Option<BreadcrumbEvent> {
+        let mouse_event = if let Event::Mouse(mouse_event) = event {
+            mouse_event
+        } else {
+            return None;
+        };
+        if mouse_event.kind != MouseEventKind::Down(MouseButton::Left) {
+            return None;
+        }
+
+        let position = Position::new(mouse_event.column, mouse_event.row);
+        self.segment_at(area, position)
+            .map(BreadcrumbEvent::SegmentClicked)
+    }
+
+    /// Returns the index of the segment rendered at provided
+    /// position, if any. Widget's area is calculated based on
+    /// provided area.
+    fn segment_at(&self, area: Rect, position: Position) -> Option<usize> {
+        if position.y != area.y {
+            return None;
+        }
+
+        self.layout(area)
+            .into_iter()
+            .find_map(|(item, x, width)| match item {
+                BreadcrumbItem::Segment(index)
+                    if (x..x + width).contains(&position.x) =>
+                {
+                    Some(index)
+                }
+                _ => None,
+            })
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl<'a> HandlesEvent for BreadcrumbWidget<'a> {
+    type Event = BreadcrumbEvent;
+
+    fn handle_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<BreadcrumbEvent> {
+        BreadcrumbWidget::handle_event(self, event, area)
+    }
+}
+
+impl<'a> AccessibleDescription for BreadcrumbWidget<'a> {
+    /// Describes all the segments, including the ones hidden
+    /// behind the ellipsis, separated with slashes instead of
+    /// the separator of the style.
+    fn accessible_description(&self) -> String {
+        let path = self
+            .style
+            .segments
+            .iter()
+            .map(|segment| segment.text)
+            .collect::<Vec<_>>()
+            .join(" / ");
+
+        describe_widget("breadcrumb", Some(&path), [])
+    }
+}
+
+impl<'a> Identifiable for BreadcrumbWidget<'a> {
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use caponata_common::AccessibleDescription;
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        widgets::Widget,
+    };
+
+    use super::BreadcrumbWidget;
+    use crate::{
+        BreadcrumbSegment,
+        BreadcrumbStyleBuilder,
+    };
+
+    fn breadcrumb() -> BreadcrumbWidget<'static> {
+        let style = BreadcrumbStyleBuilder::default()
+            .with_segments(vec![
+                BreadcrumbSegment::from("home"),
+                BreadcrumbSegment::from("projects"),
+                BreadcrumbSegment::from("caponata"),
+            ])
+            .build()
+            .unwrap();
+        BreadcrumbWidget::new(style)
+    }
+
+    #[test]
+    fn middle_segments_are_truncated() {
+        let breadcrumb = breadcrumb();
+
+        let area = Rect::new(0, 0, 26, 1);
+        let mut buf = Buffer::empty(area);
+        breadcrumb.render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["home ▸ projects ▸ caponata"]));
+
+        let area = Rect::new(0, 0, 8, 1);
+        let mut buf = Buffer::empty(area);
+        breadcrumb.render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["… ▸ capo"]));
+        assert_eq!(
+            breadcrumb.accessible_description(),
+            "breadcrumb 'home / projects / caponata'",
+        );
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn clicked_segment_is_reported() {
+        use crossterm::event::{
+            Event,
+            KeyModifiers,
+            MouseButton,
+            MouseEvent,
+            MouseEventKind,
+        };
+
+        use crate::BreadcrumbEvent;
+
+        let click = |column| {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row: 0,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        let breadcrumb = breadcrumb();
+        let area = Rect::new(0, 0, 20, 1);
+
+        assert_eq!(
+            breadcrumb.handle_event(click(0), area),
+            Some(BreadcrumbEvent::SegmentClicked(0)),
+        );
+        assert_eq!(breadcrumb.handle_event(click(5), area), None);
+        assert_eq!(breadcrumb.handle_event(click(7), area), None);
+        assert_eq!(
+            breadcrumb.handle_event(click(11), area),
+            Some(BreadcrumbEvent::SegmentClicked(2)),
+        );
+    }
+}
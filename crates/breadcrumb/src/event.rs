@@ -0,0 +1,8 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BreadcrumbEvent {
+    /// Triggered when a segment of a [`BreadcrumbWidget`] is
+    /// clicked with the left mouse button. The event includes
+    /// the index of the clicked segment.
+    SegmentClicked(usize),
+}
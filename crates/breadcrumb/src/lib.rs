@@ -0,0 +1,11 @@
+#![doc = include_str!("../README.md")]
+
+pub mod breadcrumb;
+#[cfg(feature = "crossterm")]
+pub mod event;
+pub mod style;
+
+pub use breadcrumb::*;
+#[cfg(feature = "crossterm")]
+pub use event::*;
+pub use style::*;
@@ -0,0 +1,86 @@
+use caponata_small_text::SymbolStyle;
+use derive_builder::Builder;
+
+/// A single segment of [`BreadcrumbWidget`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BreadcrumbSegment<'a> {
+    pub text: &'a str,
+    pub style: SymbolStyle,
+}
+
+impl<'a> BreadcrumbSegment<'a> {
+    pub fn new(text: &'a str, style: SymbolStyle) -> Self {
+        Self { text, style }
+    }
+}
+
+impl<'a> From<&'a str> for BreadcrumbSegment<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::new(value, SymbolStyle::default())
+    }
+}
+
+/// A styling configuration for [`BreadcrumbWidget`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Modifier};
+/// use caponata_small_text::SymbolStyleBuilder;
+/// use caponata_breadcrumb::{
+///     BreadcrumbSegment,
+///     BreadcrumbStyleBuilder,
+/// };
+///
+/// let current_segment_style = SymbolStyleBuilder::default()
+///     .with_foreground_color(Color::White)
+///     .with_modifier(Modifier::BOLD)
+///     .build()
+///     .unwrap();
+/// let separator_style = SymbolStyleBuilder::default()
+///     .with_foreground_color(Color::DarkGray)
+///     .build()
+///     .unwrap();
+/// let style = BreadcrumbStyleBuilder::default()
+///     .with_segments(vec![
+///         BreadcrumbSegment::from("home"),
+///         BreadcrumbSegment::new("caponata", current_segment_style),
+///     ])
+///     .with_separator(" / ")
+///     .with_separator_style(separator_style)
+///     .with_ellipsis("...")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(prefix = "with", into))]
+pub struct BreadcrumbStyle<'a> {
+    #[builder(default)]
+    pub(crate) segments: Vec<BreadcrumbSegment<'a>>,
+
+    #[builder(default = "\" ▸ \"")]
+    pub(crate) separator: &'a str,
+
+    #[builder(default)]
+    pub(crate) separator_style: SymbolStyle,
+
+    /// Text displayed instead of the segments that don't fit
+    /// into the area.
+    #[builder(default = "\"…\"")]
+    pub(crate) ellipsis: &'a str,
+
+    #[builder(default)]
+    pub(crate) ellipsis_style: SymbolStyle,
+}
+
+impl<'a> Default for BreadcrumbStyle<'a> {
+    fn default() -> Self {
+        Self {
+            segments: Vec::new(),
+            separator: " ▸ ",
+            separator_style: SymbolStyle::default(),
+            ellipsis: "…",
+            ellipsis_style: SymbolStyle::default(),
+        }
+    }
+}
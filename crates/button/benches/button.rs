@@ -0,0 +1,67 @@
+//! Rendering a button should take less than 1 ms
+//! per frame for texts of up to 1000 symbols.
+
+use std::hint::black_box;
+
+use caponata_button::{
+    ButtonStateStyleBuilder,
+    ButtonStyleBuilder,
+    ButtonThickness,
+    ButtonWidget,
+};
+use criterion::{
+    BenchmarkId,
+    Criterion,
+    criterion_group,
+    criterion_main,
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Color,
+    widgets::Widget,
+};
+
+const TEXT_LENGTHS: [usize; 3] = [10, 100, 1000];
+
+fn render(criterion: &mut Criterion) {
+    for thickness in [None, Some(ButtonThickness::HalfBlock)] {
+        let group_name = match thickness {
+            None => "thin_button_render",
+            Some(_) => "thick_button_render",
+        };
+        let mut group = criterion.benchmark_group(group_name);
+
+        for length in TEXT_LENGTHS {
+            let text = "a".repeat(length);
+            let mut normal_style_builder = ButtonStateStyleBuilder::default();
+            normal_style_builder
+                .with_text(text.as_str())
+                .with_background_color(Color::Blue);
+            if let Some(thickness) = thickness {
+                normal_style_builder.with_thickness(thickness);
+            }
+            let button_style = ButtonStyleBuilder::default()
+                .with_normal_style(normal_style_builder.build().unwrap())
+                .build()
+                .unwrap();
+            let mut button = ButtonWidget::new(button_style);
+
+            let area = Rect::new(0, 0, length as u16 + 2, 3);
+            let mut buf = Buffer::empty(area);
+            group.bench_function(
+                BenchmarkId::from_parameter(length),
+                |bencher| {
+                    bencher.iter(|| {
+                        button.render(area, &mut buf);
+                        black_box(&buf);
+                    });
+                },
+            );
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, render);
+criterion_main!(benches);
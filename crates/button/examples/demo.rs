@@ -28,7 +28,6 @@ use ratatui::{
 };
 
 use caponata_button::{
-    ButtonEvent,
     ButtonStateStyleBuilder,
     ButtonStatus,
     ButtonStyleBuilder,
@@ -92,6 +91,13 @@ fn make_button() -> ButtonWidget<'static> {
         .with_background_color(Color::Rgb(150, 0, 0))
         .with_text_modifier(Modifier::BOLD)
         .with_thickness(ButtonThickness::HalfBlock)
+        .with_spinner_style(spinner_style.clone())
+        .build()
+        .unwrap();
+    let loading_button_style = ButtonStateStyleBuilder::default()
+        .with_text("Loading...")
+        .with_text_color(Color::White)
+        .with_background_color(Color::Rgb(150, 0, 0))
         .with_spinner_style(spinner_style)
         .build()
         .unwrap();
@@ -107,6 +113,7 @@ fn make_button() -> ButtonWidget<'static> {
         .with_hovered_style(hovered_button_style)
         .with_pressed_style(pressed_button_style)
         .with_disabled_style(disabled_button_style)
+        .with_loading_style(loading_button_style)
         .build()
         .unwrap();
     ButtonWidget::new(button_style)
@@ -134,7 +141,6 @@ fn handle_event(
 ) -> io::Result<(bool, bool)> {
     let timeout = Duration::from_millis(100);
 
-    let mut button_event: Option<ButtonEvent> = None;
     let mut is_spinner_enabled = is_spinner_enabled;
     let mut should_exit = false;
 
@@ -153,6 +159,13 @@ fn handle_event(
                                 button.disable();
                             }
                         }
+                        'l' => {
+                            if button.status() == ButtonStatus::Loading {
+                                button.stop_loading();
+                            } else {
+                                button.start_loading();
+                            }
+                        }
                         's' => {
                             if is_spinner_enabled {
                                 is_spinner_enabled = false;
@@ -167,20 +180,12 @@ fn handle_event(
                 }
             }
             _ => {
-                button_event = button.on_crossterm_event(event, widget_area);
+                // The button displays the press and the release
+                // itself, so the produced events are not needed.
+                button.on_crossterm_event(event, widget_area);
             }
         };
     }
 
-    if let Some(event) = button_event {
-        if event == ButtonEvent::Clicked {
-            if button.status() == ButtonStatus::Pressed {
-                button.unpress();
-            } else {
-                button.press();
-            }
-        }
-    }
-
     Ok((should_exit, is_spinner_enabled))
 }
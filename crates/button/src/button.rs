@@ -1,5 +1,33 @@
+use std::{
+    collections::HashMap,
+    mem,
+};
+
+use caponata_common::{
+    AccessibleDescription,
+    EventQueue,
+    Identifiable,
+    Instant,
+    RenderMode,
+    SharedClock,
+    Transition,
+    TransitionState,
+    WidgetIdentity,
+    describe_widget,
+    render_adapted,
+    render_mode,
+};
+#[cfg(feature = "crossterm")]
+use caponata_common::{
+    Focusable,
+    HandlesEvent,
+};
+#[cfg(feature = "crossterm")]
 use crossterm::event::{
     Event,
+    KeyCode,
+    KeyEvent,
+    KeyEventKind,
     MouseButton,
     MouseEventKind,
 };
@@ -9,24 +37,40 @@ use ratatui::{
         Position,
         Rect,
     },
-    widgets::Widget,
+    style::{
+        Color,
+        Modifier,
+    },
+    widgets::{
+        Clear,
+        Widget,
+    },
 };
 
 use super::{
     ButtonEvent,
+    ButtonFeedback,
+    ButtonSizing,
+    ButtonStateStyle,
     ButtonStatus,
     ButtonStyle,
+    ButtonVerticalAlignment,
     SizedButton,
 };
 
+/// Duration of a single shift of [`ButtonFeedback::Shake`]
+/// in milliseconds.
+const SHAKE_PERIOD_MILLIS: u128 = 50;
+
 /// A widget that displays button that can update its state
 /// by being pressed, hovered or disabled.
 ///
 /// # Important
 ///
-/// Renders the button on the second line if the area's
-/// height is 3 or more; otherwise, renders it on the
-/// first line.
+/// The button is placed within the area according to the
+/// vertical alignment of its style. By default, it is
+/// centered, so a thin button is rendered on the second
+/// line of an area with a height of 3.
 ///
 /// # Example
 ///
@@ -37,13 +81,13 @@ use super::{
 ///     layout::{Alignment, Rect},
 ///     widgets::Widget,
 /// };
-/// use ratatui_button::{
+/// use caponata_button::{
 ///     ButtonThickness,
 ///     ButtonStateStyleBuilder,
 ///     ButtonStyleBuilder,
 ///     ButtonWidget,
 /// };
-/// use ratatui_small_spinner::{
+/// use caponata_small_spinner::{
 ///     SmallSpinnerType,
 ///     SmallSpinnerStyleBuilder,
 /// };
@@ -129,57 +173,493 @@ pub struct ButtonWidget<'a> {
     hovered_button: SizedButton<'a>,
     pressed_button: SizedButton<'a>,
     disabled_button: SizedButton<'a>,
+    loading_button: SizedButton<'a>,
+
+    /// The disabled button displayed with the color of
+    /// [`ButtonFeedback::Flash`] if the feedback is used.
+    flashed_button: Option<SizedButton<'a>>,
+
+    /// The button displayed after the first click while a
+    /// confirmation is awaited, if confirmation is used.
+    confirming_button: Option<SizedButton<'a>>,
+    confirmation_started_at: Option<Instant>,
+
+    /// When the last click was emitted, to ignore the clicks
+    /// following it within the click cooldown.
+    clicked_at: Option<Instant>,
+
+    /// Values of the placeholders in the text of the button
+    /// states, by their names.
+    vars: HashMap<String, String>,
     status: ButtonStatus,
+    report_disabled_clicks: bool,
+    feedback: ButtonFeedback,
+    feedback_started_at: Option<Instant>,
+    clock: SharedClock,
+    style: ButtonStyle<'a>,
+
+    /// Whether the left mouse button was pressed over the
+    /// button and has not been released yet. While captured,
+    /// the release is reported wherever it happens.
+    is_captured: bool,
+
+    /// Events returned by [`ButtonWidget::on_crossterm_event`]
+    /// that have not been drained yet.
+    events: EventQueue<ButtonEvent>,
+
+    /// Visibility of the button and transitions played when it
+    /// is shown or hidden.
+    transition_state: TransitionState,
+    identity: WidgetIdentity,
 }
 
 impl<'a> Widget for &mut ButtonWidget<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        match self.status {
-            ButtonStatus::Normal => self.normal_button.render(area, buf),
-            ButtonStatus::Hovered => self.hovered_button.render(area, buf),
-            ButtonStatus::Pressed => self.pressed_button.render(area, buf),
-            ButtonStatus::Disabled => self.disabled_button.render(area, buf),
+        let mut transition_state = mem::take(&mut self.transition_state);
+        render_adapted(area, buf, |buf| {
+            transition_state
+                .render(area, buf, |area, buf| self.render_content(area, buf));
+        });
+
+        for transition_event in transition_state.drain_events() {
+            self.events.push(transition_event.into());
+        }
+        self.transition_state = transition_state;
+
+        self.identity.record(area, &*self);
+    }
+}
+
+impl<'a> ButtonWidget<'a> {
+    fn render_content(&mut self, area: Rect, buf: &mut Buffer) {
+        if let Some(block) = &self.style.block {
+            block.clone().render(area, buf);
+        }
+        let area = self.sized_area(area);
+        let is_confirming = self.is_confirming();
+
+        if let Some(confirming_button) = &mut self.confirming_button
+            && is_confirming
+        {
+            confirming_button.render(area, buf);
+        } else {
+            let is_displayed_pressed =
+                self.style.pressed_during_cooldown && self.is_cooling_down();
+
+            match self.status {
+                ButtonStatus::Normal | ButtonStatus::Hovered
+                    if is_displayed_pressed =>
+                {
+                    self.pressed_button.render(area, buf)
+                }
+                ButtonStatus::Normal => self.normal_button.render(area, buf),
+                ButtonStatus::Hovered => self.hovered_button.render(area, buf),
+                ButtonStatus::Pressed => self.pressed_button.render(area, buf),
+                ButtonStatus::Disabled => self.render_disabled(area, buf),
+                ButtonStatus::Loading => self.loading_button.render(area, buf),
+            }
+        }
+        #[cfg(feature = "crossterm")]
+        if let Some(KeyCode::Char(mnemonic)) = self.style.shortcut {
+            let text_area = self.current_button().text_area(area);
+            mark_mnemonic(mnemonic, text_area, buf);
         }
+        mark_status(render_mode(), self.status, area, buf);
     }
 }
 
 impl<'a> ButtonWidget<'a> {
     pub fn new(style: ButtonStyle<'a>) -> Self {
+        let mut flashed_button = match style.disabled_click_feedback {
+            ButtonFeedback::Flash(color, _) => {
+                let mut flashed_style = style.disabled_style.clone();
+                flashed_style.background_color = Some(color);
+                Some(SizedButton::new(flashed_style))
+            }
+            _ => None,
+        };
+
+        let mut confirming_button =
+            style.confirmation_style.clone().map(SizedButton::new);
+
+        let mut normal_button = SizedButton::new(style.normal_style.clone());
+        let mut hovered_button = SizedButton::new(style.hovered_style.clone());
+        let mut pressed_button = SizedButton::new(style.pressed_style.clone());
+        let mut disabled_button =
+            SizedButton::new(style.disabled_style.clone());
+        let mut loading_button = SizedButton::new(style.loading_style.clone());
+        loading_button.enable_spinner();
+
+        if let Some(shadow_color) = style.shadow_color {
+            normal_button.set_shadow(shadow_color, false);
+            hovered_button.set_shadow(shadow_color, false);
+            pressed_button.set_shadow(shadow_color, true);
+            disabled_button.set_shadow(shadow_color, false);
+            loading_button.set_shadow(shadow_color, false);
+            if let Some(flashed_button) = &mut flashed_button {
+                flashed_button.set_shadow(shadow_color, false);
+            }
+            if let Some(confirming_button) = &mut confirming_button {
+                confirming_button.set_shadow(shadow_color, false);
+            }
+        }
+
         Self {
-            normal_button: SizedButton::new(style.normal_style),
-            hovered_button: SizedButton::new(style.hovered_style),
-            pressed_button: SizedButton::new(style.pressed_style),
-            disabled_button: SizedButton::new(style.disabled_style),
+            normal_button,
+            hovered_button,
+            pressed_button,
+            disabled_button,
+            loading_button,
+            flashed_button,
+            confirming_button,
+            confirmation_started_at: None,
+            clicked_at: None,
+            vars: HashMap::new(),
             status: ButtonStatus::Normal,
+            report_disabled_clicks: style.report_disabled_clicks,
+            feedback: style.disabled_click_feedback,
+            feedback_started_at: None,
+            clock: SharedClock::default(),
+            style,
+            is_captured: false,
+            events: EventQueue::new(),
+            transition_state: TransitionState::default(),
+            identity: WidgetIdentity::default(),
         }
     }
 
+    /// Creates a button with [`ButtonStyle::primary`] style
+    /// and provided text.
+    pub fn primary(text: &'a str) -> Self {
+        Self::new(ButtonStyle::primary(text))
+    }
+
+    /// Sets the text color of all the button states except
+    /// [`ButtonStatus::Disabled`].
+    pub fn fg(self, color: Color) -> Self {
+        self.map_enabled_styles(|state_style| {
+            state_style.text_color = Some(color);
+        })
+    }
+
+    /// Sets the background color of all the button states
+    /// except [`ButtonStatus::Disabled`].
+    pub fn bg(self, color: Color) -> Self {
+        self.map_enabled_styles(|state_style| {
+            state_style.background_color = Some(color);
+        })
+    }
+
+    /// Adds provided modifier to the text of all the button
+    /// states except [`ButtonStatus::Disabled`].
+    pub fn modifier(self, modifier: Modifier) -> Self {
+        self.map_enabled_styles(|state_style| {
+            state_style.text_modifier =
+                Some(state_style.text_modifier.unwrap_or_default() | modifier);
+        })
+    }
+
+    fn map_enabled_styles(
+        mut self,
+        f: impl Fn(&mut ButtonStateStyle<'a>),
+    ) -> Self {
+        let mut style = self.style.clone();
+        f(&mut style.normal_style);
+        f(&mut style.hovered_style);
+        f(&mut style.pressed_style);
+        f(&mut style.loading_style);
+        self.set_style(style);
+        self
+    }
+
+    /// Replaces the clock used to measure the duration of the
+    /// feedback and the press effects.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.normal_button.set_clock(clock.clone());
+        self.hovered_button.set_clock(clock.clone());
+        self.pressed_button.set_clock(clock.clone());
+        self.disabled_button.set_clock(clock.clone());
+        self.loading_button.set_clock(clock.clone());
+        if let Some(confirming_button) = &mut self.confirming_button {
+            confirming_button.set_clock(clock.clone());
+        }
+        self.transition_state.set_clock(clock.clone());
+        self.clock = clock;
+    }
+
+    /// Sets transitions played by [`ButtonWidget::show`] and
+    /// [`ButtonWidget::hide`]. The button appears and
+    /// disappears instantly if a transition is `None`.
+    pub fn set_transitions(
+        &mut self,
+        enter: Option<Transition>,
+        exit: Option<Transition>,
+    ) {
+        self.transition_state.set_transitions(enter, exit);
+    }
+
+    /// Starts rendering the button, playing its enter
+    /// transition. [`ButtonEvent::Shown`] is produced once the
+    /// transition completes.
+    pub fn show(&mut self) {
+        self.transition_state.show();
+    }
+
+    /// Plays the exit transition of the button, after which it
+    /// is no longer rendered and ignores events.
+    /// [`ButtonEvent::Hidden`] is produced once the transition
+    /// completes.
+    pub fn hide(&mut self) {
+        self.transition_state.hide();
+    }
+
+    /// Shows or hides the button instantly, without playing
+    /// transitions.
+    pub fn set_visible(&mut self, visible: bool) {
+        self.transition_state.set_visible(visible);
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.transition_state.is_visible()
+    }
+
+    /// Returns `true` if the button is being shown or hidden,
+    /// so it should be rendered on every frame.
+    pub fn is_transitioning(&self) -> bool {
+        self.transition_state.is_transitioning()
+    }
+
+    /// Plays the press effects of the button states, so the
+    /// effect of the state the button is displayed in is
+    /// visible. Called automatically when the button is
+    /// clicked.
+    pub fn play_press_effect(&mut self) {
+        self.normal_button.play_effect();
+        self.hovered_button.play_effect();
+        self.pressed_button.play_effect();
+        self.disabled_button.play_effect();
+        self.loading_button.play_effect();
+        if let Some(confirming_button) = &mut self.confirming_button {
+            confirming_button.play_effect();
+        }
+    }
+
+    /// Returns `true` if the button was clicked once and waits
+    /// for the second click confirming the action.
+    pub fn is_confirming(&self) -> bool {
+        let is_enabled = matches!(
+            self.status,
+            ButtonStatus::Normal
+                | ButtonStatus::Hovered
+                | ButtonStatus::Pressed
+        );
+
+        is_enabled
+            && self.confirmation_started_at.is_some_and(|started_at| {
+                self.clock.now().saturating_duration_since(started_at)
+                    < self.style.confirmation_timeout
+            })
+    }
+
+    /// Stops waiting for the second click, so the next click
+    /// requests the confirmation again.
+    pub fn cancel_confirmation(&mut self) {
+        self.confirmation_started_at = None;
+    }
+
+    /// Returns `true` if a click was emitted less than the click
+    /// cooldown ago, so further clicks are ignored.
+    pub fn is_cooling_down(&self) -> bool {
+        self.clicked_at.is_some_and(|clicked_at| {
+            self.clock.now().saturating_duration_since(clicked_at)
+                < self.style.click_cooldown
+        })
+    }
+
     pub fn status(&self) -> ButtonStatus {
         self.status
     }
 
-    fn contains(&self, area: Rect, position: Position) -> bool {
-        match self.status {
-            ButtonStatus::Normal => {
-                self.normal_button.contains(area, position)
-            }
-            ButtonStatus::Hovered => {
-                self.hovered_button.contains(area, position)
-            }
-            ButtonStatus::Pressed => {
-                self.pressed_button.contains(area, position)
+    /// Replaces the style of the button. The button status,
+    /// identity and interaction state, e.g. undrained events,
+    /// the cooldown, an awaited confirmation or a captured
+    /// press, are preserved, but the spinner is disabled for
+    /// all the button states except [`ButtonStatus::Loading`].
+    pub fn set_style(&mut self, style: ButtonStyle<'a>) {
+        let Self {
+            normal_button,
+            hovered_button,
+            pressed_button,
+            disabled_button,
+            loading_button,
+            flashed_button,
+            confirming_button,
+            report_disabled_clicks,
+            feedback,
+            style,
+            ..
+        } = Self::new(style);
+
+        if confirming_button.is_none() {
+            self.confirmation_started_at = None;
+        }
+        self.normal_button = normal_button;
+        self.hovered_button = hovered_button;
+        self.pressed_button = pressed_button;
+        self.disabled_button = disabled_button;
+        self.loading_button = loading_button;
+        self.flashed_button = flashed_button;
+        self.confirming_button = confirming_button;
+        self.report_disabled_clicks = report_disabled_clicks;
+        self.feedback = feedback;
+        self.style = style;
+
+        self.set_clock(self.clock.clone());
+        self.fill_templates();
+    }
+
+    /// Sets the value of the placeholder with provided name,
+    /// e.g. `count` for `"Retry ({count})"`, in the text of all
+    /// the button states. Only the caption line is updated, so
+    /// counters and timers don't require replacing the style.
+    pub fn set_var(&mut self, name: impl Into<String>, value: impl ToString) {
+        self.vars.insert(name.into(), value.to_string());
+        self.fill_templates();
+    }
+
+    /// Replaces the text of all the buttons with the text of
+    /// their styles, with placeholders replaced by their values.
+    fn fill_templates(&mut self) {
+        let style = &self.style;
+        let buttons = [
+            (&mut self.normal_button, &style.normal_style),
+            (&mut self.hovered_button, &style.hovered_style),
+            (&mut self.pressed_button, &style.pressed_style),
+            (&mut self.disabled_button, &style.disabled_style),
+            (&mut self.loading_button, &style.loading_style),
+        ];
+        for (button, state_style) in buttons {
+            button.set_text(fill_template(state_style.text, &self.vars));
+        }
+        if let Some(flashed_button) = &mut self.flashed_button {
+            let text = fill_template(style.disabled_style.text, &self.vars);
+            flashed_button.set_text(text);
+        }
+        if let (Some(confirming_button), Some(confirmation_style)) =
+            (&mut self.confirming_button, &style.confirmation_style)
+        {
+            let text = fill_template(confirmation_style.text, &self.vars);
+            confirming_button.set_text(text);
+        }
+    }
+
+    /// Returns the width the button needs in its current state,
+    /// or `None` if it fills the provided area.
+    pub fn desired_width(&self) -> Option<u16> {
+        match self.style.sizing {
+            ButtonSizing::Fill => None,
+            ButtonSizing::FitContent => {
+                let state_style = self.displayed_style();
+                let shadow_width = (state_style.thickness.is_some()
+                    && self.style.shadow_color.is_some())
+                    as u16;
+                let text = fill_template(state_style.text, &self.vars);
+                Some(
+                    state_style.content_width_with_text(&text)
+                        + self.style.horizontal_padding * 2
+                        + shadow_width,
+                )
             }
-            ButtonStatus::Disabled => {
-                self.disabled_button.contains(area, position)
+            ButtonSizing::Fixed(width) => Some(width),
+        }
+    }
+
+    /// Returns the area the button occupies within provided
+    /// area according to its block, sizing and vertical
+    /// alignment.
+    fn sized_area(&self, area: Rect) -> Rect {
+        let area = match &self.style.block {
+            Some(block) => block.inner(area),
+            None => area,
+        };
+        let width = match self.desired_width() {
+            Some(width) => width.min(area.width),
+            None => area.width,
+        };
+        let height = self.current_button().height().min(area.height);
+        let free_rows = area.height - height;
+        let y = match self.style.vertical_alignment {
+            ButtonVerticalAlignment::Top => area.y,
+            ButtonVerticalAlignment::Center => area.y + free_rows / 2,
+            ButtonVerticalAlignment::Bottom => area.y + free_rows,
+        };
+
+        Rect::new(area.x, y, width, height)
+    }
+
+    /// Returns the style of the button in its current state,
+    /// which is the confirmation style while confirming.
+    fn displayed_style(&self) -> ButtonStateStyle<'a> {
+        match &self.style.confirmation_style {
+            Some(confirmation_style) if self.is_confirming() => {
+                confirmation_style.clone()
             }
+            _ => self.style.state_style(self.status),
+        }
+    }
+
+    fn current_button(&self) -> &SizedButton<'a> {
+        if let Some(confirming_button) = &self.confirming_button
+            && self.is_confirming()
+        {
+            return confirming_button;
+        }
+        match self.status {
+            ButtonStatus::Normal => &self.normal_button,
+            ButtonStatus::Hovered => &self.hovered_button,
+            ButtonStatus::Pressed => &self.pressed_button,
+            ButtonStatus::Disabled => &self.disabled_button,
+            ButtonStatus::Loading => &self.loading_button,
+        }
+    }
+
+    /// Returns boolean flag indicating whether the button,
+    /// rendered in provided area in its current state,
+    /// occupies provided position.
+    pub fn hit_test(&self, area: Rect, position: Position) -> bool {
+        self.current_button()
+            .contains(self.sized_area(area), position)
+    }
+
+    /// Returns the position of the first cell of the button
+    /// content (the spinner, if displayed, or the text), rendered
+    /// in provided area in its current state. Buttons don't
+    /// track keyboard focus themselves, so call it for the
+    /// focused button and pass the result to
+    /// `Frame::set_cursor_position`. Returns `None` if the text
+    /// row doesn't fit into the area.
+    pub fn cursor_position(&self, area: Rect) -> Option<Position> {
+        let text_area = self.current_button().text_area(self.sized_area(area));
+        if text_area.is_empty() {
+            return None;
         }
+
+        let state_style = self.displayed_style();
+        let text = fill_template(state_style.text, &self.vars);
+        let content_width = state_style.content_width_with_text(&text);
+        let offset = text_area.width.saturating_sub(content_width) / 2;
+
+        Some(Position::new(text_area.x + offset, text_area.y))
     }
 
     /// Sets the button status to [`ButtonStatus::Pressed`] if it
-    /// is not currently disabled. Does nothing if the button is
-    /// disabled.
+    /// is not currently disabled or loading. Does nothing if the
+    /// button is disabled or loading.
     pub fn press(&mut self) {
-        if self.status != ButtonStatus::Disabled {
+        if !matches!(
+            self.status,
+            ButtonStatus::Disabled | ButtonStatus::Loading
+        ) {
             self.status = ButtonStatus::Pressed;
         }
     }
@@ -193,12 +673,52 @@ impl<'a> ButtonWidget<'a> {
         }
     }
 
+    /// Sets the button status to [`ButtonStatus::Hovered`] if it
+    /// is currently normal. Does nothing if the button is
+    /// pressed or disabled.
+    pub fn hover(&mut self) {
+        if self.status == ButtonStatus::Normal {
+            self.status = ButtonStatus::Hovered;
+        }
+    }
+
+    /// Resets the button status to [`ButtonStatus::Normal`] if it
+    /// is currently hovered. Does nothing if the button is not
+    /// hovered.
+    pub fn unhover(&mut self) {
+        if self.status == ButtonStatus::Hovered {
+            self.status = ButtonStatus::Normal;
+        }
+    }
+
+    /// Sets the button status to [`ButtonStatus::Loading`] if it
+    /// is not currently disabled. Does nothing if the button is
+    /// disabled.
+    pub fn start_loading(&mut self) {
+        if self.status != ButtonStatus::Disabled {
+            self.status = ButtonStatus::Loading;
+            self.is_captured = false;
+            self.confirmation_started_at = None;
+        }
+    }
+
+    /// Resets the button status to [`ButtonStatus::Normal`] if it
+    /// is currently loading. Does nothing if the button is not
+    /// loading.
+    pub fn stop_loading(&mut self) {
+        if self.status == ButtonStatus::Loading {
+            self.status = ButtonStatus::Normal;
+        }
+    }
+
     /// Sets the button status to [`ButtonStatus::Disabled`] if it
     /// is not currently disabled. Does nothing if the button is
     /// disabled.
     pub fn disable(&mut self) {
         if self.status != ButtonStatus::Disabled {
-            self.status = ButtonStatus::Disabled
+            self.status = ButtonStatus::Disabled;
+            self.is_captured = false;
+            self.confirmation_started_at = None;
         }
     }
 
@@ -223,7 +743,8 @@ impl<'a> ButtonWidget<'a> {
 
     /// Disables spinner if the button supports spinner; otherwise
     /// does nothing. Spinner will be disabled for all the button
-    /// states.
+    /// states except [`ButtonStatus::Loading`], which always
+    /// displays its spinner.
     pub fn disable_spinner(&mut self) {
         self.normal_button.disable_spinner();
         self.hovered_button.disable_spinner();
@@ -231,11 +752,84 @@ impl<'a> ButtonWidget<'a> {
         self.disabled_button.disable_spinner();
     }
 
+    /// Returns the events returned by
+    /// [`ButtonWidget::on_crossterm_event`] since they were last
+    /// drained, from the oldest to the most recent one. Useful
+    /// when events are handled once per frame rather than as
+    /// they arrive.
+    pub fn drain_events(&mut self) -> impl Iterator<Item = ButtonEvent> {
+        self.events.drain()
+    }
+
+    /// Renders the disabled button, playing the feedback if
+    /// it has not ended yet.
+    fn render_disabled(&mut self, area: Rect, buf: &mut Buffer) {
+        let elapsed = self
+            .feedback_started_at
+            .map(|started_at| {
+                self.clock.now().saturating_duration_since(started_at)
+            })
+            .filter(|elapsed| *elapsed < self.feedback.duration());
+        let Some(elapsed) = elapsed else {
+            self.feedback_started_at = None;
+            self.disabled_button.render(area, buf);
+            return;
+        };
+
+        match (self.feedback, self.flashed_button.as_mut()) {
+            (ButtonFeedback::Flash(_, _), Some(flashed_button)) => {
+                flashed_button.render(area, buf);
+            }
+            (ButtonFeedback::Shake(_), _) => {
+                // The button is shifted right on every other
+                // period of the shake.
+                let is_shifted = (elapsed.as_millis() / SHAKE_PERIOD_MILLIS)
+                    .is_multiple_of(2);
+                let area = area.intersection(buf.area);
+                Clear.render(area, buf);
+
+                let shifted_area = if is_shifted && area.width > 1 {
+                    Rect::new(area.x + 1, area.y, area.width - 1, area.height)
+                } else {
+                    area
+                };
+                self.disabled_button.render(shifted_area, buf);
+            }
+            _ => self.disabled_button.render(area, buf),
+        }
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl<'a> ButtonWidget<'a> {
+    /// Handles provided crossterm event, returning the produced
+    /// event and keeping it for [`ButtonWidget::drain_events`].
     pub fn on_crossterm_event(
         &mut self,
         event: Event,
         widget_area: Rect,
     ) -> Option<ButtonEvent> {
+        let button_event = self.handle_crossterm_event(event, widget_area);
+        if let Some(button_event) = button_event {
+            self.events.push(button_event);
+        }
+
+        button_event
+    }
+
+    fn handle_crossterm_event(
+        &mut self,
+        event: Event,
+        widget_area: Rect,
+    ) -> Option<ButtonEvent> {
+        if self.status == ButtonStatus::Loading || !self.is_visible() {
+            return None;
+        }
+
+        if let Event::Key(key_event) = event {
+            return self.on_key(key_event);
+        }
+
         if let Event::Mouse(mouse_event) = event {
             let mouse_position = Position {
                 x: mouse_event.column,
@@ -247,6 +841,14 @@ impl<'a> ButtonWidget<'a> {
                     mouse_button,
                     widget_area,
                 ),
+                MouseEventKind::Up(mouse_button) => {
+                    self.on_mouse_up(mouse_position, mouse_button, widget_area)
+                }
+                MouseEventKind::Drag(mouse_button) => self.on_mouse_dragged(
+                    mouse_position,
+                    mouse_button,
+                    widget_area,
+                ),
                 MouseEventKind::Moved => {
                     self.on_mouse_moved(mouse_position, widget_area)
                 }
@@ -257,19 +859,132 @@ impl<'a> ButtonWidget<'a> {
         }
     }
 
+    /// Captures the mouse and presses the button, so the click
+    /// is reported once the left mouse button is released over
+    /// it. Clicks of the disabled button are reported at once.
     fn on_mouse_down(
-        &self,
+        &mut self,
         mouse_position: Position,
         mouse_button: MouseButton,
         widget_area: Rect,
     ) -> Option<ButtonEvent> {
-        if mouse_button == MouseButton::Left
-            && self.status != ButtonStatus::Disabled
-            && self.contains(widget_area, mouse_position)
+        if mouse_button != MouseButton::Left
+            || !self.hit_test(widget_area, mouse_position)
         {
-            Some(ButtonEvent::Clicked)
+            return None;
+        }
+        if self.status == ButtonStatus::Disabled {
+            return self.click();
+        }
+        if !self.is_cooling_down() {
+            self.is_captured = true;
+            self.press();
+        }
+
+        None
+    }
+
+    /// Clicks the button if provided key is its shortcut.
+    fn on_key(&mut self, key_event: KeyEvent) -> Option<ButtonEvent> {
+        if !self.is_shortcut(key_event.code)
+            || key_event.kind == KeyEventKind::Release
+        {
+            return None;
+        }
+
+        self.click()
+    }
+
+    /// Returns boolean flag indicating whether provided key is
+    /// the shortcut of the button. Letters are compared
+    /// case-insensitively.
+    fn is_shortcut(&self, key_code: KeyCode) -> bool {
+        match (self.style.shortcut, key_code) {
+            (Some(KeyCode::Char(shortcut)), KeyCode::Char(key)) => {
+                shortcut.eq_ignore_ascii_case(&key)
+            }
+            (shortcut, key) => shortcut == Some(key),
+        }
+    }
+
+    /// Plays the press effect and returns
+    /// [`ButtonEvent::Clicked`], or requests the confirmation if
+    /// it is used and not awaited yet. Plays the feedback if the
+    /// button is disabled, and ignores the click if the button
+    /// is cooling down.
+    fn click(&mut self) -> Option<ButtonEvent> {
+        if self.status != ButtonStatus::Disabled {
+            if self.is_cooling_down() {
+                return None;
+            }
+            self.clicked_at = Some(self.clock.now());
+            self.play_press_effect();
+            if self.confirming_button.is_none() || self.is_confirming() {
+                self.confirmation_started_at = None;
+                return Some(ButtonEvent::Clicked);
+            }
+            self.confirmation_started_at = Some(self.clock.now());
+            return Some(ButtonEvent::ConfirmationRequested);
+        }
+
+        if self.feedback != ButtonFeedback::None {
+            self.feedback_started_at = Some(self.clock.now());
+        }
+        self.report_disabled_clicks
+            .then_some(ButtonEvent::ClickedWhileDisabled)
+    }
+
+    /// Releases the capture started by pressing the left mouse
+    /// button over the button, updating the hover status
+    /// according to the position of the release. A release
+    /// over the button clicks it, so [`ButtonEvent::ReleasedInside`]
+    /// is kept for [`ButtonWidget::drain_events`] and the result
+    /// of the click is returned.
+    fn on_mouse_up(
+        &mut self,
+        mouse_position: Position,
+        mouse_button: MouseButton,
+        widget_area: Rect,
+    ) -> Option<ButtonEvent> {
+        if mouse_button != MouseButton::Left || !self.is_captured {
+            return None;
+        }
+        self.is_captured = false;
+        self.unpress();
+
+        if self.hit_test(widget_area, mouse_position) {
+            self.hover();
+            self.events.push(ButtonEvent::ReleasedInside);
+            self.click()
         } else {
-            None
+            self.unhover();
+            Some(ButtonEvent::ReleasedOutside)
+        }
+    }
+
+    /// Tracks the cursor while the mouse is captured, so the
+    /// button looks pressed only while the cursor is over it.
+    fn on_mouse_dragged(
+        &mut self,
+        mouse_position: Position,
+        mouse_button: MouseButton,
+        widget_area: Rect,
+    ) -> Option<ButtonEvent> {
+        if mouse_button != MouseButton::Left || !self.is_captured {
+            return None;
+        }
+
+        match (self.status, self.hit_test(widget_area, mouse_position)) {
+            (ButtonStatus::Pressed, false) => {
+                self.unpress();
+                Some(ButtonEvent::Unhovered)
+            }
+            (ButtonStatus::Pressed, true) => Some(ButtonEvent::Hovered(true)),
+            (_, true) => {
+                self.press();
+                Some(ButtonEvent::Hovered(false))
+            }
+            (_, false) => None,
         }
     }
 
@@ -278,7 +993,7 @@ impl<'a> ButtonWidget<'a> {
         mouse_position: Position,
         widget_area: Rect,
     ) -> Option<ButtonEvent> {
-        match (self.status, self.contains(widget_area, mouse_position)) {
+        match (self.status, self.hit_test(widget_area, mouse_position)) {
             (ButtonStatus::Hovered, false) => {
                 self.status = ButtonStatus::Normal;
                 Some(ButtonEvent::Unhovered)
@@ -293,3 +1008,997 @@ impl<'a> ButtonWidget<'a> {
         }
     }
 }
+
+#[cfg(feature = "crossterm")]
+impl<'a> HandlesEvent for ButtonWidget<'a> {
+    type Event = ButtonEvent;
+
+    fn handle_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<ButtonEvent> {
+        self.on_crossterm_event(event, area)
+    }
+}
+
+/// A focused button is displayed as hovered and is clicked
+/// with `Enter`, `Space` or its shortcut. The produced events
+/// are kept for [`ButtonWidget::drain_events`].
+#[cfg(feature = "crossterm")]
+impl<'a> Focusable for ButtonWidget<'a> {
+    fn set_focused(&mut self, is_focused: bool) {
+        match is_focused {
+            true => self.hover(),
+            false => self.unhover(),
+        }
+    }
+
+    fn handle_key_event(&mut self, event: KeyEvent) -> bool {
+        let is_activation =
+            matches!(event.code, KeyCode::Enter | KeyCode::Char(' '))
+                || self.is_shortcut(event.code);
+        if !is_activation
+            || event.kind == KeyEventKind::Release
+            || self.status == ButtonStatus::Loading
+            || !self.is_visible()
+        {
+            return false;
+        }
+
+        if let Some(button_event) = self.click() {
+            self.events.push(button_event);
+        }
+        true
+    }
+
+    fn cursor_position(&self, area: Rect) -> Option<Position> {
+        ButtonWidget::cursor_position(self, area)
+    }
+}
+
+impl<'a> AccessibleDescription for ButtonWidget<'a> {
+    fn accessible_description(&self) -> String {
+        let (state_style, status) = match &self.style.confirmation_style {
+            Some(confirmation_style) if self.is_confirming() => {
+                (confirmation_style, "awaiting confirmation")
+            }
+            _ => match self.status {
+                ButtonStatus::Normal => (&self.style.normal_style, ""),
+                ButtonStatus::Hovered => {
+                    (&self.style.hovered_style, "hovered")
+                }
+                ButtonStatus::Pressed => {
+                    (&self.style.pressed_style, "pressed")
+                }
+                ButtonStatus::Disabled => {
+                    (&self.style.disabled_style, "disabled")
+                }
+                ButtonStatus::Loading => {
+                    (&self.style.loading_style, "loading")
+                }
+            },
+        };
+        let text = fill_template(state_style.text, &self.vars);
+        let visibility = if self.is_visible() { "" } else { "hidden" };
+
+        describe_widget("button", Some(&text), [status, visibility])
+    }
+}
+
+impl<'a> Identifiable for ButtonWidget<'a> {
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+/// Returns provided text with each `{name}` placeholder
+/// replaced by the value of `name`. Placeholders without a
+/// value are kept as they are.
+fn fill_template(template: &str, vars: &HashMap<String, String>) -> String {
+    let mut text = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        text.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let value = rest
+            .find('}')
+            .and_then(|end| Some((vars.get(&rest[1..end])?, end)));
+        match value {
+            Some((value, end)) => {
+                text.push_str(value);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                text.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    text.push_str(rest);
+
+    text
+}
+
+/// Underlines the first occurrence of provided character,
+/// ignoring case, within the text row of the button.
+#[cfg(feature = "crossterm")]
+fn mark_mnemonic(mnemonic: char, text_area: Rect, buf: &mut Buffer) {
+    let mnemonic = mnemonic.to_lowercase().to_string();
+    let position = text_area
+        .intersection(buf.area)
+        .positions()
+        .find(|position| buf[*position].symbol().to_lowercase() == mnemonic);
+
+    if let Some(position) = position {
+        buf[position].modifier.insert(Modifier::UNDERLINED);
+    }
+}
+
+/// Marks provided status of the button with modifiers when
+/// colors can't be relied on to tell the statuses apart.
+fn mark_status(
+    mode: RenderMode,
+    status: ButtonStatus,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let modifier = match status {
+        ButtonStatus::Hovered => Modifier::UNDERLINED,
+        ButtonStatus::Pressed => Modifier::UNDERLINED | Modifier::BOLD,
+        ButtonStatus::Disabled => Modifier::DIM,
+        ButtonStatus::Normal | ButtonStatus::Loading => return,
+    };
+    if mode == RenderMode::Normal {
+        return;
+    }
+
+    for position in area.intersection(buf.area).positions() {
+        buf[position].modifier.insert(modifier);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "crossterm")]
+    use std::time::Duration;
+
+    use caponata_common::{
+        AccessibleDescription,
+        Identifiable,
+        RenderMode,
+        WidgetRegistry,
+    };
+    #[cfg(feature = "crossterm")]
+    use caponata_common::{
+        AnimationGroup,
+        Focusable,
+        Transition,
+        TransitionKind,
+    };
+    use caponata_small_spinner::SmallSpinnerStyle;
+    #[cfg(feature = "crossterm")]
+    use crossterm::event::{
+        Event,
+        KeyCode,
+        KeyEvent,
+        KeyModifiers,
+        MouseButton,
+        MouseEvent,
+        MouseEventKind,
+    };
+    use ratatui::{
+        buffer::Buffer,
+        layout::{
+            Position,
+            Rect,
+        },
+        style::{
+            Color,
+            Modifier,
+            Style,
+        },
+        widgets::{
+            Block,
+            Widget,
+        },
+    };
+
+    use super::{
+        ButtonWidget,
+        mark_status,
+    };
+    #[cfg(feature = "crossterm")]
+    use crate::{
+        ButtonEffect,
+        ButtonEvent,
+        ButtonFeedback,
+    };
+    use crate::{
+        ButtonSizing,
+        ButtonStateStyleBuilder,
+        ButtonStatus,
+        ButtonStyle,
+        ButtonStyleBuilder,
+        ButtonThickness,
+        ButtonVerticalAlignment,
+    };
+
+    #[cfg(feature = "crossterm")]
+    fn release(column: u16, row: u16) -> Event {
+        Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Up(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[cfg(feature = "crossterm")]
+    fn press() -> Event {
+        Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 0,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[cfg(feature = "crossterm")]
+    fn drag(column: u16, row: u16) -> Event {
+        Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Drag(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    /// Presses and releases the left mouse button over the top
+    /// left corner of provided area, returning the event of the
+    /// release. The button is unhovered afterwards, as if the
+    /// cursor left it.
+    #[cfg(feature = "crossterm")]
+    fn click(button: &mut ButtonWidget, area: Rect) -> Option<ButtonEvent> {
+        button.on_crossterm_event(press(), area);
+        let event = button.on_crossterm_event(release(area.x, area.y), area);
+        button.unhover();
+
+        event
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn disabled_button_flashes_on_click() {
+        let disabled_style = ButtonStateStyleBuilder::default()
+            .with_text("Save")
+            .with_background_color(Color::DarkGray)
+            .build()
+            .unwrap();
+        let button_style = ButtonStyleBuilder::default()
+            .with_disabled_style(disabled_style)
+            .with_report_disabled_clicks(true)
+            .with_disabled_click_feedback(ButtonFeedback::Flash(
+                Color::Red,
+                Duration::from_millis(100),
+            ))
+            .build()
+            .unwrap();
+        let group = AnimationGroup::new();
+        let mut button = ButtonWidget::new(button_style);
+        button.set_clock(group.clock());
+        button.disable();
+
+        let area = Rect::new(0, 0, 6, 1);
+        let event = button.on_crossterm_event(press(), area);
+        assert_eq!(event, Some(ButtonEvent::ClickedWhileDisabled));
+
+        let mut buf = Buffer::empty(area);
+        button.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].bg, Color::Red);
+
+        group.advance(Duration::from_millis(100));
+        button.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].bg, Color::DarkGray);
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn shortcut_clicks_and_underlines_mnemonic() {
+        let normal_style = ButtonStateStyleBuilder::default()
+            .with_text("Save")
+            .build()
+            .unwrap();
+        let button_style = ButtonStyleBuilder::default()
+            .with_normal_style(normal_style)
+            .with_shortcut(KeyCode::Char('a'))
+            .build()
+            .unwrap();
+        let mut button = ButtonWidget::new(button_style);
+
+        let area = Rect::new(0, 0, 6, 1);
+        let mut buf = Buffer::empty(area);
+        button.render(area, &mut buf);
+        assert_eq!(buf[(2, 0)].symbol(), "a");
+        assert!(buf[(2, 0)].modifier.contains(Modifier::UNDERLINED));
+        assert!(!buf[(1, 0)].modifier.contains(Modifier::UNDERLINED));
+
+        let key = |code| Event::Key(KeyEvent::from(code));
+        assert_eq!(
+            button.on_crossterm_event(key(KeyCode::Char('A')), area),
+            Some(ButtonEvent::Clicked),
+        );
+        assert_eq!(
+            button.on_crossterm_event(key(KeyCode::Char('s')), area),
+            None,
+        );
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn focused_button_is_clicked_with_keys() {
+        let button_style = ButtonStyleBuilder::default()
+            .with_shortcut(KeyCode::Char('s'))
+            .build()
+            .unwrap();
+        let mut button = ButtonWidget::new(button_style);
+
+        button.set_focused(true);
+        assert_eq!(button.status(), ButtonStatus::Hovered);
+        assert!(button.handle_key_event(KeyEvent::from(KeyCode::Enter)));
+        assert!(!button.handle_key_event(KeyEvent::from(KeyCode::Esc)));
+        assert_eq!(
+            button.drain_events().collect::<Vec<_>>(),
+            [ButtonEvent::Clicked]
+        );
+
+        button.set_focused(false);
+        assert_eq!(button.status(), ButtonStatus::Normal);
+        assert!(button.handle_key_event(KeyEvent::from(KeyCode::Char('S'))));
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn second_click_within_timeout_is_confirmed() {
+        let normal_style = ButtonStateStyleBuilder::default()
+            .with_text("Delete")
+            .build()
+            .unwrap();
+        let confirmation_style = ButtonStateStyleBuilder::default()
+            .with_text("Sure?")
+            .with_background_color(Color::Red)
+            .build()
+            .unwrap();
+        let button_style = ButtonStyleBuilder::default()
+            .with_normal_style(normal_style)
+            .with_confirmation_style(confirmation_style)
+            .with_confirmation_timeout(Duration::from_secs(1))
+            .build()
+            .unwrap();
+        let group = AnimationGroup::new();
+        let mut button = ButtonWidget::new(button_style);
+        button.set_clock(group.clock());
+
+        let area = Rect::new(0, 0, 8, 1);
+        assert_eq!(
+            click(&mut button, area),
+            Some(ButtonEvent::ConfirmationRequested),
+        );
+        assert!(button.is_confirming());
+
+        let mut buf = Buffer::empty(area);
+        button.render(area, &mut buf);
+        assert_eq!(buf[(1, 0)].symbol(), "S");
+        assert_eq!(buf[(1, 0)].bg, Color::Red);
+
+        assert_eq!(click(&mut button, area), Some(ButtonEvent::Clicked),);
+        assert!(!button.is_confirming());
+
+        click(&mut button, area);
+        group.advance(Duration::from_secs(1));
+        assert!(!button.is_confirming());
+        button.render(area, &mut buf);
+        assert_eq!(buf[(1, 0)].symbol(), "D");
+        assert_eq!(
+            click(&mut button, area),
+            Some(ButtonEvent::ConfirmationRequested),
+        );
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn clicks_are_ignored_during_cooldown() {
+        let normal_style = ButtonStateStyleBuilder::default()
+            .with_text("Send")
+            .with_background_color(Color::Blue)
+            .build()
+            .unwrap();
+        let pressed_style = ButtonStateStyleBuilder::default()
+            .with_text("Send")
+            .with_background_color(Color::Gray)
+            .build()
+            .unwrap();
+        let button_style = ButtonStyleBuilder::default()
+            .with_normal_style(normal_style)
+            .with_pressed_style(pressed_style)
+            .with_click_cooldown(Duration::from_millis(500))
+            .with_pressed_during_cooldown(true)
+            .build()
+            .unwrap();
+        let group = AnimationGroup::new();
+        let mut button = ButtonWidget::new(button_style);
+        button.set_clock(group.clock());
+
+        let area = Rect::new(0, 0, 6, 1);
+        assert_eq!(click(&mut button, area), Some(ButtonEvent::Clicked),);
+        assert_eq!(button.on_crossterm_event(press(), area), None);
+
+        let mut buf = Buffer::empty(area);
+        button.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].bg, Color::Gray);
+
+        group.advance(Duration::from_millis(500));
+        button.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].bg, Color::Blue);
+        assert_eq!(click(&mut button, area), Some(ButtonEvent::Clicked),);
+    }
+
+    #[test]
+    fn placeholders_are_replaced_with_vars() {
+        let normal_style = ButtonStateStyleBuilder::default()
+            .with_text("Retry ({count}) {unknown}")
+            .build()
+            .unwrap();
+        let button_style = ButtonStyleBuilder::default()
+            .with_normal_style(normal_style)
+            .with_sizing(ButtonSizing::FitContent)
+            .build()
+            .unwrap();
+        let mut button = ButtonWidget::new(button_style);
+        button.set_var("count", 3);
+        assert_eq!(button.desired_width(), Some(19));
+
+        let area = Rect::new(0, 0, 19, 1);
+        let mut buf = Buffer::empty(area);
+        button.render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["Retry (3) {unknown}"]));
+
+        button.set_var("count", 12);
+        button.render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["Retry (12) {unknown"]));
+    }
+
+    #[test]
+    fn description_includes_text_and_status() {
+        let loading_style = ButtonStateStyleBuilder::default()
+            .with_text("Saving {name}")
+            .build()
+            .unwrap();
+        let button_style = ButtonStyleBuilder::default()
+            .with_normal_style(
+                ButtonStateStyleBuilder::default()
+                    .with_text("Save {name}")
+                    .build()
+                    .unwrap(),
+            )
+            .with_loading_style(loading_style)
+            .build()
+            .unwrap();
+        let mut button = ButtonWidget::new(button_style);
+        button.set_var("name", "draft");
+        assert_eq!(button.accessible_description(), "button 'Save draft'");
+
+        button.start_loading();
+        assert_eq!(
+            button.accessible_description(),
+            "button 'Saving draft', loading",
+        );
+    }
+
+    #[test]
+    fn unset_background_is_transparent() {
+        let normal_style = ButtonStateStyleBuilder::default()
+            .with_text("Ok")
+            .with_text_color(Color::White)
+            .build()
+            .unwrap();
+        let button_style = ButtonStyleBuilder::default()
+            .with_normal_style(normal_style)
+            .build()
+            .unwrap();
+        let mut button = ButtonWidget::new(button_style);
+
+        let area = Rect::new(0, 0, 4, 1);
+        let mut buf = Buffer::empty(area);
+        buf.set_style(area, Style::default().bg(Color::Blue));
+        button.render(area, &mut buf);
+
+        assert_eq!(buf[(1, 0)].symbol(), "O");
+        assert_eq!(buf[(1, 0)].fg, Color::White);
+        assert_eq!(buf[(1, 0)].bg, Color::Blue);
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn press_effect_reverts() {
+        let normal_style = ButtonStateStyleBuilder::default()
+            .with_text("Ok")
+            .with_background_color(Color::Blue)
+            .with_press_effect(ButtonEffect::Flash(
+                Color::White,
+                Duration::from_millis(100),
+            ))
+            .build()
+            .unwrap();
+        let button_style = ButtonStyleBuilder::default()
+            .with_normal_style(normal_style)
+            .build()
+            .unwrap();
+        let group = AnimationGroup::new();
+        let mut button = ButtonWidget::new(button_style);
+        button.set_clock(group.clock());
+
+        let area = Rect::new(0, 0, 4, 1);
+        let event = click(&mut button, area);
+        assert_eq!(event, Some(ButtonEvent::Clicked));
+
+        let mut buf = Buffer::empty(area);
+        button.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].bg, Color::White);
+        assert_eq!(buf[(1, 0)].symbol(), "O");
+
+        group.advance(Duration::from_millis(100));
+        button.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].bg, Color::Blue);
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn loading_button_ignores_input() {
+        let loading_style = ButtonStateStyleBuilder::default()
+            .with_text("Saving")
+            .with_spinner_style(SmallSpinnerStyle::default())
+            .build()
+            .unwrap();
+        let button_style = ButtonStyleBuilder::default()
+            .with_loading_style(loading_style)
+            .build()
+            .unwrap();
+        let mut button = ButtonWidget::new(button_style);
+
+        button.start_loading();
+        assert_eq!(button.status(), ButtonStatus::Loading);
+        button.press();
+        assert_eq!(button.status(), ButtonStatus::Loading);
+
+        let area = Rect::new(0, 0, 10, 1);
+        assert_eq!(button.on_crossterm_event(press(), area), None);
+
+        let mut buf = Buffer::empty(area);
+        button.render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec![" ⠘ Saving "]));
+
+        button.stop_loading();
+        assert_eq!(button.status(), ButtonStatus::Normal);
+        assert_eq!(click(&mut button, area), Some(ButtonEvent::Clicked),);
+    }
+
+    #[test]
+    fn thick_button_with_subtitle() {
+        let normal_style = ButtonStateStyleBuilder::default()
+            .with_text("Deploy")
+            .with_subtitle("to staging")
+            .with_thickness(ButtonThickness::HalfBlock)
+            .build()
+            .unwrap();
+        let button_style = ButtonStyleBuilder::default()
+            .with_normal_style(normal_style)
+            .build()
+            .unwrap();
+        let mut button = ButtonWidget::new(button_style);
+
+        let area = Rect::new(0, 0, 12, 5);
+        let mut buf = Buffer::empty(area);
+        button.render(area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec![
+                "▄▄▄▄▄▄▄▄▄▄▄▄",
+                "   Deploy   ",
+                " to staging ",
+                "▀▀▀▀▀▀▀▀▀▀▀▀",
+                "            ",
+            ])
+        );
+
+        assert!(button.hit_test(area, Position::new(0, 3)));
+        assert!(!button.hit_test(area, Position::new(0, 4)));
+    }
+
+    #[test]
+    fn button_fits_content() {
+        let normal_style = ButtonStateStyleBuilder::default()
+            .with_text("Ok")
+            .with_background_color(Color::Blue)
+            .build()
+            .unwrap();
+        let loading_style = ButtonStateStyleBuilder::default()
+            .with_text("Wait")
+            .with_spinner_style(SmallSpinnerStyle::default())
+            .build()
+            .unwrap();
+        let button_style = ButtonStyleBuilder::default()
+            .with_normal_style(normal_style)
+            .with_loading_style(loading_style)
+            .with_sizing(ButtonSizing::FitContent)
+            .with_horizontal_padding(1u16)
+            .build()
+            .unwrap();
+        let mut button = ButtonWidget::new(button_style);
+        assert_eq!(button.desired_width(), Some(4));
+
+        let area = Rect::new(0, 0, 8, 1);
+        let mut buf = Buffer::empty(area);
+        button.render(area, &mut buf);
+        assert_eq!(buf[(3, 0)].bg, Color::Blue);
+        assert_eq!(buf[(4, 0)].bg, Color::Reset);
+        assert!(!button.hit_test(area, Position::new(4, 0)));
+
+        button.start_loading();
+        assert_eq!(button.desired_width(), Some(8));
+    }
+
+    #[test]
+    fn bottom_aligned_button() {
+        let normal_style = ButtonStateStyleBuilder::default()
+            .with_text("Ok")
+            .build()
+            .unwrap();
+        let button_style = ButtonStyleBuilder::default()
+            .with_normal_style(normal_style)
+            .with_vertical_alignment(ButtonVerticalAlignment::Bottom)
+            .build()
+            .unwrap();
+        let mut button = ButtonWidget::new(button_style);
+
+        let area = Rect::new(0, 0, 4, 5);
+        let mut buf = Buffer::empty(area);
+        button.render(area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec!["    ", "    ", "    ", "    ", " Ok "])
+        );
+
+        assert!(button.hit_test(area, Position::new(0, 4)));
+        assert!(!button.hit_test(area, Position::new(0, 1)));
+    }
+
+    #[test]
+    fn primary_button_with_custom_text_color() {
+        let mut button = ButtonWidget::primary("Save").fg(Color::Yellow);
+
+        let area = Rect::new(0, 0, 8, 1);
+        let mut buf = Buffer::empty(area);
+        button.render(area, &mut buf);
+        assert_eq!(buf, {
+            let mut expected_buf = Buffer::with_lines(vec!["  Save  "]);
+            expected_buf.set_style(
+                area,
+                Style::default()
+                    .fg(Color::Yellow)
+                    .bg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            );
+            expected_buf
+        });
+
+        button.disable();
+        button.render(area, &mut buf);
+        assert_eq!(buf[(2, 0)].fg, Color::Gray);
+        assert_eq!(buf[(2, 0)].bg, Color::DarkGray);
+    }
+
+    #[test]
+    fn button_inside_block() {
+        let normal_style = ButtonStateStyleBuilder::default()
+            .with_text("Ok")
+            .build()
+            .unwrap();
+        let button_style = ButtonStyleBuilder::default()
+            .with_normal_style(normal_style)
+            .with_block(Block::bordered())
+            .build()
+            .unwrap();
+        let mut button = ButtonWidget::new(button_style);
+
+        let area = Rect::new(0, 0, 6, 3);
+        let mut buf = Buffer::empty(area);
+        button.render(area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec!["┌────┐", "│ Ok │", "└────┘"])
+        );
+
+        assert!(button.hit_test(area, Position::new(1, 1)));
+        assert!(!button.hit_test(area, Position::new(0, 1)));
+    }
+
+    #[test]
+    fn cursor_position_points_to_text() {
+        let normal_style = ButtonStateStyleBuilder::default()
+            .with_text("Ok")
+            .with_thickness(ButtonThickness::HalfBlock)
+            .build()
+            .unwrap();
+        let button_style = ButtonStyleBuilder::default()
+            .with_normal_style(normal_style)
+            .with_vertical_alignment(ButtonVerticalAlignment::Top)
+            .build()
+            .unwrap();
+        let button = ButtonWidget::new(button_style);
+
+        assert_eq!(
+            button.cursor_position(Rect::new(2, 1, 8, 5)),
+            Some(Position::new(5, 2)),
+        );
+        assert_eq!(button.cursor_position(Rect::new(2, 1, 8, 1)), None);
+    }
+
+    #[test]
+    fn thick_button_casts_shadow() {
+        let normal_style = ButtonStateStyleBuilder::default()
+            .with_text("Go")
+            .with_background_color(Color::Blue)
+            .with_thickness(ButtonThickness::HalfBlock)
+            .build()
+            .unwrap();
+        let button_style = ButtonStyleBuilder::default()
+            .with_normal_style(normal_style.clone())
+            .with_pressed_style(normal_style)
+            .with_shadow_color(Color::DarkGray)
+            .with_sizing(ButtonSizing::FitContent)
+            .with_horizontal_padding(1u16)
+            .build()
+            .unwrap();
+        let mut button = ButtonWidget::new(button_style);
+        assert_eq!(button.desired_width(), Some(5));
+
+        let area = Rect::new(0, 0, 5, 3);
+        let mut buf = Buffer::empty(area);
+        button.render(area, &mut buf);
+        let symbols: Vec<&str> =
+            buf.content.iter().map(|cell| cell.symbol()).collect();
+        assert_eq!(symbols.concat(), "▄▄▄▄  Go █▀▀▀▀█");
+        assert_eq!(buf[(4, 1)].fg, Color::DarkGray);
+        assert_eq!(buf[(0, 2)].bg, Color::Reset);
+        assert_eq!(buf[(1, 2)].bg, Color::DarkGray);
+
+        button.press();
+        let mut buf = Buffer::empty(area);
+        button.render(area, &mut buf);
+        let symbols: Vec<&str> =
+            buf.content.iter().map(|cell| cell.symbol()).collect();
+        assert_eq!(symbols.concat(), " ▄▄▄▄  Go  ▀▀▀▀");
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn release_outside_cancels_click() {
+        let mut button = ButtonWidget::new(ButtonStyle::default());
+        let area = Rect::new(0, 0, 4, 1);
+
+        assert_eq!(button.on_crossterm_event(press(), area), None);
+        assert_eq!(button.status(), ButtonStatus::Pressed);
+        assert_eq!(
+            button.on_crossterm_event(release(5, 0), area),
+            Some(ButtonEvent::ReleasedOutside),
+        );
+        assert_eq!(button.status(), ButtonStatus::Normal);
+        assert_eq!(button.on_crossterm_event(release(5, 0), area), None);
+
+        button.on_crossterm_event(press(), area);
+        assert_eq!(
+            button.on_crossterm_event(release(1, 0), area),
+            Some(ButtonEvent::Clicked),
+        );
+        assert_eq!(button.status(), ButtonStatus::Hovered);
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn captured_press_follows_cursor() {
+        let mut button = ButtonWidget::new(ButtonStyle::default());
+        let area = Rect::new(0, 0, 4, 1);
+
+        button.on_crossterm_event(press(), area);
+        assert_eq!(
+            button.on_crossterm_event(drag(5, 0), area),
+            Some(ButtonEvent::Unhovered),
+        );
+        assert_eq!(button.status(), ButtonStatus::Normal);
+        assert_eq!(
+            button.on_crossterm_event(drag(2, 0), area),
+            Some(ButtonEvent::Hovered(false)),
+        );
+        assert_eq!(button.status(), ButtonStatus::Pressed);
+        assert_eq!(
+            button.on_crossterm_event(release(2, 0), area),
+            Some(ButtonEvent::Clicked),
+        );
+
+        // Drags without a captured press are ignored.
+        assert_eq!(button.on_crossterm_event(drag(2, 0), area), None);
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn events_are_drained_in_order() {
+        let mut button = ButtonWidget::new(ButtonStyle::default());
+        let area = Rect::new(0, 0, 4, 1);
+
+        click(&mut button, area);
+        button.on_crossterm_event(press(), area);
+        button.on_crossterm_event(release(5, 0), area);
+
+        let events: Vec<ButtonEvent> = button.drain_events().collect();
+        assert_eq!(
+            events,
+            vec![
+                ButtonEvent::ReleasedInside,
+                ButtonEvent::Clicked,
+                ButtonEvent::ReleasedOutside,
+            ],
+        );
+        assert_eq!(button.drain_events().next(), None);
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn hidden_button_is_not_rendered() {
+        let group = AnimationGroup::new();
+        let mut button = ButtonWidget::new(ButtonStyle::default());
+        button.set_clock(group.clock());
+        button.set_transitions(
+            None,
+            Some(Transition::new(
+                TransitionKind::Fade(Color::Black),
+                Duration::from_millis(100),
+            )),
+        );
+        let area = Rect::new(0, 0, 4, 1);
+
+        button.hide();
+        button.render(area, &mut Buffer::empty(area));
+        assert!(button.is_transitioning());
+
+        group.advance(Duration::from_millis(100));
+        let mut buf = Buffer::empty(area);
+        button.render(area, &mut buf);
+        assert_eq!(buf, Buffer::empty(area));
+        assert_eq!(button.on_crossterm_event(press(), area), None);
+
+        button.show();
+        assert_eq!(
+            button.drain_events().collect::<Vec<_>>(),
+            vec![ButtonEvent::Hidden],
+        );
+        assert!(button.is_visible());
+    }
+
+    #[test]
+    fn status_is_marked_without_colors() {
+        let area = Rect::new(0, 0, 2, 1);
+        for (mode, status, expected) in [
+            (RenderMode::Normal, ButtonStatus::Hovered, Modifier::empty()),
+            (
+                RenderMode::Monochrome,
+                ButtonStatus::Normal,
+                Modifier::empty(),
+            ),
+            (
+                RenderMode::Monochrome,
+                ButtonStatus::Hovered,
+                Modifier::UNDERLINED,
+            ),
+            (
+                RenderMode::HighContrast,
+                ButtonStatus::Pressed,
+                Modifier::UNDERLINED | Modifier::BOLD,
+            ),
+            (
+                RenderMode::HighContrast,
+                ButtonStatus::Disabled,
+                Modifier::DIM,
+            ),
+        ] {
+            let mut buf = Buffer::empty(area);
+            mark_status(mode, status, area, &mut buf);
+            assert_eq!(buf[(1, 0)].modifier, expected);
+        }
+    }
+
+    #[test]
+    fn identity_is_preserved_when_style_is_replaced() {
+        let registry = WidgetRegistry::new();
+        let mut button = ButtonWidget::primary("Save");
+        button.set_id("save");
+        button.set_registry(registry.clone());
+        let mut button = button.fg(Color::Red);
+        button.disable();
+
+        let area = Rect::new(1, 1, 8, 1);
+        button.render(area, &mut Buffer::empty(Rect::new(0, 0, 10, 3)));
+        assert_eq!(button.id(), Some("save"));
+        assert_eq!(registry.ids(), ["save"]);
+        assert_eq!(registry.area("save"), Some(area));
+        assert_eq!(
+            registry.snapshot("save").as_deref(),
+            Some("button 'Save', disabled"),
+        );
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn interaction_state_is_kept_when_style_is_replaced() {
+        let normal_style = ButtonStateStyleBuilder::default()
+            .with_text("Delete")
+            .build()
+            .unwrap();
+        let confirmation_style = ButtonStateStyleBuilder::default()
+            .with_text("Sure?")
+            .build()
+            .unwrap();
+        let button_style = ButtonStyleBuilder::default()
+            .with_normal_style(normal_style)
+            .with_confirmation_style(confirmation_style)
+            .with_confirmation_timeout(Duration::from_secs(1))
+            .with_click_cooldown(Duration::from_millis(500))
+            .build()
+            .unwrap();
+        let group = AnimationGroup::new();
+        let mut button = ButtonWidget::new(button_style);
+        button.set_clock(group.clock());
+
+        let area = Rect::new(0, 0, 8, 1);
+        click(&mut button, area);
+        let mut button = button.bg(Color::Green);
+        assert!(button.is_confirming());
+        assert!(button.is_cooling_down());
+
+        group.advance(Duration::from_millis(500));
+        click(&mut button, area);
+        let mut button = button.fg(Color::White);
+        assert!(button.is_cooling_down());
+        assert_eq!(
+            button.drain_events().collect::<Vec<_>>(),
+            [
+                ButtonEvent::ReleasedInside,
+                ButtonEvent::ConfirmationRequested,
+                ButtonEvent::ReleasedInside,
+                ButtonEvent::Clicked,
+            ],
+        );
+
+        let mut buf = Buffer::empty(area);
+        button.render(area, &mut buf);
+        assert_eq!(buf[(1, 0)].fg, Color::White);
+        assert_eq!(buf[(1, 0)].bg, Color::Green);
+
+        group.advance(Duration::from_millis(500));
+        assert!(!button.is_cooling_down());
+    }
+
+    #[test]
+    fn is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<ButtonWidget>();
+        assert_send_sync::<ButtonStyle>();
+    }
+}
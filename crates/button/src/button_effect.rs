@@ -0,0 +1,25 @@
+use std::time::Duration;
+
+use ratatui::style::{
+    Color,
+    Modifier,
+};
+
+/// A brief visual effect played by a state of a
+/// [`ButtonWidget`] when the button is clicked. The button
+/// reverts to its style once the effect ends.
+///
+/// Default variant is [`ButtonEffect::None`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ButtonEffect {
+    #[default]
+    None,
+
+    /// The background of the button's text line is displayed
+    /// with provided color for provided duration.
+    Flash(Color, Duration),
+
+    /// Provided modifier is added to the button's text line
+    /// for provided duration.
+    Pulse(Modifier, Duration),
+}
@@ -1,10 +1,36 @@
+use caponata_common::{
+    TransitionEvent,
+    WidgetEvent,
+};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub enum ButtonEvent {
-    /// Triggered when a [`ButtonWidget`] is clicked
-    /// with the left mouse button.
+    /// Triggered when the left mouse button, pressed over a
+    /// [`ButtonWidget`], is released over it, or when the
+    /// shortcut of the button is pressed.
     Clicked,
 
+    /// Triggered when a disabled [`ButtonWidget`] is clicked
+    /// with the left mouse button. Only emitted if the
+    /// button style reports disabled clicks.
+    ClickedWhileDisabled,
+
+    /// Triggered when a [`ButtonWidget`] with a confirmation
+    /// style is clicked for the first time. The click is only
+    /// reported as [`ButtonEvent::Clicked`] if it is repeated
+    /// within the confirmation timeout.
+    ConfirmationRequested,
+
+    /// Triggered when the left mouse button, pressed over a
+    /// [`ButtonWidget`], is released over it.
+    ReleasedInside,
+
+    /// Triggered when the left mouse button, pressed over a
+    /// [`ButtonWidget`], is released outside of it, so the
+    /// click is cancelled.
+    ReleasedOutside,
+
     /// Triggered when the mouse cursor enters the area
     /// of a [`ButtonWidget`]. The event includes a
     /// boolean flag indicating whether the widget was
@@ -14,4 +40,40 @@ pub enum ButtonEvent {
     /// Triggered when the mouse cursor leaves the area
     /// of a [`ButtonWidget`] that was previously hovered.
     Unhovered,
+
+    /// Triggered when the enter transition of a [`ButtonWidget`]
+    /// completes after it was shown.
+    Shown,
+
+    /// Triggered when the exit transition of a [`ButtonWidget`]
+    /// completes after it was hidden, so it is no longer
+    /// rendered.
+    Hidden,
+}
+
+impl From<TransitionEvent> for ButtonEvent {
+    fn from(value: TransitionEvent) -> Self {
+        match value {
+            TransitionEvent::Shown => Self::Shown,
+            TransitionEvent::Hidden => Self::Hidden,
+        }
+    }
+}
+
+impl From<ButtonEvent> for WidgetEvent {
+    fn from(value: ButtonEvent) -> Self {
+        match value {
+            ButtonEvent::Clicked => WidgetEvent::Clicked,
+            ButtonEvent::ClickedWhileDisabled => {
+                WidgetEvent::ClickedWhileDisabled
+            }
+            ButtonEvent::ConfirmationRequested => WidgetEvent::Pressed,
+            ButtonEvent::ReleasedInside => WidgetEvent::Released,
+            ButtonEvent::ReleasedOutside => WidgetEvent::ReleasedOutside,
+            ButtonEvent::Hovered(_) => WidgetEvent::Hovered,
+            ButtonEvent::Unhovered => WidgetEvent::Unhovered,
+            ButtonEvent::Shown => WidgetEvent::Shown,
+            ButtonEvent::Hidden => WidgetEvent::Hidden,
+        }
+    }
 }
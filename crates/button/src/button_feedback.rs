@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use ratatui::style::Color;
+
+/// Visual feedback played by a [`ButtonWidget`] when it is
+/// clicked while disabled.
+///
+/// Default variant is [`ButtonFeedback::None`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ButtonFeedback {
+    #[default]
+    None,
+
+    /// The background of the button is displayed with
+    /// provided color for provided duration.
+    Flash(Color, Duration),
+
+    /// The button is shifted back and forth by one cell for
+    /// provided duration.
+    Shake(Duration),
+}
+
+impl ButtonFeedback {
+    pub fn duration(&self) -> Duration {
+        match self {
+            Self::None => Duration::ZERO,
+            Self::Flash(_, duration) | Self::Shake(duration) => *duration,
+        }
+    }
+}
@@ -1,3 +1,14 @@
+use caponata_common::SharedClock;
+use caponata_small_spinner::SmallSpinnerStyle;
+use caponata_small_text::{
+    Animation,
+    AnimationAdvanceMode,
+    AnimationRepeatMode,
+    AnimationStepBuilder,
+    AnimationStyle,
+    AnimationTarget,
+    Symbol,
+};
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
@@ -7,23 +18,24 @@ use ratatui::{
     },
     widgets::Widget,
 };
-use ratatui_small_spinner::SmallSpinnerStyle;
 
 use super::{
     LoadingLine,
     PlainLine,
 };
 use crate::{
+    ButtonEffect,
     ThickButtonStyle,
     ThinButtonStyle,
 };
 
 pub(crate) struct ButtonLineStyle<'a> {
     pub text: &'a str,
-    pub text_color: Color,
-    pub background_color: Color,
+    pub text_color: Option<Color>,
+    pub background_color: Option<Color>,
     pub text_modifier: Option<Modifier>,
     pub spinner_style: Option<SmallSpinnerStyle>,
+    pub press_effect: ButtonEffect,
 }
 
 impl<'a> From<ThickButtonStyle<'a>> for ButtonLineStyle<'a> {
@@ -34,6 +46,7 @@ impl<'a> From<ThickButtonStyle<'a>> for ButtonLineStyle<'a> {
             background_color: value.background_color,
             text_modifier: value.text_modifier,
             spinner_style: value.spinner_style,
+            press_effect: value.press_effect,
         }
     }
 }
@@ -46,45 +59,80 @@ impl<'a> From<ThinButtonStyle<'a>> for ButtonLineStyle<'a> {
             background_color: value.background_color,
             text_modifier: value.text_modifier,
             spinner_style: value.spinner_style,
+            press_effect: value.press_effect,
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub(crate) enum ButtonLine<'a> {
+enum ButtonLineContent<'a> {
     Plain(PlainLine<'a>),
-    Loading(LoadingLine<'a>),
+    Loading(Box<LoadingLine<'a>>),
 }
 
-impl<'a> Default for ButtonLine<'a> {
+impl<'a> Default for ButtonLineContent<'a> {
     fn default() -> Self {
         Self::Plain(PlainLine::default())
     }
 }
 
+/// A single-line widget that displays the text of a button
+/// and plays its press effect.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct ButtonLine<'a> {
+    content: ButtonLineContent<'a>,
+    press_effect: ButtonEffect,
+
+    /// Whether the press effect should start on the next
+    /// render, once the symbols of the line are known.
+    is_effect_requested: bool,
+    effect_animation: Option<Animation>,
+    clock: SharedClock,
+}
+
 impl<'a> Widget for &mut ButtonLine<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        match self {
-            ButtonLine::Plain(line) => line.render(area, buf),
-            ButtonLine::Loading(line) => line.render(area, buf),
+        match &mut self.content {
+            ButtonLineContent::Plain(line) => line.render(area, buf),
+            ButtonLineContent::Loading(line) => line.render(area, buf),
         };
+
+        let area = area.intersection(buf.area);
+        if !area.is_empty() {
+            self.render_effect(area, buf);
+        }
     }
 }
 
 impl<'a> ButtonLine<'a> {
     pub fn new(style: impl Into<ButtonLineStyle<'a>>) -> Self {
         let style = style.into();
+        let press_effect = style.press_effect;
+
+        let content = match style.spinner_style {
+            Some(_) => {
+                ButtonLineContent::Loading(Box::new(LoadingLine::new(style)))
+            }
+            None => ButtonLineContent::Plain(PlainLine::new(style)),
+        };
 
-        match style.spinner_style {
-            Some(_) => ButtonLine::Loading(LoadingLine::new(style)),
-            None => ButtonLine::Plain(PlainLine::new(style)),
+        Self {
+            content,
+            press_effect,
+            is_effect_requested: false,
+            effect_animation: None,
+            clock: SharedClock::default(),
         }
     }
 
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+    }
+
     /// Enables spinner if the line supports spinner; otherwise
     /// does nothing.
     pub fn enable_spinner(&mut self) {
-        if let ButtonLine::Loading(line) = self {
+        if let ButtonLineContent::Loading(line) = &mut self.content {
             line.enable_spinner();
         }
     }
@@ -92,8 +140,134 @@ impl<'a> ButtonLine<'a> {
     /// Disables spinner if the line supports spinner; otherwise
     /// does nothing.
     pub fn disable_spinner(&mut self) {
-        if let ButtonLine::Loading(line) = self {
+        if let ButtonLineContent::Loading(line) = &mut self.content {
             line.disable_spinner();
         }
     }
+
+    /// Replaces the text of the line, keeping its style and
+    /// spinner.
+    pub fn set_text(&mut self, text: String) {
+        match &mut self.content {
+            ButtonLineContent::Plain(line) => line.set_text(text),
+            ButtonLineContent::Loading(line) => line.set_text(text),
+        }
+    }
+
+    /// Starts the press effect on the next render, restarting
+    /// it if it is already playing. Does nothing if the line
+    /// has no press effect.
+    pub fn play_effect(&mut self) {
+        if self.press_effect != ButtonEffect::None {
+            self.is_effect_requested = true;
+        }
+    }
+
+    /// Applies the styles of the current frame of the press
+    /// effect to the rendered line.
+    fn render_effect(&mut self, area: Rect, buf: &mut Buffer) {
+        if self.is_effect_requested {
+            self.is_effect_requested = false;
+
+            let symbols = (0..area.width)
+                .map(|x| {
+                    let cell = &buf[(area.x + x, area.y)];
+                    let symbol = Symbol {
+                        value: cell.symbol().chars().next().unwrap_or(' '),
+                        foreground_color: Some(cell.fg),
+                        background_color: Some(cell.bg),
+                        modifier: cell.modifier,
+                    };
+                    (x, symbol)
+                })
+                .collect();
+            let animation_style =
+                effect_animation_style(self.press_effect, area.width);
+            self.effect_animation = animation_style.map(|animation_style| {
+                let mut animation = Animation::new(animation_style, symbols);
+                animation.set_clock(self.clock.clone());
+                animation
+            });
+        }
+
+        let Some(animation) = self.effect_animation.as_mut() else {
+            return;
+        };
+        let Some(frame) = animation.next_frame() else {
+            self.effect_animation = None;
+            return;
+        };
+
+        for (x, symbol) in frame.symbols {
+            if x >= area.width {
+                continue;
+            }
+            let cell = &mut buf[(area.x + x, area.y)];
+            if let Some(foreground_color) = symbol.foreground_color {
+                cell.set_fg(foreground_color);
+            }
+            if let Some(background_color) = symbol.background_color {
+                cell.set_bg(background_color);
+            }
+            cell.modifier = symbol.modifier;
+        }
+    }
+}
+
+/// Returns the line displaying provided subtitle of a
+/// button, if any.
+pub(crate) fn subtitle_line<'a>(
+    subtitle: Option<&'a str>,
+    subtitle_color: Option<Color>,
+    background_color: Option<Color>,
+    subtitle_modifier: Option<Modifier>,
+) -> Option<PlainLine<'a>> {
+    let style = ButtonLineStyle {
+        text: subtitle?,
+        text_color: subtitle_color,
+        background_color,
+        text_modifier: subtitle_modifier,
+        spinner_style: None,
+        press_effect: ButtonEffect::None,
+    };
+
+    Some(PlainLine::new(style))
+}
+
+/// Returns a single-step animation style playing provided
+/// effect on a line with provided width, or `None` if there is
+/// nothing to play, e.g. the effect has zero duration.
+fn effect_animation_style(
+    effect: ButtonEffect,
+    width: u16,
+) -> Option<AnimationStyle> {
+    let last_x = width.checked_sub(1)?;
+    let target = AnimationTarget::Range(0, last_x);
+
+    let step = match effect {
+        ButtonEffect::None => return None,
+        ButtonEffect::Flash(color, duration) => {
+            AnimationStepBuilder::default()
+                .with_duration(duration)
+                .for_target(target)
+                .update_background_color(color)
+                .then()
+                .build()
+        }
+        ButtonEffect::Pulse(modifier, duration) => {
+            AnimationStepBuilder::default()
+                .with_duration(duration)
+                .for_target(target)
+                .add_modifier(modifier)
+                .then()
+                .build()
+        }
+    };
+
+    AnimationStyle::new(
+        AnimationRepeatMode::Finite(1),
+        AnimationAdvanceMode::Auto,
+        vec![step.ok()?],
+    )
+    .ok()
 }
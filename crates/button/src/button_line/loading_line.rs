@@ -1,3 +1,9 @@
+use std::borrow::Cow;
+
+use caponata_small_spinner::{
+    SmallSpinnerStyle,
+    SmallSpinnerWidget,
+};
 use ratatui::{
     buffer::Buffer,
     layout::{
@@ -7,23 +13,20 @@ use ratatui::{
     style::{
         Color,
         Modifier,
+        Style,
         Stylize,
     },
     text::Line,
     widgets::Widget,
 };
-use ratatui_small_spinner::{
-    SmallSpinnerStyle,
-    SmallSpinnerWidget,
-};
 
 use super::ButtonLineStyle;
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub(crate) struct LoadingLineStyle<'a> {
-    text: &'a str,
-    text_color: Color,
-    background_color: Color,
+    text: Cow<'a, str>,
+    text_color: Option<Color>,
+    background_color: Option<Color>,
     spinner_style: SmallSpinnerStyle,
     text_modifier: Option<Modifier>,
 }
@@ -31,7 +34,7 @@ pub(crate) struct LoadingLineStyle<'a> {
 impl<'a> From<ButtonLineStyle<'a>> for LoadingLineStyle<'a> {
     fn from(value: ButtonLineStyle<'a>) -> Self {
         Self {
-            text: value.text,
+            text: value.text.into(),
             text_color: value.text_color,
             background_color: value.background_color,
             spinner_style: value.spinner_style.unwrap(),
@@ -58,19 +61,26 @@ impl<'a> Widget for &mut LoadingLine<'a> {
 
         // Clear cells used to render the line in case
         // the line was previously rendered with the
-        // different text.
+        // different text. Colors are left untouched, so
+        // unset colors don't overwrite the buffer.
         for x in area.x..area.x + area.width as u16 {
-            buf[(x, area.y)].reset();
+            let cell = &mut buf[(x, area.y)];
+            cell.set_symbol(" ");
+            cell.modifier = Modifier::empty();
         }
 
-        let line_text = if self.is_spinner_enabled {
+        let line_text: &str = if self.is_spinner_enabled {
             &format!("  {}", self.style.text)
         } else {
-            self.style.text
+            &self.style.text
+        };
+        let line_style = Style {
+            fg: self.style.text_color,
+            bg: self.style.background_color,
+            ..Style::default()
         };
         let mut line = Line::from(line_text)
-            .fg(self.style.text_color)
-            .bg(self.style.background_color)
+            .style(line_style)
             .alignment(Alignment::Center);
 
         line = match self.style.text_modifier {
@@ -90,7 +100,7 @@ impl<'a> Widget for &mut LoadingLine<'a> {
 impl<'a> LoadingLine<'a> {
     pub fn new(style: impl Into<LoadingLineStyle<'a>>) -> Self {
         let style = style.into();
-        let spinner = SmallSpinnerWidget::new(style.spinner_style);
+        let spinner = SmallSpinnerWidget::new(style.spinner_style.clone());
 
         Self {
             spinner,
@@ -107,6 +117,11 @@ impl<'a> LoadingLine<'a> {
         self.is_spinner_enabled = false;
     }
 
+    /// Replaces the text displayed after the spinner.
+    pub fn set_text(&mut self, text: String) {
+        self.style.text = text.into();
+    }
+
     fn render_spinner(
         &mut self,
         widget_area: Rect,
@@ -7,9 +7,13 @@ use ratatui::{
     style::{
         Color,
         Modifier,
+        Style,
         Stylize,
     },
-    text::Line,
+    text::{
+        Line,
+        Span,
+    },
     widgets::Widget,
 };
 
@@ -17,8 +21,8 @@ use super::ButtonLineStyle;
 
 pub(crate) struct PlainLineStyle<'a> {
     text: &'a str,
-    text_color: Color,
-    background_color: Color,
+    text_color: Option<Color>,
+    background_color: Option<Color>,
     text_modifier: Option<Modifier>,
 }
 
@@ -47,9 +51,12 @@ impl<'a> Widget for &PlainLine<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         // Clear cells used to render the line in case
         // the line was previously rendered with the
-        // different text.
+        // different text. Colors are left untouched, so
+        // unset colors don't overwrite the buffer.
         for x in area.x..area.x + area.width as u16 {
-            buf[(x, area.y)].reset();
+            let cell = &mut buf[(x, area.y)];
+            cell.set_symbol(" ");
+            cell.modifier = Modifier::empty();
         }
         self.line.clone().render(area, buf);
     }
@@ -59,9 +66,13 @@ impl<'a> PlainLine<'a> {
     pub fn new(style: impl Into<PlainLineStyle<'a>>) -> Self {
         let style = style.into();
 
+        let line_style = Style {
+            fg: style.text_color,
+            bg: style.background_color,
+            ..Style::default()
+        };
         let mut line = Line::from(style.text)
-            .fg(style.text_color)
-            .bg(style.background_color)
+            .style(line_style)
             .alignment(Alignment::Center);
 
         line = match style.text_modifier {
@@ -71,4 +82,9 @@ impl<'a> PlainLine<'a> {
 
         Self { line }
     }
+
+    /// Replaces the text of the line, keeping its style.
+    pub fn set_text(&mut self, text: String) {
+        self.line.spans = vec![Span::raw(text)];
+    }
 }
@@ -0,0 +1,18 @@
+/// Specifies how the width of a [`ButtonWidget`] is
+/// determined.
+///
+/// Default variant is [`ButtonSizing::Fill`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ButtonSizing {
+    /// The button fills the width of the provided area.
+    #[default]
+    Fill,
+
+    /// The button is as wide as the caption of its current
+    /// state, including the spinner and the horizontal
+    /// padding.
+    FitContent,
+
+    /// The button has provided width.
+    Fixed(u16),
+}
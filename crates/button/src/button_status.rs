@@ -5,4 +5,8 @@ pub enum ButtonStatus {
     Hovered,
     Pressed,
     Disabled,
+
+    /// The button is waiting for an action to complete and
+    /// ignores all input.
+    Loading,
 }
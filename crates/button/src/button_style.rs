@@ -1,11 +1,30 @@
+use std::time::Duration;
+
+use caponata_small_spinner::{
+    SmallSpinnerStyle,
+    SmallSpinnerStyleBuilder,
+    SmallSpinnerType,
+};
+#[cfg(feature = "crossterm")]
+use crossterm::event::KeyCode;
 use derive_builder::Builder;
-use ratatui::style::{
-    Color,
-    Modifier,
+use ratatui::{
+    style::{
+        Color,
+        Modifier,
+    },
+    text::Span,
+    widgets::Block,
 };
-use ratatui_small_spinner::SmallSpinnerStyle;
 
-use super::ButtonThickness;
+use super::{
+    ButtonEffect,
+    ButtonFeedback,
+    ButtonSizing,
+    ButtonStatus,
+    ButtonThickness,
+    ButtonVerticalAlignment,
+};
 
 /// Styling configuration for a [`ButtonWidget`].
 ///
@@ -13,7 +32,7 @@ use super::ButtonThickness;
 ///
 /// ```rust
 /// use ratatui::layout::Alignment;
-/// use ratatui_button::{ButtonStateStyle, ButtonStyleBuilder};
+/// use caponata_button::{ButtonStateStyle, ButtonStyleBuilder};
 ///
 /// let button_style = ButtonStyleBuilder::default()
 ///     .with_normal_style(ButtonStateStyle::default())
@@ -23,7 +42,7 @@ use super::ButtonThickness;
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Builder)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Builder)]
 #[builder(setter(prefix = "with", into))]
 pub struct ButtonStyle<'a> {
     /// Style applied when used when a [`ButtonWidget`]
@@ -48,6 +67,150 @@ pub struct ButtonStyle<'a> {
     /// 'pressed' and 'hovered'.
     #[builder(default)]
     pub(crate) disabled_style: ButtonStateStyle<'a>,
+
+    /// Style applied when a [`ButtonWidget`] is loading. The
+    /// spinner of this style is always enabled. This state
+    /// has higher priority than all the others.
+    #[builder(default)]
+    pub(crate) loading_style: ButtonStateStyle<'a>,
+
+    /// Whether a [`ButtonWidget`] emits
+    /// [`ButtonEvent::ClickedWhileDisabled`] when it is
+    /// clicked while disabled.
+    #[builder(default)]
+    pub(crate) report_disabled_clicks: bool,
+
+    /// Feedback played when a [`ButtonWidget`] is clicked
+    /// while disabled.
+    #[builder(default)]
+    pub(crate) disabled_click_feedback: ButtonFeedback,
+
+    /// Specifies how the width of a [`ButtonWidget`] is
+    /// determined. A button narrower than the provided area
+    /// is rendered at its left edge.
+    #[builder(default)]
+    pub(crate) sizing: ButtonSizing,
+
+    /// Number of cells added on both sides of the caption
+    /// when the width fits the content.
+    #[builder(default)]
+    pub(crate) horizontal_padding: u16,
+
+    /// Vertical position of a [`ButtonWidget`] within an area
+    /// taller than the button.
+    #[builder(default)]
+    pub(crate) vertical_alignment: ButtonVerticalAlignment,
+
+    /// Color of the drop shadow cast by thick buttons. The
+    /// shadow occupies the rightmost column of the button, and
+    /// the button shifts into it while pressed. Thin buttons
+    /// don't cast a shadow.
+    #[builder(default, setter(strip_option))]
+    pub(crate) shadow_color: Option<Color>,
+
+    /// Block rendered around the button. The button is sized
+    /// and aligned within the inner area of the block.
+    #[builder(default, setter(strip_option))]
+    pub(crate) block: Option<Block<'a>>,
+
+    /// Key clicking a [`ButtonWidget`] regardless of the mouse
+    /// position. If it is a character, its first occurrence in
+    /// the text, ignoring case, is underlined as a mnemonic.
+    #[cfg(feature = "crossterm")]
+    #[builder(default, setter(strip_option))]
+    pub(crate) shortcut: Option<KeyCode>,
+
+    /// Style displayed after the first click of a
+    /// [`ButtonWidget`], e.g. with "Are you sure?" text. If it
+    /// is set, the first click emits
+    /// [`ButtonEvent::ConfirmationRequested`], and only a second
+    /// click within the confirmation timeout emits
+    /// [`ButtonEvent::Clicked`].
+    #[builder(default, setter(strip_option))]
+    pub(crate) confirmation_style: Option<ButtonStateStyle<'a>>,
+
+    /// Duration the confirmation style is displayed for after
+    /// the first click, before the button resets.
+    #[builder(default = "Duration::from_secs(3)")]
+    pub(crate) confirmation_timeout: Duration,
+
+    /// Duration after an emitted click during which further
+    /// clicks of a [`ButtonWidget`] are ignored, e.g. to prevent
+    /// double submits.
+    #[builder(default)]
+    pub(crate) click_cooldown: Duration,
+
+    /// Whether a [`ButtonWidget`] is displayed with the pressed
+    /// style while its click cooldown lasts.
+    #[builder(default)]
+    pub(crate) pressed_during_cooldown: bool,
+}
+
+impl<'a> ButtonStyle<'a> {
+    /// Returns the style of a button with provided text
+    /// meant for the main action of a view: bold white text
+    /// on blue background, lighter when hovered and reversed
+    /// when pressed.
+    pub fn primary(text: &'a str) -> Self {
+        let normal_style = ButtonStateStyle {
+            text,
+            text_color: Some(Color::White),
+            background_color: Some(Color::Blue),
+            text_modifier: Some(Modifier::BOLD),
+            ..ButtonStateStyle::default()
+        };
+
+        Self {
+            normal_style: normal_style.clone(),
+            hovered_style: ButtonStateStyle {
+                background_color: Some(Color::LightBlue),
+                ..normal_style.clone()
+            },
+            pressed_style: ButtonStateStyle {
+                text_modifier: Some(Modifier::BOLD | Modifier::REVERSED),
+                ..normal_style.clone()
+            },
+            disabled_style: ButtonStateStyle {
+                text,
+                text_color: Some(Color::Gray),
+                background_color: Some(Color::DarkGray),
+                ..ButtonStateStyle::default()
+            },
+            loading_style: ButtonStateStyle {
+                spinner_style: SmallSpinnerStyleBuilder::default()
+                    .with_interval(
+                        SmallSpinnerType::default().recommended_interval(),
+                    )
+                    .with_foreground_color(Color::White)
+                    .with_background_color(Color::Blue)
+                    .build()
+                    .ok(),
+                ..normal_style
+            },
+            horizontal_padding: 2,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the style of provided button state.
+    pub fn state_style(&self, status: ButtonStatus) -> ButtonStateStyle<'a> {
+        match status {
+            ButtonStatus::Normal => self.normal_style.clone(),
+            ButtonStatus::Hovered => self.hovered_style.clone(),
+            ButtonStatus::Pressed => self.pressed_style.clone(),
+            ButtonStatus::Disabled => self.disabled_style.clone(),
+            ButtonStatus::Loading => self.loading_style.clone(),
+        }
+    }
+
+    /// Sets provided text for all the button states.
+    pub fn set_text(&mut self, text: &'a str) {
+        self.normal_style.text = text;
+        self.hovered_style.text = text;
+        self.pressed_style.text = text;
+        self.disabled_style.text = text;
+        self.loading_style.text = text;
+    }
 }
 
 /// Styling configuration for a specific state of a [`ButtonWidget`].
@@ -56,8 +219,8 @@ pub struct ButtonStyle<'a> {
 ///
 /// ```rust
 /// use ratatui::style::{Color, Modifier};
-/// use ratatui_small_spinner::SmallSpinnerStyle;
-/// use ratatui_button::{ButtonThickness, ButtonStateStyleBuilder};
+/// use caponata_small_spinner::SmallSpinnerStyle;
+/// use caponata_button::{ButtonThickness, ButtonStateStyleBuilder};
 ///
 /// let button_state_style = ButtonStateStyleBuilder::default()
 ///     .with_text("Submit")
@@ -69,17 +232,38 @@ pub struct ButtonStyle<'a> {
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Builder)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Builder)]
 #[builder(setter(prefix = "with", into, strip_option))]
 pub struct ButtonStateStyle<'a> {
+    /// Text of the caption. It may contain named placeholders,
+    /// e.g. `"Retry ({count})"`, replaced with the values set by
+    /// [`ButtonWidget::set_var`].
     #[builder(default = "\"\"")]
     pub(crate) text: &'a str,
 
+    /// Second line of the caption displayed below the text.
+    /// Thin buttons with a subtitle occupy two rows, and thick
+    /// buttons occupy four rows.
+    #[builder(default)]
+    pub(crate) subtitle: Option<&'a str>,
+
+    /// Color of the subtitle. The text color is used by
+    /// default.
     #[builder(default)]
-    pub(crate) text_color: Color,
+    pub(crate) subtitle_color: Option<Color>,
 
     #[builder(default)]
-    pub(crate) background_color: Color,
+    pub(crate) subtitle_modifier: Option<Modifier>,
+
+    /// Color of the text. `None` leaves the foreground color
+    /// of the cells untouched.
+    #[builder(default)]
+    pub(crate) text_color: Option<Color>,
+
+    /// Color of the background. `None` leaves the background
+    /// color of the cells untouched.
+    #[builder(default)]
+    pub(crate) background_color: Option<Color>,
 
     #[builder(default)]
     pub(crate) text_modifier: Option<Modifier>,
@@ -89,4 +273,30 @@ pub struct ButtonStateStyle<'a> {
 
     #[builder(default)]
     pub(crate) thickness: Option<ButtonThickness>,
+
+    /// Effect played when the button is clicked while
+    /// displayed in this state.
+    #[builder(default)]
+    pub(crate) press_effect: ButtonEffect,
+}
+
+impl<'a> ButtonStateStyle<'a> {
+    /// Returns the width of the widest caption line, including
+    /// the spinner displayed before the text.
+    pub fn content_width(&self) -> u16 {
+        self.content_width_with_text(self.text)
+    }
+
+    /// Returns the width of the widest caption line if provided
+    /// text is displayed instead of the text of the style.
+    pub(crate) fn content_width_with_text(&self, text: &str) -> u16 {
+        let spinner_width = if self.spinner_style.is_some() { 2 } else { 0 };
+        let text_width = Span::raw(text).width() + spinner_width;
+        let subtitle_width = self
+            .subtitle
+            .map(|subtitle| Span::raw(subtitle).width())
+            .unwrap_or(0);
+
+        text_width.max(subtitle_width) as u16
+    }
 }
@@ -0,0 +1,15 @@
+/// Vertical position of a [`ButtonWidget`] within an area
+/// taller than the button.
+///
+/// Default variant is [`ButtonVerticalAlignment::Center`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ButtonVerticalAlignment {
+    Top,
+
+    /// The button is placed in the middle of the area,
+    /// rounding down.
+    #[default]
+    Center,
+
+    Bottom,
+}
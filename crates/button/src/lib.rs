@@ -1,15 +1,23 @@
 pub mod button;
+pub mod button_effect;
 pub mod button_event;
+pub mod button_feedback;
 mod button_line;
+pub mod button_sizing;
 pub mod button_status;
 pub mod button_style;
 pub mod button_thickness;
+pub mod button_vertical_alignment;
 mod sized_button;
 
 pub use button::*;
+pub use button_effect::*;
 pub use button_event::*;
+pub use button_feedback::*;
 pub(crate) use button_line::*;
+pub use button_sizing::*;
 pub use button_status::*;
 pub use button_style::*;
 pub use button_thickness::*;
+pub use button_vertical_alignment::*;
 pub(crate) use sized_button::*;
@@ -1,9 +1,11 @@
+use caponata_common::SharedClock;
 use ratatui::{
     buffer::Buffer,
     layout::{
         Position,
         Rect,
     },
+    style::Color,
     widgets::Widget,
 };
 
@@ -42,6 +44,23 @@ impl<'a> SizedButton<'a> {
         }
     }
 
+    /// Returns the number of rows occupied by the button.
+    pub fn height(&self) -> u16 {
+        match self {
+            SizedButton::Thick(button) => button.height(),
+            SizedButton::Thin(button) => button.height(),
+        }
+    }
+
+    /// Returns the area of the row displaying the text within
+    /// provided area.
+    pub fn text_area(&self, area: Rect) -> Rect {
+        match self {
+            SizedButton::Thick(button) => button.text_area(area),
+            SizedButton::Thin(button) => button.text_area(area),
+        }
+    }
+
     /// Returns boolean flag indicating whether widget contains
     /// provided position. Widget's area is calculated based on
     /// provided area.
@@ -69,4 +88,37 @@ impl<'a> SizedButton<'a> {
             SizedButton::Thin(button) => button.disable_spinner(),
         }
     }
+
+    /// Makes the button cast a shadow if it is thick;
+    /// otherwise does nothing.
+    pub fn set_shadow(&mut self, color: Color, is_pressed: bool) {
+        if let SizedButton::Thick(button) = self {
+            button.set_shadow(color, is_pressed);
+        }
+    }
+
+    /// Replaces the text of the caption line, leaving the
+    /// other lines untouched.
+    pub fn set_text(&mut self, text: String) {
+        match self {
+            SizedButton::Thick(button) => button.set_text(text),
+            SizedButton::Thin(button) => button.set_text(text),
+        }
+    }
+
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        match self {
+            SizedButton::Thick(button) => button.set_clock(clock),
+            SizedButton::Thin(button) => button.set_clock(clock),
+        }
+    }
+
+    /// Starts the press effect of the button if it has one;
+    /// otherwise does nothing.
+    pub fn play_effect(&mut self) {
+        match self {
+            SizedButton::Thick(button) => button.play_effect(),
+            SizedButton::Thin(button) => button.play_effect(),
+        }
+    }
 }
@@ -1,5 +1,6 @@
-use std::iter::repeat;
+use caponata_common::SharedClock;
 
+use caponata_small_spinner::SmallSpinnerStyle;
 use ratatui::{
     buffer::Buffer,
     layout::{
@@ -9,38 +10,48 @@ use ratatui::{
     style::{
         Color,
         Modifier,
-        Stylize,
+        Style,
     },
     text::Line,
     widgets::Widget,
 };
-use ratatui_small_spinner::SmallSpinnerStyle;
 
 use crate::{
+    ButtonEffect,
     ButtonLine,
     ButtonStateStyle,
     ButtonThickness,
+    PlainLine,
+    subtitle_line,
 };
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub(crate) struct ThickButtonStyle<'a> {
     pub text: &'a str,
-    pub text_color: Color,
-    pub background_color: Color,
+    pub subtitle: Option<&'a str>,
+    pub subtitle_color: Option<Color>,
+    pub subtitle_modifier: Option<Modifier>,
+    pub text_color: Option<Color>,
+    pub background_color: Option<Color>,
     pub thickness: ButtonThickness,
     pub text_modifier: Option<Modifier>,
     pub spinner_style: Option<SmallSpinnerStyle>,
+    pub press_effect: ButtonEffect,
 }
 
 impl<'a> From<ButtonStateStyle<'a>> for ThickButtonStyle<'a> {
     fn from(value: ButtonStateStyle<'a>) -> Self {
         Self {
             text: value.text,
+            subtitle: value.subtitle,
+            subtitle_color: value.subtitle_color,
+            subtitle_modifier: value.subtitle_modifier,
             text_color: value.text_color,
             background_color: value.background_color,
             thickness: value.thickness.unwrap(),
             text_modifier: value.text_modifier,
             spinner_style: value.spinner_style,
+            press_effect: value.press_effect,
         }
     }
 }
@@ -58,6 +69,7 @@ pub(crate) struct ThickButton<'a> {
     top_line_symbol: &'a str,
 
     middle_line: ButtonLine<'a>,
+    subtitle_line: Option<PlainLine<'a>>,
 
     /// Symbol used to render the bottom line of
     /// the button. We don't store the line itself,
@@ -66,35 +78,87 @@ pub(crate) struct ThickButton<'a> {
     /// we should include in the line.
     bottom_line_symbol: &'a str,
 
-    background_color: Color,
+    background_color: Option<Color>,
+
+    /// Color of the shadow cast to the right and below the
+    /// button. The button doesn't cast a shadow if `None`.
+    shadow_color: Option<Color>,
+
+    /// Whether the button is rendered as pressed into its
+    /// shadow, i.e. shifted to the right without a shadow.
+    is_pressed: bool,
 }
 
 impl<'a> Widget for &mut ThickButton<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let top_line_text: String = repeat(self.top_line_symbol)
-            .take(area.width as usize)
-            .collect();
+        let shadow_color = match self.shadow_color {
+            Some(shadow_color) if area.width >= 2 => shadow_color,
+            _ => return self.render_body(area, buf),
+        };
+        let body_width = area.width - 1;
+
+        if self.is_pressed {
+            let body_area =
+                Rect::new(area.x + 1, area.y, body_width, area.height);
+            return self.render_body(body_area, buf);
+        }
+
+        let body_area = Rect::new(area.x, area.y, body_width, area.height);
+        self.render_body(body_area, buf);
+
+        // The shadow is offset by one column and half a row,
+        // so it fills the lower half of the bottom line and
+        // the whole right column below the top line.
+        let height = self.height().min(area.height);
+        let bottom_y = area.y + height - 1;
+        for x in area.x + 1..area.x + body_width {
+            buf[(x, bottom_y)].set_bg(shadow_color);
+        }
+        for y in area.y + 1..area.y + height {
+            buf[(area.x + body_width, y)]
+                .set_symbol("█")
+                .set_fg(shadow_color);
+        }
+    }
+}
+
+impl<'a> ThickButton<'a> {
+    fn render_body(&mut self, area: Rect, buf: &mut Buffer) {
+        let top_line_text = self.top_line_symbol.repeat(area.width as usize);
         let top_line_area = Rect::new(area.x, area.y, area.width, 1);
 
         Line::from(top_line_text)
-            .fg(self.background_color)
+            .style(self.edge_line_style())
             .render(top_line_area, buf);
 
         let middle_line_area = Rect::new(area.x, area.y + 1, area.width, 1);
         self.middle_line.render(middle_line_area, buf);
 
-        let bottom_line_text: String = repeat(self.bottom_line_symbol)
-            .take(area.width as usize)
-            .collect();
-        let bottom_line_area = Rect::new(area.x, area.y + 2, area.width, 1);
+        if let Some(subtitle_line) = &self.subtitle_line {
+            let subtitle_area = Rect::new(area.x, area.y + 2, area.width, 1);
+            subtitle_line.render(subtitle_area, buf);
+        }
+
+        let bottom_line_text =
+            self.bottom_line_symbol.repeat(area.width as usize);
+        let bottom_line_area =
+            Rect::new(area.x, area.y + self.height() - 1, area.width, 1);
 
         Line::from(bottom_line_text)
-            .fg(self.background_color)
+            .style(self.edge_line_style())
             .render(bottom_line_area, buf);
     }
-}
 
-impl<'a> ThickButton<'a> {
+    /// Returns the style of the top and the bottom lines,
+    /// which are drawn with the background color of the
+    /// button.
+    fn edge_line_style(&self) -> Style {
+        Style {
+            fg: self.background_color,
+            ..Style::default()
+        }
+    }
+
     pub fn new(style: impl Into<ThickButtonStyle<'a>>) -> Self {
         let style = style.into();
 
@@ -102,24 +166,63 @@ impl<'a> ThickButton<'a> {
             ButtonThickness::OneEightBlock => ("▁", "▔"),
             ButtonThickness::HalfBlock => ("▄", "▀"),
         };
+        let subtitle_line = subtitle_line(
+            style.subtitle,
+            style.subtitle_color.or(style.text_color),
+            style.background_color,
+            style.subtitle_modifier,
+        );
+        let background_color = style.background_color;
         let middle_line = ButtonLine::new(style);
 
         Self {
             top_line_symbol,
             middle_line,
+            subtitle_line,
             bottom_line_symbol,
-            background_color: style.background_color,
+            background_color,
+            shadow_color: None,
+            is_pressed: false,
         }
     }
 
+    /// Makes the button cast a shadow of provided color, or
+    /// renders it as pressed into the shadow if `is_pressed`
+    /// is `true`.
+    pub fn set_shadow(&mut self, color: Color, is_pressed: bool) {
+        self.shadow_color = Some(color);
+        self.is_pressed = is_pressed;
+    }
+
     /// Returns boolean flag indicating whether widget contains
     /// provided position. Widget's area is calculated based on
     /// provided area.
     pub fn contains(&self, area: Rect, position: Position) -> bool {
-        Rect::new(area.x, area.y, area.width, area.height.min(3))
+        Rect::new(area.x, area.y, area.width, area.height.min(self.height()))
             .contains(position)
     }
 
+    /// Returns the area of the row displaying the text within
+    /// provided area, taking the shadow into account.
+    pub fn text_area(&self, area: Rect) -> Rect {
+        if area.height < 2 {
+            return Rect::new(area.x, area.y, area.width, 0);
+        }
+
+        let (x, width) = match self.shadow_color {
+            Some(_) if area.width >= 2 => {
+                (area.x + self.is_pressed as u16, area.width - 1)
+            }
+            _ => (area.x, area.width),
+        };
+        Rect::new(x, area.y + 1, width, 1)
+    }
+
+    /// Returns the number of rows occupied by the button.
+    pub fn height(&self) -> u16 {
+        3 + self.subtitle_line.is_some() as u16
+    }
+
     /// Enables spinner if the button supports spinner; otherwise
     /// does nothing.
     pub fn enable_spinner(&mut self) {
@@ -131,4 +234,20 @@ impl<'a> ThickButton<'a> {
     pub fn disable_spinner(&mut self) {
         self.middle_line.disable_spinner();
     }
+
+    /// Replaces the text of the caption line, leaving the
+    /// other lines untouched.
+    pub fn set_text(&mut self, text: String) {
+        self.middle_line.set_text(text);
+    }
+
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.middle_line.set_clock(clock);
+    }
+
+    /// Starts the press effect of the button if it has one;
+    /// otherwise does nothing.
+    pub fn play_effect(&mut self) {
+        self.middle_line.play_effect();
+    }
 }
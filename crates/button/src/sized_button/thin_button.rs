@@ -1,3 +1,5 @@
+use caponata_common::SharedClock;
+use caponata_small_spinner::SmallSpinnerStyle;
 use ratatui::{
     buffer::Buffer,
     layout::{
@@ -10,29 +12,39 @@ use ratatui::{
     },
     widgets::Widget,
 };
-use ratatui_small_spinner::SmallSpinnerStyle;
 
 use crate::{
+    ButtonEffect,
     ButtonLine,
     ButtonStateStyle,
+    PlainLine,
+    subtitle_line,
 };
 
 pub(crate) struct ThinButtonStyle<'a> {
     pub text: &'a str,
-    pub text_color: Color,
-    pub background_color: Color,
+    pub subtitle: Option<&'a str>,
+    pub subtitle_color: Option<Color>,
+    pub subtitle_modifier: Option<Modifier>,
+    pub text_color: Option<Color>,
+    pub background_color: Option<Color>,
     pub text_modifier: Option<Modifier>,
     pub spinner_style: Option<SmallSpinnerStyle>,
+    pub press_effect: ButtonEffect,
 }
 
 impl<'a> From<ButtonStateStyle<'a>> for ThinButtonStyle<'a> {
     fn from(value: ButtonStateStyle<'a>) -> Self {
         Self {
             text: value.text,
+            subtitle: value.subtitle,
+            subtitle_color: value.subtitle_color,
+            subtitle_modifier: value.subtitle_modifier,
             text_color: value.text_color,
             background_color: value.background_color,
             text_modifier: value.text_modifier,
             spinner_style: value.spinner_style,
+            press_effect: value.press_effect,
         }
     }
 }
@@ -42,15 +54,21 @@ impl<'a> From<ButtonStateStyle<'a>> for ThinButtonStyle<'a> {
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub(crate) struct ThinButton<'a> {
     line: ButtonLine<'a>,
+    subtitle_line: Option<PlainLine<'a>>,
 }
 
-impl<'a> Widget for &ThinButton<'a> {
+impl<'a> Widget for &mut ThinButton<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        if area.height >= 3 {
-            let area = Rect::new(area.x, area.y + 1, area.width, area.height);
-            self.line.clone().render(area, buf);
-        } else {
-            self.line.clone().render(area, buf);
+        let area = self.button_area(area);
+
+        let line_area = Rect::new(area.x, area.y, area.width, 1);
+        self.line.render(line_area, buf);
+
+        if let Some(subtitle_line) = &self.subtitle_line
+            && area.height > 1
+        {
+            let subtitle_area = Rect::new(area.x, area.y + 1, area.width, 1);
+            subtitle_line.render(subtitle_area, buf);
         }
     }
 }
@@ -58,20 +76,42 @@ impl<'a> Widget for &ThinButton<'a> {
 impl<'a> ThinButton<'a> {
     pub fn new(style: impl Into<ThinButtonStyle<'a>>) -> Self {
         let style = style.into();
+        let subtitle_line = subtitle_line(
+            style.subtitle,
+            style.subtitle_color.or(style.text_color),
+            style.background_color,
+            style.subtitle_modifier,
+        );
         let line = ButtonLine::new(style);
 
-        Self { line }
+        Self {
+            line,
+            subtitle_line,
+        }
     }
 
     /// Returns boolean flag indicating whether widget contains
     /// provided position. Widget's area is calculated based on
     /// provided area.
     pub fn contains(&self, area: Rect, position: Position) -> bool {
-        if area.height >= 3 {
-            Rect::new(area.x, area.y + 1, area.width, 1).contains(position)
-        } else {
-            Rect::new(area.x, area.y, area.width, 1).contains(position)
-        }
+        self.button_area(area).contains(position)
+    }
+
+    /// Returns the area of the row displaying the text within
+    /// provided area.
+    pub fn text_area(&self, area: Rect) -> Rect {
+        Rect::new(area.x, area.y, area.width, area.height.min(1))
+    }
+
+    /// Returns the number of rows occupied by the button.
+    pub fn height(&self) -> u16 {
+        1 + self.subtitle_line.is_some() as u16
+    }
+
+    /// Returns the area of the caption rows at the top of
+    /// provided area.
+    fn button_area(&self, area: Rect) -> Rect {
+        Rect::new(area.x, area.y, area.width, self.height().min(area.height))
     }
 
     /// Enables spinner if the button supports spinner; otherwise
@@ -85,4 +125,20 @@ impl<'a> ThinButton<'a> {
     pub fn disable_spinner(&mut self) {
         self.line.disable_spinner();
     }
+
+    /// Replaces the text of the caption line, leaving the
+    /// other lines untouched.
+    pub fn set_text(&mut self, text: String) {
+        self.line.set_text(text);
+    }
+
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.line.set_clock(clock);
+    }
+
+    /// Starts the press effect of the button if it has one;
+    /// otherwise does nothing.
+    pub fn play_effect(&mut self) {
+        self.line.play_effect();
+    }
 }
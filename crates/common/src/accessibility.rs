@@ -0,0 +1,115 @@
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::String,
+    vec::Vec,
+};
+
+/// A widget that describes its content and state in plain
+/// text, e.g. for braille displays and speech synthesizers
+/// that can't interpret the rendered cells.
+///
+/// Descriptions start with the role of the widget, followed by
+/// its content in single quotes and its states, separated by
+/// commas, e.g. `"button 'Save', pressed"`. States that are
+/// usual for the widget, e.g. a button not being hovered, are
+/// omitted. Widgets composed of other widgets list the
+/// descriptions of their parts after a colon, separated by
+/// semicolons, e.g. `"status bar: text 'NORMAL'; spinner,
+/// busy"`.
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_common::AccessibleDescription;
+///
+/// struct Checkbox {
+///     label: &'static str,
+///     is_checked: bool,
+/// }
+///
+/// impl AccessibleDescription for Checkbox {
+///     fn accessible_description(&self) -> String {
+///         let state = match self.is_checked {
+///             true => "checked",
+///             false => "unchecked",
+///         };
+///         format!("checkbox '{}', {state}", self.label)
+///     }
+/// }
+///
+/// let checkbox = Checkbox {
+///     label: "Remember me",
+///     is_checked: true,
+/// };
+/// assert_eq!(
+///     checkbox.accessible_description(),
+///     "checkbox 'Remember me', checked",
+/// );
+/// ```
+pub trait AccessibleDescription {
+    /// Returns a plain-text description of the current content
+    /// and state of the widget.
+    fn accessible_description(&self) -> String;
+}
+
+/// Joins the role, the content and the states of a widget into
+/// a description in the format of [`AccessibleDescription`].
+/// Empty states are skipped, and the content is omitted if it
+/// is `None`.
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_common::describe_widget;
+///
+/// assert_eq!(
+///     describe_widget("button", Some("Save"), ["pressed", "", "loading"]),
+///     "button 'Save', pressed, loading",
+/// );
+/// assert_eq!(describe_widget("spinner", None, []), "spinner");
+/// ```
+pub fn describe_widget<'a>(
+    role: &str,
+    content: Option<&str>,
+    states: impl IntoIterator<Item = &'a str>,
+) -> String {
+    let mut description = role.to_owned();
+    if let Some(content) = content {
+        description.push_str(&format!(" '{content}'"));
+    }
+    for state in states.into_iter().filter(|state| !state.is_empty()) {
+        description.push_str(", ");
+        description.push_str(state);
+    }
+
+    description
+}
+
+/// Joins the role of a widget composed of other widgets and
+/// the descriptions of its parts into a description in the
+/// format of [`AccessibleDescription`].
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_common::describe_parts;
+///
+/// let parts = ["text 'main'".to_string(), "badge '3'".to_string()];
+/// assert_eq!(
+///     describe_parts("line", parts),
+///     "line: text 'main'; badge '3'",
+/// );
+/// assert_eq!(describe_parts("line", []), "line");
+/// ```
+pub fn describe_parts(
+    role: &str,
+    parts: impl IntoIterator<Item = String>,
+) -> String {
+    let parts: Vec<String> = parts.into_iter().collect();
+    if parts.is_empty() {
+        return role.to_owned();
+    }
+
+    format!("{role}: {}", parts.join("; "))
+}
@@ -0,0 +1,10 @@
+use core::time::Duration;
+
+/// A widget whose appearance changes over time.
+pub trait Animated {
+    /// Returns the time left until the widget should be
+    /// rendered again to display its next frame, or `None` if
+    /// the widget doesn't change by itself, e.g. its animation
+    /// is paused or has ended.
+    fn time_until_next_frame(&self) -> Option<Duration>;
+}
@@ -1,58 +1,139 @@
-use std::{
+use alloc::{
+    rc::Rc,
+    sync::Arc,
+};
+use core::{
     fmt,
     hash::{
         Hash,
         Hasher,
     },
     marker::Tuple,
-    sync::Arc,
+    sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    },
 };
 
-use uuid::Uuid;
+/// Identifier of the next created callable or shared clock.
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
 
+/// Returns a unique identifier for a new callable or shared
+/// clock, which are compared by identity.
+pub(crate) fn next_id() -> usize {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// A cheaply cloneable function that can be sent to and
+/// shared between threads. Used by styles for custom
+/// targets and callbacks.
+///
+/// Callables are compared by identity: clones of a callable
+/// are equal, while callables created from the same function
+/// are not.
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_common::Callable;
+///
+/// let double = Callable::from_fn(|(x,): (u16,)| x * 2);
+/// assert_eq!(double.call((21,)), 42);
+/// assert_eq!(double.clone(), double);
+/// ```
 pub struct Callable<Args: Tuple, R> {
-    id: Uuid,
-    function: Arc<dyn Fn(Args) -> R>,
+    id: usize,
+    function: Arc<dyn Fn(Args) -> R + Send + Sync>,
 }
 
-impl<Args: Tuple, R> fmt::Debug for Callable<Args, R> {
-    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        formatter.write_str("Callable")
-    }
+/// A cheaply cloneable function that stays on the thread it
+/// was created on. Unlike [`Callable`], it can capture values
+/// that are not [`Send`] or [`Sync`], such as [`Rc`].
+pub struct LocalCallable<Args: Tuple, R> {
+    id: usize,
+    function: Rc<dyn Fn(Args) -> R>,
 }
 
-impl<Args: Tuple, R> PartialEq for Callable<Args, R> {
-    fn eq(&self, other: &Self) -> bool {
-        self.id == other.id
+impl<Args: Tuple, R> Callable<Args, R> {
+    pub fn new(function: Arc<dyn Fn(Args) -> R + Send + Sync>) -> Self {
+        Self {
+            id: next_id(),
+            function,
+        }
     }
-}
 
-impl<Args: Tuple, R> Eq for Callable<Args, R> {}
+    pub fn from_fn(
+        function: impl Fn(Args) -> R + Send + Sync + 'static,
+    ) -> Self {
+        Self::new(Arc::new(function))
+    }
+}
 
-impl<Args: Tuple, R> Clone for Callable<Args, R> {
-    fn clone(&self) -> Self {
-        Callable {
-            id: self.id,
-            function: self.function.clone(),
+impl<Args: Tuple, R> LocalCallable<Args, R> {
+    pub fn new(function: Rc<dyn Fn(Args) -> R>) -> Self {
+        Self {
+            id: next_id(),
+            function,
         }
     }
-}
 
-impl<Args: Tuple, R> Hash for Callable<Args, R> {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.id.hash(state);
+    pub fn from_fn(function: impl Fn(Args) -> R + 'static) -> Self {
+        Self::new(Rc::new(function))
     }
 }
 
-impl<Args: Tuple, R> Callable<Args, R> {
-    pub fn new(function: Arc<dyn Fn(Args) -> R>) -> Self {
+impl<Args: Tuple + 'static, R: 'static> From<Callable<Args, R>>
+    for LocalCallable<Args, R>
+{
+    /// Converts the callable, keeping its identity.
+    fn from(value: Callable<Args, R>) -> Self {
+        let function = value.function;
+
         Self {
-            id: Uuid::new_v4(),
-            function: function,
+            id: value.id,
+            function: Rc::new(move |args| function(args)),
         }
     }
+}
 
-    pub fn call(&self, args: Args) -> R {
-        self.function.call((args,))
-    }
+macro_rules! impl_callable {
+    ($callable:ident) => {
+        impl<Args: Tuple, R> $callable<Args, R> {
+            pub fn call(&self, args: Args) -> R {
+                self.function.call((args,))
+            }
+        }
+
+        impl<Args: Tuple, R> fmt::Debug for $callable<Args, R> {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str(stringify!($callable))
+            }
+        }
+
+        impl<Args: Tuple, R> PartialEq for $callable<Args, R> {
+            fn eq(&self, other: &Self) -> bool {
+                self.id == other.id
+            }
+        }
+
+        impl<Args: Tuple, R> Eq for $callable<Args, R> {}
+
+        impl<Args: Tuple, R> Clone for $callable<Args, R> {
+            fn clone(&self) -> Self {
+                Self {
+                    id: self.id,
+                    function: self.function.clone(),
+                }
+            }
+        }
+
+        impl<Args: Tuple, R> Hash for $callable<Args, R> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.id.hash(state);
+            }
+        }
+    };
 }
+
+impl_callable!(Callable);
+impl_callable!(LocalCallable);
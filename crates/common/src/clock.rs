@@ -0,0 +1,100 @@
+use std::{
+    fmt,
+    hash::{
+        Hash,
+        Hasher,
+    },
+    sync::Arc,
+};
+
+#[cfg(target_arch = "wasm32")]
+pub use web_time::Instant;
+
+use super::next_id;
+
+/// A point in time returned by a [`Clock`].
+///
+/// It is [`std::time::Instant`] on all targets except
+/// `wasm32`, where it reads the time from `performance.now()`
+/// instead of panicking.
+#[cfg(not(target_arch = "wasm32"))]
+pub type Instant = std::time::Instant;
+
+/// A source of the current time for time-dependent widgets.
+///
+/// Widgets query the clock instead of calling [`Instant::now`]
+/// directly, so the time can be controlled from outside, e.g.
+/// frozen for a frame or advanced manually in tests.
+///
+/// Clocks are shared between widgets that may live on
+/// different threads, so they must be [`Send`] and [`Sync`].
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Instant;
+}
+
+/// A clock that returns the current system time.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A cheaply cloneable handle to a [`Clock`]. Clones of the
+/// handle share the same clock.
+///
+/// Default clock is [`SystemClock`].
+pub struct SharedClock {
+    id: usize,
+    clock: Arc<dyn Clock>,
+}
+
+impl fmt::Debug for SharedClock {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("SharedClock")
+    }
+}
+
+impl Default for SharedClock {
+    fn default() -> Self {
+        Self::new(Arc::new(SystemClock))
+    }
+}
+
+impl PartialEq for SharedClock {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for SharedClock {}
+
+impl Clone for SharedClock {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            clock: self.clock.clone(),
+        }
+    }
+}
+
+impl Hash for SharedClock {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl SharedClock {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            id: next_id(),
+            clock,
+        }
+    }
+
+    pub fn now(&self) -> Instant {
+        self.clock.now()
+    }
+}
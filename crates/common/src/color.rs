@@ -0,0 +1,184 @@
+use ratatui::style::Color;
+
+/// Colors of the first 16 indexed colors of xterm.
+const BASE_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (128, 0, 0),
+    (0, 128, 0),
+    (128, 128, 0),
+    (0, 0, 128),
+    (128, 0, 128),
+    (0, 128, 128),
+    (192, 192, 192),
+    (128, 128, 128),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (0, 0, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Returns red, green and blue components of provided color
+/// as displayed by xterm. Returns `None` for [`Color::Reset`],
+/// since it depends on the terminal.
+pub fn to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    let index = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => return Some((r, g, b)),
+        Color::Indexed(index) => index,
+        Color::Black => 0,
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::Gray => 7,
+        Color::DarkGray => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        Color::White => 15,
+    };
+
+    let rgb = match index {
+        0..=15 => BASE_COLORS[index as usize],
+        16..=231 => {
+            let cube_index = index - 16;
+            let level =
+                |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+            (
+                level(cube_index / 36),
+                level(cube_index / 6 % 6),
+                level(cube_index % 6),
+            )
+        }
+        232..=255 => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    };
+
+    Some(rgb)
+}
+
+/// Returns the color between provided colors at provided
+/// progress in the `0.0..=1.0` range. If any of the colors
+/// can't be converted to RGB, returns the nearest of them.
+pub fn interpolate_color(from: Color, to: Color, progress: f64) -> Color {
+    let progress = progress.clamp(0.0, 1.0);
+
+    let (Some(from_rgb), Some(to_rgb)) = (to_rgb(from), to_rgb(to)) else {
+        return if progress < 0.5 { from } else { to };
+    };
+    let interpolate = |from: u8, to: u8| -> u8 {
+        (from as f64 + (to as f64 - from as f64) * progress).round() as u8
+    };
+
+    Color::Rgb(
+        interpolate(from_rgb.0, to_rgb.0),
+        interpolate(from_rgb.1, to_rgb.1),
+        interpolate(from_rgb.2, to_rgb.2),
+    )
+}
+
+/// Returns the color with provided hue in degrees, and
+/// saturation and lightness in the `0.0..=1.0` range.
+pub fn hsl_to_color(hue: f64, saturation: f64, lightness: f64) -> Color {
+    let hue = hue.rem_euclid(360.0);
+    let saturation = saturation.clamp(0.0, 1.0);
+    let lightness = lightness.clamp(0.0, 1.0);
+
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let secondary = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue {
+        0.0..60.0 => (chroma, secondary, 0.0),
+        60.0..120.0 => (secondary, chroma, 0.0),
+        120.0..180.0 => (0.0, chroma, secondary),
+        180.0..240.0 => (0.0, secondary, chroma),
+        240.0..300.0 => (secondary, 0.0, chroma),
+        _ => (chroma, 0.0, secondary),
+    };
+    let offset = lightness - chroma / 2.0;
+    let component = |value: f64| ((value + offset) * 255.0).round() as u8;
+
+    Color::Rgb(component(r), component(g), component(b))
+}
+
+/// Returns hue in degrees, and saturation and lightness in
+/// the `0.0..=1.0` range of provided color. Returns `None`
+/// for [`Color::Reset`].
+pub fn color_to_hsl(color: Color) -> Option<(f64, f64, f64)> {
+    let (r, g, b) = to_rgb(color)?;
+    let (r, g, b) = (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0);
+
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let lightness = (max + min) / 2.0;
+    if delta == 0.0 {
+        return Some((0.0, 0.0, lightness));
+    }
+
+    let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+    let hue = if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+
+    Some((hue, saturation, lightness))
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use super::{
+        color_to_hsl,
+        hsl_to_color,
+        interpolate_color,
+        to_rgb,
+    };
+
+    #[test]
+    fn indexed_colors_to_rgb() {
+        assert_eq!(to_rgb(Color::Reset), None);
+        assert_eq!(to_rgb(Color::LightRed), Some((255, 0, 0)));
+        assert_eq!(to_rgb(Color::Indexed(16)), Some((0, 0, 0)));
+        assert_eq!(to_rgb(Color::Indexed(231)), Some((255, 255, 255)));
+        assert_eq!(to_rgb(Color::Indexed(232)), Some((8, 8, 8)));
+    }
+
+    #[test]
+    fn color_interpolation() {
+        assert_eq!(
+            interpolate_color(Color::Black, Color::White, 0.5),
+            Color::Rgb(128, 128, 128),
+        );
+        assert_eq!(
+            interpolate_color(Color::Reset, Color::White, 0.7),
+            Color::White,
+        );
+    }
+
+    #[test]
+    fn hsl_conversion() {
+        assert_eq!(hsl_to_color(0.0, 1.0, 0.5), Color::Rgb(255, 0, 0));
+        assert_eq!(hsl_to_color(480.0, 1.0, 0.5), Color::Rgb(0, 255, 0));
+        assert_eq!(hsl_to_color(240.0, 1.0, 0.25), Color::Rgb(0, 0, 128));
+        assert_eq!(color_to_hsl(Color::Reset), None);
+        assert_eq!(color_to_hsl(Color::LightCyan), Some((180.0, 1.0, 0.5)));
+        assert_eq!(
+            color_to_hsl(Color::Gray).map(|(h, s, _)| (h, s)),
+            Some((0.0, 0.0))
+        );
+    }
+}
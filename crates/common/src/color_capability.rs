@@ -0,0 +1,293 @@
+use std::{
+    env,
+    sync::atomic::{
+        AtomicU8,
+        Ordering,
+    },
+};
+
+use ratatui::{
+    buffer::{
+        Buffer,
+        Cell,
+    },
+    layout::Rect,
+    style::Color,
+};
+
+use super::{
+    RenderMode,
+    render_mode,
+    to_rgb,
+};
+
+/// Named colors in the order of their indexes.
+const NAMED_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+static COLOR_CAPABILITY: AtomicU8 = AtomicU8::new(0);
+
+thread_local! {
+    /// Number of [`render_adapted`] calls in progress on the
+    /// thread.
+    static ADAPTATION_DEPTH: std::cell::Cell<usize> =
+        const { std::cell::Cell::new(0) };
+}
+
+/// Colors a terminal is able to display.
+///
+/// Widgets consult the capability set with
+/// [`set_color_capability`] when rendering and replace the
+/// colors the terminal can't display with the nearest ones it
+/// can, so RGB colors don't turn into garbage, e.g. over basic
+/// SSH sessions.
+///
+/// Default variant is [`ColorCapability::TrueColor`].
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_common::ColorCapability;
+/// use ratatui::style::Color;
+///
+/// let capability = ColorCapability::Basic16;
+/// assert_eq!(capability.adapt(Color::Rgb(250, 10, 10)), Color::LightRed);
+/// assert_eq!(capability.adapt(Color::Blue), Color::Blue);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorCapability {
+    /// Any RGB color can be displayed.
+    #[default]
+    TrueColor,
+
+    /// The 256 indexed colors of xterm can be displayed.
+    Indexed256,
+
+    /// Only the 16 named colors can be displayed.
+    Basic16,
+}
+
+impl ColorCapability {
+    /// Guesses the capability of the terminal from the
+    /// `COLORTERM` and `TERM` environment variables.
+    pub fn detect() -> Self {
+        let color_term = env::var("COLORTERM").unwrap_or_default();
+        if color_term == "truecolor" || color_term == "24bit" {
+            return Self::TrueColor;
+        }
+
+        let term = env::var("TERM").unwrap_or_default();
+        if term.contains("256color") {
+            Self::Indexed256
+        } else {
+            Self::Basic16
+        }
+    }
+
+    /// Returns the color displayed instead of provided one. Named
+    /// colors and [`Color::Reset`] are always kept.
+    pub fn adapt(self, color: Color) -> Color {
+        match (self, color) {
+            (Self::TrueColor, _) | (_, Color::Reset) => color,
+            (Self::Indexed256, Color::Rgb(..)) => {
+                let rgb = to_rgb(color).expect("RGB color has components");
+                // The first 16 indexed colors depend on the theme
+                // of the terminal, so they are not used for
+                // approximation.
+                let index = (16..=255)
+                    .min_by_key(|index| distance(rgb, Color::Indexed(*index)))
+                    .expect("range is not empty");
+                Color::Indexed(index)
+            }
+            (Self::Basic16, Color::Rgb(..))
+            | (Self::Basic16, Color::Indexed(16..)) => {
+                let rgb = to_rgb(color).expect("color has components");
+                NAMED_COLORS
+                    .into_iter()
+                    .min_by_key(|named_color| distance(rgb, *named_color))
+                    .expect("array is not empty")
+            }
+            _ => color,
+        }
+    }
+
+    /// Adapts the foreground and background colors of the
+    /// cells of provided area.
+    pub fn adapt_buffer(self, area: Rect, buf: &mut Buffer) {
+        if self == Self::TrueColor {
+            return;
+        }
+
+        for position in area.intersection(buf.area).positions() {
+            let cell = &mut buf[position];
+            cell.fg = self.adapt(cell.fg);
+            cell.bg = self.adapt(cell.bg);
+        }
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            Self::TrueColor => 0,
+            Self::Indexed256 => 1,
+            Self::Basic16 => 2,
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Self::Indexed256,
+            2 => Self::Basic16,
+            _ => Self::TrueColor,
+        }
+    }
+}
+
+/// Sets the color capability all widgets render with. It is
+/// usually set once when the application starts, e.g. to
+/// [`ColorCapability::detect`].
+pub fn set_color_capability(capability: ColorCapability) {
+    COLOR_CAPABILITY.store(capability.to_u8(), Ordering::Relaxed);
+}
+
+pub fn color_capability() -> ColorCapability {
+    ColorCapability::from_u8(COLOR_CAPABILITY.load(Ordering::Relaxed))
+}
+
+/// Adapts the cells of provided area to the render mode set
+/// with [`set_render_mode`](crate::set_render_mode) and then
+/// their colors to the color capability set with
+/// [`set_color_capability`]. Does nothing while called from
+/// [`render_adapted`], which adapts the cells afterwards.
+///
+/// Adapting a cell twice may add extra modifiers, so widgets
+/// render with [`render_adapted`] instead of calling it.
+pub fn adapt_colors(area: Rect, buf: &mut Buffer) {
+    if ADAPTATION_DEPTH.get() > 0 {
+        return;
+    }
+
+    render_mode().adapt_buffer(area, buf);
+    color_capability().adapt_buffer(area, buf);
+}
+
+/// Renders with provided function and adapts the cells of
+/// provided area it changed like [`adapt_colors`].
+///
+/// Widgets rendered by the function, e.g. the buttons of a
+/// dialog, are adapted along with their parent, so each cell
+/// is adapted once. Cells left unchanged keep the adaptation
+/// done by the widgets rendered below.
+pub fn render_adapted<F>(area: Rect, buf: &mut Buffer, render: F)
+where
+    F: FnOnce(&mut Buffer),
+{
+    let mode = render_mode();
+    let capability = color_capability();
+    let is_nested = ADAPTATION_DEPTH.get() > 0;
+    if is_nested
+        || (mode == RenderMode::Normal
+            && capability == ColorCapability::TrueColor)
+    {
+        render(buf);
+        return;
+    }
+
+    let area = area.intersection(buf.area);
+    let cells_below: Vec<Cell> = area
+        .positions()
+        .map(|position| buf[position].clone())
+        .collect();
+
+    ADAPTATION_DEPTH.set(1);
+    let reset_depth = DepthReset;
+    render(buf);
+    drop(reset_depth);
+
+    for (position, cell_below) in area.positions().zip(cells_below) {
+        let cell = &mut buf[position];
+        if *cell == cell_below {
+            continue;
+        }
+        mode.adapt_cell(cell);
+        cell.fg = capability.adapt(cell.fg);
+        cell.bg = capability.adapt(cell.bg);
+    }
+}
+
+/// Resets the adaptation depth when dropped, so a panicking
+/// render doesn't disable the adaptation on the thread.
+struct DepthReset;
+
+impl Drop for DepthReset {
+    fn drop(&mut self) {
+        ADAPTATION_DEPTH.set(0);
+    }
+}
+
+/// Returns the squared distance between provided components
+/// and the components of provided color.
+fn distance(rgb: (u8, u8, u8), color: Color) -> u32 {
+    let other = to_rgb(color).unwrap_or_default();
+    let difference = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+
+    difference(rgb.0, other.0)
+        + difference(rgb.1, other.1)
+        + difference(rgb.2, other.2)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{
+            Color,
+            Style,
+        },
+    };
+
+    use super::ColorCapability;
+
+    #[test]
+    fn colors_are_adapted_to_capability() {
+        let gray = Color::Rgb(128, 128, 128);
+        assert_eq!(ColorCapability::TrueColor.adapt(gray), gray);
+        assert_eq!(
+            ColorCapability::Indexed256.adapt(gray),
+            Color::Indexed(244),
+        );
+        assert_eq!(ColorCapability::Basic16.adapt(gray), Color::DarkGray);
+
+        assert_eq!(
+            ColorCapability::Indexed256.adapt(Color::Indexed(3)),
+            Color::Indexed(3),
+        );
+        assert_eq!(
+            ColorCapability::Basic16.adapt(Color::Indexed(196)),
+            Color::LightRed,
+        );
+
+        let area = Rect::new(0, 0, 2, 1);
+        let mut buf = Buffer::empty(area);
+        buf.set_style(area, Style::new().fg(Color::Rgb(0, 0, 250)));
+        ColorCapability::Basic16.adapt_buffer(area, &mut buf);
+        assert_eq!(buf[(1, 0)].fg, Color::LightBlue);
+        assert_eq!(buf[(1, 0)].bg, Color::Reset);
+    }
+}
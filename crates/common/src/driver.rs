@@ -0,0 +1,211 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{
+        Context,
+        Poll,
+        Waker,
+    },
+    time::Duration,
+};
+
+use futures_core::Stream;
+use tokio::time::Sleep;
+
+use super::{
+    Animated,
+    Instant,
+};
+
+/// A stream of ticks delivered exactly when animated widgets
+/// need to be rendered again, so async applications can wait
+/// for it along with other events instead of polling at a
+/// fixed interval.
+///
+/// The driver doesn't own the widgets. After each render, the
+/// widgets are passed to [`AnimationDriver::schedule`], which
+/// plans the next tick. If none of them is going to change,
+/// the stream stays pending until something is scheduled.
+///
+/// # Example
+///
+/// ```rust
+/// use std::{
+///     future::poll_fn,
+///     pin::Pin,
+///     time::Duration,
+/// };
+///
+/// use futures_core::Stream;
+/// use caponata_common::{Animated, AnimationDriver, Instant};
+///
+/// struct Blinker;
+///
+/// impl Animated for Blinker {
+///     fn time_until_next_frame(&self) -> Option<Duration> {
+///         Some(Duration::from_millis(20))
+///     }
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let mut driver = AnimationDriver::new();
+/// let scheduled_at = Instant::now();
+/// driver.schedule([&Blinker as &dyn Animated]);
+///
+/// let tick = poll_fn(|cx| Pin::new(&mut driver).poll_next(cx)).await;
+/// assert!(tick.unwrap() >= scheduled_at + Duration::from_millis(20));
+/// assert!(driver.is_idle());
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct AnimationDriver {
+    deadline: Option<Instant>,
+
+    /// Created lazily, as it can only be created inside
+    /// the tokio runtime, along with the deadline it was set
+    /// for.
+    sleep: Option<(Instant, Pin<Box<Sleep>>)>,
+    waker: Option<Waker>,
+}
+
+impl AnimationDriver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if no tick is scheduled.
+    pub fn is_idle(&self) -> bool {
+        self.deadline.is_none()
+    }
+
+    /// Schedules the next tick for the earliest moment one of
+    /// provided widgets needs to be rendered again. Cancels
+    /// the scheduled tick if none of them is going to change.
+    pub fn schedule<'a>(
+        &mut self,
+        widgets: impl IntoIterator<Item = &'a dyn Animated>,
+    ) {
+        let delay = widgets
+            .into_iter()
+            .filter_map(|widget| widget.time_until_next_frame())
+            .min();
+
+        match delay {
+            Some(delay) => self.schedule_in(delay),
+            None => self.deadline = None,
+        }
+    }
+
+    /// Schedules the next tick after provided delay, replacing
+    /// the scheduled one. The stream is woken if the tick moves
+    /// earlier, so the sleep is reset to the new deadline; a
+    /// later tick is picked up when the current sleep ends.
+    pub fn schedule_in(&mut self, delay: Duration) {
+        let deadline = Instant::now() + delay;
+        let is_earlier = self
+            .deadline
+            .is_none_or(|scheduled_deadline| deadline < scheduled_deadline);
+        self.deadline = Some(deadline);
+
+        if is_earlier && let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Stream for AnimationDriver {
+    type Item = Instant;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Instant>> {
+        // The waker is kept even while sleeping, so scheduling an
+        // earlier tick can wake the stream to reset the sleep.
+        if !self
+            .waker
+            .as_ref()
+            .is_some_and(|waker| waker.will_wake(cx.waker()))
+        {
+            self.waker = Some(cx.waker().clone());
+        }
+        let Some(deadline) = self.deadline else {
+            return Poll::Pending;
+        };
+        // The deadline is converted through the remaining time,
+        // as the crate instant is not the std one on all targets.
+        let sleep_deadline = || {
+            tokio::time::Instant::now()
+                + deadline.saturating_duration_since(Instant::now())
+        };
+
+        let (sleep_set_for, sleep) = self.sleep.get_or_insert_with(|| {
+            (
+                deadline,
+                Box::pin(tokio::time::sleep_until(sleep_deadline())),
+            )
+        });
+        if *sleep_set_for != deadline {
+            *sleep_set_for = deadline;
+            sleep.as_mut().reset(sleep_deadline());
+        }
+
+        match sleep.as_mut().poll(cx) {
+            Poll::Ready(()) => {
+                self.deadline = None;
+                Poll::Ready(Some(Instant::now()))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::RefCell,
+        future::poll_fn,
+        pin::Pin,
+        rc::Rc,
+        time::Duration,
+    };
+
+    use futures_core::Stream;
+    use tokio::task::LocalSet;
+
+    use super::AnimationDriver;
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn earlier_tick_wakes_sleeping_stream() {
+        let driver = Rc::new(RefCell::new(AnimationDriver::new()));
+        driver.borrow_mut().schedule_in(Duration::from_secs(60));
+
+        // The tick is awaited in its own task, so it is only
+        // polled again once the driver wakes it.
+        let tasks = LocalSet::new();
+        let tick = tasks.spawn_local({
+            let driver = driver.clone();
+            poll_fn(move |cx| {
+                Pin::new(&mut *driver.borrow_mut()).poll_next(cx)
+            })
+        });
+        tasks.spawn_local({
+            let driver = driver.clone();
+            async move {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                driver.borrow_mut().schedule_in(Duration::from_millis(10));
+            }
+        });
+
+        let tick = tokio::time::timeout(
+            Duration::from_secs(5),
+            tasks.run_until(tick),
+        )
+        .await
+        .expect("the earlier tick is delivered")
+        .unwrap();
+        assert!(tick.is_some());
+        assert!(driver.borrow().is_idle());
+    }
+}
@@ -0,0 +1,35 @@
+/// Specifies how the progress of a transition changes over
+/// time.
+///
+/// Default variant is [`Easing::Linear`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Easing {
+    /// The progress changes uniformly.
+    #[default]
+    Linear,
+
+    /// The transition starts slow and speeds up.
+    EaseIn,
+
+    /// The transition starts fast and slows down.
+    EaseOut,
+
+    /// The transition starts and ends slow.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Maps provided progress of the transition in the
+    /// `0.0..=1.0` range to eased progress.
+    pub fn apply(&self, progress: f64) -> f64 {
+        let progress = progress.clamp(0.0, 1.0);
+
+        match self {
+            Self::Linear => progress,
+            Self::EaseIn => progress * progress,
+            Self::EaseOut => 1.0 - (1.0 - progress) * (1.0 - progress),
+            Self::EaseInOut if progress < 0.5 => 2.0 * progress * progress,
+            Self::EaseInOut => 1.0 - 2.0 * (1.0 - progress) * (1.0 - progress),
+        }
+    }
+}
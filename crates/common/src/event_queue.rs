@@ -0,0 +1,96 @@
+use alloc::collections::{
+    VecDeque,
+    vec_deque::Drain,
+};
+
+/// A bounded queue of events produced by a widget between two
+/// reads.
+///
+/// When the queue is full, pushing an event drops the oldest
+/// one, so a widget whose events are never read doesn't grow
+/// without bound.
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_common::EventQueue;
+///
+/// let mut queue = EventQueue::with_capacity(2);
+/// queue.push("first");
+/// queue.push("second");
+/// queue.push("third");
+///
+/// let events: Vec<&str> = queue.drain().collect();
+/// assert_eq!(events, vec!["second", "third"]);
+/// assert!(queue.is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EventQueue<E> {
+    events: VecDeque<E>,
+    capacity: usize,
+}
+
+impl<E> Default for EventQueue<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> EventQueue<E> {
+    /// Number of events a queue created with
+    /// [`EventQueue::new`] holds.
+    pub const DEFAULT_CAPACITY: usize = 64;
+
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Creates a queue holding at most provided number of
+    /// events. The capacity is at least one.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            events: VecDeque::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Appends provided event, dropping the oldest event if the
+    /// queue is full.
+    pub fn push(&mut self, event: E) {
+        if self.events.len() == self.capacity {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+
+    pub fn latest(&self) -> Option<&E> {
+        self.events.back()
+    }
+
+    /// Removes and returns the most recent event.
+    pub fn pop_latest(&mut self) -> Option<E> {
+        self.events.pop_back()
+    }
+
+    /// Returns the most recent event and removes all the
+    /// events from the queue.
+    pub fn take_latest(&mut self) -> Option<E> {
+        let latest = self.events.pop_back();
+        self.events.clear();
+        latest
+    }
+
+    /// Removes all the events from the queue and returns them
+    /// from the oldest to the most recent one.
+    pub fn drain(&mut self) -> Drain<'_, E> {
+        self.events.drain(..)
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
@@ -0,0 +1,324 @@
+use crossterm::event::{
+    KeyCode,
+    KeyEvent,
+    KeyEventKind,
+};
+use ratatui::layout::{
+    Position,
+    Rect,
+};
+
+/// A widget that can receive keyboard focus from a
+/// [`FocusManager`].
+pub trait Focusable {
+    /// Called when the widget gains or loses focus.
+    fn set_focused(&mut self, is_focused: bool);
+
+    /// Handles a key event delivered to the focused widget.
+    /// Returns `true` if the event was consumed.
+    fn handle_key_event(&mut self, event: KeyEvent) -> bool;
+
+    /// Returns the position the terminal cursor should be
+    /// displayed at while the widget, rendered in provided
+    /// area, is focused, e.g. to pass to
+    /// `Frame::set_cursor_position`. Returns `None` by default,
+    /// meaning the cursor should stay hidden.
+    fn cursor_position(&self, area: Rect) -> Option<Position> {
+        let _ = area;
+        None
+    }
+}
+
+/// Tracks which of the registered widgets is focused, cycles
+/// focus with `Tab` and `Shift-Tab` and dispatches other key
+/// events to the focused widget.
+///
+/// Widgets are identified by ids of type `K` in the order
+/// focus cycles through them. The manager doesn't own the
+/// widgets, so they are passed along with their ids to the
+/// methods that need them.
+///
+/// # Example
+///
+/// ```rust
+/// use crossterm::event::{KeyCode, KeyEvent};
+/// use caponata_common::{FocusManager, Focusable};
+///
+/// #[derive(Default)]
+/// struct Counter {
+///     is_focused: bool,
+///     value: u32,
+/// }
+///
+/// impl Focusable for Counter {
+///     fn set_focused(&mut self, is_focused: bool) {
+///         self.is_focused = is_focused;
+///     }
+///
+///     fn handle_key_event(&mut self, _event: KeyEvent) -> bool {
+///         self.value += 1;
+///         true
+///     }
+/// }
+///
+/// let mut first = Counter::default();
+/// let mut second = Counter::default();
+/// let mut focus_manager = FocusManager::new(["first", "second"]);
+///
+/// let tab = KeyEvent::from(KeyCode::Tab);
+/// let enter = KeyEvent::from(KeyCode::Enter);
+/// for event in [tab, enter] {
+///     focus_manager.handle_key_event(
+///         event,
+///         &mut [("first", &mut first), ("second", &mut second)],
+///     );
+/// }
+///
+/// assert_eq!(focus_manager.focused(), Some(&"second"));
+/// assert!(!first.is_focused);
+/// assert!(second.is_focused);
+/// assert_eq!((first.value, second.value), (0, 1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FocusManager<K> {
+    ids: Vec<K>,
+    focused: Option<usize>,
+}
+
+impl<K> Default for FocusManager<K> {
+    fn default() -> Self {
+        Self {
+            ids: Vec::new(),
+            focused: None,
+        }
+    }
+}
+
+impl<K: PartialEq> FocusManager<K> {
+    /// Creates a manager with provided ids. The first id, if
+    /// any, is focused.
+    pub fn new(ids: impl IntoIterator<Item = K>) -> Self {
+        let ids: Vec<K> = ids.into_iter().collect();
+        let focused = (!ids.is_empty()).then_some(0);
+
+        Self { ids, focused }
+    }
+
+    pub fn ids(&self) -> &[K] {
+        &self.ids
+    }
+
+    pub fn focused(&self) -> Option<&K> {
+        self.focused.map(|index| &self.ids[index])
+    }
+
+    pub fn is_focused(&self, id: &K) -> bool {
+        self.focused() == Some(id)
+    }
+
+    /// Appends provided id to the end of the focus order. The
+    /// id is focused if nothing is focused yet.
+    pub fn push(&mut self, id: K) {
+        self.ids.push(id);
+        if self.focused.is_none() {
+            self.focused = Some(self.ids.len() - 1);
+        }
+    }
+
+    /// Removes provided id from the focus order. If the id is
+    /// focused, focus moves to the next id.
+    pub fn remove(&mut self, id: &K) {
+        let Some(index) = self.ids.iter().position(|other| other == id) else {
+            return;
+        };
+        self.ids.remove(index);
+
+        self.focused = match self.focused {
+            _ if self.ids.is_empty() => None,
+            Some(focused) if focused > index => Some(focused - 1),
+            Some(focused) => Some(focused.min(self.ids.len() - 1)),
+            None => None,
+        };
+    }
+
+    /// Focuses provided id if it is registered; otherwise does
+    /// nothing.
+    pub fn focus(&mut self, id: &K) {
+        if let Some(index) = self.ids.iter().position(|other| other == id) {
+            self.focused = Some(index);
+        }
+    }
+
+    /// Clears the focus, so no widget is focused.
+    pub fn blur(&mut self) {
+        self.focused = None;
+    }
+
+    /// Moves focus to the next id, wrapping around to the
+    /// first one.
+    pub fn focus_next(&mut self) {
+        if self.ids.is_empty() {
+            return;
+        }
+        self.focused = Some(match self.focused {
+            Some(index) => (index + 1) % self.ids.len(),
+            None => 0,
+        });
+    }
+
+    /// Moves focus to the previous id, wrapping around to the
+    /// last one.
+    pub fn focus_previous(&mut self) {
+        if self.ids.is_empty() {
+            return;
+        }
+        self.focused = Some(match self.focused {
+            Some(index) => (index + self.ids.len() - 1) % self.ids.len(),
+            None => self.ids.len() - 1,
+        });
+    }
+
+    /// Notifies provided widgets whether they are focused.
+    /// Should be called after focus is changed manually.
+    pub fn sync(&self, widgets: &mut [(K, &mut dyn Focusable)]) {
+        for (id, widget) in widgets.iter_mut() {
+            widget.set_focused(self.is_focused(id));
+        }
+    }
+
+    /// Moves focus on `Tab` and `Shift-Tab`, notifying provided
+    /// widgets; otherwise dispatches the event to the focused
+    /// widget. Returns `true` if the event was consumed.
+    pub fn handle_key_event(
+        &mut self,
+        event: KeyEvent,
+        widgets: &mut [(K, &mut dyn Focusable)],
+    ) -> bool {
+        if event.kind == KeyEventKind::Release {
+            return false;
+        }
+
+        match event.code {
+            KeyCode::Tab => self.focus_next(),
+            KeyCode::BackTab => self.focus_previous(),
+            _ => {
+                return widgets
+                    .iter_mut()
+                    .find(|(id, _)| self.is_focused(id))
+                    .is_some_and(|(_, widget)| {
+                        widget.handle_key_event(event)
+                    });
+            }
+        }
+        self.sync(widgets);
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{
+        KeyCode,
+        KeyEvent,
+        KeyEventKind,
+    };
+
+    use super::{
+        FocusManager,
+        Focusable,
+    };
+
+    #[derive(Default)]
+    struct Field {
+        is_focused: bool,
+        keys: Vec<KeyCode>,
+    }
+
+    impl Focusable for Field {
+        fn set_focused(&mut self, is_focused: bool) {
+            self.is_focused = is_focused;
+        }
+
+        fn handle_key_event(&mut self, event: KeyEvent) -> bool {
+            self.keys.push(event.code);
+            true
+        }
+    }
+
+    #[test]
+    fn focus_wraps_around() {
+        let mut focus_manager = FocusManager::new(["a", "b", "c"]);
+        assert_eq!(focus_manager.focused(), Some(&"a"));
+
+        focus_manager.focus_previous();
+        assert_eq!(focus_manager.focused(), Some(&"c"));
+        focus_manager.focus_next();
+        assert_eq!(focus_manager.focused(), Some(&"a"));
+
+        focus_manager.blur();
+        focus_manager.focus_previous();
+        assert_eq!(focus_manager.focused(), Some(&"c"));
+        focus_manager.blur();
+        focus_manager.focus_next();
+        assert_eq!(focus_manager.focused(), Some(&"a"));
+
+        let mut empty = FocusManager::<&str>::default();
+        empty.focus_next();
+        assert_eq!(empty.focused(), None);
+    }
+
+    #[test]
+    fn keys_move_focus_and_reach_focused_widget() {
+        let (mut a, mut b) = (Field::default(), Field::default());
+        let mut focus_manager = FocusManager::new(["a", "b"]);
+        let mut send = |focus_manager: &mut FocusManager<&str>, event| {
+            focus_manager
+                .handle_key_event(event, &mut [("a", &mut a), ("b", &mut b)])
+        };
+
+        assert!(send(&mut focus_manager, KeyEvent::from(KeyCode::BackTab)));
+        assert_eq!(focus_manager.focused(), Some(&"b"));
+        assert!(send(&mut focus_manager, KeyEvent::from(KeyCode::Enter)));
+        assert!(send(&mut focus_manager, KeyEvent::from(KeyCode::Tab)));
+        assert_eq!(focus_manager.focused(), Some(&"a"));
+
+        let release = KeyEvent {
+            kind: KeyEventKind::Release,
+            ..KeyEvent::from(KeyCode::Tab)
+        };
+        assert!(!send(&mut focus_manager, release));
+        assert_eq!(focus_manager.focused(), Some(&"a"));
+
+        assert!(a.is_focused);
+        assert!(!b.is_focused);
+        assert!(a.keys.is_empty());
+        assert_eq!(b.keys, [KeyCode::Enter]);
+    }
+
+    #[test]
+    fn removing_focused_id_moves_focus() {
+        let mut focus_manager = FocusManager::new(["a", "b", "c"]);
+
+        focus_manager.focus(&"b");
+        focus_manager.remove(&"b");
+        assert_eq!(focus_manager.focused(), Some(&"c"));
+
+        focus_manager.remove(&"c");
+        assert_eq!(focus_manager.focused(), Some(&"a"));
+
+        focus_manager.remove(&"a");
+        assert_eq!(focus_manager.focused(), None);
+        assert!(focus_manager.ids().is_empty());
+    }
+
+    #[test]
+    fn removing_preceding_id_keeps_focus() {
+        let mut focus_manager = FocusManager::new(["a", "b", "c"]);
+
+        focus_manager.focus(&"c");
+        focus_manager.remove(&"a");
+        assert_eq!(focus_manager.focused(), Some(&"c"));
+        assert_eq!(focus_manager.ids(), ["b", "c"]);
+    }
+}
@@ -0,0 +1,96 @@
+use std::{
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+
+use super::{
+    Clock,
+    Instant,
+    SharedClock,
+};
+
+/// A clock that returns the time of the last frame.
+#[derive(Debug)]
+struct FrameClock {
+    frame_time: Mutex<Instant>,
+}
+
+impl Clock for FrameClock {
+    fn now(&self) -> Instant {
+        *self.frame_time.lock().unwrap()
+    }
+}
+
+/// A shared timeline for several animated widgets.
+///
+/// Widgets using the clock of the group observe the same time
+/// during a frame, so identical animations started in the same
+/// frame stay frame-locked instead of drifting apart due to
+/// independent [`Instant::now`] calls. The time of the group
+/// only changes when [`AnimationGroup::tick`] or
+/// [`AnimationGroup::advance`] is called, usually once before
+/// rendering each frame.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use caponata_common::AnimationGroup;
+///
+/// let group = AnimationGroup::new();
+/// let clock = group.clock();
+///
+/// let frame_time = clock.now();
+/// assert_eq!(clock.now(), frame_time);
+///
+/// group.advance(Duration::from_millis(100));
+/// assert_eq!(clock.now(), frame_time + Duration::from_millis(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnimationGroup {
+    frame_clock: Arc<FrameClock>,
+    clock: SharedClock,
+}
+
+impl Default for AnimationGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnimationGroup {
+    pub fn new() -> Self {
+        let frame_clock = Arc::new(FrameClock {
+            frame_time: Mutex::new(Instant::now()),
+        });
+        let clock = SharedClock::new(frame_clock.clone());
+
+        Self { frame_clock, clock }
+    }
+
+    /// Returns the clock to pass to the widgets of the group.
+    /// All returned clocks are equal.
+    pub fn clock(&self) -> SharedClock {
+        self.clock.clone()
+    }
+
+    /// Returns the time of the current frame.
+    pub fn now(&self) -> Instant {
+        self.frame_clock.now()
+    }
+
+    /// Starts a new frame at the current system time.
+    pub fn tick(&self) {
+        *self.frame_clock.frame_time.lock().unwrap() = Instant::now();
+    }
+
+    /// Starts a new frame provided duration after the current
+    /// one.
+    pub fn advance(&self, duration: Duration) {
+        *self.frame_clock.frame_time.lock().unwrap() += duration;
+    }
+}
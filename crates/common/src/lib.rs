@@ -1,6 +1,67 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![feature(tuple_trait)]
 #![feature(fn_traits)]
 
+extern crate alloc;
+
+mod accessibility;
+mod animated;
 mod callable;
+#[cfg(feature = "std")]
+mod clock;
+#[cfg(feature = "color")]
+mod color;
+#[cfg(feature = "color")]
+mod color_capability;
+#[cfg(feature = "tokio")]
+mod driver;
+mod easing;
+mod event_queue;
+#[cfg(feature = "crossterm")]
+mod focus;
+#[cfg(feature = "std")]
+mod group;
+#[cfg(feature = "crossterm")]
+mod recorder;
+#[cfg(feature = "registry")]
+mod registry;
+#[cfg(feature = "color")]
+mod render_mode;
+#[cfg(feature = "crossterm")]
+mod router;
+mod target;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "color")]
+mod transition;
+mod widget_event;
 
+pub use accessibility::*;
+pub use animated::*;
 pub use callable::*;
+#[cfg(feature = "std")]
+pub use clock::*;
+#[cfg(feature = "color")]
+pub use color::*;
+#[cfg(feature = "color")]
+pub use color_capability::*;
+#[cfg(feature = "tokio")]
+pub use driver::*;
+pub use easing::*;
+pub use event_queue::*;
+#[cfg(feature = "crossterm")]
+pub use focus::*;
+#[cfg(feature = "std")]
+pub use group::*;
+#[cfg(feature = "crossterm")]
+pub use recorder::*;
+#[cfg(feature = "registry")]
+pub use registry::*;
+#[cfg(feature = "color")]
+pub use render_mode::*;
+#[cfg(feature = "crossterm")]
+pub use router::*;
+pub use target::*;
+#[cfg(feature = "color")]
+pub use transition::*;
+pub use widget_event::*;
@@ -0,0 +1,283 @@
+use std::time::Duration;
+
+use crossterm::event::Event;
+use ratatui::layout::Rect;
+
+use super::{
+    AnimationGroup,
+    HandlesEvent,
+    Instant,
+    SharedClock,
+};
+
+/// A crossterm event captured by [`EventRecorder`] along with
+/// the area of the widget it was fed to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecordedEvent {
+    /// Time the event was recorded at, relative to the first
+    /// recorded event.
+    pub at: Duration,
+    pub area: Rect,
+    pub event: Event,
+}
+
+/// Records the crossterm events fed to widgets with their
+/// timestamps, so the interaction can be replayed later with
+/// a manually advanced clock, e.g. to reproduce a bug or to
+/// turn a manual session into a deterministic test.
+///
+/// During replay, the time between the events is reproduced by
+/// advancing an [`AnimationGroup`], so widgets given the clock
+/// of the group observe the same delays as during recording.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use caponata_common::{
+///     AnimationGroup,
+///     EventRecorder,
+///     HandlesEvent,
+///     Instant,
+///     SharedClock,
+/// };
+/// use crossterm::event::{
+///     Event,
+///     KeyCode,
+///     KeyEvent,
+/// };
+/// use ratatui::layout::Rect;
+///
+/// /// Reports the time between consecutive key presses.
+/// struct Stopwatch {
+///     clock: SharedClock,
+///     pressed_at: Option<Instant>,
+/// }
+///
+/// impl HandlesEvent for Stopwatch {
+///     type Event = Duration;
+///
+///     fn handle_event(
+///         &mut self,
+///         _event: Event,
+///         _area: Rect,
+///     ) -> Option<Duration> {
+///         let now = self.clock.now();
+///         let pressed_at = self.pressed_at.replace(now)?;
+///         Some(now - pressed_at)
+///     }
+/// }
+///
+/// let group = AnimationGroup::new();
+/// let mut recorder = EventRecorder::new();
+/// recorder.set_clock(group.clock());
+///
+/// let area = Rect::new(0, 0, 10, 1);
+/// let key = Event::Key(KeyEvent::from(KeyCode::Enter));
+/// recorder.record(&key, area);
+/// group.advance(Duration::from_millis(250));
+/// recorder.record(&key, area);
+///
+/// let replay_group = AnimationGroup::new();
+/// let mut stopwatch = Stopwatch {
+///     clock: replay_group.clock(),
+///     pressed_at: None,
+/// };
+/// assert_eq!(
+///     recorder.replay_into(&mut stopwatch, &replay_group),
+///     [Duration::from_millis(250)],
+/// );
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct EventRecorder {
+    events: Vec<RecordedEvent>,
+    clock: SharedClock,
+
+    /// Time the first event was recorded at.
+    started_at: Option<Instant>,
+}
+
+impl EventRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the clock used to timestamp the events. Should
+    /// be called before recording, as the events are
+    /// timestamped relative to the first one.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+    }
+
+    /// Records provided event fed to a widget rendered in
+    /// provided area.
+    pub fn record(&mut self, event: &Event, area: Rect) {
+        let now = self.clock.now();
+        let started_at = *self.started_at.get_or_insert(now);
+
+        self.events.push(RecordedEvent {
+            at: now.saturating_duration_since(started_at),
+            area,
+            event: event.clone(),
+        });
+    }
+
+    /// Records provided event and passes it to provided widget,
+    /// returning the event the widget produced. Meant to wrap
+    /// the regular event handling while recording.
+    pub fn feed<W>(
+        &mut self,
+        widget: &mut W,
+        event: Event,
+        area: Rect,
+    ) -> Option<W::Event>
+    where
+        W: HandlesEvent + ?Sized,
+    {
+        self.record(&event, area);
+        widget.handle_event(event, area)
+    }
+
+    pub fn events(&self) -> &[RecordedEvent] {
+        &self.events
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Removes the recorded events, so the next event is
+    /// recorded at zero.
+    pub fn clear(&mut self) {
+        self.events.clear();
+        self.started_at = None;
+    }
+
+    /// Passes the recorded events to provided handler in the
+    /// order they were recorded. Before every event, provided
+    /// group is advanced by the time that passed since the
+    /// previous one, so the handler may also render widgets
+    /// between the events.
+    pub fn replay(
+        &self,
+        group: &AnimationGroup,
+        mut handler: impl FnMut(&RecordedEvent),
+    ) {
+        let mut previous_at = Duration::ZERO;
+        for recorded_event in &self.events {
+            group.advance(recorded_event.at.saturating_sub(previous_at));
+            previous_at = recorded_event.at;
+            handler(recorded_event);
+        }
+    }
+
+    /// Replays the recorded events against provided widget in
+    /// the areas they were recorded with and returns the events
+    /// the widget produced. The widget should use the clock of
+    /// provided group.
+    pub fn replay_into<W>(
+        &self,
+        widget: &mut W,
+        group: &AnimationGroup,
+    ) -> Vec<W::Event>
+    where
+        W: HandlesEvent + ?Sized,
+    {
+        let mut produced_events = Vec::new();
+        self.replay(group, |recorded_event| {
+            let event = recorded_event.event.clone();
+            if let Some(produced_event) =
+                widget.handle_event(event, recorded_event.area)
+            {
+                produced_events.push(produced_event);
+            }
+        });
+
+        produced_events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crossterm::event::{
+        Event,
+        KeyCode,
+        KeyEvent,
+    };
+    use ratatui::layout::Rect;
+
+    use super::EventRecorder;
+    use crate::{
+        AnimationGroup,
+        HandlesEvent,
+    };
+
+    /// Returns the code of every pressed key along with the
+    /// area it was pressed in.
+    struct KeyLog;
+
+    impl HandlesEvent for KeyLog {
+        type Event = (KeyCode, Rect);
+
+        fn handle_event(
+            &mut self,
+            event: Event,
+            area: Rect,
+        ) -> Option<(KeyCode, Rect)> {
+            event.as_key_press_event().map(|key| (key.code, area))
+        }
+    }
+
+    #[test]
+    fn events_are_replayed_with_recorded_delays() {
+        let group = AnimationGroup::new();
+        let mut recorder = EventRecorder::new();
+        recorder.set_clock(group.clock());
+
+        let (left, right) = (Rect::new(0, 0, 5, 1), Rect::new(5, 0, 5, 1));
+        let key = |code| Event::Key(KeyEvent::from(code));
+        group.advance(Duration::from_millis(40));
+        let produced_event =
+            recorder.feed(&mut KeyLog, key(KeyCode::Up), left);
+        assert_eq!(produced_event, Some((KeyCode::Up, left)));
+        group.advance(Duration::from_millis(100));
+        recorder.record(&Event::FocusLost, right);
+        group.advance(Duration::from_millis(50));
+        recorder.record(&key(KeyCode::Down), right);
+
+        let at: Vec<Duration> =
+            recorder.events().iter().map(|event| event.at).collect();
+        assert_eq!(
+            at,
+            [
+                Duration::ZERO,
+                Duration::from_millis(100),
+                Duration::from_millis(150),
+            ],
+        );
+
+        let replay_group = AnimationGroup::new();
+        let started_at = replay_group.now();
+        let mut replayed_at = Vec::new();
+        recorder.replay(&replay_group, |_| {
+            replayed_at.push(replay_group.now() - started_at);
+        });
+        assert_eq!(replayed_at, at);
+        assert_eq!(
+            recorder.replay_into(&mut KeyLog, &replay_group),
+            [(KeyCode::Up, left), (KeyCode::Down, right)],
+        );
+
+        recorder.clear();
+        assert!(recorder.is_empty());
+        recorder.record(&key(KeyCode::Enter), left);
+        assert_eq!(recorder.events()[0].at, Duration::ZERO);
+    }
+}
@@ -0,0 +1,239 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use ratatui::layout::{
+    Position,
+    Rect,
+};
+
+use super::AccessibleDescription;
+
+/// The area a widget was last rendered in and the state it was
+/// in, recorded by [`WidgetRegistry`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WidgetRecord {
+    pub area: Rect,
+
+    /// The accessible description of the widget at the time it
+    /// was rendered, e.g. `"button 'Save', pressed"`.
+    pub snapshot: String,
+}
+
+/// A cheaply cloneable registry of the widgets rendered with an
+/// id, allowing end-to-end tests and automation to locate
+/// widgets and assert on their state by id. Clones of the
+/// registry share the same records.
+///
+/// Widgets record themselves every time they are rendered, so
+/// the registry holds the state of the last frame.
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_common::{
+///     AccessibleDescription,
+///     Identifiable,
+///     WidgetIdentity,
+///     WidgetRegistry,
+/// };
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::{
+///         Position,
+///         Rect,
+///     },
+///     text::Line,
+///     widgets::Widget,
+/// };
+///
+/// #[derive(Default)]
+/// struct Label {
+///     text: &'static str,
+///     identity: WidgetIdentity,
+/// }
+///
+/// impl Widget for &Label {
+///     fn render(self, area: Rect, buf: &mut Buffer) {
+///         Line::from(self.text).render(area, buf);
+///         self.identity.record(area, self);
+///     }
+/// }
+///
+/// impl AccessibleDescription for Label {
+///     fn accessible_description(&self) -> String {
+///         format!("label '{}'", self.text)
+///     }
+/// }
+///
+/// impl Identifiable for Label {
+///     fn identity(&self) -> &WidgetIdentity {
+///         &self.identity
+///     }
+///
+///     fn identity_mut(&mut self) -> &mut WidgetIdentity {
+///         &mut self.identity
+///     }
+/// }
+///
+/// let registry = WidgetRegistry::new();
+/// let mut label = Label {
+///     text: "Ready",
+///     ..Label::default()
+/// };
+/// label.set_id("status");
+/// label.set_registry(registry.clone());
+///
+/// let area = Rect::new(2, 1, 5, 1);
+/// label.render(area, &mut Buffer::empty(Rect::new(0, 0, 10, 3)));
+/// assert_eq!(registry.area("status"), Some(area));
+/// assert_eq!(
+///     registry.snapshot("status").as_deref(),
+///     Some("label 'Ready'"),
+/// );
+/// assert_eq!(
+///     registry.id_at(Position::new(4, 1)).as_deref(),
+///     Some("status"),
+/// );
+/// ```
+#[derive(Default, Clone)]
+pub struct WidgetRegistry {
+    records: Arc<Mutex<HashMap<String, WidgetRecord>>>,
+}
+
+impl fmt::Debug for WidgetRegistry {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("WidgetRegistry")
+    }
+}
+
+impl PartialEq for WidgetRegistry {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.records, &other.records)
+    }
+}
+
+impl Eq for WidgetRegistry {}
+
+impl WidgetRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records provided area and snapshot for the widget with
+    /// provided id, replacing the previous record.
+    pub fn record(&self, id: &str, area: Rect, snapshot: String) {
+        let record = WidgetRecord { area, snapshot };
+        self.records.lock().unwrap().insert(id.to_owned(), record);
+    }
+
+    /// Returns the last record of the widget with provided id,
+    /// or `None` if it hasn't been rendered.
+    pub fn get(&self, id: &str) -> Option<WidgetRecord> {
+        self.records.lock().unwrap().get(id).cloned()
+    }
+
+    pub fn area(&self, id: &str) -> Option<Rect> {
+        self.get(id).map(|record| record.area)
+    }
+
+    pub fn snapshot(&self, id: &str) -> Option<String> {
+        self.get(id).map(|record| record.snapshot)
+    }
+
+    /// Returns the id of the widget whose last area contains
+    /// provided position. If several areas contain it, the
+    /// smallest one wins, so widgets rendered inside other
+    /// widgets are found first.
+    pub fn id_at(&self, position: Position) -> Option<String> {
+        self.records
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, record)| record.area.contains(position))
+            .min_by_key(|(id, record)| (record.area.area(), (*id).clone()))
+            .map(|(id, _)| id.clone())
+    }
+
+    /// Returns the ids of all the recorded widgets in
+    /// alphabetical order.
+    pub fn ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> =
+            self.records.lock().unwrap().keys().cloned().collect();
+        ids.sort();
+
+        ids
+    }
+
+    /// Removes all the records, e.g. before rendering a frame
+    /// to find out which widgets are still displayed.
+    pub fn clear(&self) {
+        self.records.lock().unwrap().clear();
+    }
+}
+
+/// An optional id of a widget and the registry the widget
+/// records itself in when rendered with an id.
+///
+/// Widgets hold the identity in a field and expose it through
+/// [`Identifiable`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct WidgetIdentity {
+    id: Option<String>,
+    registry: Option<WidgetRegistry>,
+}
+
+impl WidgetIdentity {
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    pub fn set_id(&mut self, id: impl Into<String>) {
+        self.id = Some(id.into());
+    }
+
+    pub fn set_registry(&mut self, registry: WidgetRegistry) {
+        self.registry = Some(registry);
+    }
+
+    /// Records provided area and the accessible description of
+    /// provided widget in the registry. Does nothing unless
+    /// both the id and the registry are set.
+    pub fn record(&self, area: Rect, widget: &impl AccessibleDescription) {
+        if let (Some(id), Some(registry)) = (&self.id, &self.registry) {
+            registry.record(id, area, widget.accessible_description());
+        }
+    }
+}
+
+/// A widget that can be given an id and recorded in a
+/// [`WidgetRegistry`] every time it is rendered.
+pub trait Identifiable: AccessibleDescription {
+    fn identity(&self) -> &WidgetIdentity;
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity;
+
+    fn id(&self) -> Option<&str> {
+        self.identity().id()
+    }
+
+    /// Sets the id the widget is recorded under in its
+    /// registry.
+    fn set_id(&mut self, id: impl Into<String>)
+    where
+        Self: Sized,
+    {
+        self.identity_mut().set_id(id);
+    }
+
+    /// Replaces the registry the widget is recorded in when it
+    /// is rendered with an id.
+    fn set_registry(&mut self, registry: WidgetRegistry) {
+        self.identity_mut().set_registry(registry);
+    }
+}
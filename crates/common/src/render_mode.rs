@@ -0,0 +1,219 @@
+use std::sync::atomic::{
+    AtomicU8,
+    Ordering,
+};
+
+use ratatui::{
+    buffer::{
+        Buffer,
+        Cell,
+    },
+    layout::Rect,
+    style::{
+        Color,
+        Modifier,
+    },
+};
+
+use super::color_to_hsl;
+
+static RENDER_MODE: AtomicU8 = AtomicU8::new(0);
+
+/// Specifies how widgets convey distinctions expressed with
+/// colors, e.g. for users who can't tell colors apart or
+/// terminals without colors.
+///
+/// Widgets consult the mode set with [`set_render_mode`] when
+/// rendering. In modes other than [`RenderMode::Normal`],
+/// colors are reduced and replaced with modifiers:
+///
+/// - light backgrounds, e.g. of highlighted items, become
+///   [`Modifier::REVERSED`] in [`RenderMode::Monochrome`];
+/// - saturated foreground colors, e.g. accents, become
+///   [`Modifier::UNDERLINED`];
+/// - light foreground colors become [`Modifier::BOLD`], and
+///   dark ones become [`Modifier::DIM`].
+///
+/// Default variant is [`RenderMode::Normal`].
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_common::RenderMode;
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     style::{
+///         Color,
+///         Modifier,
+///         Style,
+///     },
+/// };
+///
+/// let area = Rect::new(0, 0, 1, 1);
+/// let mut buf = Buffer::empty(area);
+/// buf.set_style(area, Style::new().fg(Color::Red));
+///
+/// RenderMode::Monochrome.adapt_buffer(area, &mut buf);
+/// assert_eq!(buf[(0, 0)].fg, Color::Reset);
+/// assert_eq!(buf[(0, 0)].modifier, Modifier::UNDERLINED);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RenderMode {
+    /// Colors are rendered as they are.
+    #[default]
+    Normal,
+
+    /// Colors are replaced with black and white, keeping the
+    /// difference between light and dark backgrounds, and the
+    /// foreground contrasting with the background.
+    HighContrast,
+
+    /// Colors are removed completely.
+    Monochrome,
+}
+
+impl RenderMode {
+    /// Adapts the colors and the modifier of provided cell.
+    /// Adapting the cell again may add extra modifiers, e.g.
+    /// [`Modifier::BOLD`] to a foreground made white, so each
+    /// cell should be adapted once.
+    pub fn adapt_cell(self, cell: &mut Cell) {
+        if self == Self::Normal {
+            return;
+        }
+
+        let has_light_background = color_to_hsl(cell.bg)
+            .is_some_and(|(_, _, lightness)| lightness >= 0.5);
+        if let Some((_, saturation, lightness)) = color_to_hsl(cell.fg) {
+            if saturation >= 0.5 && (0.2..=0.8).contains(&lightness) {
+                cell.modifier.insert(Modifier::UNDERLINED);
+            } else if lightness >= 0.7 {
+                cell.modifier.insert(Modifier::BOLD);
+            } else if lightness <= 0.3 && !has_light_background {
+                cell.modifier.insert(Modifier::DIM);
+            }
+        }
+
+        match self {
+            Self::HighContrast => {
+                if cell.bg != Color::Reset {
+                    cell.bg = match has_light_background {
+                        true => Color::White,
+                        false => Color::Black,
+                    };
+                }
+                if cell.fg != Color::Reset {
+                    cell.fg = match has_light_background {
+                        true => Color::Black,
+                        false => Color::White,
+                    };
+                }
+            }
+            Self::Monochrome => {
+                if has_light_background {
+                    cell.modifier.insert(Modifier::REVERSED);
+                }
+                cell.fg = Color::Reset;
+                cell.bg = Color::Reset;
+            }
+            Self::Normal => {}
+        }
+    }
+
+    /// Adapts the cells of provided area like
+    /// [`RenderMode::adapt_cell`].
+    pub fn adapt_buffer(self, area: Rect, buf: &mut Buffer) {
+        if self == Self::Normal {
+            return;
+        }
+
+        for position in area.intersection(buf.area).positions() {
+            self.adapt_cell(&mut buf[position]);
+        }
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            Self::Normal => 0,
+            Self::HighContrast => 1,
+            Self::Monochrome => 2,
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Self::HighContrast,
+            2 => Self::Monochrome,
+            _ => Self::Normal,
+        }
+    }
+}
+
+/// Sets the render mode all widgets render with. It is
+/// usually set once when the application starts.
+pub fn set_render_mode(mode: RenderMode) {
+    RENDER_MODE.store(mode.to_u8(), Ordering::Relaxed);
+}
+
+pub fn render_mode() -> RenderMode {
+    RenderMode::from_u8(RENDER_MODE.load(Ordering::Relaxed))
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{
+            Color,
+            Modifier,
+            Style,
+        },
+    };
+
+    use super::RenderMode;
+
+    #[test]
+    fn colors_are_replaced_with_modifiers() {
+        let area = Rect::new(0, 0, 3, 1);
+        let styled_buffer = || {
+            let mut buf = Buffer::empty(area);
+            buf[(0, 0)].set_style(Style::new().fg(Color::White));
+            buf[(1, 0)]
+                .set_style(Style::new().fg(Color::Black).bg(Color::Gray));
+            buf[(2, 0)].set_style(
+                Style::new().fg(Color::Rgb(40, 200, 40)).bg(Color::Black),
+            );
+            buf
+        };
+
+        let mut buf = styled_buffer();
+        RenderMode::Normal.adapt_buffer(area, &mut buf);
+        assert_eq!(buf, styled_buffer());
+
+        let mut buf = styled_buffer();
+        RenderMode::Monochrome.adapt_buffer(area, &mut buf);
+        let mut expected = Buffer::empty(area);
+        expected[(0, 0)].set_style(Modifier::BOLD);
+        expected[(1, 0)].set_style(Modifier::REVERSED);
+        expected[(2, 0)].set_style(Modifier::UNDERLINED);
+        assert_eq!(buf, expected);
+
+        let mut buf = styled_buffer();
+        RenderMode::HighContrast.adapt_buffer(area, &mut buf);
+        let mut expected = Buffer::empty(area);
+        expected[(0, 0)].set_style(
+            Style::new().fg(Color::White).add_modifier(Modifier::BOLD),
+        );
+        expected[(1, 0)]
+            .set_style(Style::new().fg(Color::Black).bg(Color::White));
+        expected[(2, 0)].set_style(
+            Style::new()
+                .fg(Color::White)
+                .bg(Color::Black)
+                .add_modifier(Modifier::UNDERLINED),
+        );
+        assert_eq!(buf, expected);
+    }
+}
@@ -0,0 +1,382 @@
+use std::fmt;
+
+use crossterm::event::{
+    Event,
+    MouseEventKind,
+};
+use ratatui::layout::{
+    Position,
+    Rect,
+};
+
+/// A widget that handles crossterm events given the area it
+/// was rendered in.
+pub trait HandlesEvent {
+    type Event;
+
+    fn handle_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<Self::Event>;
+}
+
+type Handler<'a, E> = Box<dyn FnMut(Event, Rect) -> Option<E> + 'a>;
+
+struct Route<'a, K, E> {
+    id: K,
+    area: Rect,
+    handler: Handler<'a, E>,
+}
+
+/// Dispatches crossterm events to the widgets registered
+/// along with the areas they were rendered in.
+///
+/// A mouse event is delivered to the widget whose area
+/// contains the cursor. If several areas contain it, the
+/// widget registered last wins, as it is usually rendered on
+/// top. Mouse moves are additionally delivered to all the
+/// other widgets, so they can notice the cursor leaving them.
+///
+/// The widget a mouse button was pressed over captures the
+/// mouse: drags and the release are delivered to it wherever
+/// the cursor is, so it can tell a release inside from a
+/// release outside. The capture is kept across [`clear`]
+/// calls and ends with the release.
+///
+/// Key and paste events are delivered to the focused widget,
+/// set with [`focus`], e.g. to the one a [`FocusManager`]
+/// reports as focused. The focus is kept across [`clear`]
+/// calls as well. Other events are ignored.
+///
+/// [`clear`]: EventRouter::clear
+/// [`focus`]: EventRouter::focus
+/// [`FocusManager`]: crate::FocusManager
+///
+/// Widgets producing different events can be registered in
+/// the same router as long as their events convert into the
+/// event type of the router.
+///
+/// # Example
+///
+/// ```rust
+/// use crossterm::event::{
+///     Event,
+///     KeyModifiers,
+///     MouseButton,
+///     MouseEvent,
+///     MouseEventKind,
+/// };
+/// use ratatui::layout::Rect;
+/// use caponata_common::{EventRouter, HandlesEvent};
+///
+/// struct Clickable;
+///
+/// impl HandlesEvent for Clickable {
+///     type Event = &'static str;
+///
+///     fn handle_event(
+///         &mut self,
+///         event: Event,
+///         _area: Rect,
+///     ) -> Option<&'static str> {
+///         match event {
+///             Event::Mouse(mouse_event)
+///                 if mouse_event.kind
+///                     == MouseEventKind::Down(MouseButton::Left) =>
+///             {
+///                 Some("clicked")
+///             }
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let (mut left, mut right) = (Clickable, Clickable);
+///
+/// let mut router = EventRouter::new();
+/// router.register("left", Rect::new(0, 0, 10, 1), &mut left);
+/// router.register("right", Rect::new(10, 0, 10, 1), &mut right);
+///
+/// let event = Event::Mouse(MouseEvent {
+///     kind: MouseEventKind::Down(MouseButton::Left),
+///     column: 12,
+///     row: 0,
+///     modifiers: KeyModifiers::NONE,
+/// });
+/// assert_eq!(router.dispatch(event), vec![("right", "clicked")]);
+/// ```
+pub struct EventRouter<'a, K, E> {
+    routes: Vec<Route<'a, K, E>>,
+    captured: Option<K>,
+    focused: Option<K>,
+}
+
+impl<'a, K, E> fmt::Debug for EventRouter<'a, K, E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("EventRouter")
+    }
+}
+
+impl<'a, K, E> Default for EventRouter<'a, K, E> {
+    fn default() -> Self {
+        Self {
+            routes: Vec::new(),
+            captured: None,
+            focused: None,
+        }
+    }
+}
+
+impl<'a, K: Clone + PartialEq, E> EventRouter<'a, K, E> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers provided widget rendered in provided area.
+    /// Should be called every frame, as the areas may change.
+    pub fn register<W>(&mut self, id: K, area: Rect, widget: &'a mut W)
+    where
+        W: HandlesEvent + ?Sized,
+        W::Event: Into<E>,
+    {
+        let handler = move |event: Event, area: Rect| {
+            widget.handle_event(event, area).map(Into::into)
+        };
+        self.routes.push(Route {
+            id,
+            area,
+            handler: Box::new(handler),
+        });
+    }
+
+    /// Removes all the registered widgets. The widget
+    /// capturing the mouse and the focused widget keep the
+    /// mouse and the focus if registered again.
+    pub fn clear(&mut self) {
+        self.routes.clear();
+    }
+
+    pub fn focused(&self) -> Option<&K> {
+        self.focused.as_ref()
+    }
+
+    /// Focuses the widget with provided id, so key and paste
+    /// events are delivered to it.
+    pub fn focus(&mut self, id: K) {
+        self.focused = Some(id);
+    }
+
+    /// Removes the focus, so key and paste events are ignored.
+    pub fn blur(&mut self) {
+        self.focused = None;
+    }
+
+    /// Dispatches provided event and returns the events
+    /// produced by the widgets along with their ids.
+    pub fn dispatch(&mut self, event: Event) -> Vec<(K, E)> {
+        let mouse_event = match event {
+            Event::Mouse(mouse_event) => mouse_event,
+            Event::Key(_) | Event::Paste(_) => {
+                return self.dispatch_to_focused(event);
+            }
+            _ => return Vec::new(),
+        };
+        let position = Position::new(mouse_event.column, mouse_event.row);
+        let captured_target = match mouse_event.kind {
+            MouseEventKind::Up(_) | MouseEventKind::Drag(_) => {
+                self.captured.as_ref().and_then(|captured| {
+                    self.routes.iter().position(|route| route.id == *captured)
+                })
+            }
+            _ => None,
+        };
+        let target = captured_target.or_else(|| {
+            self.routes
+                .iter()
+                .rposition(|route| route.area.contains(position))
+        });
+        match mouse_event.kind {
+            MouseEventKind::Down(_) => {
+                self.captured =
+                    target.map(|index| self.routes[index].id.clone());
+            }
+            MouseEventKind::Up(_) => self.captured = None,
+            _ => {}
+        }
+
+        let mut produced_events = Vec::new();
+        for (index, route) in self.routes.iter_mut().enumerate() {
+            let is_target = Some(index) == target;
+            if !is_target && mouse_event.kind != MouseEventKind::Moved {
+                continue;
+            }
+            if let Some(produced_event) =
+                (route.handler)(event.clone(), route.area)
+            {
+                produced_events.push((route.id.clone(), produced_event));
+            }
+        }
+
+        produced_events
+    }
+
+    fn dispatch_to_focused(&mut self, event: Event) -> Vec<(K, E)> {
+        let Some(focused) = self.focused.as_ref() else {
+            return Vec::new();
+        };
+        let Some(route) =
+            self.routes.iter_mut().find(|route| route.id == *focused)
+        else {
+            return Vec::new();
+        };
+
+        (route.handler)(event, route.area)
+            .map(|produced_event| (route.id.clone(), produced_event))
+            .into_iter()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{
+        Event,
+        KeyCode,
+        KeyEvent,
+        KeyModifiers,
+        MouseButton,
+        MouseEvent,
+        MouseEventKind,
+    };
+    use ratatui::layout::Rect;
+
+    use super::{
+        EventRouter,
+        HandlesEvent,
+    };
+
+    /// Reports the kind of every event it receives.
+    struct Recorder;
+
+    impl HandlesEvent for Recorder {
+        type Event = &'static str;
+
+        fn handle_event(
+            &mut self,
+            event: Event,
+            _area: Rect,
+        ) -> Option<&'static str> {
+            match event {
+                Event::Mouse(mouse_event) => match mouse_event.kind {
+                    MouseEventKind::Down(_) => Some("down"),
+                    MouseEventKind::Up(_) => Some("up"),
+                    MouseEventKind::Drag(_) => Some("drag"),
+                    MouseEventKind::Moved => Some("moved"),
+                    _ => None,
+                },
+                Event::Key(_) => Some("key"),
+                _ => None,
+            }
+        }
+    }
+
+    fn mouse(kind: MouseEventKind, column: u16, row: u16) -> Event {
+        Event::Mouse(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn widget_registered_last_wins() {
+        let (mut below, mut above) = (Recorder, Recorder);
+        let mut router = EventRouter::<_, &str>::new();
+        router.register("below", Rect::new(0, 0, 10, 10), &mut below);
+        router.register("above", Rect::new(5, 5, 10, 10), &mut above);
+
+        let press = MouseEventKind::Down(MouseButton::Left);
+        assert_eq!(
+            router.dispatch(mouse(press, 6, 6)),
+            vec![("above", "down")]
+        );
+        assert_eq!(
+            router.dispatch(mouse(press, 2, 2)),
+            vec![("below", "down")]
+        );
+        assert_eq!(router.dispatch(mouse(press, 20, 20)), vec![]);
+    }
+
+    #[test]
+    fn mouse_moves_are_broadcast() {
+        let (mut left, mut right) = (Recorder, Recorder);
+        let mut router = EventRouter::<_, &str>::new();
+        router.register("left", Rect::new(0, 0, 10, 1), &mut left);
+        router.register("right", Rect::new(10, 0, 10, 1), &mut right);
+
+        assert_eq!(
+            router.dispatch(mouse(MouseEventKind::Moved, 30, 5)),
+            vec![("left", "moved"), ("right", "moved")],
+        );
+    }
+
+    #[test]
+    fn key_events_reach_focused_widget() {
+        let (mut left, mut right) = (Recorder, Recorder);
+        let mut router = EventRouter::<_, &str>::new();
+        router.register("left", Rect::new(0, 0, 10, 1), &mut left);
+        router.register("right", Rect::new(10, 0, 10, 1), &mut right);
+
+        let key = Event::Key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(router.dispatch(key.clone()), vec![]);
+
+        router.focus("right");
+        assert_eq!(router.focused(), Some(&"right"));
+        assert_eq!(router.dispatch(key.clone()), vec![("right", "key")]);
+
+        router.blur();
+        assert_eq!(router.dispatch(key), vec![]);
+        assert_eq!(router.dispatch(Event::FocusGained), vec![]);
+    }
+
+    #[test]
+    fn pressed_widget_captures_mouse() {
+        let (mut left, mut right) = (Recorder, Recorder);
+        let (mut next_left, mut next_right) = (Recorder, Recorder);
+        let left_area = Rect::new(0, 0, 10, 1);
+        let right_area = Rect::new(10, 0, 10, 1);
+        let mut router = EventRouter::<_, &str>::new();
+        router.register("left", left_area, &mut left);
+        router.register("right", right_area, &mut right);
+
+        let press = MouseEventKind::Down(MouseButton::Left);
+        let drag = MouseEventKind::Drag(MouseButton::Left);
+        let release = MouseEventKind::Up(MouseButton::Left);
+        assert_eq!(
+            router.dispatch(mouse(press, 2, 0)),
+            vec![("left", "down")]
+        );
+        assert_eq!(
+            router.dispatch(mouse(drag, 12, 0)),
+            vec![("left", "drag")]
+        );
+
+        // The capture survives the widgets being registered
+        // again on the next frame.
+        router.clear();
+        router.register("left", left_area, &mut next_left);
+        router.register("right", right_area, &mut next_right);
+        assert_eq!(
+            router.dispatch(mouse(release, 12, 0)),
+            vec![("left", "up")]
+        );
+
+        // The release ends the capture.
+        assert_eq!(
+            router.dispatch(mouse(release, 12, 0)),
+            vec![("right", "up")],
+        );
+    }
+}
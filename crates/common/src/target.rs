@@ -1,12 +1,16 @@
-use std::cmp::Ordering;
+use alloc::boxed::Box;
+use core::{
+    cmp::Ordering,
+    iter,
+};
 
-use caponata_common::Callable;
+use super::Callable;
 
 type TargetCustomCallable =
     Callable<(Box<dyn Iterator<Item = u16>>,), Box<dyn Iterator<Item = u16>>>;
 
-/// Represents the selection of symbol positions to which
-/// styles should be applied to [`SmallTextWidget`].
+/// Represents the selection of symbol positions of a text to
+/// which styles should be applied.
 ///
 /// # Applying order:
 ///
@@ -68,7 +72,9 @@ pub enum Target {
     Untouched,
 }
 
-pub(crate) fn target_sorter(a: &Target, b: &Target) -> Ordering {
+/// Orders targets by their applying order, from the last
+/// applied to the first applied one.
+pub fn target_sorter(a: &Target, b: &Target) -> Ordering {
     let priority = |item: &Target| match item {
         Target::Custom(_) => 7,
         Target::Every(_) => 6,
@@ -79,5 +85,30 @@ pub(crate) fn target_sorter(a: &Target, b: &Target) -> Ordering {
         Target::Single(_) => 1,
         Target::Untouched => 0,
     };
-    priority(&a).cmp(&priority(&b))
+    priority(a).cmp(&priority(b))
+}
+
+/// Returns virtual x coordinates resolved from provided
+/// target. Panics if provided target is [`Target::Untouched`].
+pub fn resolve_target(
+    target: Target,
+    char_count: u16,
+) -> Box<dyn Iterator<Item = u16>> {
+    let all = 0..char_count;
+
+    match target {
+        Target::Single(x) => Box::new(iter::once(x)),
+        Target::Range(start, end) => Box::new(start..=end),
+        Target::Every(n) => Box::new(all.step_by(n as usize)),
+        Target::EveryFrom(n, offset) => {
+            Box::new(all.skip(offset as usize).step_by(n as usize))
+        }
+        Target::ExceptEvery(n) => Box::new(all.filter(move |x| x % n != 0)),
+        Target::ExceptEveryFrom(n, offset) => Box::new(
+            all.skip(offset as usize)
+                .filter(move |x| x + offset % n != 0),
+        ),
+        Target::Custom(callable) => callable.call((Box::new(all),)),
+        Target::Untouched => Box::new(iter::empty()),
+    }
 }
@@ -0,0 +1,200 @@
+//! Helpers for snapshot tests of widget rendering.
+//!
+//! Widgets are rendered into a [`Buffer`] with a manually
+//! advanced clock, and the buffer is converted into a stable
+//! string that can be compared with a stored snapshot, e.g.
+//! with [`insta`](https://docs.rs/insta).
+
+use std::{
+    fmt::Write,
+    time::Duration,
+};
+
+use ratatui::{
+    buffer::{
+        Buffer,
+        Cell,
+    },
+    layout::Rect,
+    style::{
+        Color,
+        Modifier,
+    },
+    widgets::Widget,
+};
+
+use crate::{
+    AnimationGroup,
+    SharedClock,
+};
+
+/// Renders widgets into a buffer of fixed size with a clock
+/// that only moves when it is advanced, so time-dependent
+/// widgets render the same frames on every run.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use caponata_common::testing::SnapshotRenderer;
+/// use ratatui::{
+///     style::Stylize,
+///     text::Span,
+/// };
+///
+/// let renderer = SnapshotRenderer::new(6, 1);
+/// // Time-dependent widgets are given the clock of the
+/// // renderer with `widget.set_clock(renderer.clock())` and
+/// // rendered by reference with `renderer.snapshot(&mut widget)`.
+/// renderer.advance(Duration::from_millis(100));
+///
+/// let snapshot = renderer.snapshot(Span::raw("hi ").bold());
+/// assert_eq!(snapshot, "\"hi    \"\n0: 0..3 +BOLD\n");
+/// ```
+#[derive(Debug, Default)]
+pub struct SnapshotRenderer {
+    area: Rect,
+    group: AnimationGroup,
+}
+
+impl SnapshotRenderer {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            area: Rect::new(0, 0, width, height),
+            group: AnimationGroup::new(),
+        }
+    }
+
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Returns the clock the widgets should be given.
+    pub fn clock(&self) -> SharedClock {
+        self.group.clock()
+    }
+
+    /// Moves the clock forward by provided duration.
+    pub fn advance(&self, duration: Duration) {
+        self.group.advance(duration);
+    }
+
+    /// Renders provided widget into an empty buffer.
+    pub fn render<W: Widget>(&self, widget: W) -> Buffer {
+        let mut buf = Buffer::empty(self.area);
+        widget.render(self.area, &mut buf);
+        buf
+    }
+
+    /// Renders provided widget into an empty buffer and
+    /// returns its [`buffer_snapshot`].
+    pub fn snapshot<W: Widget>(&self, widget: W) -> String {
+        buffer_snapshot(&self.render(widget))
+    }
+}
+
+/// Returns a string representation of provided buffer. Every
+/// row is written as its symbols in quotes, followed by the
+/// runs of cells with non-default styles, one per line:
+///
+/// ```text
+/// "hello "
+/// 0: 0..5 fg=Red bg=#102030 +BOLD+UNDERLINED
+/// ```
+///
+/// Runs are described by the row, the range of columns, the
+/// colors other than [`Color::Reset`] and the modifiers.
+pub fn buffer_snapshot(buf: &Buffer) -> String {
+    let area = buf.area;
+    let mut snapshot = String::new();
+
+    for y in area.top()..area.bottom() {
+        let cells: Vec<&Cell> =
+            (area.left()..area.right()).map(|x| &buf[(x, y)]).collect();
+        let symbols: String = cells.iter().map(|cell| cell.symbol()).collect();
+        writeln!(snapshot, "{symbols:?}").expect("string is writable");
+
+        let mut start = 0;
+        while start < cells.len() {
+            let style = style_annotation(cells[start]);
+            let end = (start..cells.len())
+                .find(|x| style_annotation(cells[*x]) != style)
+                .unwrap_or(cells.len());
+
+            if !style.is_empty() {
+                writeln!(
+                    snapshot,
+                    "{}: {}..{}{style}",
+                    y - area.top(),
+                    start,
+                    end,
+                )
+                .expect("string is writable");
+            }
+            start = end;
+        }
+    }
+
+    snapshot
+}
+
+/// Returns the compact description of the style of provided
+/// cell, or an empty string if the style is the default one.
+fn style_annotation(cell: &Cell) -> String {
+    let mut annotation = String::new();
+    if cell.fg != Color::Reset {
+        write!(annotation, " fg={}", cell.fg).expect("string is writable");
+    }
+    if cell.bg != Color::Reset {
+        write!(annotation, " bg={}", cell.bg).expect("string is writable");
+    }
+    if cell.modifier != Modifier::empty() {
+        annotation.push(' ');
+        for (name, _) in cell.modifier.iter_names() {
+            write!(annotation, "+{name}").expect("string is writable");
+        }
+    }
+
+    annotation
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{
+            Color,
+            Modifier,
+            Style,
+        },
+    };
+
+    use super::buffer_snapshot;
+
+    #[test]
+    fn buffer_is_written_with_style_runs() {
+        let mut buf = Buffer::empty(Rect::new(2, 1, 5, 2));
+        buf.set_string(2, 1, "ab", Style::new().fg(Color::Red));
+        buf.set_string(
+            4,
+            1,
+            "c",
+            Style::new()
+                .bg(Color::Rgb(16, 32, 48))
+                .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+        );
+        buf.set_string(3, 2, "\"d", Style::new());
+
+        assert_eq!(
+            buffer_snapshot(&buf),
+            concat!(
+                "\"abc  \"\n",
+                "0: 0..2 fg=Red\n",
+                "0: 2..3 bg=#102030 +BOLD+REVERSED\n",
+                "\" \\\"d  \"\n",
+            ),
+        );
+    }
+}
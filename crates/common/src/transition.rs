@@ -0,0 +1,564 @@
+use std::time::Duration;
+
+use ratatui::{
+    buffer::{
+        Buffer,
+        Cell,
+    },
+    layout::Rect,
+    style::Color,
+};
+
+use super::{
+    Animated,
+    Easing,
+    EventQueue,
+    Instant,
+    SharedClock,
+    WidgetEvent,
+    interpolate_color,
+};
+
+/// A direction in which a transition moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransitionDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// Specifies how a widget appears or disappears.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransitionKind {
+    /// The widget moves into its area, or out of it, in
+    /// provided direction.
+    Slide(TransitionDirection),
+
+    /// Colors of the widget change from provided color to
+    /// their own ones, or back. Usually it is the background
+    /// color of the surrounding area.
+    Fade(Color),
+
+    /// The widget is revealed, or covered, column by column or
+    /// row by row in provided direction.
+    Wipe(TransitionDirection),
+}
+
+/// An animation played when a widget appears or disappears.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use caponata_common::{
+///     Easing,
+///     Transition,
+///     TransitionDirection,
+///     TransitionKind,
+/// };
+///
+/// let transition = Transition::new(
+///     TransitionKind::Slide(TransitionDirection::Right),
+///     Duration::from_millis(300),
+/// )
+/// .with_easing(Easing::EaseOut);
+/// assert_eq!(transition.easing, Easing::EaseOut);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Transition {
+    pub kind: TransitionKind,
+    pub duration: Duration,
+
+    /// Default value is [`Easing::Linear`].
+    pub easing: Easing,
+}
+
+impl Transition {
+    pub fn new(kind: TransitionKind, duration: Duration) -> Self {
+        Self {
+            kind,
+            duration,
+            easing: Easing::default(),
+        }
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Applies the transition to the cells of provided area.
+    /// Visibility is in the `0.0..=1.0` range, where `1.0`
+    /// means that the widget is fully displayed. `background`
+    /// holds the cells of the area before the widget was
+    /// rendered, row by row.
+    fn apply(
+        &self,
+        visibility: f64,
+        entering: bool,
+        area: Rect,
+        background: &[Cell],
+        buf: &mut Buffer,
+    ) {
+        let hidden = 1.0 - visibility;
+        let cell_index = |x: u16, y: u16| {
+            (y - area.y) as usize * area.width as usize + (x - area.x) as usize
+        };
+
+        match self.kind {
+            TransitionKind::Fade(color) => {
+                for (x, y) in area.positions().map(|p| (p.x, p.y)) {
+                    let cell = &mut buf[(x, y)];
+                    cell.fg = interpolate_color(color, cell.fg, visibility);
+                    cell.bg = interpolate_color(color, cell.bg, visibility);
+                }
+            }
+            TransitionKind::Wipe(direction) => {
+                for (x, y) in area.positions().map(|p| (p.x, p.y)) {
+                    let (offset, length) = match direction {
+                        TransitionDirection::Right => (x - area.x, area.width),
+                        TransitionDirection::Left => {
+                            (area.right() - 1 - x, area.width)
+                        }
+                        TransitionDirection::Down => (y - area.y, area.height),
+                        TransitionDirection::Up => {
+                            (area.bottom() - 1 - y, area.height)
+                        }
+                    };
+                    // Entering widgets are revealed from the start
+                    // of the direction, exiting ones are covered
+                    // from it.
+                    let boundary = |fraction: f64| {
+                        (fraction * length as f64).round() as u16
+                    };
+                    let visible = match entering {
+                        true => offset < boundary(visibility),
+                        false => offset >= boundary(hidden),
+                    };
+                    if !visible {
+                        buf[(x, y)] = background[cell_index(x, y)].clone();
+                    }
+                }
+            }
+            TransitionKind::Slide(direction) => {
+                let rendered: Vec<Cell> =
+                    area.positions().map(|p| buf[p].clone()).collect();
+                // Entering widgets move from behind the edge
+                // opposite to the direction, exiting ones move
+                // past the edge in the direction.
+                let sign = if entering { -1 } else { 1 };
+                let shift = |length: u16| {
+                    sign * (hidden * length as f64).round() as i32
+                };
+                let (dx, dy) = match direction {
+                    TransitionDirection::Right => (shift(area.width), 0),
+                    TransitionDirection::Left => (-shift(area.width), 0),
+                    TransitionDirection::Down => (0, shift(area.height)),
+                    TransitionDirection::Up => (0, -shift(area.height)),
+                };
+
+                for (x, y) in area.positions().map(|p| (p.x, p.y)) {
+                    let source_x = x as i32 - dx;
+                    let source_y = y as i32 - dy;
+                    let inside = (area.x as i32..area.right() as i32)
+                        .contains(&source_x)
+                        && (area.y as i32..area.bottom() as i32)
+                            .contains(&source_y);
+                    buf[(x, y)] = match inside {
+                        true => rendered
+                            [cell_index(source_x as u16, source_y as u16)]
+                        .clone(),
+                        false => background[cell_index(x, y)].clone(),
+                    };
+                }
+            }
+        }
+    }
+}
+
+/// An event produced when a transition of a widget completes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransitionEvent {
+    /// The widget is fully displayed after being shown.
+    Shown,
+
+    /// The widget is no longer rendered after being hidden.
+    Hidden,
+}
+
+impl From<TransitionEvent> for WidgetEvent {
+    fn from(value: TransitionEvent) -> Self {
+        match value {
+            TransitionEvent::Shown => Self::Shown,
+            TransitionEvent::Hidden => Self::Hidden,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum TransitionPhase {
+    Shown,
+    Entering(Instant),
+    Exiting(Instant),
+    Hidden,
+}
+
+/// Visibility of a widget along with the transitions played
+/// when it changes.
+///
+/// Widgets render themselves through
+/// [`TransitionState::render`], which applies the running
+/// transition on top of their regular output and skips
+/// rendering once the widget is hidden. The blended colors
+/// are not adapted, so widgets call it from
+/// [`render_adapted`](crate::render_adapted).
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use caponata_common::{
+///     AnimationGroup,
+///     Transition,
+///     TransitionEvent,
+///     TransitionKind,
+///     TransitionState,
+/// };
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     style::Color,
+///     widgets::Widget,
+/// };
+///
+/// let group = AnimationGroup::new();
+/// let fade = Transition::new(
+///     TransitionKind::Fade(Color::Black),
+///     Duration::from_millis(100),
+/// );
+/// let mut state = TransitionState::new(None, Some(fade));
+/// state.set_clock(group.clock());
+///
+/// let area = Rect::new(0, 0, 2, 1);
+/// let mut buf = Buffer::empty(area);
+/// state.hide();
+/// group.advance(Duration::from_millis(100));
+/// state.render(area, &mut buf, |area, buf| "hi".render(area, buf));
+///
+/// assert!(!state.is_visible());
+/// assert_eq!(buf, Buffer::with_lines(["  "]));
+/// assert_eq!(state.take_event(), Some(TransitionEvent::Hidden));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransitionState {
+    enter: Option<Transition>,
+    exit: Option<Transition>,
+    phase: TransitionPhase,
+    clock: SharedClock,
+    events: EventQueue<TransitionEvent>,
+}
+
+impl Default for TransitionState {
+    fn default() -> Self {
+        Self::new(None, None)
+    }
+}
+
+impl TransitionState {
+    /// Interval between the frames of a running transition,
+    /// reported by [`Animated::time_until_next_frame`].
+    pub const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+    /// Creates a state of a visible widget with provided
+    /// transitions played by [`Self::show`] and
+    /// [`Self::hide`]. The widget appears and disappears
+    /// instantly if a transition is `None`.
+    pub fn new(enter: Option<Transition>, exit: Option<Transition>) -> Self {
+        Self {
+            enter,
+            exit,
+            phase: TransitionPhase::Shown,
+            clock: SharedClock::default(),
+            events: EventQueue::new(),
+        }
+    }
+
+    pub fn set_transitions(
+        &mut self,
+        enter: Option<Transition>,
+        exit: Option<Transition>,
+    ) {
+        self.enter = enter;
+        self.exit = exit;
+    }
+
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+    }
+
+    /// Starts displaying the widget, playing the enter
+    /// transition. An exit transition in progress is reversed
+    /// from its current point.
+    pub fn show(&mut self) {
+        let now = self.clock.now();
+        let elapsed = match self.phase {
+            TransitionPhase::Shown | TransitionPhase::Entering(_) => return,
+            TransitionPhase::Hidden => Duration::ZERO,
+            TransitionPhase::Exiting(started_at) => self
+                .exit
+                .zip(self.enter)
+                .map_or(Duration::ZERO, |(exit, enter)| {
+                    reversed_elapsed(&exit, &enter, started_at, now)
+                }),
+        };
+
+        self.phase = match self.enter {
+            Some(_) => TransitionPhase::Entering(now - elapsed),
+            None => {
+                self.events.push(TransitionEvent::Shown);
+                TransitionPhase::Shown
+            }
+        };
+    }
+
+    /// Plays the exit transition, after which the widget is no
+    /// longer rendered. An enter transition in progress is
+    /// reversed from its current point.
+    pub fn hide(&mut self) {
+        let now = self.clock.now();
+        let elapsed = match self.phase {
+            TransitionPhase::Hidden | TransitionPhase::Exiting(_) => return,
+            TransitionPhase::Shown => Duration::ZERO,
+            TransitionPhase::Entering(started_at) => self
+                .enter
+                .zip(self.exit)
+                .map_or(Duration::ZERO, |(enter, exit)| {
+                    reversed_elapsed(&enter, &exit, started_at, now)
+                }),
+        };
+
+        self.phase = match self.exit {
+            Some(_) => TransitionPhase::Exiting(now - elapsed),
+            None => {
+                self.events.push(TransitionEvent::Hidden);
+                TransitionPhase::Hidden
+            }
+        };
+    }
+
+    /// Shows or hides the widget instantly, without playing
+    /// transitions or producing events.
+    pub fn set_visible(&mut self, visible: bool) {
+        self.phase = match visible {
+            true => TransitionPhase::Shown,
+            false => TransitionPhase::Hidden,
+        };
+    }
+
+    /// Returns `false` if the widget is hidden and its exit
+    /// transition has completed.
+    pub fn is_visible(&self) -> bool {
+        self.phase != TransitionPhase::Hidden
+    }
+
+    /// Returns `true` if a transition is in progress.
+    pub fn is_transitioning(&self) -> bool {
+        matches!(
+            self.phase,
+            TransitionPhase::Entering(_) | TransitionPhase::Exiting(_)
+        )
+    }
+
+    /// Returns the time left until the running transition
+    /// completes, or `None` if there is no such transition.
+    pub fn time_until_completion(&self) -> Option<Duration> {
+        let now = self.clock.now();
+        let (transition, started_at) = self.running_transition()?;
+        let elapsed = now.saturating_duration_since(started_at);
+        Some(transition.duration.saturating_sub(elapsed))
+    }
+
+    /// Returns the time left until the next frame of the
+    /// running transition, or `None` if there is no such
+    /// transition.
+    pub fn time_until_next_frame(&self) -> Option<Duration> {
+        self.time_until_completion()
+            .map(|time| time.min(Self::FRAME_INTERVAL))
+    }
+
+    /// Renders the widget with provided function, applying the
+    /// running transition. Does nothing once the widget is
+    /// hidden.
+    pub fn render<F>(&mut self, area: Rect, buf: &mut Buffer, render: F)
+    where
+        F: FnOnce(Rect, &mut Buffer),
+    {
+        self.update();
+
+        let Some((transition, started_at)) = self.running_transition() else {
+            if self.phase == TransitionPhase::Shown {
+                render(area, buf);
+            }
+            return;
+        };
+
+        let area = area.intersection(buf.area);
+        let background: Vec<Cell> =
+            area.positions().map(|p| buf[p].clone()).collect();
+        render(area, buf);
+
+        let entering = matches!(self.phase, TransitionPhase::Entering(_));
+        let elapsed = self.clock.now().saturating_duration_since(started_at);
+        let progress = match transition.duration.is_zero() {
+            true => 1.0,
+            false => elapsed.as_secs_f64() / transition.duration.as_secs_f64(),
+        };
+        let progress = transition.easing.apply(progress);
+        let visibility = if entering { progress } else { 1.0 - progress };
+        transition.apply(visibility, entering, area, &background, buf);
+    }
+
+    pub fn drain_events(&mut self) -> impl Iterator<Item = TransitionEvent> {
+        self.events.drain()
+    }
+
+    pub fn take_event(&mut self) -> Option<TransitionEvent> {
+        self.events.take_latest()
+    }
+
+    /// Completes the running transition if its duration has
+    /// passed or the transition was removed.
+    fn update(&mut self) {
+        if self
+            .time_until_completion()
+            .is_some_and(|time| !time.is_zero())
+        {
+            return;
+        }
+
+        match self.phase {
+            TransitionPhase::Entering(_) => {
+                self.phase = TransitionPhase::Shown;
+                self.events.push(TransitionEvent::Shown);
+            }
+            TransitionPhase::Exiting(_) => {
+                self.phase = TransitionPhase::Hidden;
+                self.events.push(TransitionEvent::Hidden);
+            }
+            _ => {}
+        }
+    }
+
+    fn running_transition(&self) -> Option<(Transition, Instant)> {
+        match self.phase {
+            TransitionPhase::Entering(started_at) => {
+                Some((self.enter?, started_at))
+            }
+            TransitionPhase::Exiting(started_at) => {
+                Some((self.exit?, started_at))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Animated for TransitionState {
+    fn time_until_next_frame(&self) -> Option<Duration> {
+        TransitionState::time_until_next_frame(self)
+    }
+}
+
+/// Returns the time the reversing transition should be
+/// considered running for, so it starts at the point where the
+/// interrupted transition stopped.
+fn reversed_elapsed(
+    interrupted: &Transition,
+    reversing: &Transition,
+    started_at: Instant,
+    now: Instant,
+) -> Duration {
+    if interrupted.duration.is_zero() {
+        return Duration::ZERO;
+    }
+
+    let elapsed = now.saturating_duration_since(started_at);
+    let progress =
+        (elapsed.as_secs_f64() / interrupted.duration.as_secs_f64()).min(1.0);
+    reversing.duration.mul_f64(1.0 - progress)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        widgets::Widget,
+    };
+
+    use super::{
+        Transition,
+        TransitionDirection,
+        TransitionEvent,
+        TransitionKind,
+        TransitionState,
+    };
+    use crate::AnimationGroup;
+
+    #[test]
+    fn slide_and_wipe_transitions() {
+        let group = AnimationGroup::new();
+        let duration = Duration::from_millis(100);
+        let slide = Transition::new(
+            TransitionKind::Slide(TransitionDirection::Right),
+            duration,
+        );
+        let wipe = Transition::new(
+            TransitionKind::Wipe(TransitionDirection::Left),
+            duration,
+        );
+        let mut state = TransitionState::new(Some(slide), Some(wipe));
+        state.set_clock(group.clock());
+        state.set_visible(false);
+
+        let area = Rect::new(0, 0, 4, 1);
+        let render = |state: &mut TransitionState| {
+            let mut buf = Buffer::with_lines(["...."]);
+            state.render(area, &mut buf, |area, buf| "abcd".render(area, buf));
+            buf
+        };
+        assert_eq!(render(&mut state), Buffer::with_lines(["...."]));
+
+        state.show();
+        group.advance(Duration::from_millis(50));
+        assert_eq!(render(&mut state), Buffer::with_lines(["cd.."]));
+        assert!(state.is_transitioning());
+
+        group.advance(Duration::from_millis(50));
+        assert_eq!(render(&mut state), Buffer::with_lines(["abcd"]));
+        assert_eq!(state.take_event(), Some(TransitionEvent::Shown));
+
+        state.hide();
+        group.advance(Duration::from_millis(25));
+        assert_eq!(render(&mut state), Buffer::with_lines(["abc."]));
+
+        // Showing the widget again reverses the exit transition.
+        state.show();
+        assert_eq!(state.time_until_completion(), Some(duration / 4));
+        group.advance(duration / 4);
+        assert_eq!(render(&mut state), Buffer::with_lines(["abcd"]));
+
+        state.hide();
+        group.advance(duration);
+        assert_eq!(render(&mut state), Buffer::with_lines(["...."]));
+        assert!(!state.is_visible());
+        assert_eq!(
+            state.drain_events().collect::<Vec<_>>(),
+            vec![TransitionEvent::Shown, TransitionEvent::Hidden],
+        );
+    }
+}
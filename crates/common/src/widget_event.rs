@@ -0,0 +1,79 @@
+/// An event produced by any caponata widget, allowing
+/// applications to handle the events of different widgets in
+/// a single match or to pass them through a single channel.
+///
+/// Widget-specific events convert into this one, dropping the
+/// details that are specific to the widget. Such details are
+/// still available through the events of the widgets
+/// themselves.
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_common::WidgetEvent;
+///
+/// enum AppEvent {
+///     Widget(&'static str, WidgetEvent),
+///     Quit,
+/// }
+///
+/// fn handle(event: AppEvent) -> bool {
+///     match event {
+///         AppEvent::Widget(_, WidgetEvent::Clicked) => true,
+///         AppEvent::Widget(_, _) => false,
+///         AppEvent::Quit => false,
+///     }
+/// }
+///
+/// assert!(handle(AppEvent::Widget("save", WidgetEvent::Clicked)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WidgetEvent {
+    /// The widget was clicked with the left mouse button.
+    Clicked,
+
+    /// The widget was clicked while disabled.
+    ClickedWhileDisabled,
+
+    /// A mouse button was pressed over the widget.
+    Pressed,
+
+    /// A mouse button, pressed over the widget, was released
+    /// over it.
+    Released,
+
+    /// A mouse button, pressed over the widget, was released
+    /// outside of it.
+    ReleasedOutside,
+
+    /// The mouse cursor is over the widget.
+    Hovered,
+
+    /// The mouse cursor left the widget.
+    Unhovered,
+
+    /// An item with provided index was selected.
+    Selected(usize),
+
+    /// The selected part of the content of the widget, e.g. a
+    /// range of symbols, changed.
+    SelectionChanged,
+
+    /// The selected part of the content of the widget was
+    /// copied.
+    Copied,
+
+    /// An animation of the widget generated a new frame.
+    AnimationFrameGenerated,
+
+    /// An animation of the widget ended.
+    AnimationEnded,
+
+    /// The widget is fully displayed after its enter
+    /// transition.
+    Shown,
+
+    /// The widget stopped rendering after its exit transition.
+    Hidden,
+}
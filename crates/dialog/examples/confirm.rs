@@ -0,0 +1,142 @@
+use std::{
+    io,
+    time::Duration,
+};
+
+use crossterm::{
+    event::{
+        EnableMouseCapture,
+        Event,
+        KeyCode,
+        poll,
+        read,
+    },
+    execute,
+};
+use ratatui::{
+    DefaultTerminal,
+    layout::Rect,
+    style::{
+        Color,
+        Modifier,
+        Style,
+    },
+    text::Line,
+};
+
+use caponata_button::{
+    ButtonStateStyleBuilder,
+    ButtonStyle,
+    ButtonStyleBuilder,
+    ButtonThickness,
+};
+use caponata_dialog::{
+    DialogEvent,
+    DialogStyleBuilder,
+    DialogWidget,
+};
+use caponata_small_text::{
+    SmallTextStyleBuilder,
+    Target,
+};
+
+fn main() -> Result<(), io::Error> {
+    execute!(io::stdout(), EnableMouseCapture)?;
+
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal);
+
+    ratatui::restore();
+    result
+}
+
+fn run(terminal: &mut DefaultTerminal) -> io::Result<()> {
+    let mut dialog: Option<DialogWidget> = None;
+    let mut status = "Press 'd' to delete the file, 'q' to quit.";
+
+    let mut should_exit = false;
+    let mut area = Rect::default();
+
+    while !should_exit {
+        terminal.draw(|frame| {
+            area = frame.area();
+            frame.render_widget(
+                Line::from(status),
+                Rect::new(area.x + 1, area.y + 1, area.width - 1, 1),
+            );
+            if let Some(dialog) = dialog.as_mut() {
+                frame.render_widget(dialog, area);
+            }
+        })?;
+
+        let timeout = Duration::from_millis(100);
+        if !poll(timeout)? {
+            continue;
+        }
+        let event = read()?;
+
+        if let Some(dialog_widget) = dialog.as_mut() {
+            match dialog_widget.on_crossterm_event(event, area) {
+                Some(DialogEvent::Confirmed) => {
+                    status = "The file is deleted.";
+                    dialog = None;
+                }
+                Some(DialogEvent::Cancelled) => {
+                    status = "Deletion is cancelled.";
+                    dialog = None;
+                }
+                _ => {}
+            }
+        } else if let Event::Key(key) = event {
+            match key.code {
+                KeyCode::Char('q') => should_exit = true,
+                KeyCode::Char('d') => dialog = Some(make_dialog()),
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+fn make_dialog() -> DialogWidget<'static> {
+    let message = SmallTextStyleBuilder::default()
+        .with_text("Delete the file permanently?")
+        .for_target(Target::Untouched)
+        .set_foreground_color(Color::White)
+        .then()
+        .build();
+
+    let dialog_style = DialogStyleBuilder::default()
+        .with_title(" Confirm ")
+        .with_title_style(Style::default().add_modifier(Modifier::BOLD))
+        .with_border_style(Style::default().fg(Color::Red))
+        .with_message(message)
+        .with_confirm_button_style(make_button_style("Delete", Color::Red))
+        .with_cancel_button_style(make_button_style("Keep", Color::Blue))
+        .build()
+        .unwrap();
+    DialogWidget::new(dialog_style)
+}
+
+fn make_button_style(text: &str, color: Color) -> ButtonStyle<'_> {
+    let normal_style = ButtonStateStyleBuilder::default()
+        .with_text(text)
+        .with_text_color(Color::White)
+        .with_background_color(color)
+        .build()
+        .unwrap();
+    let hovered_style = ButtonStateStyleBuilder::default()
+        .with_text(text)
+        .with_text_color(Color::White)
+        .with_background_color(color)
+        .with_text_modifier(Modifier::BOLD)
+        .with_thickness(ButtonThickness::OneEightBlock)
+        .build()
+        .unwrap();
+
+    ButtonStyleBuilder::default()
+        .with_normal_style(normal_style)
+        .with_hovered_style(hovered_style)
+        .build()
+        .unwrap()
+}
@@ -0,0 +1,577 @@
+#[cfg(feature = "crossterm")]
+use caponata_button::ButtonEvent;
+use caponata_button::ButtonWidget;
+use caponata_common::{
+    AccessibleDescription,
+    Identifiable,
+    WidgetIdentity,
+    describe_widget,
+    render_adapted,
+};
+#[cfg(feature = "crossterm")]
+use caponata_common::{
+    EventQueue,
+    Focusable,
+};
+use caponata_small_text::SmallTextWidget;
+#[cfg(feature = "crossterm")]
+use crossterm::event::{
+    Event,
+    KeyCode,
+    KeyEvent,
+    KeyEventKind,
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::{
+        Constraint,
+        Flex,
+        Layout,
+        Margin,
+        Position,
+        Rect,
+    },
+    widgets::{
+        Block,
+        Clear,
+        Widget,
+    },
+};
+
+#[cfg(feature = "crossterm")]
+use super::DialogEvent;
+use super::{
+    DialogButton,
+    DialogStyle,
+};
+
+/// Height of the dialog window, including borders.
+const WINDOW_HEIGHT: u16 = 8;
+
+/// Areas of the parts of a [`DialogWidget`].
+struct DialogLayout {
+    window: Rect,
+    message: Rect,
+    confirm_button: Rect,
+    cancel_button: Rect,
+}
+
+/// A modal widget that asks to confirm or cancel an action.
+/// The dialog window is centered in the provided area, while
+/// the rest of the area is dimmed.
+///
+/// Focus is moved between the buttons with `Tab`, `BackTab`,
+/// `Left` and `Right`, the focused button is activated with
+/// `Enter`, and `Esc` cancels the dialog. The focused button
+/// is displayed as hovered.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DialogWidget<'a> {
+    style: DialogStyle<'a>,
+    message: SmallTextWidget,
+    confirm_button: ButtonWidget<'a>,
+    cancel_button: ButtonWidget<'a>,
+    focus: DialogButton,
+
+    /// Events returned by [`DialogWidget::on_crossterm_event`]
+    /// or produced by keys delivered through [`Focusable`] that
+    /// have not been drained yet.
+    #[cfg(feature = "crossterm")]
+    events: EventQueue<DialogEvent>,
+    identity: WidgetIdentity,
+}
+
+impl<'a> Widget for &mut DialogWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        render_adapted(area, buf, |buf| {
+            buf.set_style(area, self.style.dim_style);
+
+            let layout = self.layout(area);
+            Clear.render(layout.window, buf);
+            Block::bordered()
+                .title(self.style.title)
+                .title_style(self.style.title_style)
+                .border_style(self.style.border_style)
+                .style(self.style.style)
+                .render(layout.window, buf);
+
+            self.message.render(layout.message, buf);
+            self.confirm_button.render(layout.confirm_button, buf);
+            self.cancel_button.render(layout.cancel_button, buf);
+        });
+        self.identity.record(area, &*self);
+    }
+}
+
+impl<'a> DialogWidget<'a> {
+    pub fn new(style: DialogStyle<'a>) -> Self {
+        let mut dialog = Self {
+            message: SmallTextWidget::new(style.message.clone()),
+            confirm_button: ButtonWidget::new(
+                style.confirm_button_style.clone(),
+            ),
+            cancel_button: ButtonWidget::new(
+                style.cancel_button_style.clone(),
+            ),
+            focus: style.initial_focus,
+            style,
+            #[cfg(feature = "crossterm")]
+            events: EventQueue::new(),
+            identity: WidgetIdentity::default(),
+        };
+        dialog.set_focus(dialog.focus);
+
+        dialog
+    }
+
+    pub fn focus(&self) -> DialogButton {
+        self.focus
+    }
+
+    /// Moves focus to provided button, displaying it as
+    /// hovered.
+    pub fn set_focus(&mut self, button: DialogButton) {
+        self.focus = button;
+
+        match button {
+            DialogButton::Confirm => {
+                self.confirm_button.hover();
+                self.cancel_button.unhover();
+            }
+            DialogButton::Cancel => {
+                self.cancel_button.hover();
+                self.confirm_button.unhover();
+            }
+        }
+    }
+
+    /// Returns the position of the text of the focused button
+    /// if the dialog is rendered in provided area. Pass it to
+    /// `Frame::set_cursor_position` to display the terminal
+    /// cursor on the focused button.
+    pub fn cursor_position(&self, area: Rect) -> Option<Position> {
+        let layout = self.layout(area);
+
+        match self.focus {
+            DialogButton::Confirm => {
+                self.confirm_button.cursor_position(layout.confirm_button)
+            }
+            DialogButton::Cancel => {
+                self.cancel_button.cursor_position(layout.cancel_button)
+            }
+        }
+    }
+
+    fn layout(&self, area: Rect) -> DialogLayout {
+        let [window] =
+            Layout::horizontal([Constraint::Length(self.style.width)])
+                .flex(Flex::Center)
+                .areas(area);
+        let [window] = Layout::vertical([Constraint::Length(WINDOW_HEIGHT)])
+            .flex(Flex::Center)
+            .areas(window);
+
+        let inner = window.inner(Margin::new(2, 1));
+        let [_, message_row, buttons_row] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(3),
+        ])
+        .areas(inner);
+
+        let message_width = self.message.symbols().len() as u16;
+        let [message] =
+            Layout::horizontal([Constraint::Length(message_width)])
+                .flex(Flex::Center)
+                .areas(message_row);
+
+        let [confirm_button, cancel_button] =
+            Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)])
+                .spacing(2)
+                .areas(buttons_row);
+
+        DialogLayout {
+            window,
+            message,
+            confirm_button,
+            cancel_button,
+        }
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl<'a> DialogWidget<'a> {
+    /// Handles provided event as if the dialog was rendered in
+    /// provided area. Mouse events outside of the dialog
+    /// window are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use crossterm::event::{Event, KeyCode, KeyEvent};
+    /// use ratatui::layout::Rect;
+    /// use caponata_dialog::{
+    ///     DialogButton,
+    ///     DialogEvent,
+    ///     DialogStyleBuilder,
+    ///     DialogWidget,
+    /// };
+    ///
+    /// let dialog_style = DialogStyleBuilder::default()
+    ///     .with_title("Quit")
+    ///     .build()
+    ///     .unwrap();
+    /// let mut dialog = DialogWidget::new(dialog_style);
+    /// assert_eq!(dialog.focus(), DialogButton::Cancel);
+    ///
+    /// let area = Rect::new(0, 0, 80, 24);
+    /// let tab = Event::Key(KeyEvent::from(KeyCode::Tab));
+    /// let enter = Event::Key(KeyEvent::from(KeyCode::Enter));
+    ///
+    /// assert_eq!(dialog.on_crossterm_event(tab, area), None);
+    /// assert_eq!(dialog.focus(), DialogButton::Confirm);
+    /// assert_eq!(
+    ///     dialog.on_crossterm_event(enter, area),
+    ///     Some(DialogEvent::Confirmed),
+    /// );
+    /// ```
+    pub fn on_crossterm_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<DialogEvent> {
+        let dialog_event = self.handle_crossterm_event(event, area);
+        if let Some(dialog_event) = dialog_event {
+            self.events.push(dialog_event);
+        }
+
+        dialog_event
+    }
+
+    /// Returns the events returned by
+    /// [`DialogWidget::on_crossterm_event`] or produced by keys
+    /// delivered through [`Focusable`] since they were last
+    /// drained, from the oldest to the most recent one.
+    pub fn drain_events(&mut self) -> impl Iterator<Item = DialogEvent> {
+        self.events.drain()
+    }
+
+    fn handle_crossterm_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<DialogEvent> {
+        match event {
+            Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                self.on_key_pressed(key_event.code)
+            }
+            Event::Mouse(_) => self.on_mouse_event(event, area),
+            _ => None,
+        }
+    }
+
+    /// Returns boolean flag indicating whether provided key
+    /// moves the focus between the buttons or activates one.
+    fn handles_key(key_code: KeyCode) -> bool {
+        matches!(
+            key_code,
+            KeyCode::Tab
+                | KeyCode::BackTab
+                | KeyCode::Left
+                | KeyCode::Right
+                | KeyCode::Enter
+                | KeyCode::Esc
+        )
+    }
+
+    fn on_key_pressed(&mut self, key_code: KeyCode) -> Option<DialogEvent> {
+        match key_code {
+            KeyCode::Tab
+            | KeyCode::BackTab
+            | KeyCode::Left
+            | KeyCode::Right => {
+                self.set_focus(self.focus.next());
+                None
+            }
+            KeyCode::Enter => Some(self.activate(self.focus)),
+            KeyCode::Esc => Some(DialogEvent::Cancelled),
+            _ => None,
+        }
+    }
+
+    fn on_mouse_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<DialogEvent> {
+        let layout = self.layout(area);
+        let confirm_event = self
+            .confirm_button
+            .on_crossterm_event(event.clone(), layout.confirm_button);
+        let cancel_event = self
+            .cancel_button
+            .on_crossterm_event(event, layout.cancel_button);
+
+        let button_events = [
+            (DialogButton::Confirm, confirm_event),
+            (DialogButton::Cancel, cancel_event),
+        ];
+
+        for (button, button_event) in button_events {
+            if button_event == Some(ButtonEvent::Clicked) {
+                return Some(self.activate(button));
+            }
+        }
+        let hovered_button =
+            button_events
+                .into_iter()
+                .find_map(|(button, button_event)| match button_event {
+                    Some(ButtonEvent::Hovered(_)) => Some(button),
+                    _ => None,
+                });
+
+        // The focused button may have been unhovered by the mouse
+        // cursor leaving it, so the focus is always restored.
+        self.set_focus(hovered_button.unwrap_or(self.focus));
+        None
+    }
+
+    fn activate(&mut self, button: DialogButton) -> DialogEvent {
+        self.set_focus(button);
+
+        match button {
+            DialogButton::Confirm => DialogEvent::Confirmed,
+            DialogButton::Cancel => DialogEvent::Cancelled,
+        }
+    }
+}
+
+/// A focused dialog displays its focused button as hovered
+/// and handles the keys handled by
+/// [`DialogWidget::on_crossterm_event`]. The produced events
+/// are kept for [`DialogWidget::drain_events`].
+#[cfg(feature = "crossterm")]
+impl<'a> Focusable for DialogWidget<'a> {
+    fn set_focused(&mut self, is_focused: bool) {
+        if is_focused {
+            self.set_focus(self.focus);
+        } else {
+            self.confirm_button.unhover();
+            self.cancel_button.unhover();
+        }
+    }
+
+    fn handle_key_event(&mut self, event: KeyEvent) -> bool {
+        if event.kind != KeyEventKind::Press || !Self::handles_key(event.code)
+        {
+            return false;
+        }
+
+        if let Some(dialog_event) = self.on_key_pressed(event.code) {
+            self.events.push(dialog_event);
+        }
+        true
+    }
+
+    /// Returns the cursor position of the focused button.
+    fn cursor_position(&self, area: Rect) -> Option<Position> {
+        let layout = self.layout(area);
+        match self.focus {
+            DialogButton::Confirm => {
+                self.confirm_button.cursor_position(layout.confirm_button)
+            }
+            DialogButton::Cancel => {
+                self.cancel_button.cursor_position(layout.cancel_button)
+            }
+        }
+    }
+}
+
+impl<'a> AccessibleDescription for DialogWidget<'a> {
+    /// Describes the title, the message and the focused
+    /// button, e.g. `"dialog 'Confirm', text 'Delete?', focus
+    /// on button 'No', hovered"`.
+    fn accessible_description(&self) -> String {
+        let focused_button = match self.focus {
+            DialogButton::Confirm => &self.confirm_button,
+            DialogButton::Cancel => &self.cancel_button,
+        };
+        let message = self.message.accessible_description();
+        let focus =
+            format!("focus on {}", focused_button.accessible_description());
+
+        describe_widget(
+            "dialog",
+            Some(self.style.title.trim()),
+            [message.as_str(), focus.as_str()],
+        )
+    }
+}
+
+impl<'a> Identifiable for DialogWidget<'a> {
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use caponata_button::{
+        ButtonStateStyleBuilder,
+        ButtonStyle,
+        ButtonStyleBuilder,
+    };
+    use caponata_small_text::{
+        SmallTextStyleBuilder,
+        Target,
+    };
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        style::Color,
+        widgets::Widget,
+    };
+
+    use super::DialogWidget;
+    use crate::{
+        DialogButton,
+        DialogStyleBuilder,
+    };
+
+    fn button_style(text: &str) -> ButtonStyle<'_> {
+        let normal_style = ButtonStateStyleBuilder::default()
+            .with_text(text)
+            .build()
+            .unwrap();
+        let hovered_style = ButtonStateStyleBuilder::default()
+            .with_text(text)
+            .with_background_color(Color::Blue)
+            .build()
+            .unwrap();
+        ButtonStyleBuilder::default()
+            .with_normal_style(normal_style)
+            .with_hovered_style(hovered_style)
+            .build()
+            .unwrap()
+    }
+
+    fn dialog() -> DialogWidget<'static> {
+        let message = SmallTextStyleBuilder::default()
+            .with_text("Quit?")
+            .for_target(Target::Untouched)
+            .then()
+            .build();
+        let style = DialogStyleBuilder::default()
+            .with_title("Exit")
+            .with_message(message)
+            .with_confirm_button_style(button_style("Yes"))
+            .with_cancel_button_style(button_style("No"))
+            .with_width(20u16)
+            .build()
+            .unwrap();
+        DialogWidget::new(style)
+    }
+
+    #[test]
+    fn focused_button_is_highlighted() {
+        let mut dialog = dialog();
+        assert_eq!(dialog.focus(), DialogButton::Cancel);
+
+        let area = Rect::new(0, 0, 20, 8);
+        let mut buf = Buffer::empty(area);
+        dialog.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "┌");
+        assert_eq!(buf[(8, 2)].symbol(), "Q");
+        assert_eq!(buf[(4, 4)].symbol(), "Y");
+        assert_eq!(buf[(4, 4)].bg, Color::Reset);
+        assert_eq!(buf[(13, 4)].symbol(), "N");
+        assert_eq!(buf[(13, 4)].bg, Color::Blue);
+
+        dialog.set_focus(DialogButton::Confirm);
+        dialog.render(area, &mut buf);
+        assert_eq!(buf[(4, 4)].bg, Color::Blue);
+        assert_eq!(buf[(13, 4)].bg, Color::Reset);
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn buttons_are_activated_with_keys_and_clicks() {
+        use crossterm::event::{
+            Event,
+            KeyCode,
+            KeyEvent,
+            KeyModifiers,
+            MouseButton,
+            MouseEvent,
+            MouseEventKind,
+        };
+
+        use crate::DialogEvent;
+
+        let key = |code| Event::Key(KeyEvent::from(code));
+        let mut dialog = dialog();
+        let area = Rect::new(0, 0, 20, 8);
+
+        assert_eq!(
+            dialog.on_crossterm_event(key(KeyCode::Enter), area),
+            Some(DialogEvent::Cancelled),
+        );
+        dialog.on_crossterm_event(key(KeyCode::Right), area);
+        assert_eq!(
+            dialog.on_crossterm_event(key(KeyCode::Enter), area),
+            Some(DialogEvent::Confirmed),
+        );
+        assert_eq!(
+            dialog.on_crossterm_event(key(KeyCode::Esc), area),
+            Some(DialogEvent::Cancelled),
+        );
+
+        let mouse = |kind| {
+            Event::Mouse(MouseEvent {
+                kind,
+                column: 13,
+                row: 4,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        let press = mouse(MouseEventKind::Down(MouseButton::Left));
+        let release = mouse(MouseEventKind::Up(MouseButton::Left));
+        assert_eq!(dialog.on_crossterm_event(press, area), None);
+        assert_eq!(
+            dialog.on_crossterm_event(release, area),
+            Some(DialogEvent::Cancelled),
+        );
+        assert_eq!(dialog.focus(), DialogButton::Cancel);
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn focused_dialog_keeps_key_events() {
+        use caponata_common::Focusable;
+        use crossterm::event::{
+            KeyCode,
+            KeyEvent,
+        };
+        use ratatui::layout::Position;
+
+        use crate::DialogEvent;
+
+        let mut dialog = dialog();
+        let area = Rect::new(0, 0, 20, 8);
+
+        dialog.set_focused(true);
+        assert!(dialog.handle_key_event(KeyEvent::from(KeyCode::Left)));
+        assert!(dialog.handle_key_event(KeyEvent::from(KeyCode::Enter)));
+        assert!(!dialog.handle_key_event(KeyEvent::from(KeyCode::Char('x'))));
+        assert_eq!(dialog.focus(), DialogButton::Confirm);
+        assert_eq!(
+            dialog.drain_events().collect::<Vec<_>>(),
+            [DialogEvent::Confirmed]
+        );
+        assert_eq!(dialog.cursor_position(area), Some(Position::new(4, 4)),);
+    }
+}
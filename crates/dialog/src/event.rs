@@ -0,0 +1,12 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DialogEvent {
+    /// Triggered when the confirm button of a [`DialogWidget`]
+    /// is clicked or activated with the keyboard.
+    Confirmed,
+
+    /// Triggered when the cancel button of a [`DialogWidget`]
+    /// is clicked or activated with the keyboard, or when
+    /// `Esc` is pressed.
+    Cancelled,
+}
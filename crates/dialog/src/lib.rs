@@ -0,0 +1,11 @@
+#![doc = include_str!("../README.md")]
+
+pub mod dialog;
+#[cfg(feature = "crossterm")]
+pub mod event;
+pub mod style;
+
+pub use dialog::*;
+#[cfg(feature = "crossterm")]
+pub use event::*;
+pub use style::*;
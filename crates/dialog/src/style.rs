@@ -0,0 +1,126 @@
+use caponata_button::ButtonStyle;
+use caponata_small_text::SmallTextStyle;
+use derive_builder::Builder;
+use ratatui::style::{
+    Color,
+    Modifier,
+    Style,
+};
+
+/// A button of a [`DialogWidget`].
+///
+/// Default variant is [`DialogButton::Cancel`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DialogButton {
+    Confirm,
+
+    #[default]
+    Cancel,
+}
+
+impl DialogButton {
+    /// Returns the button that receives focus after this one.
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Confirm => Self::Cancel,
+            Self::Cancel => Self::Confirm,
+        }
+    }
+}
+
+/// Styling configuration for a [`DialogWidget`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use caponata_button::{ButtonStateStyleBuilder, ButtonStyleBuilder};
+/// use caponata_small_text::{SmallTextStyleBuilder, Target};
+/// use caponata_dialog::{DialogButton, DialogStyleBuilder};
+///
+/// let message = SmallTextStyleBuilder::default()
+///     .with_text("Delete the file?")
+///     .for_target(Target::Untouched)
+///     .set_foreground_color(Color::White)
+///     .then()
+///     .build();
+/// let confirm_button_style = ButtonStyleBuilder::default()
+///     .with_normal_style(
+///         ButtonStateStyleBuilder::default()
+///             .with_text("Yes")
+///             .with_background_color(Color::Red)
+///             .build()
+///             .unwrap(),
+///     )
+///     .build()
+///     .unwrap();
+///
+/// let dialog_style = DialogStyleBuilder::default()
+///     .with_title(" Confirm ")
+///     .with_message(message)
+///     .with_confirm_button_style(confirm_button_style)
+///     .with_border_style(Style::default().fg(Color::Red))
+///     .with_initial_focus(DialogButton::Confirm)
+///     .with_width(30u16)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(prefix = "with", into))]
+pub struct DialogStyle<'a> {
+    #[builder(default = "\"\"")]
+    pub(crate) title: &'a str,
+
+    #[builder(default)]
+    pub(crate) title_style: Style,
+
+    #[builder(default)]
+    pub(crate) border_style: Style,
+
+    /// Style of the dialog window.
+    #[builder(default)]
+    pub(crate) style: Style,
+
+    /// Style applied to the whole area behind the dialog
+    /// window.
+    #[builder(
+        default = "Style::default().fg(Color::DarkGray).add_modifier(Modifier::DIM)"
+    )]
+    pub(crate) dim_style: Style,
+
+    #[builder(default)]
+    pub(crate) message: SmallTextStyle<'a>,
+
+    #[builder(default)]
+    pub(crate) confirm_button_style: ButtonStyle<'a>,
+
+    #[builder(default)]
+    pub(crate) cancel_button_style: ButtonStyle<'a>,
+
+    /// Button focused when the dialog is created.
+    #[builder(default)]
+    pub(crate) initial_focus: DialogButton,
+
+    /// Width of the dialog window, including borders.
+    #[builder(default = "40")]
+    pub(crate) width: u16,
+}
+
+impl<'a> Default for DialogStyle<'a> {
+    fn default() -> Self {
+        Self {
+            title: "",
+            title_style: Style::default(),
+            border_style: Style::default(),
+            style: Style::default(),
+            dim_style: Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::DIM),
+            message: SmallTextStyle::default(),
+            confirm_button_style: ButtonStyle::default(),
+            cancel_button_style: ButtonStyle::default(),
+            initial_focus: DialogButton::default(),
+            width: 40,
+        }
+    }
+}
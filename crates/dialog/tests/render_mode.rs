@@ -0,0 +1,77 @@
+//! The render mode is global, so the dialog is rendered in a
+//! separate test binary, not affecting the other tests.
+
+use caponata_button::{
+    ButtonStateStyleBuilder,
+    ButtonStyle,
+    ButtonStyleBuilder,
+};
+use caponata_common::{
+    RenderMode,
+    set_render_mode,
+};
+use caponata_dialog::{
+    DialogStyleBuilder,
+    DialogWidget,
+};
+use caponata_small_text::SmallTextStyleBuilder;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{
+        Color,
+        Modifier,
+    },
+    widgets::Widget,
+};
+
+fn button_style(text: &str) -> ButtonStyle<'_> {
+    let normal_style = ButtonStateStyleBuilder::default()
+        .with_text(text)
+        .with_text_color(Color::Red)
+        .build()
+        .unwrap();
+    let hovered_style = ButtonStateStyleBuilder::default()
+        .with_text(text)
+        .with_text_color(Color::Red)
+        .with_background_color(Color::Blue)
+        .build()
+        .unwrap();
+    ButtonStyleBuilder::default()
+        .with_normal_style(normal_style)
+        .with_hovered_style(hovered_style)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn button_cells_are_adapted_once() {
+    let message = SmallTextStyleBuilder::default().with_text("Quit?").build();
+    let style = DialogStyleBuilder::default()
+        .with_title("Exit")
+        .with_message(message)
+        .with_confirm_button_style(button_style("Yes"))
+        .with_cancel_button_style(button_style("No"))
+        .with_width(20u16)
+        .build()
+        .unwrap();
+    let mut dialog = DialogWidget::new(style);
+
+    set_render_mode(RenderMode::HighContrast);
+    let area = Rect::new(0, 0, 20, 8);
+    let mut buf = Buffer::empty(area);
+    dialog.render(area, &mut buf);
+
+    // The saturated text is underlined, and the focused button
+    // is marked as hovered, not as pressed.
+    let confirm_cell = &buf[(4, 4)];
+    assert_eq!(confirm_cell.symbol(), "Y");
+    assert_eq!(confirm_cell.fg, Color::White);
+    assert_eq!(confirm_cell.modifier, Modifier::UNDERLINED);
+
+    let cancel_cell = &buf[(13, 4)];
+    assert_eq!(cancel_cell.symbol(), "N");
+    assert_eq!(cancel_cell.fg, Color::White);
+    assert_eq!(cancel_cell.bg, Color::Black);
+    assert_eq!(cancel_cell.modifier, Modifier::UNDERLINED);
+}
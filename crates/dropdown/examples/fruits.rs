@@ -0,0 +1,142 @@
+use std::{
+    io,
+    time::Duration,
+};
+
+use crossterm::{
+    event::{
+        EnableMouseCapture,
+        Event,
+        KeyCode,
+        poll,
+        read,
+    },
+    execute,
+};
+use ratatui::{
+    DefaultTerminal,
+    layout::Rect,
+    style::{
+        Color,
+        Modifier,
+        Style,
+    },
+    text::Line,
+};
+
+use caponata_button::{
+    ButtonStateStyleBuilder,
+    ButtonStyleBuilder,
+};
+use caponata_dropdown::{
+    DropdownEvent,
+    DropdownStyleBuilder,
+    DropdownWidget,
+};
+
+const FRUITS: [&str; 7] = [
+    "Apple", "Banana", "Cherry", "Grape", "Lemon", "Mango", "Orange",
+];
+
+fn main() -> Result<(), io::Error> {
+    execute!(io::stdout(), EnableMouseCapture)?;
+
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal);
+
+    ratatui::restore();
+    result
+}
+
+fn run(terminal: &mut DefaultTerminal) -> io::Result<()> {
+    let mut dropdown = make_dropdown();
+
+    let mut should_exit = false;
+    let mut widget_area = Rect::default();
+    let mut last_event: Option<DropdownEvent> = None;
+
+    while !should_exit {
+        terminal.draw(|frame| {
+            let area = frame.area();
+            let status = format!("Last event: {:?}", last_event);
+            frame.render_widget(
+                Line::from(status),
+                Rect::new(area.x + 1, area.y, area.width - 1, 1),
+            );
+
+            widget_area = Rect::new(area.x + 1, area.y + 2, 20, 1);
+            frame.render_widget(&mut dropdown, widget_area);
+        })?;
+        (should_exit, last_event) =
+            handle_event(&mut dropdown, widget_area, last_event)?;
+    }
+    Ok(())
+}
+
+fn make_dropdown() -> DropdownWidget<'static> {
+    let normal_button_style = ButtonStateStyleBuilder::default()
+        .with_text_color(Color::White)
+        .with_background_color(Color::Blue)
+        .build()
+        .unwrap();
+    let hovered_button_style = ButtonStateStyleBuilder::default()
+        .with_text_color(Color::White)
+        .with_background_color(Color::LightBlue)
+        .build()
+        .unwrap();
+    let pressed_button_style = ButtonStateStyleBuilder::default()
+        .with_text_color(Color::White)
+        .with_background_color(Color::LightBlue)
+        .with_text_modifier(Modifier::BOLD)
+        .build()
+        .unwrap();
+    let button_style = ButtonStyleBuilder::default()
+        .with_normal_style(normal_button_style)
+        .with_hovered_style(hovered_button_style)
+        .with_pressed_style(pressed_button_style)
+        .build()
+        .unwrap();
+
+    let dropdown_style = DropdownStyleBuilder::default()
+        .with_button_style(button_style)
+        .with_options(FRUITS.to_vec())
+        .with_placeholder("Pick a fruit")
+        .with_option_style(Style::default().fg(Color::White).bg(Color::Black))
+        .with_selected_option_style(Style::default().fg(Color::LightBlue))
+        .with_highlighted_option_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        )
+        .with_max_visible_options(4u16)
+        .build()
+        .unwrap();
+    DropdownWidget::new(dropdown_style)
+}
+
+fn handle_event(
+    dropdown: &mut DropdownWidget,
+    widget_area: Rect,
+    last_event: Option<DropdownEvent>,
+) -> io::Result<(bool, Option<DropdownEvent>)> {
+    let timeout = Duration::from_millis(100);
+
+    let mut last_event = last_event;
+    let mut should_exit = false;
+
+    if poll(timeout)? {
+        let event = read()?;
+
+        if let Event::Key(key) = event
+            && key.code == KeyCode::Char('q')
+        {
+            should_exit = true;
+        } else if let Some(dropdown_event) =
+            dropdown.on_crossterm_event(event, widget_area)
+        {
+            last_event = Some(dropdown_event);
+        }
+    }
+
+    Ok((should_exit, last_event))
+}
@@ -0,0 +1,680 @@
+#[cfg(feature = "crossterm")]
+use caponata_button::ButtonEvent;
+use caponata_button::{
+    ButtonStatus,
+    ButtonWidget,
+};
+#[cfg(feature = "crossterm")]
+use caponata_common::Focusable;
+use caponata_common::{
+    AccessibleDescription,
+    Identifiable,
+    WidgetIdentity,
+    describe_widget,
+    render_adapted,
+};
+#[cfg(feature = "crossterm")]
+use crossterm::event::{
+    Event,
+    KeyCode,
+    KeyEvent,
+    KeyEventKind,
+    MouseButton,
+    MouseEvent,
+    MouseEventKind,
+};
+#[cfg(feature = "crossterm")]
+use ratatui::layout::Position;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{
+        Clear,
+        Widget,
+    },
+};
+
+#[cfg(feature = "crossterm")]
+use super::DropdownEvent;
+use super::DropdownStyle;
+
+/// A widget that displays a button showing the selected option
+/// and a list of options that pops up below the button when
+/// it is clicked.
+///
+/// # Important
+///
+/// The option list is rendered directly below the provided
+/// area, so the area should only cover the button.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     widgets::Widget,
+/// };
+/// use caponata_dropdown::{
+///     DropdownStyleBuilder,
+///     DropdownWidget,
+/// };
+///
+/// let dropdown_style = DropdownStyleBuilder::default()
+///     .with_options(vec!["Apple", "Banana", "Cherry"])
+///     .with_placeholder("Fruit")
+///     .build()
+///     .unwrap();
+/// let mut dropdown = DropdownWidget::new(dropdown_style);
+///
+/// let area = Rect::new(0, 0, 10, 4);
+/// let mut buf = Buffer::empty(area);
+///
+/// dropdown.open();
+/// dropdown.render(Rect::new(0, 0, 10, 1), &mut buf);
+///
+/// let expected_buf = Buffer::with_lines(vec![
+///     "  Fruit   ",
+///     " Apple    ",
+///     " Banana   ",
+///     " Cherry   ",
+/// ]);
+/// assert_eq!(buf, expected_buf);
+///
+/// dropdown.select(Some(1));
+/// assert_eq!(dropdown.selected(), Some(1));
+/// assert!(!dropdown.is_open());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DropdownWidget<'a> {
+    style: DropdownStyle<'a>,
+    button: ButtonWidget<'a>,
+    selected: Option<usize>,
+    highlighted: usize,
+
+    /// Index of the first option displayed in the option list.
+    offset: usize,
+    is_open: bool,
+    is_focused: bool,
+    identity: WidgetIdentity,
+}
+
+impl<'a> Widget for &mut DropdownWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.button.render(area, buf);
+        self.identity.record(area, &*self);
+
+        if !self.is_open {
+            return;
+        }
+        let list_area = self.list_area(area).intersection(buf.area);
+        if list_area.is_empty() {
+            return;
+        }
+        self.scroll_to_highlighted(list_area.height as usize);
+
+        // The list is below the button, so the cells adapted by
+        // the button are not adapted again.
+        render_adapted(list_area, buf, |buf| {
+            for row in 0..list_area.height {
+                let index = self.offset + row as usize;
+                let row_area = Rect::new(
+                    list_area.x,
+                    list_area.y + row,
+                    list_area.width,
+                    1,
+                );
+                self.render_option(index, row_area, buf);
+            }
+        });
+    }
+}
+
+impl<'a> DropdownWidget<'a> {
+    pub fn new(style: DropdownStyle<'a>) -> Self {
+        let mut button_style = style.button_style.clone();
+        button_style.set_text(style.placeholder);
+
+        Self {
+            style,
+            button: ButtonWidget::new(button_style),
+            selected: None,
+            highlighted: 0,
+            offset: 0,
+            is_open: false,
+            is_focused: false,
+            identity: WidgetIdentity::default(),
+        }
+    }
+
+    /// Returns the index of the selected option if any.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Returns the index of the option highlighted in the
+    /// option list.
+    pub fn highlighted(&self) -> usize {
+        self.highlighted
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Selects the option with provided index and closes the
+    /// option list. Clears the selection if the index is
+    /// `None` or out of range.
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected =
+            index.filter(|index| *index < self.style.options.len());
+        self.close();
+
+        let text = match self.selected {
+            Some(index) => self.style.options[index],
+            None => self.style.placeholder,
+        };
+        let mut button_style = self.style.button_style.clone();
+        button_style.set_text(text);
+        self.button.set_style(button_style);
+    }
+
+    /// Opens the option list, highlighting the selected option.
+    /// Does nothing if the dropdown is disabled.
+    pub fn open(&mut self) {
+        if self.is_open || self.button.status() == ButtonStatus::Disabled {
+            return;
+        }
+        self.is_open = true;
+        self.highlighted = self.selected.unwrap_or(0);
+        self.button.press();
+    }
+
+    /// Closes the option list if it is open; otherwise does
+    /// nothing. The button of a focused dropdown is displayed
+    /// as hovered again.
+    pub fn close(&mut self) {
+        if self.is_open {
+            self.is_open = false;
+            self.button.unpress();
+            if self.is_focused {
+                self.button.hover();
+            }
+        }
+    }
+
+    /// Closes the option list and disables the dropdown, so
+    /// it ignores all events until it is enabled.
+    pub fn disable(&mut self) {
+        self.close();
+        self.button.disable();
+    }
+
+    pub fn enable(&mut self) {
+        self.button.enable();
+    }
+
+    /// Returns the area of the option list. The area is
+    /// calculated based on provided widget area.
+    fn list_area(&self, widget_area: Rect) -> Rect {
+        let height = self
+            .style
+            .max_visible_options
+            .min(self.style.options.len() as u16);
+
+        Rect::new(
+            widget_area.x,
+            widget_area.y.saturating_add(widget_area.height),
+            widget_area.width,
+            height,
+        )
+    }
+
+    /// Updates the offset so the highlighted option is visible
+    /// in the list with provided height.
+    fn scroll_to_highlighted(&mut self, height: usize) {
+        if self.highlighted < self.offset {
+            self.offset = self.highlighted;
+        } else if self.highlighted >= self.offset + height {
+            self.offset = self.highlighted + 1 - height;
+        }
+        self.offset = self
+            .offset
+            .min(self.style.options.len().saturating_sub(height));
+    }
+
+    fn render_option(&self, index: usize, area: Rect, buf: &mut Buffer) {
+        let Some(text) = self.style.options.get(index) else {
+            return;
+        };
+
+        let mut style = self.style.option_style;
+        if self.selected == Some(index) {
+            style = style.patch(self.style.selected_option_style);
+        }
+        if self.highlighted == index {
+            style = style.patch(self.style.highlighted_option_style);
+        }
+
+        Clear.render(area, buf);
+        buf.set_style(area, style);
+        if area.width > 2 {
+            let max_width = (area.width - 2) as usize;
+            buf.set_stringn(area.x + 1, area.y, text, max_width, style);
+        }
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl<'a> DropdownWidget<'a> {
+    /// Handles provided crossterm event as if the dropdown was
+    /// rendered in provided area.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use crossterm::event::{
+    ///     Event,
+    ///     KeyCode,
+    ///     KeyEvent,
+    /// };
+    /// use ratatui::layout::Rect;
+    /// use caponata_dropdown::{
+    ///     DropdownEvent,
+    ///     DropdownStyleBuilder,
+    ///     DropdownWidget,
+    /// };
+    ///
+    /// let dropdown_style = DropdownStyleBuilder::default()
+    ///     .with_options(vec!["Apple", "Banana", "Cherry"])
+    ///     .with_placeholder("Pick a fruit")
+    ///     .build()
+    ///     .unwrap();
+    /// let mut dropdown = DropdownWidget::new(dropdown_style);
+    ///
+    /// let area = Rect::new(0, 0, 16, 1);
+    /// let mut send_key = |key_code| {
+    ///     let event = Event::Key(KeyEvent::from(key_code));
+    ///     dropdown.on_crossterm_event(event, area)
+    /// };
+    ///
+    /// assert_eq!(send_key(KeyCode::Enter), Some(DropdownEvent::Opened));
+    /// assert_eq!(send_key(KeyCode::Down), None);
+    /// assert_eq!(
+    ///     send_key(KeyCode::Enter),
+    ///     Some(DropdownEvent::SelectionChanged(1)),
+    /// );
+    /// assert_eq!(dropdown.selected(), Some(1));
+    /// ```
+    pub fn on_crossterm_event(
+        &mut self,
+        event: Event,
+        widget_area: Rect,
+    ) -> Option<DropdownEvent> {
+        if self.button.status() == ButtonStatus::Disabled {
+            return None;
+        }
+
+        match event {
+            Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                self.on_key_pressed(key_event.code)
+            }
+            Event::Mouse(mouse_event) => {
+                self.on_mouse_event(event, mouse_event, widget_area)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns boolean flag indicating whether provided key
+    /// controls the dropdown while the option list is open or
+    /// closed, as it currently is.
+    fn handles_key(&self, key_code: KeyCode) -> bool {
+        match self.is_open {
+            true => matches!(
+                key_code,
+                KeyCode::Up | KeyCode::Down | KeyCode::Enter | KeyCode::Esc
+            ),
+            false => matches!(key_code, KeyCode::Enter | KeyCode::Down),
+        }
+    }
+
+    fn on_key_pressed(&mut self, key_code: KeyCode) -> Option<DropdownEvent> {
+        match (self.is_open, key_code) {
+            (false, KeyCode::Enter | KeyCode::Down) => {
+                self.open();
+                Some(DropdownEvent::Opened)
+            }
+            (true, KeyCode::Up) => {
+                let option_count = self.style.options.len().max(1);
+                self.highlighted =
+                    (self.highlighted + option_count - 1) % option_count;
+                None
+            }
+            (true, KeyCode::Down) => {
+                let option_count = self.style.options.len().max(1);
+                self.highlighted = (self.highlighted + 1) % option_count;
+                None
+            }
+            (true, KeyCode::Enter) => self.choose(self.highlighted),
+            (true, KeyCode::Esc) => {
+                self.close();
+                Some(DropdownEvent::Closed)
+            }
+            _ => None,
+        }
+    }
+
+    fn on_mouse_event(
+        &mut self,
+        event: Event,
+        mouse_event: MouseEvent,
+        widget_area: Rect,
+    ) -> Option<DropdownEvent> {
+        let mouse_position = Position {
+            x: mouse_event.column,
+            y: mouse_event.row,
+        };
+        if let Some(index) = self.option_at(widget_area, mouse_position) {
+            return match mouse_event.kind {
+                MouseEventKind::Down(MouseButton::Left) => self.choose(index),
+                MouseEventKind::Moved => {
+                    self.highlighted = index;
+                    None
+                }
+                _ => None,
+            };
+        }
+
+        // A press over the button is not a press outside the
+        // dropdown: the list is closed by the click on release.
+        let is_outside = !self.button.hit_test(widget_area, mouse_position);
+        let button_event = self.button.on_crossterm_event(event, widget_area);
+        match (self.is_open, button_event, mouse_event.kind) {
+            (false, Some(ButtonEvent::Clicked), _) => {
+                self.open();
+                Some(DropdownEvent::Opened)
+            }
+            (true, Some(ButtonEvent::Clicked), _) => {
+                self.close();
+                Some(DropdownEvent::Closed)
+            }
+            (true, _, MouseEventKind::Down(_)) if is_outside => {
+                self.close();
+                Some(DropdownEvent::Closed)
+            }
+            _ => None,
+        }
+    }
+
+    /// Closes the option list and selects the option with
+    /// provided index.
+    fn choose(&mut self, index: usize) -> Option<DropdownEvent> {
+        if index >= self.style.options.len() || self.selected == Some(index) {
+            self.close();
+            return Some(DropdownEvent::Closed);
+        }
+        self.select(Some(index));
+
+        Some(DropdownEvent::SelectionChanged(index))
+    }
+
+    /// Returns the index of the option displayed at provided
+    /// position if the option list is open.
+    fn option_at(
+        &self,
+        widget_area: Rect,
+        position: Position,
+    ) -> Option<usize> {
+        let list_area = self.list_area(widget_area);
+        if !self.is_open || !list_area.contains(position) {
+            return None;
+        }
+        let index = self.offset + (position.y - list_area.y) as usize;
+
+        (index < self.style.options.len()).then_some(index)
+    }
+}
+
+/// A focused dropdown is displayed as hovered and is
+/// controlled with the keys handled by
+/// [`DropdownWidget::on_crossterm_event`]. The option list is
+/// closed when the dropdown loses focus.
+#[cfg(feature = "crossterm")]
+impl<'a> Focusable for DropdownWidget<'a> {
+    fn set_focused(&mut self, is_focused: bool) {
+        self.is_focused = is_focused;
+        if is_focused {
+            self.button.hover();
+        } else {
+            self.close();
+            self.button.unhover();
+        }
+    }
+
+    fn handle_key_event(&mut self, event: KeyEvent) -> bool {
+        if self.button.status() == ButtonStatus::Disabled
+            || event.kind != KeyEventKind::Press
+            || !self.handles_key(event.code)
+        {
+            return false;
+        }
+
+        self.on_key_pressed(event.code);
+        true
+    }
+
+    fn cursor_position(&self, area: Rect) -> Option<Position> {
+        self.button.cursor_position(area)
+    }
+}
+
+impl<'a> AccessibleDescription for DropdownWidget<'a> {
+    /// Describes the selected option, or the placeholder if
+    /// nothing is selected, and the highlighted option while
+    /// the option list is open.
+    fn accessible_description(&self) -> String {
+        let (text, selection) = match self.selected {
+            Some(index) => (self.style.options[index], ""),
+            None => (self.style.placeholder, "nothing selected"),
+        };
+        let highlighted = match self.style.options.get(self.highlighted) {
+            Some(option) if self.is_open => {
+                format!("expanded, highlighted '{option}'")
+            }
+            _ => String::new(),
+        };
+        let status = match self.button.status() {
+            ButtonStatus::Disabled => "disabled",
+            _ => "",
+        };
+
+        describe_widget(
+            "dropdown",
+            Some(text),
+            [selection, &highlighted, status],
+        )
+    }
+}
+
+impl<'a> Identifiable for DropdownWidget<'a> {
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "crossterm")]
+    use crossterm::event::{
+        Event,
+        KeyCode,
+        KeyEvent,
+        KeyModifiers,
+        MouseButton,
+        MouseEvent,
+        MouseEventKind,
+    };
+    use ratatui::layout::Rect;
+
+    use super::DropdownWidget;
+    #[cfg(feature = "crossterm")]
+    use crate::DropdownEvent;
+    use crate::DropdownStyleBuilder;
+
+    const AREA: Rect = Rect::new(0, 0, 16, 1);
+
+    fn make_dropdown() -> DropdownWidget<'static> {
+        let style = DropdownStyleBuilder::default()
+            .with_options(vec!["Apple", "Banana", "Cherry"])
+            .with_placeholder("Pick a fruit")
+            .build()
+            .unwrap();
+        DropdownWidget::new(style)
+    }
+
+    #[cfg(feature = "crossterm")]
+    fn send_key(
+        dropdown: &mut DropdownWidget,
+        key_code: KeyCode,
+    ) -> Option<DropdownEvent> {
+        let event = Event::Key(KeyEvent::from(key_code));
+        dropdown.on_crossterm_event(event, AREA)
+    }
+
+    #[cfg(feature = "crossterm")]
+    fn send_mouse(
+        dropdown: &mut DropdownWidget,
+        kind: MouseEventKind,
+        column: u16,
+        row: u16,
+    ) -> Option<DropdownEvent> {
+        let event = Event::Mouse(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        });
+        dropdown.on_crossterm_event(event, AREA)
+    }
+
+    #[test]
+    fn open_highlights_selected_option() {
+        let mut dropdown = make_dropdown();
+        dropdown.select(Some(2));
+        dropdown.open();
+        assert!(dropdown.is_open());
+        assert_eq!(dropdown.highlighted(), 2);
+
+        dropdown.close();
+        assert!(!dropdown.is_open());
+        assert_eq!(dropdown.selected(), Some(2));
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn keys_open_and_close_list() {
+        let mut dropdown = make_dropdown();
+        assert_eq!(
+            send_key(&mut dropdown, KeyCode::Down),
+            Some(DropdownEvent::Opened),
+        );
+        assert!(dropdown.is_open());
+
+        assert_eq!(
+            send_key(&mut dropdown, KeyCode::Esc),
+            Some(DropdownEvent::Closed),
+        );
+        assert!(!dropdown.is_open());
+        assert_eq!(dropdown.selected(), None);
+        assert_eq!(send_key(&mut dropdown, KeyCode::Esc), None);
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn highlight_wraps_around() {
+        let mut dropdown = make_dropdown();
+        send_key(&mut dropdown, KeyCode::Enter);
+
+        assert_eq!(send_key(&mut dropdown, KeyCode::Up), None);
+        assert_eq!(dropdown.highlighted(), 2);
+        assert_eq!(send_key(&mut dropdown, KeyCode::Down), None);
+        assert_eq!(dropdown.highlighted(), 0);
+        assert_eq!(send_key(&mut dropdown, KeyCode::Down), None);
+        assert_eq!(dropdown.highlighted(), 1);
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn enter_selects_highlighted_option() {
+        let mut dropdown = make_dropdown();
+        send_key(&mut dropdown, KeyCode::Enter);
+        send_key(&mut dropdown, KeyCode::Up);
+
+        assert_eq!(
+            send_key(&mut dropdown, KeyCode::Enter),
+            Some(DropdownEvent::SelectionChanged(2)),
+        );
+        assert!(!dropdown.is_open());
+        assert_eq!(dropdown.selected(), Some(2));
+
+        // Choosing the selected option again only closes the list.
+        send_key(&mut dropdown, KeyCode::Enter);
+        assert_eq!(dropdown.highlighted(), 2);
+        assert_eq!(
+            send_key(&mut dropdown, KeyCode::Enter),
+            Some(DropdownEvent::Closed),
+        );
+        assert_eq!(dropdown.selected(), Some(2));
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn option_is_selected_with_mouse() {
+        let mut dropdown = make_dropdown();
+        let press = MouseEventKind::Down(MouseButton::Left);
+        let release = MouseEventKind::Up(MouseButton::Left);
+
+        assert_eq!(send_mouse(&mut dropdown, press, 0, 0), None);
+        assert_eq!(
+            send_mouse(&mut dropdown, release, 0, 0),
+            Some(DropdownEvent::Opened),
+        );
+
+        // The options are listed below the button.
+        assert_eq!(
+            send_mouse(&mut dropdown, MouseEventKind::Moved, 2, 3),
+            None,
+        );
+        assert_eq!(dropdown.highlighted(), 2);
+        assert_eq!(
+            send_mouse(&mut dropdown, press, 2, 2),
+            Some(DropdownEvent::SelectionChanged(1)),
+        );
+        assert!(!dropdown.is_open());
+        assert_eq!(dropdown.selected(), Some(1));
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn press_outside_closes_list() {
+        let mut dropdown = make_dropdown();
+        send_key(&mut dropdown, KeyCode::Enter);
+
+        assert_eq!(
+            send_mouse(
+                &mut dropdown,
+                MouseEventKind::Down(MouseButton::Left),
+                20,
+                10,
+            ),
+            Some(DropdownEvent::Closed),
+        );
+        assert!(!dropdown.is_open());
+        assert_eq!(dropdown.selected(), None);
+    }
+}
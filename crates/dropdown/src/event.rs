@@ -0,0 +1,17 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DropdownEvent {
+    /// Triggered when the option list of a [`DropdownWidget`]
+    /// is opened.
+    Opened,
+
+    /// Triggered when the option list of a [`DropdownWidget`]
+    /// is closed without changing the selection.
+    Closed,
+
+    /// Triggered when an option of a [`DropdownWidget`] other
+    /// than the currently selected one is chosen. The event
+    /// includes the index of the chosen option. The option
+    /// list is closed at the same time.
+    SelectionChanged(usize),
+}
@@ -0,0 +1,81 @@
+use caponata_button::ButtonStyle;
+use derive_builder::Builder;
+use ratatui::style::Style;
+
+/// Styling configuration for a [`DropdownWidget`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Modifier, Style};
+/// use caponata_button::{ButtonStateStyleBuilder, ButtonStyleBuilder};
+/// use caponata_dropdown::DropdownStyleBuilder;
+///
+/// let button_state_style = ButtonStateStyleBuilder::default()
+///     .with_text_color(Color::White)
+///     .with_background_color(Color::Blue)
+///     .build()
+///     .unwrap();
+/// let button_style = ButtonStyleBuilder::default()
+///     .with_normal_style(button_state_style)
+///     .build()
+///     .unwrap();
+///
+/// let dropdown_style = DropdownStyleBuilder::default()
+///     .with_button_style(button_style)
+///     .with_options(vec!["Apple", "Banana", "Cherry"])
+///     .with_placeholder("Pick a fruit")
+///     .with_option_style(Style::default().bg(Color::DarkGray))
+///     .with_highlighted_option_style(
+///         Style::default().bg(Color::Blue).add_modifier(Modifier::BOLD),
+///     )
+///     .with_max_visible_options(2u16)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(prefix = "with", into))]
+pub struct DropdownStyle<'a> {
+    /// Style of the button that opens the option list. Text
+    /// of the button is replaced with the selected option or
+    /// with the placeholder if no option is selected.
+    #[builder(default)]
+    pub(crate) button_style: ButtonStyle<'a>,
+
+    #[builder(default)]
+    pub(crate) options: Vec<&'a str>,
+
+    #[builder(default = "\"\"")]
+    pub(crate) placeholder: &'a str,
+
+    #[builder(default)]
+    pub(crate) option_style: Style,
+
+    /// Style applied to the option that is currently
+    /// highlighted by the keyboard or the mouse cursor. Has
+    /// higher priority than the selected option style.
+    #[builder(default)]
+    pub(crate) highlighted_option_style: Style,
+
+    #[builder(default)]
+    pub(crate) selected_option_style: Style,
+
+    /// Maximum height of the option list. Other options are
+    /// reachable by scrolling with the keyboard.
+    #[builder(default = "5")]
+    pub(crate) max_visible_options: u16,
+}
+
+impl<'a> Default for DropdownStyle<'a> {
+    fn default() -> Self {
+        Self {
+            button_style: ButtonStyle::default(),
+            options: Vec::new(),
+            placeholder: "",
+            option_style: Style::default(),
+            highlighted_option_style: Style::default(),
+            selected_option_style: Style::default(),
+            max_visible_options: 5,
+        }
+    }
+}
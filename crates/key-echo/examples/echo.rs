@@ -0,0 +1,84 @@
+use std::{
+    io,
+    time::Duration,
+};
+
+use caponata_common::{
+    Easing,
+    Transition,
+    TransitionKind,
+};
+use caponata_key_echo::{
+    KeyEchoStyleBuilder,
+    KeyEchoWidget,
+};
+use crossterm::event::{
+    Event,
+    KeyCode,
+    KeyModifiers,
+    poll,
+    read,
+};
+use ratatui::{
+    DefaultTerminal,
+    layout::{
+        Alignment,
+        Rect,
+    },
+    style::{
+        Color,
+        Modifier,
+    },
+    widgets::Paragraph,
+};
+
+pub fn main() -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+fn run(terminal: &mut DefaultTerminal) -> io::Result<()> {
+    let fade = Transition::new(
+        TransitionKind::Fade(Color::Rgb(0, 0, 0)),
+        Duration::from_millis(400),
+    )
+    .with_easing(Easing::EaseIn);
+    let style = KeyEchoStyleBuilder::default()
+        .with_color(Color::Rgb(255, 255, 255))
+        .with_background_color(Color::Rgb(60, 60, 60))
+        .with_modifier(Modifier::BOLD)
+        .with_alignment(Alignment::Right)
+        .with_display_duration(Duration::from_millis(1500))
+        .with_exit_transition(fade)
+        .build()
+        .unwrap();
+    let mut key_echo = KeyEchoWidget::new(style);
+
+    loop {
+        terminal.draw(|frame| {
+            let area = frame.area();
+            let hint = Paragraph::new("Press any keys, Ctrl+c to quit");
+            frame
+                .render_widget(hint, Rect::new(area.x + 1, area.y + 1, 40, 1));
+
+            let echo_area =
+                Rect::new(area.x, area.bottom() - 2, area.width - 2, 1);
+            frame.render_widget(&mut key_echo, echo_area);
+        })?;
+
+        let timeout = Duration::from_millis(16);
+        if !poll(timeout)? {
+            continue;
+        }
+        let event = read()?;
+        if let Event::Key(key) = event
+            && key.code == KeyCode::Char('c')
+            && key.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            return Ok(());
+        }
+        key_echo.handle_event(event);
+    }
+}
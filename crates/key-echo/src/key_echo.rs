@@ -0,0 +1,430 @@
+use std::time::Duration;
+
+use caponata_common::{
+    AccessibleDescription,
+    Animated,
+    Identifiable,
+    Instant,
+    SharedClock,
+    TransitionState,
+    WidgetIdentity,
+    describe_widget,
+    render_adapted,
+};
+#[cfg(feature = "crossterm")]
+use crossterm::event::{
+    Event,
+    KeyCode,
+    KeyEvent,
+    KeyEventKind,
+    KeyModifiers,
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    text::{
+        Line,
+        Span,
+    },
+    widgets::Widget,
+};
+
+use super::KeyEchoStyle;
+
+/// A one-line widget that displays the last pressed key
+/// combination, e.g. `Ctrl+s`, like key overlays of
+/// screencasts. The combination disappears with the exit
+/// transition of the style once it hasn't been pressed for the
+/// display duration. Repeated presses of the same combination
+/// are counted, e.g. `j ×3`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     widgets::Widget,
+/// };
+/// use caponata_common::AnimationGroup;
+/// use caponata_key_echo::{
+///     KeyEchoStyleBuilder,
+///     KeyEchoWidget,
+/// };
+///
+/// let style = KeyEchoStyleBuilder::default()
+///     .with_display_duration(Duration::from_secs(1))
+///     .with_exit_transition(None)
+///     .build()
+///     .unwrap();
+/// let mut key_echo = KeyEchoWidget::new(style);
+/// let group = AnimationGroup::new();
+/// key_echo.set_clock(group.clock());
+///
+/// key_echo.push("Ctrl+s");
+/// key_echo.push("Ctrl+s");
+///
+/// let area = Rect::new(0, 0, 10, 1);
+/// let mut buf = Buffer::empty(area);
+/// key_echo.render(area, &mut buf);
+/// assert_eq!(buf, Buffer::with_lines(["Ctrl+s ×2 "]));
+///
+/// group.advance(Duration::from_secs(1));
+/// let mut buf = Buffer::empty(area);
+/// key_echo.render(area, &mut buf);
+/// assert_eq!(buf, Buffer::empty(area));
+/// assert_eq!(key_echo.combination(), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyEchoWidget<'a> {
+    style: KeyEchoStyle<'a>,
+
+    /// The last pushed combination and the number of times it
+    /// was pushed in a row.
+    combination: Option<(String, usize)>,
+
+    /// Time of the last push.
+    pushed_at: Option<Instant>,
+
+    /// Visibility of the combination and the transition played
+    /// when it disappears.
+    transition_state: TransitionState,
+
+    clock: SharedClock,
+    identity: WidgetIdentity,
+}
+
+impl<'a> Default for KeyEchoWidget<'a> {
+    fn default() -> Self {
+        Self::new(KeyEchoStyle::default())
+    }
+}
+
+impl<'a> Widget for &mut KeyEchoWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        self.update();
+        self.identity.record(area, &*self);
+
+        let Some(text) = self.displayed_text() else {
+            return;
+        };
+        let mut style = Style::default()
+            .fg(self.style.color)
+            .bg(self.style.background_color);
+        if let Some(modifier) = self.style.modifier {
+            style = style.add_modifier(modifier);
+        }
+        let line = Line::from(Span::styled(text, style))
+            .alignment(self.style.alignment);
+
+        let transition_state = &mut self.transition_state;
+        render_adapted(area, buf, |buf| {
+            transition_state
+                .render(area, buf, |area, buf| line.render(area, buf));
+        });
+    }
+}
+
+impl<'a> KeyEchoWidget<'a> {
+    pub fn new(style: KeyEchoStyle<'a>) -> Self {
+        let mut transition_state =
+            TransitionState::new(None, style.exit_transition);
+        transition_state.set_visible(false);
+
+        Self {
+            style,
+            combination: None,
+            pushed_at: None,
+            transition_state,
+            clock: SharedClock::default(),
+            identity: WidgetIdentity::default(),
+        }
+    }
+
+    /// Displays provided combination, e.g. `Ctrl+s`. If the
+    /// same combination is still displayed, increases the
+    /// number of its presses instead.
+    pub fn push(&mut self, combination: impl Into<String>) {
+        let combination = combination.into();
+        let count = match &self.combination {
+            Some((displayed, count))
+                if *displayed == combination
+                    && self.transition_state.is_visible() =>
+            {
+                count + 1
+            }
+            _ => 1,
+        };
+
+        self.combination = Some((combination, count));
+        self.pushed_at = Some(self.clock.now());
+        self.transition_state.set_visible(true);
+    }
+
+    /// Returns the displayed combination, or `None` if it has
+    /// disappeared.
+    pub fn combination(&self) -> Option<&str> {
+        self.combination
+            .as_ref()
+            .filter(|_| self.transition_state.is_visible())
+            .map(|(combination, _)| combination.as_str())
+    }
+
+    /// Hides the combination instantly.
+    pub fn clear(&mut self) {
+        self.combination = None;
+        self.pushed_at = None;
+        self.transition_state.set_visible(false);
+    }
+
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.transition_state.set_clock(clock.clone());
+        self.clock = clock;
+    }
+
+    /// Returns the time left until the combination starts
+    /// disappearing, or until the next frame of its exit
+    /// transition. Returns `None` if it has disappeared.
+    pub fn time_until_next_frame(&self) -> Option<Duration> {
+        if !self.transition_state.is_visible() {
+            return None;
+        }
+        if self.transition_state.is_transitioning() {
+            return self.transition_state.time_until_next_frame();
+        }
+
+        let elapsed = self.elapsed_since_push()?;
+        Some(self.style.display_duration.saturating_sub(elapsed))
+    }
+
+    fn elapsed_since_push(&self) -> Option<Duration> {
+        self.pushed_at.map(|pushed_at| {
+            self.clock.now().saturating_duration_since(pushed_at)
+        })
+    }
+
+    /// Starts the exit transition once the display duration
+    /// has passed since the last push.
+    fn update(&mut self) {
+        let is_expired = self
+            .elapsed_since_push()
+            .is_some_and(|elapsed| elapsed >= self.style.display_duration);
+        if is_expired
+            && self.transition_state.is_visible()
+            && !self.transition_state.is_transitioning()
+        {
+            self.transition_state.hide();
+        }
+    }
+
+    fn displayed_text(&self) -> Option<String> {
+        let (combination, count) = self.combination.as_ref()?;
+        match count {
+            1 => Some(combination.clone()),
+            count => Some(format!("{combination} ×{count}")),
+        }
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl<'a> KeyEchoWidget<'a> {
+    /// Displays the combination of provided key event. Events
+    /// other than key presses and repeats are ignored.
+    pub fn handle_event(&mut self, event: Event) {
+        let Event::Key(key_event) = event else {
+            return;
+        };
+        if key_event.kind == KeyEventKind::Release {
+            return;
+        }
+
+        self.push(self.format_key_event(key_event));
+    }
+
+    /// Returns the modifiers and the key of provided event
+    /// joined with the key separator, e.g. `Ctrl+Alt+Del`.
+    /// Shift is omitted for characters, as they are already
+    /// displayed in upper case.
+    fn format_key_event(&self, key_event: KeyEvent) -> String {
+        let mut modifiers = key_event.modifiers;
+        if matches!(key_event.code, KeyCode::Char(_)) {
+            modifiers.remove(KeyModifiers::SHIFT);
+        }
+
+        let mut parts: Vec<String> = [
+            (KeyModifiers::CONTROL, "Ctrl"),
+            (KeyModifiers::ALT, "Alt"),
+            (KeyModifiers::SUPER, "Super"),
+            (KeyModifiers::HYPER, "Hyper"),
+            (KeyModifiers::META, "Meta"),
+            (KeyModifiers::SHIFT, "Shift"),
+        ]
+        .into_iter()
+        .filter(|(modifier, _)| modifiers.contains(*modifier))
+        .map(|(_, name)| name.to_string())
+        .collect();
+        parts.push(key_event.code.to_string());
+
+        parts.join(self.style.key_separator)
+    }
+}
+
+impl<'a> Animated for KeyEchoWidget<'a> {
+    fn time_until_next_frame(&self) -> Option<Duration> {
+        KeyEchoWidget::time_until_next_frame(self)
+    }
+}
+
+impl<'a> AccessibleDescription for KeyEchoWidget<'a> {
+    fn accessible_description(&self) -> String {
+        let presses = match &self.combination {
+            Some((_, count)) if *count > 1 && self.combination().is_some() => {
+                format!("pressed {count} times")
+            }
+            _ => String::new(),
+        };
+
+        describe_widget("key echo", self.combination(), [&*presses])
+    }
+}
+
+impl<'a> Identifiable for KeyEchoWidget<'a> {
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use caponata_common::{
+        AccessibleDescription,
+        AnimationGroup,
+        Transition,
+        TransitionKind,
+    };
+    use ratatui::{
+        buffer::Buffer,
+        layout::{
+            Alignment,
+            Rect,
+        },
+        style::Color,
+        widgets::Widget,
+    };
+
+    use super::KeyEchoWidget;
+    use crate::KeyEchoStyleBuilder;
+
+    #[test]
+    fn combination_fades_out_after_display_duration() {
+        let fade = Transition::new(
+            TransitionKind::Fade(Color::Rgb(0, 0, 0)),
+            Duration::from_millis(100),
+        );
+        let style = KeyEchoStyleBuilder::default()
+            .with_color(Color::Rgb(200, 200, 200))
+            .with_alignment(Alignment::Right)
+            .with_display_duration(Duration::from_millis(500))
+            .with_exit_transition(fade)
+            .build()
+            .unwrap();
+        let mut key_echo = KeyEchoWidget::new(style);
+        let group = AnimationGroup::new();
+        key_echo.set_clock(group.clock());
+        assert_eq!(key_echo.time_until_next_frame(), None);
+
+        key_echo.push("q");
+        group.advance(Duration::from_millis(300));
+        assert_eq!(
+            key_echo.time_until_next_frame(),
+            Some(Duration::from_millis(200)),
+        );
+
+        key_echo.push("w");
+        group.advance(Duration::from_millis(500));
+        let area = Rect::new(0, 0, 3, 1);
+        let mut buf = Buffer::empty(area);
+        key_echo.render(area, &mut buf);
+        assert_eq!(key_echo.combination(), Some("w"));
+        assert_eq!(buf[(2, 0)].symbol(), "w");
+        assert_eq!(buf[(2, 0)].fg, Color::Rgb(200, 200, 200));
+
+        group.advance(Duration::from_millis(50));
+        key_echo.render(area, &mut buf);
+        assert_eq!(buf[(2, 0)].fg, Color::Rgb(100, 100, 100));
+
+        group.advance(Duration::from_millis(50));
+        let mut buf = Buffer::empty(area);
+        key_echo.render(area, &mut buf);
+        assert_eq!(buf, Buffer::empty(area));
+        assert_eq!(key_echo.combination(), None);
+        assert_eq!(key_echo.time_until_next_frame(), None);
+        assert_eq!(key_echo.accessible_description(), "key echo");
+
+        key_echo.push("x");
+        key_echo.push("x");
+        assert_eq!(
+            key_echo.accessible_description(),
+            "key echo 'x', pressed 2 times",
+        );
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn key_events_are_formatted() {
+        use crossterm::event::{
+            Event,
+            KeyCode,
+            KeyEvent,
+            KeyEventKind,
+            KeyEventState,
+            KeyModifiers,
+        };
+
+        let key = |code, modifiers, kind| {
+            Event::Key(KeyEvent {
+                code,
+                modifiers,
+                kind,
+                state: KeyEventState::NONE,
+            })
+        };
+        let mut key_echo = KeyEchoWidget::default();
+
+        key_echo.handle_event(key(
+            KeyCode::Char('S'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+            KeyEventKind::Press,
+        ));
+        assert_eq!(key_echo.combination(), Some("Ctrl+S"));
+
+        key_echo.handle_event(key(
+            KeyCode::Tab,
+            KeyModifiers::SHIFT | KeyModifiers::ALT,
+            KeyEventKind::Press,
+        ));
+        key_echo.handle_event(key(
+            KeyCode::Tab,
+            KeyModifiers::SHIFT | KeyModifiers::ALT,
+            KeyEventKind::Release,
+        ));
+        assert_eq!(key_echo.combination(), Some("Alt+Shift+Tab"));
+        assert_eq!(
+            key_echo.displayed_text().as_deref(),
+            Some("Alt+Shift+Tab")
+        );
+    }
+}
@@ -0,0 +1,7 @@
+#![doc = include_str!("../README.md")]
+
+pub mod key_echo;
+pub mod style;
+
+pub use key_echo::*;
+pub use style::*;
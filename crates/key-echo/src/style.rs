@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use caponata_common::{
+    Transition,
+    TransitionKind,
+};
+use derive_builder::Builder;
+use ratatui::{
+    layout::Alignment,
+    style::{
+        Color,
+        Modifier,
+    },
+};
+
+/// A styling configuration for [`KeyEchoWidget`].
+///
+/// [`KeyEchoWidget`]: crate::KeyEchoWidget
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::{
+///     layout::Alignment,
+///     style::{Color, Modifier},
+/// };
+/// use caponata_common::{
+///     Transition,
+///     TransitionKind,
+/// };
+/// use caponata_key_echo::KeyEchoStyleBuilder;
+///
+/// let fade = Transition::new(
+///     TransitionKind::Fade(Color::Rgb(0, 0, 0)),
+///     Duration::from_millis(500),
+/// );
+/// let style = KeyEchoStyleBuilder::default()
+///     .with_color(Color::Rgb(255, 255, 255))
+///     .with_modifier(Modifier::BOLD)
+///     .with_alignment(Alignment::Right)
+///     .with_display_duration(Duration::from_secs(2))
+///     .with_exit_transition(fade)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Builder)]
+#[builder(setter(prefix = "with", into))]
+pub struct KeyEchoStyle<'a> {
+    #[builder(default)]
+    pub(crate) color: Color,
+
+    #[builder(default)]
+    pub(crate) background_color: Color,
+
+    #[builder(default, setter(strip_option))]
+    pub(crate) modifier: Option<Modifier>,
+
+    #[builder(default = "Alignment::Left")]
+    pub(crate) alignment: Alignment,
+
+    /// Text displayed between the modifiers and the key of a
+    /// combination, e.g. `+` in `Ctrl+s`.
+    #[builder(default = "\"+\"")]
+    pub(crate) key_separator: &'a str,
+
+    /// Time the combination is displayed for after the last
+    /// press, before the exit transition starts.
+    #[builder(default = "Duration::from_secs(1)")]
+    pub(crate) display_duration: Duration,
+
+    /// Transition played when the combination disappears. The
+    /// combination disappears instantly if it is `None`.
+    #[builder(default = "Some(Self::default_exit_transition())")]
+    pub(crate) exit_transition: Option<Transition>,
+}
+
+impl<'a> Default for KeyEchoStyle<'a> {
+    fn default() -> Self {
+        Self {
+            color: Color::default(),
+            background_color: Color::default(),
+            modifier: None,
+            alignment: Alignment::Left,
+            key_separator: "+",
+            display_duration: Duration::from_secs(1),
+            exit_transition: Some(
+                KeyEchoStyleBuilder::default_exit_transition(),
+            ),
+        }
+    }
+}
+
+impl<'a> KeyEchoStyleBuilder<'a> {
+    /// Returns a fade to black lasting 300 milliseconds.
+    fn default_exit_transition() -> Transition {
+        Transition::new(
+            TransitionKind::Fade(Color::Black),
+            Duration::from_millis(300),
+        )
+    }
+}
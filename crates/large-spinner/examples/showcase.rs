@@ -0,0 +1,90 @@
+use std::{
+    io,
+    time::Duration,
+};
+
+use caponata_common::AnimationGroup;
+use caponata_large_spinner::{
+    LargeSpinnerType,
+    LargeSpinnerWidget,
+};
+use crossterm::event::{
+    Event,
+    KeyCode,
+    poll,
+    read,
+};
+use ratatui::{
+    DefaultTerminal,
+    layout::{
+        Constraint,
+        Layout,
+        Margin,
+    },
+    style::Color,
+    text::Line,
+};
+
+pub fn main() -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal);
+
+    ratatui::restore();
+    result
+}
+
+fn run(terminal: &mut DefaultTerminal) -> io::Result<()> {
+    let group = AnimationGroup::new();
+    let mut spinners: Vec<LargeSpinnerWidget> = LargeSpinnerType::ALL
+        .into_iter()
+        .map(|spinner_type| {
+            let mut spinner =
+                LargeSpinnerWidget::of_type(spinner_type).fg(Color::Cyan);
+            spinner.set_clock(group.clock());
+            spinner
+        })
+        .collect();
+
+    loop {
+        group.tick();
+        terminal.draw(|frame| {
+            let area = frame.area().inner(Margin::new(1, 1));
+            let [list_area, hint_area] =
+                Layout::vertical([Constraint::Fill(1), Constraint::Length(1)])
+                    .areas(area);
+            let rows =
+                Layout::vertical([Constraint::Length(3); 4]).split(list_area);
+
+            for ((spinner, spinner_type), row) in spinners
+                .iter_mut()
+                .zip(LargeSpinnerType::ALL)
+                .zip(rows.iter())
+            {
+                let [spinner_area, label_area] = Layout::horizontal([
+                    Constraint::Length(4),
+                    Constraint::Fill(1),
+                ])
+                .areas(*row);
+                frame.render_widget(&mut *spinner, spinner_area);
+                frame.render_widget(
+                    Line::from(spinner_type.name()),
+                    label_area,
+                );
+            }
+            frame.render_widget(Line::from("Press q to quit"), hint_area);
+        })?;
+
+        let timeout = spinners
+            .iter()
+            .map(LargeSpinnerWidget::time_until_next_frame)
+            .min()
+            .unwrap_or(Duration::from_millis(100));
+        if !poll(timeout)? {
+            continue;
+        }
+        if matches!(read()?, Event::Key(key) if key.code == KeyCode::Char('q'))
+        {
+            return Ok(());
+        }
+    }
+}
@@ -0,0 +1,7 @@
+#![doc = include_str!("../README.md")]
+
+pub mod spinner;
+pub mod style;
+
+pub use spinner::*;
+pub use style::*;
@@ -0,0 +1,341 @@
+use std::time::Duration;
+
+use caponata_common::{
+    AccessibleDescription,
+    Animated,
+    Identifiable,
+    Instant,
+    SharedClock,
+    WidgetIdentity,
+    describe_widget,
+    render_adapted,
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::{
+        Alignment,
+        Rect,
+    },
+    style::{
+        Color,
+        Style,
+    },
+    widgets::Widget,
+};
+
+use super::{
+    LargeSpinnerStyle,
+    LargeSpinnerType,
+    LargeSpinnerVerticalAlignment,
+};
+
+/// A widget that displays an animated spinner spanning
+/// several cells, e.g. two braille characters or a square of
+/// two by two quadrant blocks, for contexts where a
+/// single-cell spinner is too subtle.
+///
+/// All the cells of the spinner switch to the next frame
+/// together, once the interval of the style has passed.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::{
+///         Alignment,
+///         Rect,
+///     },
+///     widgets::Widget,
+/// };
+/// use caponata_large_spinner::{
+///     LargeSpinnerStyleBuilder,
+///     LargeSpinnerType,
+///     LargeSpinnerWidget,
+/// };
+///
+/// let style = LargeSpinnerStyleBuilder::default()
+///     .with_type(LargeSpinnerType::BlockSquare)
+///     .with_interval(Duration::ZERO)
+///     .with_alignment(Alignment::Right)
+///     .build()
+///     .unwrap();
+/// let mut spinner = LargeSpinnerWidget::new(style);
+///
+/// let area = Rect::new(0, 0, 3, 2);
+/// let mut buf = Buffer::empty(area);
+/// spinner.render(area, &mut buf);
+/// assert_eq!(buf, Buffer::with_lines([" ▀▘", "   "]));
+///
+/// spinner.render(area, &mut buf);
+/// assert_eq!(buf, Buffer::with_lines([" ▝▀", "   "]));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LargeSpinnerWidget {
+    style: LargeSpinnerStyle,
+
+    /// Index of the displayed frame.
+    frame_index: usize,
+    clock: SharedClock,
+    last_rendered_at: Option<Instant>,
+    identity: WidgetIdentity,
+}
+
+impl Widget for &mut LargeSpinnerWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+
+        render_adapted(area, buf, |buf| {
+            let frame = self.advance();
+            let (width, height) = self.size();
+            let x = match self.style.alignment {
+                Alignment::Left => area.x,
+                Alignment::Center => {
+                    area.x + area.width.saturating_sub(width) / 2
+                }
+                Alignment::Right => {
+                    area.right().saturating_sub(width).max(area.x)
+                }
+            };
+            let y = match self.style.vertical_alignment {
+                LargeSpinnerVerticalAlignment::Top => area.y,
+                LargeSpinnerVerticalAlignment::Center => {
+                    area.y + area.height.saturating_sub(height) / 2
+                }
+                LargeSpinnerVerticalAlignment::Bottom => {
+                    area.bottom().saturating_sub(height).max(area.y)
+                }
+            };
+
+            let style = self.cell_style();
+            for (row, y) in frame.iter().zip(y..area.bottom()) {
+                for (character, x) in row.chars().zip(x..area.right()) {
+                    buf[(x, y)].set_char(character).set_style(style);
+                }
+            }
+        });
+        self.identity.record(area, &*self);
+    }
+}
+
+impl LargeSpinnerWidget {
+    pub fn new(style: LargeSpinnerStyle) -> Self {
+        Self {
+            style,
+            frame_index: 0,
+            clock: SharedClock::default(),
+            last_rendered_at: None,
+            identity: WidgetIdentity::default(),
+        }
+    }
+
+    /// Creates a spinner of provided type switching its frames
+    /// at the recommended interval of the type.
+    pub fn of_type(spinner_type: LargeSpinnerType) -> Self {
+        Self::new(LargeSpinnerStyle {
+            type_: spinner_type,
+            interval: spinner_type.recommended_interval(),
+            ..LargeSpinnerStyle::default()
+        })
+    }
+
+    /// Sets the foreground color of the spinner.
+    pub fn fg(mut self, color: Color) -> Self {
+        self.style.foreground_color = Some(color);
+        self
+    }
+
+    /// Sets the background color of the spinner.
+    pub fn bg(mut self, color: Color) -> Self {
+        self.style.background_color = Some(color);
+        self
+    }
+
+    /// Replaces the clock used to measure time. Spinners
+    /// sharing the clock of an [`AnimationGroup`] switch their
+    /// frames in the same frame of the application.
+    ///
+    /// [`AnimationGroup`]: caponata_common::AnimationGroup
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+    }
+
+    /// Replaces the style of the spinner. The current frame is
+    /// preserved, wrapping around if the new type has fewer
+    /// frames.
+    pub fn set_style(&mut self, style: LargeSpinnerStyle) {
+        self.style = style;
+        self.frame_index %= self.style.type_.frame_count().max(1);
+    }
+
+    /// Resets the spinner's animation to its first frame.
+    pub fn reset(&mut self) {
+        self.frame_index = 0;
+        self.last_rendered_at = None;
+    }
+
+    /// Returns the number of columns and rows the spinner
+    /// occupies.
+    pub fn size(&self) -> (u16, u16) {
+        self.style.type_.size()
+    }
+
+    /// Returns the time left until the spinner displays its
+    /// next frame.
+    pub fn time_until_next_frame(&self) -> Duration {
+        let Some(last_rendered_at) = self.last_rendered_at else {
+            return Duration::ZERO;
+        };
+
+        self.style.interval.saturating_sub(
+            self.clock.now().saturating_duration_since(last_rendered_at),
+        )
+    }
+
+    /// Returns the frame to display now, switching to the next
+    /// frame if the interval has passed since the previous
+    /// switch.
+    fn advance(&mut self) -> &'static [&'static str] {
+        let frames = self.style.type_.frames();
+        if frames.is_empty() {
+            return &[];
+        }
+
+        let now = self.clock.now();
+        match self.last_rendered_at {
+            Some(last_rendered_at)
+                if now.saturating_duration_since(last_rendered_at)
+                    >= self.style.interval =>
+            {
+                self.frame_index = (self.frame_index + 1) % frames.len();
+                self.last_rendered_at = Some(now);
+            }
+            Some(_) => {}
+            None => self.last_rendered_at = Some(now),
+        }
+
+        frames[self.frame_index % frames.len()]
+    }
+
+    fn cell_style(&self) -> Style {
+        Style {
+            fg: self.style.foreground_color,
+            bg: self
+                .style
+                .background_color
+                .filter(|_| !self.style.respect_existing_background),
+            ..Style::default()
+        }
+    }
+}
+
+impl Animated for LargeSpinnerWidget {
+    fn time_until_next_frame(&self) -> Option<Duration> {
+        Some(LargeSpinnerWidget::time_until_next_frame(self))
+    }
+}
+
+impl AccessibleDescription for LargeSpinnerWidget {
+    fn accessible_description(&self) -> String {
+        describe_widget("spinner", None, ["busy"])
+    }
+}
+
+impl Identifiable for LargeSpinnerWidget {
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use caponata_common::{
+        AccessibleDescription,
+        AnimationGroup,
+    };
+    use ratatui::{
+        buffer::Buffer,
+        layout::{
+            Alignment,
+            Rect,
+        },
+        style::Color,
+        widgets::Widget,
+    };
+
+    use super::LargeSpinnerWidget;
+    use crate::{
+        LargeSpinnerStyleBuilder,
+        LargeSpinnerType,
+        LargeSpinnerVerticalAlignment,
+    };
+
+    #[test]
+    fn frames_switch_together_after_interval() {
+        let mut spinner =
+            LargeSpinnerWidget::of_type(LargeSpinnerType::BrailleSquare)
+                .fg(Color::Green);
+        let group = AnimationGroup::new();
+        spinner.set_clock(group.clock());
+        assert_eq!(spinner.size(), (2, 2));
+
+        let area = Rect::new(0, 0, 2, 2);
+        let mut buf = Buffer::empty(area);
+        spinner.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "⠉");
+        assert_eq!(buf[(1, 1)].fg, Color::Green);
+
+        group.advance(Duration::from_millis(40));
+        spinner.render(area, &mut buf);
+        assert_eq!(buf[(1, 0)].symbol(), "⠉");
+        assert_eq!(spinner.time_until_next_frame(), Duration::from_millis(20));
+
+        group.advance(Duration::from_millis(20));
+        spinner.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "⠈");
+        assert_eq!(buf[(1, 0)].symbol(), "⠙");
+
+        spinner.reset();
+        spinner.render(area, &mut buf);
+        assert_eq!(buf[(1, 0)].symbol(), "⠉");
+        assert_eq!(spinner.accessible_description(), "spinner, busy");
+    }
+
+    #[test]
+    fn spinner_is_aligned_and_clipped() {
+        let style = LargeSpinnerStyleBuilder::default()
+            .with_type(LargeSpinnerType::BlockSquare)
+            .with_alignment(Alignment::Center)
+            .with_vertical_alignment(LargeSpinnerVerticalAlignment::Bottom)
+            .with_background_color(Color::Blue)
+            .build()
+            .unwrap();
+        let mut spinner = LargeSpinnerWidget::new(style);
+
+        let area = Rect::new(0, 0, 4, 3);
+        let mut buf = Buffer::empty(area);
+        spinner.render(area, &mut buf);
+        assert_eq!(buf[(1, 1)].symbol(), "▀");
+        assert_eq!(buf[(2, 1)].symbol(), "▘");
+        assert_eq!(buf[(2, 2)].bg, Color::Blue);
+        assert_eq!(buf[(3, 2)].bg, Color::Reset);
+
+        // The interval is zero, so every render shows the next
+        // frame.
+        let area = Rect::new(0, 0, 1, 1);
+        let mut buf = Buffer::empty(area);
+        spinner.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "▝");
+    }
+}
@@ -0,0 +1,238 @@
+use std::time::Duration;
+
+use derive_builder::Builder;
+use ratatui::{
+    layout::Alignment,
+    style::Color,
+};
+
+/// Type of animation for [`LargeSpinnerWidget`]. Every frame
+/// is made of several rows of characters animated together.
+///
+/// Default variant is [`LargeSpinnerType::BrailleSnake`].
+///
+/// [`LargeSpinnerWidget`]: crate::LargeSpinnerWidget
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LargeSpinnerType {
+    /// A snake of three braille dots crawling along the edges
+    /// of two cells, e.g. `⠈⠉`.
+    #[default]
+    BrailleSnake,
+
+    /// A snake of four braille dots crawling along the edges
+    /// of a square of two by two cells.
+    BrailleSquare,
+
+    /// A snake of two quadrant blocks crawling along the
+    /// edges of two cells, e.g. `▝▘`.
+    BlockSnake,
+
+    /// A snake of three quadrant blocks crawling along the
+    /// edges of a square of two by two cells.
+    BlockSquare,
+
+    /// Frames provided by the user, each made of rows of
+    /// characters. A spinner without frames displays nothing.
+    Custom(&'static [&'static [&'static str]]),
+}
+
+impl LargeSpinnerType {
+    /// All built-in spinner types in the order they are
+    /// declared.
+    pub const ALL: [Self; 4] = [
+        Self::BrailleSnake,
+        Self::BrailleSquare,
+        Self::BlockSnake,
+        Self::BlockSquare,
+    ];
+
+    /// Returns the human-readable name of the spinner type,
+    /// e.g. `"braille snake"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::BrailleSnake => "braille snake",
+            Self::BrailleSquare => "braille square",
+            Self::BlockSnake => "block snake",
+            Self::BlockSquare => "block square",
+            Self::Custom(_) => "custom",
+        }
+    }
+
+    /// Returns the frames the spinner cycles through, each
+    /// made of rows of characters.
+    pub fn frames(&self) -> &'static [&'static [&'static str]] {
+        match self {
+            Self::BrailleSnake => &[
+                &["⠉⠁"],
+                &["⠈⠉"],
+                &["⠀⠙"],
+                &["⠀⠸"],
+                &["⠀⢰"],
+                &["⠀⣠"],
+                &["⢀⣀"],
+                &["⣀⡀"],
+                &["⣄⠀"],
+                &["⡆⠀"],
+                &["⠇⠀"],
+                &["⠋⠀"],
+            ],
+            Self::BrailleSquare => &[
+                &["⠉⠉", "⠀⠀"],
+                &["⠈⠙", "⠀⠀"],
+                &["⠀⠹", "⠀⠀"],
+                &["⠀⢸", "⠀⠀"],
+                &["⠀⢰", "⠀⠈"],
+                &["⠀⢠", "⠀⠘"],
+                &["⠀⢀", "⠀⠸"],
+                &["⠀⠀", "⠀⢸"],
+                &["⠀⠀", "⠀⣰"],
+                &["⠀⠀", "⢀⣠"],
+                &["⠀⠀", "⣀⣀"],
+                &["⠀⠀", "⣄⡀"],
+                &["⠀⠀", "⣆⠀"],
+                &["⠀⠀", "⡇⠀"],
+                &["⡀⠀", "⠇⠀"],
+                &["⡄⠀", "⠃⠀"],
+                &["⡆⠀", "⠁⠀"],
+                &["⡇⠀", "⠀⠀"],
+                &["⠏⠀", "⠀⠀"],
+                &["⠋⠁", "⠀⠀"],
+            ],
+            Self::BlockSnake => &[
+                &["▀ "],
+                &["▝▘"],
+                &[" ▀"],
+                &[" ▐"],
+                &[" ▄"],
+                &["▗▖"],
+                &["▄ "],
+                &["▌ "],
+            ],
+            Self::BlockSquare => &[
+                &["▀▘", "  "],
+                &["▝▀", "  "],
+                &[" ▜", "  "],
+                &[" ▐", " ▝"],
+                &[" ▗", " ▐"],
+                &["  ", " ▟"],
+                &["  ", "▗▄"],
+                &["  ", "▄▖"],
+                &["  ", "▙ "],
+                &["▖ ", "▌ "],
+                &["▌ ", "▘ "],
+                &["▛ ", "  "],
+            ],
+            Self::Custom(frames) => frames,
+        }
+    }
+
+    /// Returns the number of frames the spinner cycles
+    /// through.
+    pub fn frame_count(&self) -> usize {
+        self.frames().len()
+    }
+
+    /// Returns the number of columns and rows of the widest
+    /// and the tallest frames.
+    pub fn size(&self) -> (u16, u16) {
+        self.frames().iter().fold((0, 0), |(width, height), frame| {
+            let frame_width = frame
+                .iter()
+                .map(|row| row.chars().count() as u16)
+                .max()
+                .unwrap_or_default();
+            (width.max(frame_width), height.max(frame.len() as u16))
+        })
+    }
+
+    /// Returns the interval between the frames the spinner
+    /// looks smooth with.
+    pub fn recommended_interval(&self) -> Duration {
+        let millis = match self {
+            Self::BrailleSnake | Self::BrailleSquare => 60,
+            Self::BlockSnake | Self::BlockSquare => 100,
+            Self::Custom(_) => 100,
+        };
+
+        Duration::from_millis(millis)
+    }
+}
+
+/// Vertical position of [`LargeSpinnerWidget`] within an area
+/// taller than the spinner.
+///
+/// Default variant is [`LargeSpinnerVerticalAlignment::Top`].
+///
+/// [`LargeSpinnerWidget`]: crate::LargeSpinnerWidget
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LargeSpinnerVerticalAlignment {
+    #[default]
+    Top,
+
+    /// The spinner is placed in the middle of the area,
+    /// rounding down.
+    Center,
+
+    Bottom,
+}
+
+/// A styling configuration for [`LargeSpinnerWidget`].
+///
+/// [`LargeSpinnerWidget`]: crate::LargeSpinnerWidget
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::{
+///     style::Color,
+///     layout::Alignment,
+/// };
+/// use caponata_large_spinner::{
+///     LargeSpinnerType,
+///     LargeSpinnerStyleBuilder,
+///     LargeSpinnerVerticalAlignment,
+/// };
+///
+/// let style = LargeSpinnerStyleBuilder::default()
+///     .with_type(LargeSpinnerType::BlockSquare)
+///     .with_interval(Duration::from_millis(100))
+///     .with_alignment(Alignment::Center)
+///     .with_vertical_alignment(LargeSpinnerVerticalAlignment::Center)
+///     .with_foreground_color(Color::White)
+///     .with_background_color(Color::Black)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Builder)]
+#[builder(setter(prefix = "with", into))]
+pub struct LargeSpinnerStyle {
+    #[builder(default, setter(name = "with_type"))]
+    pub(crate) type_: LargeSpinnerType,
+
+    #[builder(default)]
+    pub(crate) interval: Duration,
+
+    #[builder(default)]
+    pub(crate) alignment: Alignment,
+
+    #[builder(default)]
+    pub(crate) vertical_alignment: LargeSpinnerVerticalAlignment,
+
+    /// Foreground color of the spinner. `None` leaves the
+    /// foreground color of the cells untouched.
+    #[builder(default)]
+    pub(crate) foreground_color: Option<Color>,
+
+    /// Background color of the spinner. `None` leaves the
+    /// background color of the cells untouched.
+    #[builder(default)]
+    pub(crate) background_color: Option<Color>,
+
+    /// Whether the background of the cells the spinner is
+    /// rendered into is left untouched instead of being
+    /// overwritten with the background color.
+    #[builder(default)]
+    pub(crate) respect_existing_background: bool,
+}
@@ -0,0 +1,100 @@
+use std::{
+    io,
+    time::Duration,
+};
+
+use caponata_common::WidgetEvent;
+use caponata_line_composer::{
+    InlineBadge,
+    InlineElement,
+    LineComposer,
+    LineComposerEvent,
+};
+use caponata_small_spinner::SmallSpinnerWidget;
+use caponata_small_text::{
+    SmallTextWidget,
+    SymbolStyleBuilder,
+};
+use crossterm::{
+    event::{
+        DisableMouseCapture,
+        EnableMouseCapture,
+        Event,
+        KeyCode,
+        poll,
+        read,
+    },
+    execute,
+};
+use ratatui::{
+    DefaultTerminal,
+    layout::{
+        Constraint,
+        Rect,
+    },
+    style::{
+        Color,
+        Modifier,
+    },
+};
+
+const STATUS_INDEX: usize = 2;
+const BADGE_INDEX: usize = 4;
+
+pub fn main() -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    execute!(io::stdout(), EnableMouseCapture)?;
+    let result = run(&mut terminal);
+
+    execute!(io::stdout(), DisableMouseCapture)?;
+    ratatui::restore();
+    result
+}
+
+fn run(terminal: &mut DefaultTerminal) -> io::Result<()> {
+    let badge_style = SymbolStyleBuilder::default()
+        .with_foreground_color(Color::Black)
+        .with_background_color(Color::Yellow)
+        .with_modifier(Modifier::BOLD)
+        .build()
+        .unwrap();
+    let mut composer: LineComposer = LineComposer::new()
+        .with_spacing(1)
+        .with_element(SmallSpinnerWidget::braille().fg(Color::Cyan))
+        .with_element(SmallTextWidget::plain("Fetching results…"))
+        .with_constrained_element(
+            SmallTextWidget::plain("click the badge"),
+            Constraint::Fill(1),
+        )
+        .with_element(SmallTextWidget::plain("q to quit").fg(Color::DarkGray))
+        .with_element(InlineBadge::new("3 new", badge_style));
+
+    loop {
+        let mut composer_area = Rect::default();
+        terminal.draw(|frame| {
+            let area = frame.area();
+            composer_area = Rect::new(area.x + 1, area.y + 1, 60, 1);
+            frame.render_widget(&mut composer, composer_area);
+        })?;
+
+        let timeout = Duration::from_millis(50);
+        if !poll(timeout)? {
+            continue;
+        }
+        let event = read()?;
+        if matches!(event, Event::Key(key) if key.code == KeyCode::Char('q')) {
+            return Ok(());
+        }
+
+        let clicked_badge = LineComposerEvent {
+            index: BADGE_INDEX,
+            event: WidgetEvent::Clicked,
+        };
+        if composer.handle_event(event, composer_area) == Some(clicked_badge)
+            && let Some(InlineElement::Text(status)) =
+                composer.element_mut(STATUS_INDEX)
+        {
+            status.set_text("badge clicked");
+        }
+    }
+}
@@ -0,0 +1,486 @@
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    time::Duration,
+};
+
+use caponata_common::{
+    AccessibleDescription,
+    Animated,
+    Identifiable,
+    SharedClock,
+    WidgetIdentity,
+    describe_parts,
+};
+#[cfg(feature = "crossterm")]
+use caponata_common::{
+    EventQueue,
+    HandlesEvent,
+};
+#[cfg(feature = "crossterm")]
+use crossterm::event::Event;
+#[cfg(feature = "crossterm")]
+use ratatui::layout::Position;
+use ratatui::{
+    buffer::Buffer,
+    layout::{
+        Constraint,
+        Layout,
+        Rect,
+    },
+    widgets::Widget,
+};
+
+use super::InlineElement;
+#[cfg(feature = "crossterm")]
+use super::LineComposerEvent;
+
+/// An element along with the constraint of its width.
+#[derive(Debug, Clone)]
+struct ComposedElement<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    element: InlineElement<'a, K>,
+
+    /// Constraint of the width of the element. The element
+    /// takes the width it needs if `None`.
+    constraint: Option<Constraint>,
+}
+
+/// A single-line analogue of ratatui's `Layout` that renders
+/// an ordered list of [`InlineElement`]s, e.g. a spinner, a
+/// text and a badge, side by side and routes events to them.
+///
+/// Each element takes the width it needs unless it was added
+/// with a constraint. The widths are computed by ratatui's
+/// layout solver, so elements shrink when the area is too
+/// narrow.
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_line_composer::{
+///     InlineBadge,
+///     LineComposer,
+/// };
+/// use caponata_small_spinner::SmallSpinnerWidget;
+/// use caponata_small_text::{
+///     SmallTextWidget,
+///     SymbolStyle,
+/// };
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::{
+///         Constraint,
+///         Rect,
+///     },
+///     widgets::Widget,
+/// };
+///
+/// let mut composer: LineComposer = LineComposer::new()
+///     .with_spacing(1)
+///     .with_element(SmallSpinnerWidget::braille())
+///     .with_constrained_element(
+///         SmallTextWidget::plain("Fetching results…"),
+///         Constraint::Fill(1),
+///     )
+///     .with_element(InlineBadge::new("3", SymbolStyle::default()));
+///
+/// let area = Rect::new(0, 0, 24, 1);
+/// assert_eq!(
+///     composer.areas(area),
+///     vec![
+///         Rect::new(0, 0, 1, 1),
+///         Rect::new(2, 0, 18, 1),
+///         Rect::new(21, 0, 3, 1),
+///     ],
+/// );
+///
+/// let mut buf = Buffer::empty(area);
+/// composer.render(area, &mut buf);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineComposer<'a, K = ()>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    elements: Vec<ComposedElement<'a, K>>,
+
+    /// Number of cells between adjacent elements.
+    spacing: u16,
+
+    /// Events returned by [`LineComposer::handle_event`] that
+    /// have not been drained yet.
+    #[cfg(feature = "crossterm")]
+    events: EventQueue<LineComposerEvent>,
+    identity: WidgetIdentity,
+}
+
+impl<'a, K> Default for LineComposer<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn default() -> Self {
+        Self {
+            elements: Vec::new(),
+            spacing: 0,
+            #[cfg(feature = "crossterm")]
+            events: EventQueue::new(),
+            identity: WidgetIdentity::default(),
+        }
+    }
+}
+
+impl<'a, K> Widget for &mut LineComposer<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let areas = self.areas(area);
+        for (composed, element_area) in self.elements.iter_mut().zip(areas) {
+            composed.element.render(element_area, buf);
+        }
+        self.identity.record(area, &*self);
+    }
+}
+
+impl<'a, K> LineComposer<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds provided element after the others, taking the
+    /// width it needs.
+    pub fn with_element(
+        mut self,
+        element: impl Into<InlineElement<'a, K>>,
+    ) -> Self {
+        self.push(element, None);
+        self
+    }
+
+    /// Adds provided element after the others, with its width
+    /// determined by provided constraint.
+    pub fn with_constrained_element(
+        mut self,
+        element: impl Into<InlineElement<'a, K>>,
+        constraint: Constraint,
+    ) -> Self {
+        self.push(element, Some(constraint));
+        self
+    }
+
+    pub fn with_spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Adds provided element after the others, with its width
+    /// determined by provided constraint, or taking the width
+    /// it needs if the constraint is `None`.
+    pub fn push(
+        &mut self,
+        element: impl Into<InlineElement<'a, K>>,
+        constraint: Option<Constraint>,
+    ) {
+        self.elements.push(ComposedElement {
+            element: element.into(),
+            constraint,
+        });
+    }
+
+    /// Returns the element with provided index, in the order
+    /// the elements were added.
+    pub fn element(&self, index: usize) -> Option<&InlineElement<'a, K>> {
+        self.elements.get(index).map(|composed| &composed.element)
+    }
+
+    /// Returns the element with provided index mutably, e.g.
+    /// to replace the text of a status.
+    pub fn element_mut(
+        &mut self,
+        index: usize,
+    ) -> Option<&mut InlineElement<'a, K>> {
+        self.elements
+            .get_mut(index)
+            .map(|composed| &mut composed.element)
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the areas the elements are rendered in within
+    /// the first row of provided area, in the order the
+    /// elements were added.
+    pub fn areas(&self, area: Rect) -> Vec<Rect> {
+        let row = Rect {
+            height: area.height.min(1),
+            ..area
+        };
+        let constraints = self.elements.iter().map(|composed| {
+            composed
+                .constraint
+                .unwrap_or(Constraint::Length(composed.element.width()))
+        });
+
+        Layout::horizontal(constraints)
+            .spacing(self.spacing)
+            .split(row)
+            .to_vec()
+    }
+
+    /// Replaces the clock used by the animated elements.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        for composed in self.elements.iter_mut() {
+            composed.element.set_clock(clock.clone());
+        }
+    }
+
+    /// Returns the time left until any of the elements displays
+    /// its next frame, or `None` if none of them changes by
+    /// itself.
+    pub fn time_until_next_frame(&self) -> Option<Duration> {
+        self.elements
+            .iter()
+            .filter_map(|composed| composed.element.time_until_next_frame())
+            .min()
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl<'a, K> LineComposer<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    /// Delivers provided mouse event to all the elements, so
+    /// they can notice the cursor leaving them, and returns the
+    /// event of the element under the cursor, or of the first
+    /// element producing one. All the produced events are kept
+    /// for [`LineComposer::drain_events`].
+    pub fn handle_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<LineComposerEvent> {
+        let Event::Mouse(mouse_event) = event else {
+            return None;
+        };
+        let position = Position::new(mouse_event.column, mouse_event.row);
+        let areas = self.areas(area);
+
+        let mut routed_event = None;
+        let elements = self.elements.iter_mut().zip(areas).enumerate();
+        for (index, (composed, element_area)) in elements {
+            let Some(event) =
+                composed.element.handle_event(event.clone(), element_area)
+            else {
+                continue;
+            };
+            let event = LineComposerEvent { index, event };
+            self.events.push(event);
+
+            if routed_event.is_none() || element_area.contains(position) {
+                routed_event = Some(event);
+            }
+        }
+
+        routed_event
+    }
+
+    /// Returns the events produced by the elements since they
+    /// were last drained, from the oldest to the most recent
+    /// one.
+    pub fn drain_events(
+        &mut self,
+    ) -> impl Iterator<Item = LineComposerEvent> + '_ {
+        self.events.drain()
+    }
+}
+
+impl<'a, K> Animated for LineComposer<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn time_until_next_frame(&self) -> Option<Duration> {
+        LineComposer::time_until_next_frame(self)
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl<'a, K> HandlesEvent for LineComposer<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    type Event = LineComposerEvent;
+
+    fn handle_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<LineComposerEvent> {
+        LineComposer::handle_event(self, event, area)
+    }
+}
+
+impl<'a, K> AccessibleDescription for LineComposer<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn accessible_description(&self) -> String {
+        let parts = self
+            .elements
+            .iter()
+            .map(|composed| composed.element.accessible_description());
+
+        describe_parts("line", parts)
+    }
+}
+
+impl<'a, K> Identifiable for LineComposer<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use caponata_common::{
+        AccessibleDescription,
+        AnimationGroup,
+    };
+    use caponata_small_spinner::{
+        SmallSpinnerType,
+        SmallSpinnerWidget,
+    };
+    use caponata_small_text::{
+        SmallTextWidget,
+        SymbolStyleBuilder,
+    };
+    use ratatui::{
+        buffer::Buffer,
+        layout::{
+            Constraint,
+            Rect,
+        },
+        style::{
+            Color,
+            Style,
+        },
+        widgets::Widget,
+    };
+
+    use super::LineComposer;
+    use crate::InlineBadge;
+
+    fn composer() -> LineComposer<'static> {
+        let badge_style = SymbolStyleBuilder::default()
+            .with_background_color(Color::Red)
+            .build()
+            .unwrap();
+
+        LineComposer::new()
+            .with_spacing(1)
+            .with_element(SmallSpinnerWidget::of_type(SmallSpinnerType::Ascii))
+            .with_constrained_element(
+                SmallTextWidget::plain("Loading"),
+                Constraint::Fill(1),
+            )
+            .with_element(InlineBadge::new("2", badge_style))
+    }
+
+    #[test]
+    fn elements_are_rendered_side_by_side() {
+        let mut composer = composer();
+        let group = AnimationGroup::new();
+        composer.set_clock(group.clock());
+        assert_eq!(composer.time_until_next_frame(), Some(Duration::ZERO));
+
+        let area = Rect::new(0, 0, 14, 2);
+        let mut buf = Buffer::empty(area);
+        composer.render(area, &mut buf);
+
+        let frame = SmallSpinnerType::Ascii.frames()[0];
+        let expected_line = format!("{frame} Loading   2 ");
+        let mut expected_buf =
+            Buffer::with_lines([expected_line.as_str(), ""]);
+        expected_buf.set_style(
+            Rect::new(11, 0, 3, 1),
+            Style::default().bg(Color::Red),
+        );
+        assert_eq!(buf, expected_buf);
+        assert_eq!(
+            composer.accessible_description(),
+            "line: spinner, busy; text 'Loading'; badge '2'",
+        );
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn events_are_routed_to_elements() {
+        use caponata_common::WidgetEvent;
+        use crossterm::event::{
+            Event,
+            KeyModifiers,
+            MouseButton,
+            MouseEvent,
+            MouseEventKind,
+        };
+
+        use crate::LineComposerEvent;
+
+        let mouse = |kind, column| {
+            Event::Mouse(MouseEvent {
+                kind,
+                column,
+                row: 0,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        let mut composer = composer();
+        let area = Rect::new(0, 0, 14, 1);
+
+        assert_eq!(
+            composer.handle_event(mouse(MouseEventKind::Moved, 3), area),
+            Some(LineComposerEvent {
+                index: 1,
+                event: WidgetEvent::Hovered,
+            }),
+        );
+        assert_eq!(
+            composer.handle_event(
+                mouse(MouseEventKind::Down(MouseButton::Left), 12),
+                area,
+            ),
+            Some(LineComposerEvent {
+                index: 2,
+                event: WidgetEvent::Clicked,
+            }),
+        );
+        assert_eq!(
+            composer.handle_event(mouse(MouseEventKind::Moved, 12), area),
+            Some(LineComposerEvent {
+                index: 1,
+                event: WidgetEvent::Unhovered,
+            }),
+        );
+        assert_eq!(composer.drain_events().count(), 3);
+    }
+}
@@ -0,0 +1,232 @@
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    time::Duration,
+};
+
+#[cfg(feature = "crossterm")]
+use caponata_common::WidgetEvent;
+use caponata_common::{
+    AccessibleDescription,
+    SharedClock,
+    describe_widget,
+};
+use caponata_small_spinner::SmallSpinnerWidget;
+use caponata_small_text::{
+    AnimatedSmallTextWidget,
+    SmallTextWidget,
+    SymbolStyle,
+};
+#[cfg(feature = "crossterm")]
+use crossterm::event::{
+    Event,
+    MouseButton,
+    MouseEventKind,
+};
+#[cfg(feature = "crossterm")]
+use ratatui::layout::Position;
+use ratatui::{
+    buffer::Buffer,
+    layout::{
+        Alignment,
+        Rect,
+    },
+    style::Style,
+    text::{
+        Line,
+        Span,
+    },
+    widgets::Widget,
+};
+
+/// A short label displayed on a filled background, e.g. a
+/// counter of new messages.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InlineBadge<'a> {
+    pub text: &'a str,
+    pub style: SymbolStyle,
+
+    /// Number of cells added on both sides of the text.
+    pub padding: u16,
+}
+
+impl<'a> InlineBadge<'a> {
+    /// Creates a badge with provided text and style, padded
+    /// with a single cell on both sides.
+    pub fn new(text: &'a str, style: SymbolStyle) -> Self {
+        Self {
+            text,
+            style,
+            padding: 1,
+        }
+    }
+
+    pub fn width(&self) -> u16 {
+        Span::raw(self.text).width() as u16 + self.padding * 2
+    }
+}
+
+impl<'a> AccessibleDescription for InlineBadge<'a> {
+    fn accessible_description(&self) -> String {
+        describe_widget("badge", Some(self.text), [])
+    }
+}
+
+/// An element of a single-line composition rendered by
+/// [`LineComposer`].
+///
+/// [`LineComposer`]: crate::LineComposer
+#[derive(Debug, Clone)]
+pub enum InlineElement<'a, K = ()>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    Text(SmallTextWidget),
+    AnimatedText(Box<AnimatedSmallTextWidget<K>>),
+    Spinner(SmallSpinnerWidget),
+    Badge(InlineBadge<'a>),
+}
+
+impl<'a, K> Widget for &mut InlineElement<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        match self {
+            InlineElement::Text(text) => text.render(area, buf),
+            InlineElement::AnimatedText(text) => text.render(area, buf),
+            InlineElement::Spinner(spinner) => spinner.render(area, buf),
+            InlineElement::Badge(badge) => {
+                let area = area.intersection(buf.area);
+                let style: Style = badge.style.into();
+                buf.set_style(area, style);
+                Line::from(badge.text)
+                    .alignment(Alignment::Center)
+                    .render(area, buf);
+            }
+        }
+    }
+}
+
+impl<'a, K> InlineElement<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    /// Returns the number of cells the element needs to be
+    /// displayed completely.
+    pub fn width(&self) -> u16 {
+        match self {
+            InlineElement::Text(text) => text.width(),
+            InlineElement::AnimatedText(text) => text.width(),
+            InlineElement::Spinner(spinner) => spinner.width(),
+            InlineElement::Badge(badge) => badge.width(),
+        }
+    }
+
+    /// Replaces the clock used by animated elements; otherwise
+    /// does nothing.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        match self {
+            InlineElement::AnimatedText(text) => text.set_clock(clock),
+            InlineElement::Spinner(spinner) => spinner.set_clock(clock),
+            InlineElement::Text(_) | InlineElement::Badge(_) => {}
+        }
+    }
+
+    /// Returns the time left until the element displays its
+    /// next frame, or `None` if it doesn't change by itself.
+    pub fn time_until_next_frame(&self) -> Option<Duration> {
+        match self {
+            InlineElement::AnimatedText(text) => text.time_until_next_frame(),
+            InlineElement::Spinner(spinner) => {
+                Some(spinner.time_until_next_frame())
+            }
+            InlineElement::Text(_) | InlineElement::Badge(_) => None,
+        }
+    }
+
+    /// Handles provided crossterm event for the element
+    /// rendered in provided area. Badges report clicks with the
+    /// left mouse button, and texts report their interactions.
+    #[cfg(feature = "crossterm")]
+    pub fn handle_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<WidgetEvent> {
+        match self {
+            InlineElement::Text(text) => {
+                text.handle_event(event, area).map(Into::into)
+            }
+            InlineElement::AnimatedText(text) => {
+                text.handle_crossterm_event(event, area).map(Into::into)
+            }
+            InlineElement::Spinner(_) => None,
+            InlineElement::Badge(_) => match event {
+                Event::Mouse(mouse_event)
+                    if mouse_event.kind
+                        == MouseEventKind::Down(MouseButton::Left)
+                        && area.contains(Position::new(
+                            mouse_event.column,
+                            mouse_event.row,
+                        )) =>
+                {
+                    Some(WidgetEvent::Clicked)
+                }
+                _ => None,
+            },
+        }
+    }
+}
+
+impl<'a, K> From<SmallTextWidget> for InlineElement<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn from(value: SmallTextWidget) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl<'a, K> From<AnimatedSmallTextWidget<K>> for InlineElement<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn from(value: AnimatedSmallTextWidget<K>) -> Self {
+        Self::AnimatedText(Box::new(value))
+    }
+}
+
+impl<'a, K> From<SmallSpinnerWidget> for InlineElement<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn from(value: SmallSpinnerWidget) -> Self {
+        Self::Spinner(value)
+    }
+}
+
+impl<'a, K> From<InlineBadge<'a>> for InlineElement<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn from(value: InlineBadge<'a>) -> Self {
+        Self::Badge(value)
+    }
+}
+
+impl<'a, K> AccessibleDescription for InlineElement<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn accessible_description(&self) -> String {
+        match self {
+            InlineElement::Text(text) => text.accessible_description(),
+            InlineElement::AnimatedText(text) => text.accessible_description(),
+            InlineElement::Spinner(spinner) => {
+                spinner.accessible_description()
+            }
+            InlineElement::Badge(badge) => badge.accessible_description(),
+        }
+    }
+}
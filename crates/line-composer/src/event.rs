@@ -0,0 +1,11 @@
+use caponata_common::WidgetEvent;
+
+/// An event produced by an element of [`LineComposer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LineComposerEvent {
+    /// Index of the element that produced the event, in the
+    /// order the elements were added.
+    pub index: usize,
+
+    pub event: WidgetEvent,
+}
@@ -0,0 +1,11 @@
+#![doc = include_str!("../README.md")]
+
+pub mod composer;
+pub mod element;
+#[cfg(feature = "crossterm")]
+pub mod event;
+
+pub use composer::*;
+pub use element::*;
+#[cfg(feature = "crossterm")]
+pub use event::*;
@@ -0,0 +1,99 @@
+use std::{
+    io,
+    time::Duration,
+};
+
+use caponata_odometer::{
+    OdometerEasing,
+    OdometerStyleBuilder,
+    OdometerTransition,
+    OdometerWidget,
+};
+use caponata_small_text::SymbolStyleBuilder;
+use crossterm::event::{
+    Event,
+    KeyCode,
+    poll,
+    read,
+};
+use ratatui::{
+    DefaultTerminal,
+    layout::Rect,
+    style::{
+        Color,
+        Modifier,
+    },
+};
+
+pub fn main() -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal);
+
+    ratatui::restore();
+    result
+}
+
+fn run(terminal: &mut DefaultTerminal) -> io::Result<()> {
+    let mut odometer = make_odometer();
+
+    let mut is_running = true;
+    while is_running {
+        terminal.draw(|frame| {
+            let area = frame.area();
+            let odometer_area = Rect::new(area.x + 1, area.y + 1, 16, 1);
+            frame.render_widget(&mut odometer, odometer_area);
+        })?;
+        is_running = !handle_event(&mut odometer)?;
+    }
+
+    Ok(())
+}
+
+fn make_odometer() -> OdometerWidget {
+    let symbol_style = SymbolStyleBuilder::default()
+        .with_foreground_color(Color::White)
+        .build()
+        .unwrap();
+    let highlight_style = SymbolStyleBuilder::default()
+        .with_foreground_color(Color::Yellow)
+        .with_modifier(Modifier::BOLD)
+        .build()
+        .unwrap();
+
+    let style = OdometerStyleBuilder::default()
+        .with_precision(1)
+        .with_thousands_separator(',')
+        .with_transition(OdometerTransition::Roll)
+        .with_easing(OdometerEasing::EaseOut)
+        .with_duration(Duration::from_millis(400))
+        .with_style(symbol_style)
+        .with_highlight_style(highlight_style)
+        .build()
+        .unwrap();
+    OdometerWidget::new(style, 995.0)
+}
+
+/// Handles a crossterm event and returns a flag indicating
+/// whether the application should be closed.
+fn handle_event(odometer: &mut OdometerWidget) -> io::Result<bool> {
+    let timeout = Duration::from_millis(20);
+    if !poll(timeout)? {
+        return Ok(false);
+    }
+
+    match read()? {
+        Event::Key(key) => match key.code {
+            KeyCode::Char('q') => Ok(true),
+            KeyCode::Up => {
+                odometer.set_value(odometer.value() + 1.5);
+                Ok(false)
+            }
+            KeyCode::Down => {
+                odometer.set_value(odometer.value() - 1.5);
+                Ok(false)
+            }
+            _ => Ok(false),
+        },
+        _ => Ok(false),
+    }
+}
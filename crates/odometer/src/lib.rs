@@ -0,0 +1,7 @@
+#![doc = include_str!("../README.md")]
+
+pub mod odometer;
+pub mod style;
+
+pub use odometer::*;
+pub use style::*;
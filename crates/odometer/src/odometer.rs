@@ -0,0 +1,419 @@
+use std::{
+    collections::HashMap,
+    time::Duration,
+};
+
+use caponata_common::{
+    AccessibleDescription,
+    Identifiable,
+    WidgetIdentity,
+    describe_widget,
+};
+use caponata_small_text::{
+    AnimatedSmallTextWidget,
+    AnimationAction,
+    AnimationEvent,
+    AnimationRepeatMode,
+    AnimationStep,
+    AnimationStepBuilder,
+    AnimationStyle,
+    AnimationStyleBuilder,
+    AnimationStyleError,
+    AnimationTarget,
+    SmallTextStyleBuilder,
+    SymbolStyle,
+    Target,
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::Widget,
+};
+
+use super::{
+    OdometerStyle,
+    OdometerTransition,
+};
+
+/// A widget that displays a number and animates transitions
+/// between its values.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     widgets::Widget,
+/// };
+/// use caponata_odometer::{
+///     OdometerStyleBuilder,
+///     OdometerTransition,
+///     OdometerWidget,
+/// };
+///
+/// let style = OdometerStyleBuilder::default()
+///     .with_transition(OdometerTransition::Roll)
+///     .with_duration(Duration::from_millis(100))
+///     .build()
+///     .unwrap();
+/// let mut odometer = OdometerWidget::new(style, 18.0);
+/// odometer.set_value(21.0);
+///
+/// let area = Rect::new(0, 0, 2, 1);
+/// let mut buf = Buffer::empty(area);
+///
+/// // The first frame of the transition displays the old value.
+/// odometer.render(area, &mut buf);
+/// assert_eq!(buf, Buffer::with_lines(vec!["18"]));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OdometerWidget {
+    style: OdometerStyle,
+    value: f64,
+    text: AnimatedSmallTextWidget<()>,
+    identity: WidgetIdentity,
+}
+
+impl Widget for &mut OdometerWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.text.render(area, buf);
+        self.identity.record(area, &*self);
+    }
+}
+
+impl OdometerWidget {
+    pub fn new(style: OdometerStyle, value: f64) -> Self {
+        let text = make_text(&style.format_value(value), style.style, None);
+
+        Self {
+            style,
+            value,
+            text,
+            identity: WidgetIdentity::default(),
+        }
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the formatted value the widget displays once
+    /// the current transition ends.
+    pub fn text(&self) -> String {
+        self.style.format_value(self.value)
+    }
+
+    pub fn take_animation_event(&mut self) -> Option<AnimationEvent> {
+        self.text.take_animation_event()
+    }
+
+    /// Sets the displayed value, starting the transition from
+    /// the previous one. Has no effect on the displayed text
+    /// if the formatted values are equal.
+    pub fn set_value(&mut self, value: f64) {
+        let old_text = self.text();
+        let is_increasing = value >= self.value;
+        self.value = value;
+        let new_text = self.text();
+
+        if old_text == new_text {
+            return;
+        }
+
+        let steps = match self.style.transition {
+            OdometerTransition::Roll => {
+                self.roll_steps(&old_text, &new_text, is_increasing)
+            }
+            OdometerTransition::Flash => {
+                self.flash_steps(&old_text, &new_text)
+            }
+            OdometerTransition::None => Ok(Vec::new()),
+        };
+        // Transitions without steps, with empty steps or with zero
+        // duration are rejected by the builders, so the new value
+        // is displayed immediately.
+        let animation_style = steps
+            .and_then(|steps| {
+                AnimationStyleBuilder::default()
+                    .with_repeat_mode(AnimationRepeatMode::Finite(1))
+                    .with_steps(steps)
+                    .build()
+            })
+            .ok();
+
+        self.text = make_text(&new_text, self.style.style, animation_style);
+        self.text.enable_animation(&());
+    }
+
+    /// Returns steps rolling the changed digits upwards if the
+    /// value is increasing; otherwise downwards. Changed
+    /// symbols that are not digits are replaced on the last
+    /// step.
+    fn roll_steps(
+        &self,
+        old_text: &str,
+        new_text: &str,
+        is_increasing: bool,
+    ) -> Result<Vec<AnimationStep>, AnimationStyleError> {
+        let changes = changed_symbols(old_text, new_text);
+
+        let distance = |old: char, new: char| -> u32 {
+            match (old.to_digit(10), new.to_digit(10)) {
+                (Some(old), Some(new)) if is_increasing => {
+                    (new + 10 - old) % 10
+                }
+                (Some(old), Some(new)) => (old + 10 - new) % 10,
+                _ => 1,
+            }
+        };
+        let step_count = changes
+            .iter()
+            .map(|(_, old, new)| distance(*old, *new))
+            .max()
+            .unwrap_or(1)
+            .max(1);
+
+        let mut steps = Vec::new();
+        for i in 0..=step_count {
+            let mut step_builder = AnimationStepBuilder::default()
+                .with_duration(self.step_duration(i, step_count));
+
+            for (x, old, new) in changes.iter() {
+                let symbol_value = match old.to_digit(10) {
+                    Some(old_digit) if new.is_ascii_digit() => {
+                        let passed = i.min(distance(*old, *new));
+                        let digit = if is_increasing {
+                            (old_digit + passed) % 10
+                        } else {
+                            (old_digit + 10 - passed) % 10
+                        };
+                        char::from_digit(digit, 10).unwrap()
+                    }
+                    _ if i == step_count => *new,
+                    _ => *old,
+                };
+                let symbol_style = if i == step_count {
+                    self.style.style
+                } else {
+                    self.style.highlight_style
+                };
+
+                let mut actions = style_actions(symbol_style);
+                actions.push(AnimationAction::UpdateCharacter(symbol_value));
+                step_builder = actions
+                    .into_iter()
+                    .fold(
+                        step_builder.for_target(AnimationTarget::Single(*x)),
+                        |accumulator, action| accumulator.do_action(action),
+                    )
+                    .then();
+            }
+            steps.push(step_builder.build()?);
+        }
+
+        Ok(steps)
+    }
+
+    fn flash_steps(
+        &self,
+        old_text: &str,
+        new_text: &str,
+    ) -> Result<Vec<AnimationStep>, AnimationStyleError> {
+        let changes = changed_symbols(old_text, new_text);
+
+        [
+            (self.style.highlight_style, self.style.duration),
+            (self.style.style, Duration::ZERO),
+        ]
+        .into_iter()
+        .map(|(symbol_style, duration)| {
+            let mut step_builder =
+                AnimationStepBuilder::default().with_duration(duration);
+            for (x, _, _) in changes.iter() {
+                step_builder = style_actions(symbol_style)
+                    .into_iter()
+                    .fold(
+                        step_builder.for_target(AnimationTarget::Single(*x)),
+                        |accumulator, action| accumulator.do_action(action),
+                    )
+                    .then();
+            }
+            step_builder.build()
+        })
+        .collect()
+    }
+
+    /// Returns the duration of the step with provided index.
+    /// The last step only displays the final value, so it has
+    /// zero duration.
+    fn step_duration(&self, index: u32, step_count: u32) -> Duration {
+        if index >= step_count {
+            return Duration::ZERO;
+        }
+
+        let easing = self.style.easing;
+        let start = easing.apply(index as f64 / step_count as f64);
+        let end = easing.apply((index + 1) as f64 / step_count as f64);
+
+        self.style.duration.mul_f64(end - start)
+    }
+}
+
+impl AccessibleDescription for OdometerWidget {
+    /// Describes the value displayed once the transition ends,
+    /// so the intermediate digits are not read out.
+    fn accessible_description(&self) -> String {
+        let transition = match self.text.time_until_next_frame() {
+            Some(_) => "changing",
+            None => "",
+        };
+
+        describe_widget("odometer", Some(&self.text()), [transition])
+    }
+}
+
+impl Identifiable for OdometerWidget {
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+fn make_text(
+    text: &str,
+    symbol_style: SymbolStyle,
+    animation_style: Option<AnimationStyle>,
+) -> AnimatedSmallTextWidget<()> {
+    let text_style = SmallTextStyleBuilder::default()
+        .with_text(text)
+        .for_target(Target::Untouched)
+        .set_style(symbol_style)
+        .then()
+        .build();
+    let animation_styles: HashMap<(), AnimationStyle> = animation_style
+        .map(|animation_style| ((), animation_style))
+        .into_iter()
+        .collect();
+
+    AnimatedSmallTextWidget::new(text_style, animation_styles)
+}
+
+/// Returns positions of the symbols of the new text that
+/// differ from the old text, along with the old and the new
+/// symbol values. Texts are aligned to the right.
+fn changed_symbols(old_text: &str, new_text: &str) -> Vec<(u16, char, char)> {
+    let new_chars: Vec<char> = new_text.chars().collect();
+    let old_chars: Vec<char> = old_text.chars().collect();
+
+    let padding = new_chars.len().saturating_sub(old_chars.len());
+    let skipped = old_chars.len().saturating_sub(new_chars.len());
+    let old_chars: Vec<char> = std::iter::repeat_n(' ', padding)
+        .chain(old_chars.into_iter().skip(skipped))
+        .collect();
+
+    new_chars
+        .into_iter()
+        .zip(old_chars)
+        .enumerate()
+        .filter(|(_, (new, old))| new != old)
+        .map(|(x, (new, old))| (x as u16, old, new))
+        .collect()
+}
+
+/// Returns actions applying provided style to a symbol.
+/// Animation actions can't make a color transparent, so unset
+/// colors are reset instead.
+fn style_actions(symbol_style: SymbolStyle) -> Vec<AnimationAction> {
+    vec![
+        AnimationAction::UpdateForegroundColor(
+            symbol_style.foreground_color.unwrap_or_default(),
+        ),
+        AnimationAction::UpdateBackgroundColor(
+            symbol_style.background_color.unwrap_or_default(),
+        ),
+        AnimationAction::RemoveAllModifiers,
+        AnimationAction::AddModifier(symbol_style.modifier),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use caponata_common::AccessibleDescription;
+    use caponata_small_text::{
+        AnimationEvent,
+        SymbolStyleBuilder,
+    };
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        style::Color,
+        widgets::Widget,
+    };
+
+    use super::OdometerWidget;
+    use crate::{
+        OdometerStyleBuilder,
+        OdometerTransition,
+    };
+
+    #[test]
+    fn value_is_formatted_and_rendered() {
+        let style = OdometerStyleBuilder::default()
+            .with_precision(2)
+            .with_thousands_separator(',')
+            .with_transition(OdometerTransition::None)
+            .build()
+            .unwrap();
+        let mut odometer = OdometerWidget::new(style, 999.5);
+        odometer.set_value(1234.5);
+
+        let area = Rect::new(0, 0, 8, 1);
+        let mut buf = Buffer::empty(area);
+        odometer.render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["1,234.50"]));
+        assert_eq!(odometer.take_animation_event(), None);
+        assert_eq!(odometer.accessible_description(), "odometer '1,234.50'",);
+    }
+
+    #[test]
+    fn changed_digits_are_flashed() {
+        let highlight_style = SymbolStyleBuilder::default()
+            .with_foreground_color(Color::Red)
+            .build()
+            .unwrap();
+        let style = OdometerStyleBuilder::default()
+            .with_transition(OdometerTransition::Flash)
+            .with_duration(Duration::from_secs(60))
+            .with_highlight_style(highlight_style)
+            .build()
+            .unwrap();
+        let mut odometer = OdometerWidget::new(style, 10.0);
+        odometer.set_value(12.0);
+
+        let area = Rect::new(0, 0, 2, 1);
+        let mut buf = Buffer::empty(area);
+        odometer.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "1");
+        assert_eq!(buf[(0, 0)].fg, Color::Reset);
+        assert_eq!(buf[(1, 0)].symbol(), "2");
+        assert_eq!(buf[(1, 0)].fg, Color::Red);
+
+        odometer.render(area, &mut buf);
+        assert_eq!(
+            odometer.take_animation_event(),
+            Some(AnimationEvent::FrameGenerated),
+        );
+        assert_eq!(
+            odometer.accessible_description(),
+            "odometer '12', changing",
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use std::time::Duration;
+
+use caponata_small_text::SymbolStyle;
+use derive_builder::Builder;
+
+/// Type of the animation played by [`OdometerWidget`] when
+/// its value changes.
+///
+/// Default variant is [`OdometerTransition::Roll`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OdometerTransition {
+    /// Changed digits roll through intermediate digits
+    /// towards the new ones.
+    #[default]
+    Roll,
+
+    /// Changed symbols are briefly highlighted with the
+    /// highlight style.
+    Flash,
+
+    /// The new value is displayed immediately.
+    None,
+}
+
+/// Specifies how the duration of a transition is distributed
+/// between its steps.
+///
+/// Default variant is [`OdometerEasing::Linear`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OdometerEasing {
+    /// All steps last the same time.
+    #[default]
+    Linear,
+
+    /// Steps start slow and speed up.
+    EaseIn,
+
+    /// Steps start fast and slow down.
+    EaseOut,
+}
+
+impl OdometerEasing {
+    /// Maps provided progress of the transition in the
+    /// `0.0..=1.0` range to eased progress.
+    pub fn apply(&self, progress: f64) -> f64 {
+        let progress = progress.clamp(0.0, 1.0);
+
+        match self {
+            Self::Linear => progress,
+            Self::EaseIn => progress * progress,
+            Self::EaseOut => 1.0 - (1.0 - progress) * (1.0 - progress),
+        }
+    }
+}
+
+/// A styling configuration for [`OdometerWidget`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::style::{Color, Modifier};
+/// use caponata_small_text::SymbolStyleBuilder;
+/// use caponata_odometer::{
+///     OdometerStyleBuilder,
+///     OdometerTransition,
+/// };
+///
+/// let highlight_style = SymbolStyleBuilder::default()
+///     .with_foreground_color(Color::Black)
+///     .with_background_color(Color::Yellow)
+///     .with_modifier(Modifier::BOLD)
+///     .build()
+///     .unwrap();
+/// let style = OdometerStyleBuilder::default()
+///     .with_precision(1)
+///     .with_decimal_separator(',')
+///     .with_thousands_separator(' ')
+///     .with_transition(OdometerTransition::Flash)
+///     .with_highlight_style(highlight_style)
+///     .with_duration(Duration::from_millis(200))
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(style.format_value(-12345.67), "-12 345,7");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Builder)]
+#[builder(setter(prefix = "with", into))]
+pub struct OdometerStyle {
+    /// Number of digits displayed after the decimal
+    /// separator.
+    #[builder(default)]
+    pub(crate) precision: u8,
+
+    #[builder(default = "'.'")]
+    pub(crate) decimal_separator: char,
+
+    #[builder(default, setter(strip_option))]
+    pub(crate) thousands_separator: Option<char>,
+
+    #[builder(default)]
+    pub(crate) transition: OdometerTransition,
+
+    #[builder(default)]
+    pub(crate) easing: OdometerEasing,
+
+    /// The total duration of a transition.
+    #[builder(default)]
+    pub(crate) duration: Duration,
+
+    /// Style of the symbols that are not being changed.
+    #[builder(default)]
+    pub(crate) style: SymbolStyle,
+
+    /// Style of the changed symbols during a transition.
+    #[builder(default)]
+    pub(crate) highlight_style: SymbolStyle,
+}
+
+impl Default for OdometerStyle {
+    fn default() -> Self {
+        Self {
+            precision: 0,
+            decimal_separator: '.',
+            thousands_separator: None,
+            transition: OdometerTransition::default(),
+            easing: OdometerEasing::default(),
+            duration: Duration::default(),
+            style: SymbolStyle::default(),
+            highlight_style: SymbolStyle::default(),
+        }
+    }
+}
+
+impl OdometerStyle {
+    /// Formats provided value according to the precision and
+    /// the separators of the style.
+    pub fn format_value(&self, value: f64) -> String {
+        let formatted = format!("{:.*}", self.precision as usize, value.abs());
+        let (integer_part, fractional_part) = match formatted.split_once('.') {
+            Some((integer_part, fractional_part)) => {
+                (integer_part, Some(fractional_part))
+            }
+            None => (formatted.as_str(), None),
+        };
+
+        let mut result = String::new();
+        let is_zero = formatted.chars().all(|c| c == '0' || c == '.');
+        if value.is_sign_negative() && !is_zero {
+            result.push('-');
+        }
+
+        let digit_count = integer_part.chars().count();
+        for (i, digit) in integer_part.chars().enumerate() {
+            let digits_left = digit_count - i;
+            if i != 0
+                && digits_left % 3 == 0
+                && let Some(separator) = self.thousands_separator
+            {
+                result.push(separator);
+            }
+            result.push(digit);
+        }
+
+        if let Some(fractional_part) = fractional_part {
+            result.push(self.decimal_separator);
+            result.push_str(fractional_part);
+        }
+
+        result
+    }
+}
@@ -0,0 +1,92 @@
+use std::{
+    io,
+    time::Duration,
+};
+
+use caponata_common::Easing;
+use caponata_pagination::{
+    PaginationKind,
+    PaginationStyleBuilder,
+    PaginationWidget,
+};
+use crossterm::{
+    event::{
+        DisableMouseCapture,
+        EnableMouseCapture,
+        Event,
+        KeyCode,
+        poll,
+        read,
+    },
+    execute,
+};
+use ratatui::{
+    DefaultTerminal,
+    layout::Rect,
+    style::{
+        Color,
+        Modifier,
+    },
+};
+
+pub fn main() -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    execute!(io::stdout(), EnableMouseCapture)?;
+    let result = run(&mut terminal);
+
+    execute!(io::stdout(), DisableMouseCapture)?;
+    ratatui::restore();
+    result
+}
+
+fn run(terminal: &mut DefaultTerminal) -> io::Result<()> {
+    let dots_style = PaginationStyleBuilder::default()
+        .with_color(Color::DarkGray)
+        .with_current_page_color(Color::Cyan)
+        .with_highlight_duration(Duration::from_millis(300))
+        .with_highlight_easing(Easing::EaseOut)
+        .build()
+        .unwrap();
+    let fraction_style = PaginationStyleBuilder::default()
+        .with_kind(PaginationKind::Fraction)
+        .with_color(Color::DarkGray)
+        .with_current_page_color(Color::Cyan)
+        .with_current_page_modifier(Modifier::BOLD)
+        .build()
+        .unwrap();
+    let mut dots = PaginationWidget::new(dots_style, 5);
+    let mut fraction = PaginationWidget::new(fraction_style, 14);
+
+    loop {
+        let mut dots_area = Rect::default();
+        terminal.draw(|frame| {
+            let area = frame.area();
+            dots_area = Rect::new(area.x + 1, area.y + 1, 9, 1);
+            let fraction_area = Rect::new(area.x + 1, area.y + 3, 5, 1);
+            frame.render_widget(&dots, dots_area);
+            frame.render_widget(&fraction, fraction_area);
+        })?;
+
+        let timeout = Duration::from_millis(16);
+        if !poll(timeout)? {
+            continue;
+        }
+        match read()? {
+            Event::Key(key) => match key.code {
+                KeyCode::Char('q') => return Ok(()),
+                KeyCode::Left => {
+                    dots.prev();
+                    fraction.prev();
+                }
+                KeyCode::Right => {
+                    dots.next();
+                    fraction.next();
+                }
+                _ => {}
+            },
+            event => {
+                dots.handle_event(event, dots_area);
+            }
+        }
+    }
+}
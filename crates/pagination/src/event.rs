@@ -0,0 +1,10 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PaginationEvent {
+    /// Triggered when a page symbol of a [`PaginationWidget`]
+    /// is clicked with the left mouse button. The event
+    /// includes the index of the new current page.
+    ///
+    /// [`PaginationWidget`]: crate::PaginationWidget
+    PageChanged(usize),
+}
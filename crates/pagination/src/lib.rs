@@ -0,0 +1,11 @@
+#![doc = include_str!("../README.md")]
+
+#[cfg(feature = "crossterm")]
+pub mod event;
+pub mod pagination;
+pub mod style;
+
+#[cfg(feature = "crossterm")]
+pub use event::*;
+pub use pagination::*;
+pub use style::*;
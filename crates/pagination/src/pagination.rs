@@ -0,0 +1,485 @@
+use std::time::Duration;
+
+#[cfg(feature = "crossterm")]
+use caponata_common::HandlesEvent;
+use caponata_common::{
+    AccessibleDescription,
+    Animated,
+    Identifiable,
+    Instant,
+    SharedClock,
+    WidgetIdentity,
+    describe_widget,
+    interpolate_color,
+    render_adapted,
+};
+#[cfg(feature = "crossterm")]
+use crossterm::event::{
+    Event,
+    MouseButton,
+    MouseEventKind,
+};
+#[cfg(feature = "crossterm")]
+use ratatui::layout::Position;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{
+        Color,
+        Style,
+    },
+    text::Span,
+    widgets::Widget,
+};
+
+#[cfg(feature = "crossterm")]
+use super::PaginationEvent;
+use super::{
+    PaginationKind,
+    PaginationStyle,
+};
+
+/// A compact one-line widget that displays the current page
+/// among the others, either as a symbol for each page, e.g.
+/// `● ○ ○ ○`, or as a fraction, e.g. `2/14`.
+///
+/// When the page is changed, the highlight moves from the
+/// previous page to the new one with an animation configured
+/// by the style.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     widgets::Widget,
+/// };
+/// use caponata_pagination::{
+///     PaginationStyleBuilder,
+///     PaginationWidget,
+/// };
+///
+/// let style = PaginationStyleBuilder::default()
+///     .with_highlight_duration(Duration::ZERO)
+///     .build()
+///     .unwrap();
+/// let mut pagination = PaginationWidget::new(style, 4);
+/// pagination.next();
+///
+/// let area = Rect::new(0, 0, 7, 1);
+/// let mut buf = Buffer::empty(area);
+/// pagination.render(area, &mut buf);
+/// assert_eq!(buf, Buffer::with_lines(["○ ● ○ ○"]));
+///
+/// let area = Rect::new(0, 0, 5, 1);
+/// let mut buf = Buffer::empty(area);
+/// pagination.render(area, &mut buf);
+/// assert_eq!(buf, Buffer::with_lines(["2/4  "]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaginationWidget {
+    style: PaginationStyle,
+    page_count: usize,
+    page: usize,
+
+    /// The page that was current before the last change, and
+    /// the time of the change. Used to animate the highlight.
+    previous_page: Option<(usize, Instant)>,
+
+    clock: SharedClock,
+    identity: WidgetIdentity,
+}
+
+impl Default for PaginationWidget {
+    fn default() -> Self {
+        Self::new(PaginationStyle::default(), 0)
+    }
+}
+
+impl Widget for &PaginationWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.identity.record(area, self);
+        let area = area.intersection(buf.area);
+        if area.is_empty() || self.page_count == 0 {
+            return;
+        }
+
+        render_adapted(area, buf, |buf| {
+            if self.displays_dots(area) {
+                self.render_dots(area, buf);
+            } else {
+                self.render_fraction(area, buf);
+            }
+        });
+    }
+}
+
+impl PaginationWidget {
+    /// Interval between the frames of the highlight animation,
+    /// reported by [`Animated::time_until_next_frame`].
+    pub const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+    /// Creates a widget with provided number of pages, with
+    /// the first page being the current one.
+    pub fn new(style: PaginationStyle, page_count: usize) -> Self {
+        Self {
+            style,
+            page_count,
+            page: 0,
+            previous_page: None,
+            clock: SharedClock::default(),
+            identity: WidgetIdentity::default(),
+        }
+    }
+
+    /// Returns the index of the current page.
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.page_count
+    }
+
+    /// Makes the page with provided index the current one,
+    /// moving the highlight to it. Indices past the last page
+    /// are clamped to it.
+    pub fn set_page(&mut self, page: usize) {
+        let page = page.min(self.page_count.saturating_sub(1));
+        if page == self.page {
+            return;
+        }
+
+        self.previous_page = Some((self.page, self.clock.now()));
+        self.page = page;
+    }
+
+    /// Makes the next page the current one. Does nothing if
+    /// the current page is the last one.
+    pub fn next(&mut self) {
+        self.set_page(self.page + 1);
+    }
+
+    /// Makes the previous page the current one. Does nothing
+    /// if the current page is the first one.
+    pub fn prev(&mut self) {
+        self.set_page(self.page.saturating_sub(1));
+    }
+
+    /// Sets provided number of pages. The current page is
+    /// clamped to the last one.
+    pub fn set_page_count(&mut self, page_count: usize) {
+        self.page_count = page_count;
+        self.previous_page = None;
+        self.page = self.page.min(page_count.saturating_sub(1));
+    }
+
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+    }
+
+    /// Returns the time left until the next frame of the
+    /// highlight animation, or `None` if it has completed.
+    pub fn time_until_next_frame(&self) -> Option<Duration> {
+        let (_, changed_at) = self.previous_page?;
+        let elapsed = self.clock.now().saturating_duration_since(changed_at);
+        let time_left = self.style.highlight_duration.checked_sub(elapsed)?;
+
+        (!time_left.is_zero()).then_some(time_left.min(Self::FRAME_INTERVAL))
+    }
+
+    /// Returns the progress of the highlight animation in the
+    /// `0.0..=1.0` range, with easing applied.
+    fn highlight_progress(&self) -> f64 {
+        let Some((_, changed_at)) = self.previous_page else {
+            return 1.0;
+        };
+        let duration = self.style.highlight_duration;
+        if duration.is_zero() {
+            return 1.0;
+        }
+
+        let elapsed = self.clock.now().saturating_duration_since(changed_at);
+        let progress = elapsed.as_secs_f64() / duration.as_secs_f64();
+        self.style.highlight_easing.apply(progress)
+    }
+
+    /// Returns the color of the page with provided index,
+    /// taking the highlight animation into account.
+    fn page_color(&self, page: usize, progress: f64) -> Color {
+        let (color, current_page_color) =
+            (self.style.color, self.style.current_page_color);
+
+        match self.previous_page {
+            _ if page == self.page => {
+                interpolate_color(color, current_page_color, progress)
+            }
+            Some((previous_page, _)) if page == previous_page => {
+                interpolate_color(current_page_color, color, progress)
+            }
+            _ => color,
+        }
+    }
+
+    fn page_style(&self, page: usize, progress: f64) -> Style {
+        let mut style = Style::default().fg(self.page_color(page, progress));
+        if page == self.page
+            && let Some(modifier) = self.style.current_page_modifier
+        {
+            style = style.add_modifier(modifier);
+        }
+        style
+    }
+
+    fn dots_width(&self) -> usize {
+        let spacing_count = self.page_count.saturating_sub(1);
+        self.page_count + spacing_count * self.style.spacing as usize
+    }
+
+    fn displays_dots(&self, area: Rect) -> bool {
+        self.style.kind == PaginationKind::Dots
+            && self.dots_width() <= area.width as usize
+    }
+
+    /// Returns the x coordinate of the symbol of the page with
+    /// provided index.
+    fn dot_x(&self, area: Rect, page: usize) -> u16 {
+        area.x + page as u16 * (self.style.spacing + 1)
+    }
+
+    fn render_dots(&self, area: Rect, buf: &mut Buffer) {
+        let progress = self.highlight_progress();
+        for page in 0..self.page_count {
+            let symbol = match page == self.page {
+                true => self.style.current_page_symbol,
+                false => self.style.page_symbol,
+            };
+            buf[(self.dot_x(area, page), area.y)]
+                .set_char(symbol)
+                .set_style(self.page_style(page, progress));
+        }
+    }
+
+    fn render_fraction(&self, area: Rect, buf: &mut Buffer) {
+        let progress = self.highlight_progress();
+        let page_number = (self.page + 1).to_string();
+        let page_count = format!("/{}", self.page_count);
+        let page_count_x = area.x + Span::raw(&page_number).width() as u16;
+
+        buf.set_stringn(
+            area.x,
+            area.y,
+            &page_number,
+            area.width as usize,
+            self.page_style(self.page, progress),
+        );
+        if page_count_x < area.right() {
+            buf.set_stringn(
+                page_count_x,
+                area.y,
+                page_count,
+                (area.right() - page_count_x) as usize,
+                Style::default().fg(self.style.color),
+            );
+        }
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl PaginationWidget {
+    /// Jumps to the page whose symbol is clicked with the left
+    /// mouse button. Pages displayed as a fraction can't be
+    /// clicked.
+    pub fn handle_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<PaginationEvent> {
+        let Event::Mouse(mouse_event) = event else {
+            return None;
+        };
+        if mouse_event.kind != MouseEventKind::Down(MouseButton::Left) {
+            return None;
+        }
+
+        let position = Position::new(mouse_event.column, mouse_event.row);
+        let page = self.page_at(area, position)?;
+        if page == self.page {
+            return None;
+        }
+        self.set_page(page);
+        Some(PaginationEvent::PageChanged(page))
+    }
+
+    /// Returns the index of the page whose symbol is rendered
+    /// at provided position, if any. Widget's area is
+    /// calculated based on provided area.
+    fn page_at(&self, area: Rect, position: Position) -> Option<usize> {
+        if position.y != area.y
+            || !area.contains(position)
+            || !self.displays_dots(area)
+        {
+            return None;
+        }
+
+        (0..self.page_count).find(|page| self.dot_x(area, *page) == position.x)
+    }
+}
+
+impl Animated for PaginationWidget {
+    fn time_until_next_frame(&self) -> Option<Duration> {
+        PaginationWidget::time_until_next_frame(self)
+    }
+}
+
+impl AccessibleDescription for PaginationWidget {
+    fn accessible_description(&self) -> String {
+        let page = match self.page_count {
+            0 => "no pages".to_string(),
+            page_count => format!("page {} of {page_count}", self.page + 1),
+        };
+
+        describe_widget("pagination", None, [&*page])
+    }
+}
+
+impl Identifiable for PaginationWidget {
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl HandlesEvent for PaginationWidget {
+    type Event = PaginationEvent;
+
+    fn handle_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<PaginationEvent> {
+        PaginationWidget::handle_event(self, event, area)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use caponata_common::{
+        AccessibleDescription,
+        AnimationGroup,
+    };
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        style::Color,
+        widgets::Widget,
+    };
+
+    use super::PaginationWidget;
+    use crate::{
+        PaginationKind,
+        PaginationStyleBuilder,
+    };
+
+    #[test]
+    fn highlight_moves_to_new_page() {
+        let style = PaginationStyleBuilder::default()
+            .with_color(Color::Rgb(0, 0, 0))
+            .with_current_page_color(Color::Rgb(200, 200, 200))
+            .with_highlight_duration(Duration::from_millis(100))
+            .build()
+            .unwrap();
+        let mut pagination = PaginationWidget::new(style, 3);
+        let group = AnimationGroup::new();
+        pagination.set_clock(group.clock());
+        assert_eq!(pagination.time_until_next_frame(), None);
+
+        pagination.prev();
+        assert_eq!(pagination.page(), 0);
+        pagination.set_page(5);
+        assert_eq!(pagination.page(), 2);
+        group.advance(Duration::from_millis(50));
+
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buf = Buffer::empty(area);
+        pagination.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "○");
+        assert_eq!(buf[(0, 0)].fg, Color::Rgb(100, 100, 100));
+        assert_eq!(buf[(2, 0)].fg, Color::Rgb(0, 0, 0));
+        assert_eq!(buf[(4, 0)].symbol(), "●");
+        assert_eq!(buf[(4, 0)].fg, Color::Rgb(100, 100, 100));
+        assert_eq!(
+            pagination.time_until_next_frame(),
+            Some(PaginationWidget::FRAME_INTERVAL),
+        );
+
+        group.advance(Duration::from_millis(50));
+        pagination.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].fg, Color::Rgb(0, 0, 0));
+        assert_eq!(buf[(4, 0)].fg, Color::Rgb(200, 200, 200));
+        assert_eq!(pagination.time_until_next_frame(), None);
+    }
+
+    #[test]
+    fn fraction_is_rendered() {
+        let style = PaginationStyleBuilder::default()
+            .with_kind(PaginationKind::Fraction)
+            .build()
+            .unwrap();
+        let mut pagination = PaginationWidget::new(style, 14);
+        pagination.set_page(11);
+        pagination.next();
+        pagination.next();
+
+        let area = Rect::new(0, 0, 6, 1);
+        let mut buf = Buffer::empty(area);
+        pagination.render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["14/14 "]));
+        assert_eq!(
+            pagination.accessible_description(),
+            "pagination, page 14 of 14"
+        );
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn clicked_page_becomes_current() {
+        use crossterm::event::{
+            Event,
+            KeyModifiers,
+            MouseButton,
+            MouseEvent,
+            MouseEventKind,
+        };
+
+        use crate::PaginationEvent;
+
+        let click = |column| {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row: 0,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        let mut pagination = PaginationWidget::new(Default::default(), 4);
+        let area = Rect::new(0, 0, 10, 1);
+
+        assert_eq!(
+            pagination.handle_event(click(4), area),
+            Some(PaginationEvent::PageChanged(2)),
+        );
+        assert_eq!(pagination.page(), 2);
+        assert_eq!(pagination.handle_event(click(4), area), None);
+        assert_eq!(pagination.handle_event(click(5), area), None);
+    }
+}
@@ -0,0 +1,105 @@
+use std::time::Duration;
+
+use caponata_common::Easing;
+use derive_builder::Builder;
+use ratatui::style::{
+    Color,
+    Modifier,
+};
+
+/// Specifies how a [`PaginationWidget`] displays the pages.
+///
+/// Default variant is [`PaginationKind::Dots`].
+///
+/// [`PaginationWidget`]: crate::PaginationWidget
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PaginationKind {
+    /// A symbol for each page, e.g. `● ○ ○ ○`. Falls back to
+    /// [`PaginationKind::Fraction`] when the symbols don't fit
+    /// into the area.
+    #[default]
+    Dots,
+
+    /// The number of the current page and the number of
+    /// pages, e.g. `2/14`.
+    Fraction,
+}
+
+/// A styling configuration for [`PaginationWidget`].
+///
+/// [`PaginationWidget`]: crate::PaginationWidget
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::style::Color;
+/// use caponata_common::Easing;
+/// use caponata_pagination::{
+///     PaginationKind,
+///     PaginationStyleBuilder,
+/// };
+///
+/// let style = PaginationStyleBuilder::default()
+///     .with_kind(PaginationKind::Dots)
+///     .with_page_symbol('·')
+///     .with_current_page_symbol('•')
+///     .with_color(Color::DarkGray)
+///     .with_current_page_color(Color::White)
+///     .with_highlight_duration(Duration::from_millis(150))
+///     .with_highlight_easing(Easing::EaseOut)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Builder)]
+#[builder(setter(prefix = "with", into, strip_option))]
+pub struct PaginationStyle {
+    #[builder(default)]
+    pub(crate) kind: PaginationKind,
+
+    #[builder(default = "'○'")]
+    pub(crate) page_symbol: char,
+
+    #[builder(default = "'●'")]
+    pub(crate) current_page_symbol: char,
+
+    /// Number of cells between adjacent page symbols.
+    #[builder(default = "1")]
+    pub(crate) spacing: u16,
+
+    #[builder(default)]
+    pub(crate) color: Color,
+
+    #[builder(default)]
+    pub(crate) current_page_color: Color,
+
+    #[builder(default)]
+    pub(crate) current_page_modifier: Option<Modifier>,
+
+    /// Duration of the animation in which the color of the
+    /// current page changes to the highlighted one, and the
+    /// color of the previous page changes back, after the
+    /// page is changed. Zero duration disables the animation.
+    #[builder(default = "Duration::from_millis(200)")]
+    pub(crate) highlight_duration: Duration,
+
+    #[builder(default)]
+    pub(crate) highlight_easing: Easing,
+}
+
+impl Default for PaginationStyle {
+    fn default() -> Self {
+        Self {
+            kind: PaginationKind::default(),
+            page_symbol: '○',
+            current_page_symbol: '●',
+            spacing: 1,
+            color: Color::default(),
+            current_page_color: Color::default(),
+            current_page_modifier: None,
+            highlight_duration: Duration::from_millis(200),
+            highlight_easing: Easing::default(),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use std::{
+    io,
+    time::Duration,
+};
+
+use caponata_slider::{
+    SliderEvent,
+    SliderLabel,
+    SliderStateStyleBuilder,
+    SliderStatus,
+    SliderStyleBuilder,
+    SliderWidget,
+};
+use crossterm::{
+    event::{
+        EnableMouseCapture,
+        Event,
+        KeyCode,
+        poll,
+        read,
+    },
+    execute,
+};
+use ratatui::{
+    DefaultTerminal,
+    layout::Rect,
+    style::{
+        Color,
+        Modifier,
+    },
+    text::Line,
+};
+
+pub fn main() -> io::Result<()> {
+    execute!(io::stdout(), EnableMouseCapture)?;
+
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal);
+
+    ratatui::restore();
+    result
+}
+
+fn run(terminal: &mut DefaultTerminal) -> io::Result<()> {
+    let mut slider = make_slider();
+    slider.set_value(40.0);
+
+    let mut should_exit = false;
+    let mut widget_area = Rect::default();
+    let mut status = String::from("Use arrows, mouse or 'e' to toggle");
+
+    while !should_exit {
+        terminal.draw(|frame| {
+            let area = frame.area();
+            frame.render_widget(
+                Line::from(status.as_str()),
+                Rect::new(area.x + 1, area.y + 1, area.width - 1, 1),
+            );
+            widget_area = Rect::new(area.x + 1, area.y + 3, 40, 1);
+            frame.render_widget(&slider, widget_area);
+        })?;
+
+        let timeout = Duration::from_millis(100);
+        if !poll(timeout)? {
+            continue;
+        }
+        let event = read()?;
+
+        if let Event::Key(key) = event {
+            match key.code {
+                KeyCode::Char('q') => should_exit = true,
+                KeyCode::Char('e') => {
+                    if slider.status() == SliderStatus::Disabled {
+                        slider.enable();
+                    } else {
+                        slider.disable();
+                    }
+                }
+                _ => {}
+            }
+        }
+        if let Some(SliderEvent::ValueChanged(value)) =
+            slider.on_crossterm_event(event, widget_area)
+        {
+            status = format!("Volume changed to {}", value);
+        }
+    }
+
+    Ok(())
+}
+
+fn make_slider() -> SliderWidget {
+    let normal_style = SliderStateStyleBuilder::default()
+        .with_handle_color(Color::White)
+        .build()
+        .unwrap();
+    let hovered_style = SliderStateStyleBuilder::default()
+        .with_handle_color(Color::LightGreen)
+        .with_handle_modifier(Modifier::BOLD)
+        .build()
+        .unwrap();
+    let dragged_style = SliderStateStyleBuilder::default()
+        .with_handle_symbol('◉')
+        .with_handle_color(Color::Green)
+        .with_handle_modifier(Modifier::BOLD)
+        .build()
+        .unwrap();
+    let disabled_style = SliderStateStyleBuilder::default()
+        .with_handle_color(Color::DarkGray)
+        .build()
+        .unwrap();
+
+    let slider_style = SliderStyleBuilder::default()
+        .with_min(0.0)
+        .with_max(100.0)
+        .with_step(5.0)
+        .with_label(SliderLabel::Value)
+        .with_filled_track_color(Color::Green)
+        .with_track_color(Color::DarkGray)
+        .with_normal_style(normal_style)
+        .with_hovered_style(hovered_style)
+        .with_dragged_style(dragged_style)
+        .with_disabled_style(disabled_style)
+        .build()
+        .unwrap();
+    SliderWidget::new(slider_style)
+}
@@ -0,0 +1,8 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum SliderEvent {
+    /// Triggered when the value of a [`SliderWidget`] is
+    /// changed by the mouse or the keyboard. The event
+    /// includes the new value.
+    ValueChanged(f64),
+}
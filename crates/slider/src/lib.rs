@@ -0,0 +1,13 @@
+#![doc = include_str!("../README.md")]
+
+#[cfg(feature = "crossterm")]
+pub mod event;
+pub mod slider;
+pub mod status;
+pub mod style;
+
+#[cfg(feature = "crossterm")]
+pub use event::*;
+pub use slider::*;
+pub use status::*;
+pub use style::*;
@@ -0,0 +1,558 @@
+use caponata_common::{
+    AccessibleDescription,
+    Identifiable,
+    WidgetIdentity,
+    describe_widget,
+    render_adapted,
+};
+#[cfg(feature = "crossterm")]
+use caponata_common::{
+    Focusable,
+    HandlesEvent,
+};
+#[cfg(feature = "crossterm")]
+use crossterm::event::{
+    Event,
+    KeyCode,
+    KeyEvent,
+    KeyEventKind,
+    MouseButton,
+    MouseEventKind,
+};
+#[cfg(feature = "crossterm")]
+use ratatui::layout::Position;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    widgets::Widget,
+};
+
+#[cfg(feature = "crossterm")]
+use super::SliderEvent;
+use super::{
+    SliderLabel,
+    SliderStateStyle,
+    SliderStatus,
+    SliderStyle,
+};
+
+/// A one-line widget that displays a value between the minimum
+/// and the maximum as a handle on a track.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     widgets::Widget,
+/// };
+/// use caponata_slider::{
+///     SliderLabel,
+///     SliderStyleBuilder,
+///     SliderWidget,
+/// };
+///
+/// let slider_style = SliderStyleBuilder::default()
+///     .with_min(0.0)
+///     .with_max(10.0)
+///     .with_step(2.5)
+///     .with_label(SliderLabel::Percent)
+///     .build()
+///     .unwrap();
+/// let mut slider = SliderWidget::new(slider_style);
+///
+/// slider.set_value(6.0);
+/// assert_eq!(slider.value(), 5.0);
+///
+/// let area = Rect::new(0, 0, 14, 1);
+/// let mut buf = Buffer::empty(area);
+/// slider.render(area, &mut buf);
+///
+/// assert_eq!(buf, Buffer::with_lines(vec!["━━━━●────  50%"]));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SliderWidget {
+    style: SliderStyle,
+    value: f64,
+    status: SliderStatus,
+    is_focused: bool,
+    identity: WidgetIdentity,
+}
+
+impl Widget for &SliderWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.identity.record(area, self);
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+
+        render_adapted(area, buf, |buf| {
+            let track_area = self.track_area(area);
+            let handle_x = self.handle_x(track_area);
+            let state_style = self.state_style();
+
+            for x in track_area.x..track_area.x + track_area.width {
+                let (symbol, style) = if x == handle_x {
+                    let mut style =
+                        Style::default().fg(state_style.handle_color);
+                    if let Some(modifier) = state_style.handle_modifier {
+                        style = style.add_modifier(modifier);
+                    }
+                    (state_style.handle_symbol, style)
+                } else if x < handle_x {
+                    let style =
+                        Style::default().fg(self.style.filled_track_color);
+                    (self.style.filled_track_symbol, style)
+                } else {
+                    let style = Style::default().fg(self.style.track_color);
+                    (self.style.track_symbol, style)
+                };
+                buf[(x, area.y)].set_char(symbol).set_style(style);
+            }
+
+            if let Some(label) = self.label() {
+                let label_area = Rect::new(
+                    track_area.x + track_area.width,
+                    area.y,
+                    area.width - track_area.width,
+                    1,
+                );
+                let label =
+                    format!("{:>1$}", label, label_area.width as usize);
+                let style = Style::default().fg(self.style.label_color);
+                buf.set_stringn(
+                    label_area.x,
+                    label_area.y,
+                    label,
+                    label_area.width as usize,
+                    style,
+                );
+            }
+        });
+    }
+}
+
+impl SliderWidget {
+    pub fn new(style: SliderStyle) -> Self {
+        Self {
+            value: style.min,
+            style,
+            status: SliderStatus::Normal,
+            is_focused: false,
+            identity: WidgetIdentity::default(),
+        }
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn status(&self) -> SliderStatus {
+        self.status
+    }
+
+    /// Sets provided value, rounded to the nearest step and
+    /// clamped to the range of the slider.
+    pub fn set_value(&mut self, value: f64) {
+        let (min, max, step) =
+            (self.style.min, self.style.max, self.style.step);
+        if value.is_nan() || max <= min {
+            self.value = min;
+            return;
+        }
+
+        let value = if step > 0.0 {
+            min + ((value - min) / step).round() * step
+        } else {
+            value
+        };
+        self.value = value.clamp(min, max);
+    }
+
+    /// Increases the value by one step. If the step is zero,
+    /// the value is increased by one percent of the range.
+    pub fn increment(&mut self) {
+        self.set_value(self.value + self.keyboard_step());
+    }
+
+    /// Decreases the value by one step. If the step is zero,
+    /// the value is decreased by one percent of the range.
+    pub fn decrement(&mut self) {
+        self.set_value(self.value - self.keyboard_step());
+    }
+
+    /// Sets the slider status to [`SliderStatus::Disabled`] if
+    /// it is not currently disabled. Does nothing if the
+    /// slider is disabled.
+    pub fn disable(&mut self) {
+        self.status = SliderStatus::Disabled;
+    }
+
+    /// Sets the slider status to [`SliderStatus::Normal`] if it
+    /// is currently disabled. Does nothing if the slider is
+    /// not disabled.
+    pub fn enable(&mut self) {
+        if self.status == SliderStatus::Disabled {
+            self.status = SliderStatus::Normal;
+        }
+    }
+
+    fn keyboard_step(&self) -> f64 {
+        if self.style.step > 0.0 {
+            self.style.step
+        } else {
+            (self.style.max - self.style.min) / 100.0
+        }
+    }
+
+    fn state_style(&self) -> SliderStateStyle {
+        match self.status {
+            SliderStatus::Normal => self.style.normal_style,
+            SliderStatus::Hovered => self.style.hovered_style,
+            SliderStatus::Dragged => self.style.dragged_style,
+            SliderStatus::Disabled => self.style.disabled_style,
+        }
+    }
+
+    /// Returns the position of the value between the minimum
+    /// and the maximum in the `0.0..=1.0` range.
+    fn ratio(&self) -> f64 {
+        let range = self.style.max - self.style.min;
+        if range > 0.0 {
+            (self.value - self.style.min) / range
+        } else {
+            0.0
+        }
+    }
+
+    fn label(&self) -> Option<String> {
+        match self.style.label {
+            SliderLabel::None => None,
+            SliderLabel::Value => Some(self.format_value(self.value)),
+            SliderLabel::Percent => {
+                Some(format!("{:.0}%", self.ratio() * 100.0))
+            }
+        }
+    }
+
+    fn format_value(&self, value: f64) -> String {
+        format!("{:.*}", self.style.precision as usize, value)
+    }
+
+    /// Returns the width reserved for the label, including
+    /// the space separating it from the track. The width does
+    /// not depend on the value, so the track doesn't resize
+    /// while the slider is dragged.
+    fn label_width(&self) -> u16 {
+        let width = match self.style.label {
+            SliderLabel::None => return 0,
+            SliderLabel::Value => self
+                .format_value(self.style.min)
+                .chars()
+                .count()
+                .max(self.format_value(self.style.max).chars().count()),
+            SliderLabel::Percent => "100%".len(),
+        };
+
+        width as u16 + 1
+    }
+
+    /// Returns the area of the track. The area is calculated
+    /// based on provided area.
+    fn track_area(&self, area: Rect) -> Rect {
+        let width = area.width.saturating_sub(self.label_width());
+        Rect::new(area.x, area.y, width, 1)
+    }
+
+    fn handle_x(&self, track_area: Rect) -> u16 {
+        let last_offset = track_area.width.saturating_sub(1) as f64;
+        track_area.x + (self.ratio() * last_offset).round() as u16
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl SliderWidget {
+    pub fn on_crossterm_event(
+        &mut self,
+        event: Event,
+        widget_area: Rect,
+    ) -> Option<SliderEvent> {
+        if self.status == SliderStatus::Disabled {
+            return None;
+        }
+        let old_value = self.value;
+
+        match event {
+            Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                self.on_key_pressed(key_event.code);
+            }
+            Event::Mouse(mouse_event) => {
+                let mouse_position = Position {
+                    x: mouse_event.column,
+                    y: mouse_event.row,
+                };
+                self.on_mouse_event(
+                    mouse_event.kind,
+                    mouse_position,
+                    widget_area,
+                )
+            }
+            _ => {}
+        }
+
+        (self.value != old_value)
+            .then_some(SliderEvent::ValueChanged(self.value))
+    }
+
+    /// Returns boolean flag indicating whether provided key
+    /// is handled by the slider.
+    fn on_key_pressed(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Left | KeyCode::Down => self.decrement(),
+            KeyCode::Right | KeyCode::Up => self.increment(),
+            KeyCode::Home => self.set_value(self.style.min),
+            KeyCode::End => self.set_value(self.style.max),
+            _ => return false,
+        }
+
+        true
+    }
+
+    fn on_mouse_event(
+        &mut self,
+        kind: MouseEventKind,
+        mouse_position: Position,
+        widget_area: Rect,
+    ) {
+        let track_area = self.track_area(widget_area);
+        let is_over_track = track_area.contains(mouse_position);
+
+        match kind {
+            MouseEventKind::Down(MouseButton::Left) if is_over_track => {
+                self.status = SliderStatus::Dragged;
+                self.set_value(self.value_at(track_area, mouse_position.x));
+            }
+            MouseEventKind::Drag(MouseButton::Left)
+                if self.status == SliderStatus::Dragged =>
+            {
+                self.set_value(self.value_at(track_area, mouse_position.x));
+            }
+            MouseEventKind::Up(MouseButton::Left) | MouseEventKind::Moved => {
+                self.status = if is_over_track {
+                    SliderStatus::Hovered
+                } else {
+                    SliderStatus::Normal
+                };
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the value corresponding to provided x
+    /// coordinate. Coordinates outside of the track are
+    /// clamped to its edges.
+    fn value_at(&self, track_area: Rect, x: u16) -> f64 {
+        let last_offset = track_area.width.saturating_sub(1);
+        if last_offset == 0 {
+            return self.style.min;
+        }
+        let offset = x.saturating_sub(track_area.x).min(last_offset);
+        let ratio = offset as f64 / last_offset as f64;
+
+        self.style.min + ratio * (self.style.max - self.style.min)
+    }
+}
+
+/// A focused slider is displayed as hovered.
+#[cfg(feature = "crossterm")]
+impl Focusable for SliderWidget {
+    fn set_focused(&mut self, is_focused: bool) {
+        self.is_focused = is_focused;
+        self.status = match (self.status, is_focused) {
+            (SliderStatus::Normal, true) => SliderStatus::Hovered,
+            (SliderStatus::Hovered, false) => SliderStatus::Normal,
+            (status, _) => status,
+        };
+    }
+
+    fn handle_key_event(&mut self, event: KeyEvent) -> bool {
+        if self.status == SliderStatus::Disabled
+            || event.kind != KeyEventKind::Press
+        {
+            return false;
+        }
+
+        self.on_key_pressed(event.code)
+    }
+
+    /// Returns the position of the handle while the slider is
+    /// focused and enabled.
+    fn cursor_position(&self, area: Rect) -> Option<Position> {
+        let track_area = self.track_area(area);
+        if !self.is_focused
+            || self.status == SliderStatus::Disabled
+            || track_area.is_empty()
+        {
+            return None;
+        }
+
+        Some(Position::new(self.handle_x(track_area), track_area.y))
+    }
+}
+
+impl AccessibleDescription for SliderWidget {
+    /// Describes the value and the range of the slider, e.g.
+    /// `"slider '40', range 0 to 100, focused"`.
+    fn accessible_description(&self) -> String {
+        let range = format!(
+            "range {} to {}",
+            self.format_value(self.style.min),
+            self.format_value(self.style.max),
+        );
+        let status = match self.status {
+            SliderStatus::Normal => "",
+            SliderStatus::Hovered => "hovered",
+            SliderStatus::Dragged => "dragged",
+            SliderStatus::Disabled => "disabled",
+        };
+        let focus = if self.is_focused { "focused" } else { "" };
+
+        describe_widget(
+            "slider",
+            Some(&self.format_value(self.value)),
+            [range.as_str(), status, focus],
+        )
+    }
+}
+
+impl Identifiable for SliderWidget {
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl HandlesEvent for SliderWidget {
+    type Event = SliderEvent;
+
+    fn handle_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<SliderEvent> {
+        self.on_crossterm_event(event, area)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use caponata_common::AccessibleDescription;
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        widgets::Widget,
+    };
+
+    use super::SliderWidget;
+    use crate::{
+        SliderLabel,
+        SliderStyleBuilder,
+    };
+
+    fn slider() -> SliderWidget {
+        let style = SliderStyleBuilder::default()
+            .with_max(10.0)
+            .with_label(SliderLabel::Percent)
+            .build()
+            .unwrap();
+        SliderWidget::new(style)
+    }
+
+    #[test]
+    fn handle_and_label_follow_value() {
+        let mut slider = slider();
+        slider.set_value(4.6);
+        assert_eq!(slider.value(), 5.0);
+
+        let area = Rect::new(0, 0, 16, 1);
+        let mut buf = Buffer::empty(area);
+        slider.render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["━━━━━●─────  50%"]));
+
+        slider.set_value(42.0);
+        slider.render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["━━━━━━━━━━● 100%"]));
+        assert_eq!(
+            slider.accessible_description(),
+            "slider '10', range 0 to 10",
+        );
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn value_is_changed_with_keys_and_mouse() {
+        use crossterm::event::{
+            Event,
+            KeyCode,
+            KeyEvent,
+            KeyModifiers,
+            MouseButton,
+            MouseEvent,
+            MouseEventKind,
+        };
+
+        use crate::{
+            SliderEvent,
+            SliderStatus,
+        };
+
+        let key = |code| Event::Key(KeyEvent::from(code));
+        let mouse = |kind, column| {
+            Event::Mouse(MouseEvent {
+                kind,
+                column,
+                row: 0,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        let mut slider = slider();
+        let area = Rect::new(0, 0, 16, 1);
+
+        assert_eq!(
+            slider.on_crossterm_event(key(KeyCode::Right), area),
+            Some(SliderEvent::ValueChanged(1.0)),
+        );
+        assert_eq!(
+            slider.on_crossterm_event(key(KeyCode::End), area),
+            Some(SliderEvent::ValueChanged(10.0)),
+        );
+        assert_eq!(slider.on_crossterm_event(key(KeyCode::End), area), None);
+
+        let down = MouseEventKind::Down(MouseButton::Left);
+        assert_eq!(
+            slider.on_crossterm_event(mouse(down, 2), area),
+            Some(SliderEvent::ValueChanged(2.0)),
+        );
+        assert_eq!(slider.status(), SliderStatus::Dragged);
+
+        let drag = MouseEventKind::Drag(MouseButton::Left);
+        assert_eq!(
+            slider.on_crossterm_event(mouse(drag, 14), area),
+            Some(SliderEvent::ValueChanged(10.0)),
+        );
+        let up = MouseEventKind::Up(MouseButton::Left);
+        assert_eq!(slider.on_crossterm_event(mouse(up, 14), area), None);
+        assert_eq!(slider.status(), SliderStatus::Normal);
+
+        slider.disable();
+        assert_eq!(slider.on_crossterm_event(key(KeyCode::Home), area), None);
+        assert_eq!(slider.value(), 10.0);
+    }
+}
@@ -0,0 +1,8 @@
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SliderStatus {
+    #[default]
+    Normal,
+    Hovered,
+    Dragged,
+    Disabled,
+}
@@ -0,0 +1,164 @@
+use derive_builder::Builder;
+use ratatui::style::{
+    Color,
+    Modifier,
+};
+
+/// Text displayed to the right of the track of a
+/// [`SliderWidget`].
+///
+/// Default variant is [`SliderLabel::None`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SliderLabel {
+    #[default]
+    None,
+
+    /// The value formatted with the precision of the style,
+    /// e.g. `42.5`.
+    Value,
+
+    /// The position of the value between the minimum and
+    /// the maximum in percents, e.g. `42%`.
+    Percent,
+}
+
+/// Styling configuration for a [`SliderWidget`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Modifier};
+/// use caponata_slider::{
+///     SliderLabel,
+///     SliderStateStyleBuilder,
+///     SliderStyleBuilder,
+/// };
+///
+/// let hovered_style = SliderStateStyleBuilder::default()
+///     .with_handle_color(Color::LightBlue)
+///     .with_handle_modifier(Modifier::BOLD)
+///     .build()
+///     .unwrap();
+/// let slider_style = SliderStyleBuilder::default()
+///     .with_min(0.0)
+///     .with_max(10.0)
+///     .with_step(0.5)
+///     .with_label(SliderLabel::Value)
+///     .with_precision(1)
+///     .with_hovered_style(hovered_style)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Builder)]
+#[builder(setter(prefix = "with", into))]
+pub struct SliderStyle {
+    #[builder(default = "0.0")]
+    pub(crate) min: f64,
+
+    #[builder(default = "100.0")]
+    pub(crate) max: f64,
+
+    /// Distance between two adjacent values. Values are
+    /// counted from the minimum. Zero step allows any value.
+    #[builder(default = "1.0")]
+    pub(crate) step: f64,
+
+    #[builder(default = "'─'")]
+    pub(crate) track_symbol: char,
+
+    /// Symbol of the track part between the minimum and the
+    /// handle.
+    #[builder(default = "'━'")]
+    pub(crate) filled_track_symbol: char,
+
+    #[builder(default)]
+    pub(crate) track_color: Color,
+
+    #[builder(default)]
+    pub(crate) filled_track_color: Color,
+
+    #[builder(default)]
+    pub(crate) label: SliderLabel,
+
+    #[builder(default)]
+    pub(crate) label_color: Color,
+
+    /// Number of digits displayed after the decimal point
+    /// by [`SliderLabel::Value`].
+    #[builder(default)]
+    pub(crate) precision: u8,
+
+    /// Style applied when a [`SliderWidget`] is not hovered,
+    /// dragged or disabled.
+    #[builder(default)]
+    pub(crate) normal_style: SliderStateStyle,
+
+    #[builder(default)]
+    pub(crate) hovered_style: SliderStateStyle,
+
+    #[builder(default)]
+    pub(crate) dragged_style: SliderStateStyle,
+
+    #[builder(default)]
+    pub(crate) disabled_style: SliderStateStyle,
+}
+
+impl Default for SliderStyle {
+    fn default() -> Self {
+        Self {
+            min: 0.0,
+            max: 100.0,
+            step: 1.0,
+            track_symbol: '─',
+            filled_track_symbol: '━',
+            track_color: Color::default(),
+            filled_track_color: Color::default(),
+            label: SliderLabel::default(),
+            label_color: Color::default(),
+            precision: 0,
+            normal_style: SliderStateStyle::default(),
+            hovered_style: SliderStateStyle::default(),
+            dragged_style: SliderStateStyle::default(),
+            disabled_style: SliderStateStyle::default(),
+        }
+    }
+}
+
+/// Styling configuration of the handle for a specific state
+/// of a [`SliderWidget`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Modifier};
+/// use caponata_slider::SliderStateStyleBuilder;
+///
+/// let slider_state_style = SliderStateStyleBuilder::default()
+///     .with_handle_symbol('◆')
+///     .with_handle_color(Color::Yellow)
+///     .with_handle_modifier(Modifier::BOLD)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Builder)]
+#[builder(setter(prefix = "with", into, strip_option))]
+pub struct SliderStateStyle {
+    #[builder(default = "'●'")]
+    pub(crate) handle_symbol: char,
+
+    #[builder(default)]
+    pub(crate) handle_color: Color,
+
+    #[builder(default)]
+    pub(crate) handle_modifier: Option<Modifier>,
+}
+
+impl Default for SliderStateStyle {
+    fn default() -> Self {
+        Self {
+            handle_symbol: '●',
+            handle_color: Color::default(),
+            handle_modifier: None,
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::{
+    io,
+    time::Duration,
+};
+
+use caponata_small_gauge::{
+    SmallGaugeOrientation,
+    SmallGaugeStyleBuilder,
+    SmallGaugeWidget,
+};
+use crossterm::event::{
+    Event,
+    KeyCode,
+    poll,
+    read,
+};
+use ratatui::{
+    DefaultTerminal,
+    buffer::Buffer,
+    layout::{
+        Alignment,
+        Rect,
+    },
+    style::{
+        Color,
+        Modifier,
+        Stylize,
+    },
+    text::Line,
+    widgets::Widget,
+};
+
+pub fn main() -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal);
+
+    ratatui::restore();
+    result
+}
+
+fn run(terminal: &mut DefaultTerminal) -> io::Result<()> {
+    let mut app = AppWidget::new();
+
+    let mut is_running = true;
+    while is_running {
+        terminal.draw(|frame| {
+            frame.render_widget(&mut app, frame.area());
+        })?;
+        app.tick();
+        is_running = !handle_event()?;
+    }
+
+    Ok(())
+}
+
+struct AppWidget {
+    gauges: Vec<(String, SmallGaugeWidget)>,
+    value: f32,
+}
+
+impl Widget for &mut AppWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        for ((name, gauge), area_y) in
+            self.gauges.iter().zip(area.y..area.y + area.height)
+        {
+            let gauge_area = Rect::new(area.x, area_y, 1, 1);
+            gauge.render(gauge_area, buf);
+
+            let line_area =
+                Rect::new(area.x + 2, area_y, area.width.saturating_sub(2), 1);
+            Line::from(format!("{} ({:.0}%)", name, gauge.value() * 100.0))
+                .alignment(Alignment::Left)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD)
+                .render(line_area, buf);
+        }
+    }
+}
+
+impl AppWidget {
+    fn new() -> Self {
+        let vertical_gauge_style = SmallGaugeStyleBuilder::default()
+            .with_orientation(SmallGaugeOrientation::Vertical)
+            .build()
+            .unwrap();
+        let horizontal_gauge_style = SmallGaugeStyleBuilder::default()
+            .with_orientation(SmallGaugeOrientation::Horizontal)
+            .build()
+            .unwrap();
+
+        let gauges = vec![
+            (
+                "vertical".to_string(),
+                SmallGaugeWidget::new(vertical_gauge_style),
+            ),
+            (
+                "horizontal".to_string(),
+                SmallGaugeWidget::new(horizontal_gauge_style),
+            ),
+        ];
+
+        Self { gauges, value: 0.0 }
+    }
+
+    fn tick(&mut self) {
+        self.value = if self.value >= 1.0 {
+            0.0
+        } else {
+            self.value + 0.05
+        };
+        for (_, gauge) in self.gauges.iter_mut() {
+            gauge.set_value(self.value);
+        }
+    }
+}
+
+/// Handles a crossterm event and returns a flag indicating
+/// whether the application should be closed.
+fn handle_event() -> io::Result<bool> {
+    let timeout = Duration::from_millis(100);
+    if !poll(timeout)? {
+        return Ok(false);
+    }
+
+    match read()? {
+        Event::Key(key) => {
+            if let KeyCode::Char('q') = key.code {
+                Ok(true)
+            } else {
+                Ok(false)
+            }
+        }
+        _ => Ok(false),
+    }
+}
@@ -0,0 +1,203 @@
+use caponata_common::{
+    AccessibleDescription,
+    Identifiable,
+    WidgetIdentity,
+    describe_widget,
+    render_adapted,
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::{
+        Alignment,
+        Rect,
+    },
+    widgets::Widget,
+};
+
+use super::SmallGaugeStyle;
+
+/// A widget that displays a single-character level indicator.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::Alignment,
+///     style::Color,
+/// };
+/// use caponata_small_gauge::{
+///     SmallGaugeOrientation,
+///     SmallGaugeStyleBuilder,
+///     SmallGaugeWidget,
+/// };
+///
+/// let gauge_style = SmallGaugeStyleBuilder::default()
+///     .with_orientation(SmallGaugeOrientation::Vertical)
+///     .with_alignment(Alignment::Left)
+///     .with_background_color(Color::Black)
+///     .build()
+///     .unwrap();
+/// let mut gauge = SmallGaugeWidget::new(gauge_style);
+/// gauge.set_value(0.3);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SmallGaugeWidget {
+    style: SmallGaugeStyle,
+    value: f32,
+    identity: WidgetIdentity,
+}
+
+impl Widget for &SmallGaugeWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.identity.record(area, self);
+        if area.height < 1 || area.width < 1 {
+            return;
+        }
+
+        render_adapted(area, buf, |buf| {
+            let symbols = self.style.orientation.symbols();
+            let level = (self.value * (symbols.len() - 1) as f32).round();
+            let symbol_to_render = symbols[level as usize];
+
+            let x = if area.width == 1 {
+                area.x
+            } else {
+                match self.style.alignment {
+                    Alignment::Left => area.x,
+                    Alignment::Center => area.x + area.width / 2,
+                    Alignment::Right => area.x + area.width - 1,
+                }
+            };
+            buf[(x, area.y)]
+                .set_symbol(symbol_to_render)
+                .set_bg(self.style.background_color)
+                .set_fg(self.style.color_for(self.value));
+        });
+    }
+}
+
+impl SmallGaugeWidget {
+    pub fn new(style: SmallGaugeStyle) -> Self {
+        Self {
+            style,
+            value: 0.0,
+            identity: WidgetIdentity::default(),
+        }
+    }
+
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Sets the value displayed by the gauge. The value is
+    /// clamped to the `0.0..=1.0` range; `NaN` is treated
+    /// as `0.0`.
+    pub fn set_value(&mut self, value: f32) {
+        self.value = if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(0.0, 1.0)
+        };
+    }
+}
+
+impl AccessibleDescription for SmallGaugeWidget {
+    /// Describes the value as a percentage, e.g.
+    /// `"gauge '30%'"`.
+    fn accessible_description(&self) -> String {
+        let percentage = format!("{:.0}%", self.value * 100.0);
+
+        describe_widget("gauge", Some(&percentage), [])
+    }
+}
+
+impl Identifiable for SmallGaugeWidget {
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use caponata_common::AccessibleDescription;
+    use ratatui::{
+        buffer::Buffer,
+        layout::{
+            Alignment,
+            Position,
+            Rect,
+        },
+        style::Color,
+        widgets::Widget,
+    };
+
+    use super::SmallGaugeWidget;
+    use crate::{
+        SmallGaugeOrientation,
+        SmallGaugeStyleBuilder,
+    };
+
+    #[test]
+    fn vertical_gauge_levels() {
+        let gauge_style = SmallGaugeStyleBuilder::default()
+            .with_orientation(SmallGaugeOrientation::Vertical)
+            .with_alignment(Alignment::Center)
+            .build()
+            .unwrap();
+        let mut gauge = SmallGaugeWidget::new(gauge_style);
+
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buf = Buffer::empty(area);
+        let gauge_cell_position = Position::new(2, 0);
+
+        gauge.set_value(0.0);
+        gauge.render(area, &mut buf);
+        let gauge_cell = buf.cell(gauge_cell_position).unwrap();
+        assert_eq!(gauge_cell.symbol(), " ");
+        assert_eq!(gauge_cell.fg, Color::Red);
+
+        gauge.set_value(0.25);
+        gauge.render(area, &mut buf);
+        let gauge_cell = buf.cell(gauge_cell_position).unwrap();
+        assert_eq!(gauge_cell.symbol(), "▂");
+        assert_eq!(gauge_cell.fg, Color::Yellow);
+
+        gauge.set_value(2.0);
+        gauge.render(area, &mut buf);
+        let gauge_cell = buf.cell(gauge_cell_position).unwrap();
+        assert_eq!(gauge_cell.symbol(), "█");
+        assert_eq!(gauge_cell.fg, Color::Green);
+    }
+
+    #[test]
+    fn horizontal_gauge_levels() {
+        let gauge_style = SmallGaugeStyleBuilder::default()
+            .with_orientation(SmallGaugeOrientation::Horizontal)
+            .with_alignment(Alignment::Right)
+            .with_low_color(Color::Blue)
+            .build()
+            .unwrap();
+        let mut gauge = SmallGaugeWidget::new(gauge_style);
+
+        let area = Rect::new(0, 0, 4, 1);
+        let mut buf = Buffer::empty(area);
+        let gauge_cell_position = Position::new(3, 0);
+
+        gauge.set_value(0.1);
+        gauge.render(area, &mut buf);
+        let gauge_cell = buf.cell(gauge_cell_position).unwrap();
+        assert_eq!(gauge_cell.symbol(), "▏");
+        assert_eq!(gauge_cell.fg, Color::Blue);
+
+        gauge.set_value(0.5);
+        gauge.render(area, &mut buf);
+        let gauge_cell = buf.cell(gauge_cell_position).unwrap();
+        assert_eq!(gauge_cell.symbol(), "▌");
+        assert_eq!(gauge_cell.fg, Color::Green);
+        assert_eq!(gauge.accessible_description(), "gauge '50%'");
+    }
+}
@@ -0,0 +1,7 @@
+#![doc = include_str!("../README.md")]
+
+pub mod gauge;
+pub mod style;
+
+pub use gauge::*;
+pub use style::*;
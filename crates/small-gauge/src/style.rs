@@ -0,0 +1,114 @@
+use derive_builder::Builder;
+use ratatui::{
+    layout::Alignment,
+    style::Color,
+};
+
+/// Direction in which [`SmallGaugeWidget`] fills its cell.
+///
+/// Default variant is [`SmallGaugeOrientation::Vertical`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SmallGaugeOrientation {
+    /// [" ", "▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"]
+    #[default]
+    Vertical,
+
+    /// [" ", "▏", "▎", "▍", "▌", "▋", "▊", "▉", "█"]
+    Horizontal,
+}
+
+impl SmallGaugeOrientation {
+    /// Returns the symbols used to display the levels, from
+    /// the empty one to the full one.
+    pub fn symbols(&self) -> [&'static str; 9] {
+        match self {
+            Self::Vertical => [" ", "▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"],
+            Self::Horizontal => [" ", "▏", "▎", "▍", "▌", "▋", "▊", "▉", "█"],
+        }
+    }
+}
+
+/// A styling configuration for [`SmallGaugeWidget`].
+///
+/// The foreground color of the gauge depends on its value:
+///
+/// * below `medium_threshold` - `low_color`;
+/// * below `high_threshold` - `medium_color`;
+/// * otherwise - `high_color`.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     style::Color,
+///     layout::Alignment,
+/// };
+/// use caponata_small_gauge::{
+///     SmallGaugeOrientation,
+///     SmallGaugeStyleBuilder,
+/// };
+///
+/// let style = SmallGaugeStyleBuilder::default()
+///     .with_orientation(SmallGaugeOrientation::Horizontal)
+///     .with_alignment(Alignment::Center)
+///     .with_medium_threshold(0.25)
+///     .with_high_threshold(0.75)
+///     .with_background_color(Color::Black)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Builder)]
+#[builder(setter(prefix = "with", into))]
+pub struct SmallGaugeStyle {
+    #[builder(default)]
+    pub(crate) orientation: SmallGaugeOrientation,
+
+    #[builder(default)]
+    pub(crate) alignment: Alignment,
+
+    #[builder(default = "0.2")]
+    pub(crate) medium_threshold: f32,
+
+    #[builder(default = "0.5")]
+    pub(crate) high_threshold: f32,
+
+    #[builder(default = "Color::Red")]
+    pub(crate) low_color: Color,
+
+    #[builder(default = "Color::Yellow")]
+    pub(crate) medium_color: Color,
+
+    #[builder(default = "Color::Green")]
+    pub(crate) high_color: Color,
+
+    #[builder(default)]
+    pub(crate) background_color: Color,
+}
+
+impl Default for SmallGaugeStyle {
+    fn default() -> Self {
+        Self {
+            orientation: SmallGaugeOrientation::default(),
+            alignment: Alignment::default(),
+            medium_threshold: 0.2,
+            high_threshold: 0.5,
+            low_color: Color::Red,
+            medium_color: Color::Yellow,
+            high_color: Color::Green,
+            background_color: Color::default(),
+        }
+    }
+}
+
+impl SmallGaugeStyle {
+    /// Returns the foreground color matching provided value.
+    pub fn color_for(&self, value: f32) -> Color {
+        if value < self.medium_threshold {
+            self.low_color
+        } else if value < self.high_threshold {
+            self.medium_color
+        } else {
+            self.high_color
+        }
+    }
+}
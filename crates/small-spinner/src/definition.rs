@@ -0,0 +1,210 @@
+#[cfg(feature = "json")]
+use std::collections::BTreeMap;
+use std::{
+    error::Error,
+    fmt,
+    time::Duration,
+};
+
+use ratatui::text::Span;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use super::{
+    SmallSpinnerStyle,
+    SmallSpinnerStyleBuilder,
+    SmallSpinnerType,
+};
+
+/// A spinner frame set that can be loaded from an asset file
+/// in any format supported by serde, e.g. TOML or JSON.
+///
+/// The fields match the entries of the popular cli-spinners
+/// JSON file, so its definitions can be reused directly.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use caponata_small_spinner::SmallSpinnerDefinition;
+///
+/// let definition = SmallSpinnerDefinition {
+///     interval: 80,
+///     frames: vec!["◜".to_string(), "◝".to_string()],
+/// };
+///
+/// let spinner_type = definition.to_type().unwrap();
+/// assert_eq!(spinner_type.frames(), ["◜", "◝"]);
+/// assert_eq!(definition.interval(), Duration::from_millis(80));
+/// ```
+#[derive(
+    Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub struct SmallSpinnerDefinition {
+    /// Interval between the frames in milliseconds.
+    pub interval: u64,
+
+    pub frames: Vec<String>,
+}
+
+impl SmallSpinnerDefinition {
+    pub fn interval(&self) -> Duration {
+        Duration::from_millis(self.interval)
+    }
+
+    /// Returns a [`SmallSpinnerType::Custom`] spinner type
+    /// cycling through the frames of the definition.
+    ///
+    /// The frames are leaked to get the static lifetime
+    /// spinner types require, so definitions should be
+    /// converted once, e.g. when the application starts.
+    pub fn to_type(
+        &self,
+    ) -> Result<SmallSpinnerType, SmallSpinnerDefinitionError> {
+        self.validate()?;
+
+        let frames: Vec<&'static str> = self
+            .frames
+            .iter()
+            .map(|frame| &*Box::leak(frame.clone().into_boxed_str()))
+            .collect();
+
+        Ok(SmallSpinnerType::Custom(Box::leak(
+            frames.into_boxed_slice(),
+        )))
+    }
+
+    /// Returns a style of a spinner cycling through the frames
+    /// of the definition at its interval. See
+    /// [`Self::to_type`] for details.
+    pub fn to_style(
+        &self,
+    ) -> Result<SmallSpinnerStyle, SmallSpinnerDefinitionError> {
+        Ok(SmallSpinnerStyleBuilder::default()
+            .with_type(self.to_type()?)
+            .with_interval(self.interval())
+            .build()
+            .unwrap())
+    }
+
+    fn validate(&self) -> Result<(), SmallSpinnerDefinitionError> {
+        if self.frames.is_empty() {
+            return Err(SmallSpinnerDefinitionError::EmptyFrames);
+        }
+
+        let invalid_frame_index = self.frames.iter().position(|frame| {
+            let width = Span::raw(frame.as_str()).width();
+            frame.is_empty() || width > 2
+        });
+        match invalid_frame_index {
+            Some(index) => {
+                Err(SmallSpinnerDefinitionError::InvalidFrame { index })
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl SmallSpinnerDefinition {
+    /// Parses a single definition from JSON, e.g.
+    /// `{"interval": 80, "frames": ["-", "+"]}`.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Parses the contents of the `spinners.json` file of
+    /// cli-spinners, which maps spinner names to their
+    /// definitions. Definitions whose frames don't fit a
+    /// single cell are kept, but can't be converted to
+    /// spinner types.
+    pub fn from_cli_spinners_json(
+        json: &str,
+    ) -> Result<BTreeMap<String, Self>, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+/// An error returned when a [`SmallSpinnerDefinition`] can't
+/// be converted to a spinner type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SmallSpinnerDefinitionError {
+    /// The definition has no frames.
+    EmptyFrames,
+
+    /// A frame is empty or wider than two columns, so it
+    /// doesn't fit a single cell. Includes the index of the
+    /// frame.
+    InvalidFrame { index: usize },
+}
+
+impl fmt::Display for SmallSpinnerDefinitionError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyFrames => {
+                write!(formatter, "spinner definition has no frames")
+            }
+            Self::InvalidFrame { index } => write!(
+                formatter,
+                "frame {} of spinner definition doesn't fit a single cell",
+                index,
+            ),
+        }
+    }
+}
+
+impl Error for SmallSpinnerDefinitionError {}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{
+        SmallSpinnerDefinition,
+        SmallSpinnerDefinitionError,
+    };
+
+    #[test]
+    fn invalid_definitions() {
+        let definition = SmallSpinnerDefinition::default();
+        assert_eq!(
+            definition.to_type(),
+            Err(SmallSpinnerDefinitionError::EmptyFrames),
+        );
+
+        let definition = SmallSpinnerDefinition {
+            interval: 100,
+            frames: vec!["-".to_string(), "[= ]".to_string()],
+        };
+        assert_eq!(
+            definition.to_type(),
+            Err(SmallSpinnerDefinitionError::InvalidFrame { index: 1 }),
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn cli_spinners_json() {
+        let json = r#"{
+            "dots": {
+                "interval": 80,
+                "frames": ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"]
+            },
+            "simpleDots": {
+                "interval": 400,
+                "frames": [".  ", ".. ", "...", "   "]
+            }
+        }"#;
+        let definitions =
+            SmallSpinnerDefinition::from_cli_spinners_json(json).unwrap();
+
+        let dots_style = definitions["dots"].to_style().unwrap();
+        assert_eq!(dots_style.type_.frame_count(), 10);
+        assert_eq!(dots_style.interval, Duration::from_millis(80));
+        assert!(definitions["simpleDots"].to_type().is_err());
+    }
+}
@@ -0,0 +1,10 @@
+use super::SmallSpinnerType;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SpinnerGalleryEvent {
+    /// Triggered when a spinner type of a
+    /// [`SpinnerGalleryWidget`] is chosen with the keyboard
+    /// or the mouse. The event includes the chosen type.
+    Selected(SmallSpinnerType),
+}
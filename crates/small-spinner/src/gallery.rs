@@ -0,0 +1,456 @@
+#[cfg(feature = "crossterm")]
+use caponata_common::HandlesEvent;
+use caponata_common::{
+    AccessibleDescription,
+    Identifiable,
+    SharedClock,
+    WidgetIdentity,
+    describe_widget,
+    render_adapted,
+};
+#[cfg(feature = "crossterm")]
+use crossterm::event::{
+    Event,
+    KeyCode,
+    KeyEventKind,
+    MouseButton,
+    MouseEventKind,
+};
+use derive_builder::Builder;
+#[cfg(feature = "crossterm")]
+use ratatui::layout::Position;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{
+        Modifier,
+        Style,
+    },
+    widgets::Widget,
+};
+
+#[cfg(feature = "crossterm")]
+use super::SpinnerGalleryEvent;
+use super::{
+    SmallSpinnerAlignment,
+    SmallSpinnerStyle,
+    SmallSpinnerType,
+    SmallSpinnerVerticalAlignment,
+    SmallSpinnerWidget,
+};
+
+/// A styling configuration for [`SpinnerGalleryWidget`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use caponata_small_spinner::SpinnerGalleryStyleBuilder;
+///
+/// let style = SpinnerGalleryStyleBuilder::default()
+///     .with_column_count(3u16)
+///     .with_label_style(Style::new().fg(Color::White))
+///     .with_selected_label_style(Style::new().fg(Color::Green))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(prefix = "with", into))]
+pub struct SpinnerGalleryStyle {
+    /// Style of the spinners. Their types and alignments are
+    /// overridden by the gallery, and their blocks are
+    /// ignored.
+    #[builder(default)]
+    pub(crate) spinner_style: SmallSpinnerStyle,
+
+    #[builder(default = "4")]
+    pub(crate) column_count: u16,
+
+    #[builder(default)]
+    pub(crate) label_style: Style,
+
+    /// Style patching the label of the highlighted spinner.
+    #[builder(default = "Style::new().add_modifier(Modifier::REVERSED)")]
+    pub(crate) highlighted_label_style: Style,
+
+    /// Style patching the label of the selected spinner.
+    #[builder(default = "Style::new().add_modifier(Modifier::BOLD)")]
+    pub(crate) selected_label_style: Style,
+}
+
+impl Default for SpinnerGalleryStyle {
+    fn default() -> Self {
+        Self {
+            spinner_style: SmallSpinnerStyle::default(),
+            column_count: 4,
+            label_style: Style::default(),
+            highlighted_label_style: Style::new()
+                .add_modifier(Modifier::REVERSED),
+            selected_label_style: Style::new().add_modifier(Modifier::BOLD),
+        }
+    }
+}
+
+/// A widget that displays every [`SmallSpinnerType`] along
+/// with its name in a grid and lets the user choose one of
+/// them.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     widgets::Widget,
+/// };
+/// use caponata_small_spinner::{
+///     SmallSpinnerType,
+///     SpinnerGalleryStyle,
+///     SpinnerGalleryWidget,
+/// };
+///
+/// let gallery_style = SpinnerGalleryStyle::default();
+/// let mut gallery = SpinnerGalleryWidget::new(gallery_style);
+///
+/// let area = Rect::new(0, 0, 80, 7);
+/// let mut buf = Buffer::empty(area);
+/// gallery.render(area, &mut buf);
+///
+/// gallery.select(Some(SmallSpinnerType::Clock));
+/// assert_eq!(gallery.selected(), Some(SmallSpinnerType::Clock));
+/// assert_eq!(gallery.highlighted(), SmallSpinnerType::Clock);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpinnerGalleryWidget {
+    style: SpinnerGalleryStyle,
+    spinners: Vec<SmallSpinnerWidget>,
+    highlighted: usize,
+    selected: Option<usize>,
+
+    /// Index of the first displayed row.
+    offset: usize,
+    identity: WidgetIdentity,
+}
+
+impl Default for SpinnerGalleryWidget {
+    fn default() -> Self {
+        Self::new(SpinnerGalleryStyle::default())
+    }
+}
+
+impl Widget for &mut SpinnerGalleryWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        self.scroll_to_highlighted(area.height as usize);
+
+        render_adapted(area, buf, |buf| {
+            for index in 0..self.spinners.len() {
+                let Some(cell_area) = self.cell_area(area, index) else {
+                    continue;
+                };
+                let spinner_area = Rect::new(cell_area.x, cell_area.y, 1, 1);
+                self.spinners[index].render(spinner_area, buf);
+
+                if cell_area.width > 2 {
+                    let mut label_style = self.style.label_style;
+                    if self.selected == Some(index) {
+                        label_style =
+                            label_style.patch(self.style.selected_label_style);
+                    }
+                    if self.highlighted == index {
+                        label_style = label_style
+                            .patch(self.style.highlighted_label_style);
+                    }
+                    let name = SmallSpinnerType::ALL[index].name();
+                    let max_width = (cell_area.width - 2) as usize;
+                    buf.set_stringn(
+                        cell_area.x + 2,
+                        cell_area.y,
+                        name,
+                        max_width,
+                        label_style,
+                    );
+                }
+            }
+        });
+        self.identity.record(area, &*self);
+    }
+}
+
+impl SpinnerGalleryWidget {
+    pub fn new(style: SpinnerGalleryStyle) -> Self {
+        let spinners = SmallSpinnerType::ALL
+            .iter()
+            .map(|spinner_type| {
+                let mut spinner_style = style.spinner_style.clone();
+                spinner_style.type_ = *spinner_type;
+                spinner_style.alignment = SmallSpinnerAlignment::Left;
+                spinner_style.vertical_alignment =
+                    SmallSpinnerVerticalAlignment::Top;
+                spinner_style.block = None;
+                SmallSpinnerWidget::new(spinner_style)
+            })
+            .collect();
+
+        Self {
+            style,
+            spinners,
+            highlighted: 0,
+            selected: None,
+            offset: 0,
+            identity: WidgetIdentity::default(),
+        }
+    }
+
+    /// Replaces the clock used to measure time by all the
+    /// spinners of the gallery.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        for spinner in self.spinners.iter_mut() {
+            spinner.set_clock(clock.clone());
+        }
+    }
+
+    pub fn highlighted(&self) -> SmallSpinnerType {
+        SmallSpinnerType::ALL[self.highlighted]
+    }
+
+    pub fn selected(&self) -> Option<SmallSpinnerType> {
+        self.selected.map(|index| SmallSpinnerType::ALL[index])
+    }
+
+    /// Selects provided spinner type and highlights it.
+    /// Clears the selection if the type is `None`.
+    pub fn select(&mut self, spinner_type: Option<SmallSpinnerType>) {
+        self.selected = spinner_type.and_then(|spinner_type| {
+            SmallSpinnerType::ALL
+                .iter()
+                .position(|other| *other == spinner_type)
+        });
+        if let Some(index) = self.selected {
+            self.highlighted = index;
+        }
+    }
+
+    fn column_count(&self) -> usize {
+        self.style.column_count.max(1) as usize
+    }
+
+    /// Returns the area of the cell displaying the spinner
+    /// with provided index if the cell is visible. The area is
+    /// calculated based on provided widget area.
+    fn cell_area(&self, area: Rect, index: usize) -> Option<Rect> {
+        let column_count = self.column_count();
+        let row = (index / column_count).checked_sub(self.offset)?;
+        if row >= area.height as usize {
+            return None;
+        }
+        let column_width = area.width / column_count as u16;
+        let column = (index % column_count) as u16;
+
+        Some(Rect::new(
+            area.x + column * column_width,
+            area.y + row as u16,
+            column_width,
+            1,
+        ))
+    }
+
+    /// Updates the offset so the row of the highlighted
+    /// spinner is visible in the grid with provided height.
+    fn scroll_to_highlighted(&mut self, height: usize) {
+        let row = self.highlighted / self.column_count();
+        if row < self.offset {
+            self.offset = row;
+        } else if row >= self.offset + height {
+            self.offset = row + 1 - height;
+        }
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl SpinnerGalleryWidget {
+    pub fn on_crossterm_event(
+        &mut self,
+        event: Event,
+        widget_area: Rect,
+    ) -> Option<SpinnerGalleryEvent> {
+        match event {
+            Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                self.on_key_pressed(key_event.code)
+            }
+            Event::Mouse(mouse_event) => {
+                let mouse_position = Position {
+                    x: mouse_event.column,
+                    y: mouse_event.row,
+                };
+                let index = self.spinner_at(widget_area, mouse_position)?;
+                match mouse_event.kind {
+                    MouseEventKind::Down(MouseButton::Left) => {
+                        self.choose(index)
+                    }
+                    MouseEventKind::Moved => {
+                        self.highlighted = index;
+                        None
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn on_key_pressed(
+        &mut self,
+        key_code: KeyCode,
+    ) -> Option<SpinnerGalleryEvent> {
+        let column_count = self.column_count();
+        let last_index = self.spinners.len() - 1;
+
+        self.highlighted = match key_code {
+            KeyCode::Left => self.highlighted.saturating_sub(1),
+            KeyCode::Right => (self.highlighted + 1).min(last_index),
+            KeyCode::Up => self
+                .highlighted
+                .checked_sub(column_count)
+                .unwrap_or(self.highlighted),
+            KeyCode::Down if self.highlighted + column_count <= last_index => {
+                self.highlighted + column_count
+            }
+            KeyCode::Home => 0,
+            KeyCode::End => last_index,
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                return self.choose(self.highlighted);
+            }
+            _ => self.highlighted,
+        };
+
+        None
+    }
+
+    fn choose(&mut self, index: usize) -> Option<SpinnerGalleryEvent> {
+        let spinner_type = SmallSpinnerType::ALL[index];
+        self.select(Some(spinner_type));
+
+        Some(SpinnerGalleryEvent::Selected(spinner_type))
+    }
+
+    /// Returns the index of the spinner displayed at provided
+    /// position. Widget's area is calculated based on provided
+    /// area.
+    fn spinner_at(&self, area: Rect, position: Position) -> Option<usize> {
+        (0..self.spinners.len()).find(|index| {
+            self.cell_area(area, *index)
+                .is_some_and(|cell_area| cell_area.contains(position))
+        })
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl HandlesEvent for SpinnerGalleryWidget {
+    type Event = SpinnerGalleryEvent;
+
+    fn handle_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<SpinnerGalleryEvent> {
+        self.on_crossterm_event(event, area)
+    }
+}
+
+impl AccessibleDescription for SpinnerGalleryWidget {
+    /// Describes the highlighted spinner type and its position
+    /// in the gallery, e.g. `"spinner gallery 'ascii', 1 of
+    /// 20, selected"`.
+    fn accessible_description(&self) -> String {
+        let position = format!(
+            "{} of {}",
+            self.highlighted + 1,
+            SmallSpinnerType::ALL.len(),
+        );
+        let selected = match self.selected == Some(self.highlighted) {
+            true => "selected",
+            false => "",
+        };
+
+        describe_widget(
+            "spinner gallery",
+            Some(self.highlighted().name()),
+            [position.as_str(), selected],
+        )
+    }
+}
+
+impl Identifiable for SpinnerGalleryWidget {
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use caponata_common::AccessibleDescription;
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        style::Style,
+        widgets::Widget,
+    };
+
+    use super::{
+        SpinnerGalleryStyleBuilder,
+        SpinnerGalleryWidget,
+    };
+    use crate::SmallSpinnerStyleBuilder;
+
+    #[test]
+    fn gallery_scrolls_to_highlighted_spinner() {
+        let spinner_style = SmallSpinnerStyleBuilder::default()
+            .with_interval(Duration::from_secs(60))
+            .build()
+            .unwrap();
+        let gallery_style = SpinnerGalleryStyleBuilder::default()
+            .with_spinner_style(spinner_style)
+            .with_column_count(2u16)
+            .with_highlighted_label_style(Style::default())
+            .with_selected_label_style(Style::default())
+            .build()
+            .unwrap();
+        let mut gallery = SpinnerGalleryWidget::new(gallery_style);
+
+        let area = Rect::new(0, 0, 20, 2);
+        let mut buf = Buffer::empty(area);
+        gallery.render(area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec![
+                "| ascii   │ box draw",
+                "↑ arrow   ⇑ double a",
+            ])
+        );
+
+        gallery.select(Some(crate::SmallSpinnerType::Canadian));
+        let mut buf = Buffer::empty(area);
+        gallery.render(area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec![
+                "\u{1680} ogham c ⎛ parenthe",
+                "ᔐ canadian          ",
+            ])
+        );
+        assert_eq!(
+            gallery.accessible_description(),
+            "spinner gallery 'canadian', 25 of 25, selected",
+        );
+    }
+}
@@ -1,9 +1,19 @@
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "serde")]
+pub mod definition;
+#[cfg(feature = "crossterm")]
+pub mod event;
+pub mod gallery;
 pub mod spinner;
 pub mod style;
 mod symbol_cycle;
 
+#[cfg(feature = "serde")]
+pub use definition::*;
+#[cfg(feature = "crossterm")]
+pub use event::*;
+pub use gallery::*;
 pub use spinner::*;
 pub use style::*;
 pub(crate) use symbol_cycle::*;
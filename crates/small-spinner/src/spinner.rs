@@ -1,16 +1,34 @@
-use std::time::Instant;
-
+use std::time::Duration;
+
+use caponata_common::{
+    AccessibleDescription,
+    Animated,
+    Identifiable,
+    Instant,
+    SharedClock,
+    WidgetIdentity,
+    describe_widget,
+    render_adapted,
+};
 use ratatui::{
     buffer::Buffer,
-    layout::{
-        Alignment,
-        Rect,
+    layout::Rect,
+    style::{
+        Color,
+        Style,
+    },
+    text::{
+        Line,
+        Span,
     },
     widgets::Widget,
 };
 
 use super::{
+    SmallSpinnerAlignment,
     SmallSpinnerStyle,
+    SmallSpinnerType,
+    SmallSpinnerVerticalAlignment,
     SymbolCycle,
 };
 
@@ -46,7 +64,7 @@ enum RenderIntervalCheckResult {
 ///     style::Color,
 ///     widgets::Widget,
 /// };
-/// use ratatui_small_spinner::{
+/// use caponata_small_spinner::{
 ///     SmallSpinnerType,
 ///     SmallSpinnerStyleBuilder,
 ///     SmallSpinnerWidget,
@@ -66,17 +84,91 @@ enum RenderIntervalCheckResult {
 pub struct SmallSpinnerWidget {
     symbol_cycle: SymbolCycle,
     style: SmallSpinnerStyle,
+    clock: SharedClock,
     last_rendered_at: Option<Instant>,
+
+    /// Time the spinner was first rendered at since it was
+    /// created or reset. Used to display the elapsed time.
+    started_at: Option<Instant>,
+    identity: WidgetIdentity,
 }
 
 impl Widget for &mut SmallSpinnerWidget {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        render_adapted(area, buf, |buf| {
+            let inner_area = match &self.style.block {
+                Some(block) => {
+                    block.clone().render(area, buf);
+                    block.inner(area)
+                }
+                None => area,
+            };
+            self.render_symbol(inner_area, buf);
+        });
+        self.identity.record(area, &*self);
+    }
+}
+
+impl SmallSpinnerWidget {
+    fn render_symbol(&mut self, area: Rect, buf: &mut Buffer) {
         if area.height < 1 || area.width < 1 {
             return;
         }
 
-        let now = Instant::now();
+        let symbol_to_render = self.advance();
+        let elapsed_text = self.elapsed_text();
+        let width = self.width();
+        let x = match self.style.alignment {
+            SmallSpinnerAlignment::Left => area.x,
+            SmallSpinnerAlignment::Center => {
+                area.x + area.width.saturating_sub(width - 1) / 2
+            }
+            SmallSpinnerAlignment::Right => {
+                area.right().saturating_sub(width).max(area.x)
+            }
+            SmallSpinnerAlignment::Offset(offset) => {
+                area.x + offset.min(area.width.saturating_sub(width))
+            }
+        };
+        let y = match self.style.vertical_alignment {
+            SmallSpinnerVerticalAlignment::Top => area.y,
+            SmallSpinnerVerticalAlignment::Center => {
+                area.y + (area.height - 1) / 2
+            }
+            SmallSpinnerVerticalAlignment::Bottom => area.bottom() - 1,
+        };
+        let cell = buf[(x, y)].set_symbol(symbol_to_render);
+        if let Some(foreground_color) = self.style.foreground_color {
+            cell.set_fg(foreground_color);
+        }
+        if let Some(background_color) = self.style.background_color
+            && !self.style.respect_existing_background
+        {
+            cell.set_bg(background_color);
+        }
+
+        if let Some(elapsed_text) = elapsed_text
+            && x + 2 < area.right()
+        {
+            buf.set_stringn(
+                x + 2,
+                y,
+                elapsed_text,
+                (area.right() - x - 2) as usize,
+                self.span_style(),
+            );
+        }
+    }
+}
+
+impl SmallSpinnerWidget {
+    /// Returns the symbol to display now, switching to the
+    /// next symbol if the interval has passed since the
+    /// previous switch.
+    fn advance(&mut self) -> &'static str {
+        let now = self.clock.now();
         let interval = self.style.interval;
+        self.started_at.get_or_insert(now);
 
         let interval_check_result = match self.last_rendered_at {
             Some(last_rendered_at) => {
@@ -88,7 +180,7 @@ impl Widget for &mut SmallSpinnerWidget {
             }
             None => RenderIntervalCheckResult::FirstTime,
         };
-        let symbol_to_render = match interval_check_result {
+        match interval_check_result {
             RenderIntervalCheckResult::Ready => {
                 self.last_rendered_at = Some(now);
                 self.symbol_cycle.next_symbol()
@@ -100,36 +192,204 @@ impl Widget for &mut SmallSpinnerWidget {
             RenderIntervalCheckResult::TooSoon => {
                 self.symbol_cycle.current_symbol()
             }
-        };
-
-        let x = if area.width == 1 {
-            area.x
-        } else {
-            match self.style.alignment {
-                Alignment::Left => area.x,
-                Alignment::Center => area.x + area.width / 2,
-                Alignment::Right => area.x + area.width - 1,
-            }
-        };
-        buf[(x, area.y)]
-            .set_symbol(symbol_to_render)
-            .set_bg(self.style.background_color)
-            .set_fg(self.style.foreground_color);
+        }
     }
 }
 
 impl SmallSpinnerWidget {
     pub fn new(style: SmallSpinnerStyle) -> Self {
+        let mut symbol_cycle = SymbolCycle::new(style.type_);
+        symbol_cycle.reset(style.start);
+
         Self {
-            symbol_cycle: SymbolCycle::new(style.type_),
+            symbol_cycle,
             style,
+            clock: SharedClock::default(),
             last_rendered_at: None,
+            started_at: None,
+            identity: WidgetIdentity::default(),
         }
     }
 
+    /// Creates a spinner of provided type switching its
+    /// symbols at the recommended interval of the type.
+    pub fn of_type(spinner_type: SmallSpinnerType) -> Self {
+        Self::new(SmallSpinnerStyle {
+            type_: spinner_type,
+            interval: spinner_type.recommended_interval(),
+            ..SmallSpinnerStyle::default()
+        })
+    }
+
+    /// Creates a [`SmallSpinnerType::BrailleDouble`] spinner
+    /// switching its symbols at the recommended interval.
+    pub fn braille() -> Self {
+        Self::of_type(SmallSpinnerType::BrailleDouble)
+    }
+
+    /// Sets the foreground color of the spinner.
+    pub fn fg(mut self, color: Color) -> Self {
+        self.style.foreground_color = Some(color);
+        self
+    }
+
+    /// Sets the background color of the spinner.
+    pub fn bg(mut self, color: Color) -> Self {
+        self.style.background_color = Some(color);
+        self
+    }
+
+    /// Replaces the clock used to measure time. Spinners
+    /// sharing the clock of an [`AnimationGroup`] switch their
+    /// symbols in the same frame.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+    }
+
+    /// Replaces the style of the spinner. The current phase of
+    /// the spinner is preserved; call [`Self::reset`] to
+    /// start the animation over.
+    pub fn set_style(&mut self, style: SmallSpinnerStyle) {
+        self.symbol_cycle.set_type(style.type_);
+        self.style = style;
+    }
+
+    /// Replaces the type of the spinner, preserving its
+    /// current phase.
+    pub fn set_type(&mut self, spinner_type: SmallSpinnerType) {
+        self.symbol_cycle.set_type(spinner_type);
+        self.style.type_ = spinner_type;
+    }
+
+    /// Replaces the interval between the symbols of the
+    /// spinner. The time elapsed since the last symbol was
+    /// displayed counts towards the new interval.
+    pub fn set_interval(&mut self, interval: Duration) {
+        self.style.interval = interval;
+    }
+
     /// Resets the spinner's animation to its initial state.
+    /// Spinners with [`crate::SmallSpinnerStart::Random`] start
+    /// from a new random symbol. The elapsed time starts over
+    /// from the next render.
     pub fn reset(&mut self) {
-        self.symbol_cycle.reset();
+        self.symbol_cycle.reset(self.style.start);
+        self.started_at = None;
+    }
+
+    /// Returns the time elapsed since the spinner was first
+    /// rendered after it was created or reset.
+    pub fn elapsed(&self) -> Duration {
+        self.started_at.map_or(Duration::ZERO, |started_at| {
+            self.clock.now().saturating_duration_since(started_at)
+        })
+    }
+
+    /// Returns the number of cells the spinner needs to be
+    /// displayed completely, including the elapsed time if it
+    /// is shown.
+    pub fn width(&self) -> u16 {
+        match self.elapsed_text() {
+            Some(elapsed_text) => 2 + elapsed_text.chars().count() as u16,
+            None => 1,
+        }
+    }
+
+    /// Returns the elapsed time formatted as `MM:SS`, or
+    /// `H:MM:SS` after an hour, if it is shown.
+    fn elapsed_text(&self) -> Option<String> {
+        if !self.style.show_elapsed {
+            return None;
+        }
+
+        let seconds = self.elapsed().as_secs();
+        let (hours, minutes, seconds) =
+            (seconds / 3600, seconds / 60 % 60, seconds % 60);
+        match hours {
+            0 => Some(format!("{minutes:02}:{seconds:02}")),
+            hours => Some(format!("{hours}:{minutes:02}:{seconds:02}")),
+        }
+    }
+
+    fn span_style(&self) -> Style {
+        Style {
+            fg: self.style.foreground_color,
+            bg: self
+                .style
+                .background_color
+                .filter(|_| !self.style.respect_existing_background),
+            ..Style::default()
+        }
+    }
+
+    /// Returns the symbol to display now as a styled span,
+    /// advancing the spinner the same way rendering does.
+    /// Useful to show a spinner in a ratatui `Table` cell or
+    /// `List` item, which accept spans. The span has no
+    /// background color if the existing background is
+    /// respected, so it inherits the background of the cell.
+    pub fn next_span(&mut self) -> Span<'static> {
+        Span::styled(self.advance(), self.span_style())
+    }
+
+    /// Returns a line with the current symbol followed by
+    /// provided label, padded with spaces, advancing the
+    /// spinner the same way rendering does. Pass it to
+    /// `Block::title` on every frame to display a spinner in
+    /// the title of a block, e.g. `┌ ⠘ Loading ┐`.
+    pub fn title(&mut self, label: &str) -> Line<'static> {
+        Line::from(vec![
+            Span::raw(" "),
+            self.next_span(),
+            Span::raw(format!(" {} ", label)),
+        ])
+    }
+
+    /// Returns the time left until the spinner displays its
+    /// next symbol, or the next second of the elapsed time if
+    /// it comes earlier.
+    pub fn time_until_next_frame(&self) -> Duration {
+        let Some(last_rendered_at) = self.last_rendered_at else {
+            return Duration::ZERO;
+        };
+        let time_until_next_symbol = self.style.interval.saturating_sub(
+            self.clock.now().saturating_duration_since(last_rendered_at),
+        );
+        if !self.style.show_elapsed {
+            return time_until_next_symbol;
+        }
+
+        let second = Duration::from_secs(1);
+        let time_until_next_second = second
+            - Duration::from_nanos(self.elapsed().subsec_nanos() as u64);
+        time_until_next_symbol.min(time_until_next_second)
+    }
+}
+
+impl Animated for SmallSpinnerWidget {
+    fn time_until_next_frame(&self) -> Option<Duration> {
+        Some(SmallSpinnerWidget::time_until_next_frame(self))
+    }
+}
+
+impl AccessibleDescription for SmallSpinnerWidget {
+    fn accessible_description(&self) -> String {
+        let elapsed = self
+            .elapsed_text()
+            .map(|elapsed_text| format!("{elapsed_text} elapsed"))
+            .unwrap_or_default();
+
+        describe_widget("spinner", None, ["busy", &elapsed])
+    }
+}
+
+impl Identifiable for SmallSpinnerWidget {
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
     }
 }
 
@@ -137,20 +397,38 @@ impl SmallSpinnerWidget {
 mod tests {
     use std::time::Duration;
 
+    use caponata_common::{
+        AccessibleDescription,
+        AnimationGroup,
+    };
     use ratatui::{
         buffer::Buffer,
+        layout::Constraint,
         layout::{
             Alignment,
             Position,
             Rect,
         },
-        widgets::Widget,
+        style::{
+            Color,
+            Style,
+        },
+        widgets::{
+            Block,
+            Cell,
+            Row,
+            Table,
+            Widget,
+        },
     };
 
     use super::SmallSpinnerWidget;
     use crate::{
+        SmallSpinnerAlignment,
+        SmallSpinnerStart,
         SmallSpinnerStyleBuilder,
         SmallSpinnerType,
+        SmallSpinnerVerticalAlignment,
     };
 
     #[test]
@@ -281,4 +559,249 @@ mod tests {
         let spinner_cell = buf.cell(spinner_cell_position).unwrap();
         assert_eq!(spinner_cell.symbol(), "⠘");
     }
+
+    #[test]
+    fn type_swap_preserves_phase() {
+        let spinner_style = SmallSpinnerStyleBuilder::default()
+            .with_type(SmallSpinnerType::Arrow)
+            .with_interval(Duration::from_secs(0))
+            .build()
+            .unwrap();
+        let mut spinner = SmallSpinnerWidget::new(spinner_style);
+
+        let area = Rect::new(0, 0, 1, 1);
+        let mut buf = Buffer::empty(area);
+        let spinner_cell_position = Position::new(0, 0);
+
+        for _ in 0..6 {
+            spinner.render(area, &mut buf);
+        }
+        let spinner_cell = buf.cell(spinner_cell_position).unwrap();
+        assert_eq!(spinner_cell.symbol(), "↙");
+
+        spinner.set_type(SmallSpinnerType::Ascii);
+        spinner.render(area, &mut buf);
+        let spinner_cell = buf.cell(spinner_cell_position).unwrap();
+        assert_eq!(spinner_cell.symbol(), "-");
+
+        spinner.set_interval(Duration::from_secs(60));
+        spinner.render(area, &mut buf);
+        let spinner_cell = buf.cell(spinner_cell_position).unwrap();
+        assert_eq!(spinner_cell.symbol(), "-");
+
+        spinner.reset();
+        spinner.render(area, &mut buf);
+        let spinner_cell = buf.cell(spinner_cell_position).unwrap();
+        assert_eq!(spinner_cell.symbol(), "|");
+    }
+
+    #[test]
+    fn spinner_with_start_index() {
+        let spinner_style = SmallSpinnerStyleBuilder::default()
+            .with_type(SmallSpinnerType::Ascii)
+            .with_interval(Duration::from_secs(0))
+            .with_start(SmallSpinnerStart::Index(6))
+            .build()
+            .unwrap();
+        let mut spinner = SmallSpinnerWidget::new(spinner_style);
+
+        let area = Rect::new(0, 0, 1, 1);
+        let mut buf = Buffer::empty(area);
+        let spinner_cell_position = Position::new(0, 0);
+
+        spinner.render(area, &mut buf);
+        let spinner_cell = buf.cell(spinner_cell_position).unwrap();
+        assert_eq!(spinner_cell.symbol(), "-");
+    }
+
+    #[test]
+    fn braille_spinner_with_colors() {
+        let mut spinner = SmallSpinnerWidget::braille().fg(Color::Cyan);
+        assert_eq!(
+            spinner.style.interval,
+            SmallSpinnerType::BrailleDouble.recommended_interval(),
+        );
+
+        let area = Rect::new(0, 0, 1, 1);
+        let mut buf = Buffer::empty(area);
+        spinner.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "⠘");
+        assert_eq!(buf[(0, 0)].fg, Color::Cyan);
+    }
+
+    #[test]
+    fn spinner_in_table_cell() {
+        let mut spinner = SmallSpinnerWidget::braille().fg(Color::Green);
+        let row =
+            Row::new(vec![Cell::from("job"), Cell::from(spinner.next_span())]);
+        let table = Table::new(vec![row], [Constraint::Length(3); 2]);
+
+        let area = Rect::new(0, 0, 7, 1);
+        let mut buf = Buffer::empty(area);
+        Widget::render(table, area, &mut buf);
+
+        assert_eq!(buf[(4, 0)].symbol(), "⠘");
+        assert_eq!(buf[(4, 0)].fg, Color::Green);
+    }
+
+    #[test]
+    fn spinner_at_offset_in_tall_area() {
+        let spinner_style = SmallSpinnerStyleBuilder::default()
+            .with_alignment(SmallSpinnerAlignment::Offset(2))
+            .with_vertical_alignment(SmallSpinnerVerticalAlignment::Center)
+            .build()
+            .unwrap();
+        let mut spinner = SmallSpinnerWidget::new(spinner_style);
+
+        let area = Rect::new(0, 0, 4, 4);
+        let mut buf = Buffer::empty(area);
+        spinner.render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["    ", "  ⠘ ", "    ", "    "]));
+
+        let spinner_style = SmallSpinnerStyleBuilder::default()
+            .with_alignment(SmallSpinnerAlignment::Offset(10))
+            .with_vertical_alignment(SmallSpinnerVerticalAlignment::Bottom)
+            .build()
+            .unwrap();
+        let mut spinner = SmallSpinnerWidget::new(spinner_style);
+
+        let area = Rect::new(0, 0, 3, 2);
+        let mut buf = Buffer::empty(area);
+        spinner.render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["   ", "  ⠘"]));
+    }
+
+    #[test]
+    fn spinner_inside_block() {
+        let spinner_style = SmallSpinnerStyleBuilder::default()
+            .with_alignment(Alignment::Right)
+            .with_block(Block::bordered())
+            .build()
+            .unwrap();
+        let mut spinner = SmallSpinnerWidget::new(spinner_style);
+
+        let area = Rect::new(0, 0, 4, 3);
+        let mut buf = Buffer::empty(area);
+        spinner.render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["┌──┐", "│ ⠘│", "└──┘"]));
+    }
+
+    #[test]
+    fn spinner_in_block_title() {
+        let spinner_style = SmallSpinnerStyleBuilder::default()
+            .with_interval(Duration::from_secs(0))
+            .build()
+            .unwrap();
+        let mut spinner = SmallSpinnerWidget::new(spinner_style);
+
+        let area = Rect::new(0, 0, 13, 2);
+        for expected_title in ["┌ ⠘ Loading ┐", "┌ ⠰ Loading ┐"] {
+            let mut buf = Buffer::empty(area);
+            Block::bordered()
+                .title(spinner.title("Loading"))
+                .render(area, &mut buf);
+            assert_eq!(
+                buf,
+                Buffer::with_lines(vec![expected_title, "└───────────┘"])
+            );
+        }
+    }
+
+    #[test]
+    fn existing_background_is_respected() {
+        let spinner_style = SmallSpinnerStyleBuilder::default()
+            .with_background_color(Color::Red)
+            .with_respect_existing_background(true)
+            .build()
+            .unwrap();
+        let mut spinner = SmallSpinnerWidget::new(spinner_style);
+
+        let area = Rect::new(0, 0, 1, 1);
+        let mut buf = Buffer::empty(area);
+        buf[(0, 0)].set_bg(Color::Blue);
+        spinner.render(area, &mut buf);
+
+        assert_eq!(buf[(0, 0)].symbol(), "⠘");
+        assert_eq!(buf[(0, 0)].bg, Color::Blue);
+        assert_eq!(spinner.next_span().style.bg, None);
+    }
+
+    #[test]
+    fn elapsed_time_is_displayed_after_spinner() {
+        let spinner_style = SmallSpinnerStyleBuilder::default()
+            .with_type(SmallSpinnerType::Ascii)
+            .with_interval(Duration::from_secs(60))
+            .with_alignment(Alignment::Right)
+            .with_foreground_color(Color::Yellow)
+            .with_show_elapsed(true)
+            .build()
+            .unwrap();
+        let mut spinner = SmallSpinnerWidget::new(spinner_style);
+        let group = AnimationGroup::new();
+        spinner.set_clock(group.clock());
+        assert_eq!(spinner.width(), 7);
+
+        let area = Rect::new(0, 0, 8, 1);
+        let render = |spinner: &mut SmallSpinnerWidget| {
+            let mut buf = Buffer::empty(area);
+            spinner.render(area, &mut buf);
+            buf
+        };
+
+        let buf = render(&mut spinner);
+        assert_eq!(buf, {
+            let mut expected_buf = Buffer::with_lines([" | 00:00"]);
+            expected_buf.set_style(
+                Rect::new(1, 0, 1, 1),
+                Style::default().fg(Color::Yellow),
+            );
+            expected_buf.set_style(
+                Rect::new(3, 0, 5, 1),
+                Style::default().fg(Color::Yellow),
+            );
+            expected_buf
+        });
+
+        group.advance(Duration::from_millis(42_300));
+        assert_eq!(
+            spinner.time_until_next_frame(),
+            Duration::from_millis(700)
+        );
+        let buf = render(&mut spinner);
+        assert_eq!(buffer_text(&buf), " | 00:42");
+
+        group.advance(Duration::from_secs(3600));
+        let buf = render(&mut spinner);
+        assert_eq!(buffer_text(&buf), "/ 1:00:4");
+
+        spinner.reset();
+        group.advance(Duration::from_secs(5));
+        let buf = render(&mut spinner);
+        assert_eq!(buffer_text(&buf), " | 00:00");
+
+        group.advance(Duration::from_secs(5));
+        let buf = render(&mut spinner);
+        assert_eq!(buffer_text(&buf), " | 00:05");
+        assert_eq!(
+            spinner.accessible_description(),
+            "spinner, busy, 00:05 elapsed",
+        );
+    }
+
+    fn buffer_text(buf: &Buffer) -> String {
+        buf.content.iter().map(|cell| cell.symbol()).collect()
+    }
+
+    #[test]
+    fn unset_colors_are_transparent() {
+        let mut spinner = SmallSpinnerWidget::default();
+
+        let area = Rect::new(0, 0, 1, 1);
+        let mut buf = Buffer::empty(area);
+        buf[(0, 0)].set_fg(Color::Green).set_bg(Color::Blue);
+        spinner.render(area, &mut buf);
+
+        assert_eq!(buf[(0, 0)].fg, Color::Green);
+        assert_eq!(buf[(0, 0)].bg, Color::Blue);
+    }
 }
@@ -4,6 +4,7 @@ use derive_builder::Builder;
 use ratatui::{
     layout::Alignment,
     style::Color,
+    widgets::Block,
 };
 
 /// Type of animation for [`SmallSpinnerWidget`].
@@ -89,6 +90,217 @@ pub enum SmallSpinnerType {
 
     /// ["ᔐ", "ᯇ", "ᔑ", "ᯇ"]
     Canadian,
+
+    /// Symbols provided by the user, e.g. loaded from an
+    /// asset file. A spinner without symbols displays a
+    /// space.
+    Custom(&'static [&'static str]),
+}
+
+impl SmallSpinnerType {
+    /// All built-in spinner types in the order they are
+    /// declared.
+    pub const ALL: [Self; 25] = [
+        Self::Ascii,
+        Self::BoxDrawing,
+        Self::Arrow,
+        Self::DoubleArrow,
+        Self::QuadrantBlock,
+        Self::QuadrantBlockCrack,
+        Self::VerticalBlock,
+        Self::HorizontalBlock,
+        Self::TriangleCorners,
+        Self::WhiteSquare,
+        Self::WhiteCircle,
+        Self::BlackCircle,
+        Self::Clock,
+        Self::MoonPhases,
+        Self::BrailleOne,
+        Self::BrailleDouble,
+        Self::BrailleSix,
+        Self::BrailleSixDouble,
+        Self::BrailleEight,
+        Self::BrailleEightDouble,
+        Self::OghamA,
+        Self::OghamB,
+        Self::OghamC,
+        Self::Parenthesis,
+        Self::Canadian,
+    ];
+
+    /// Returns the human-readable name of the spinner type,
+    /// e.g. `"braille double"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Ascii => "ascii",
+            Self::BoxDrawing => "box drawing",
+            Self::Arrow => "arrow",
+            Self::DoubleArrow => "double arrow",
+            Self::QuadrantBlock => "quadrant block",
+            Self::QuadrantBlockCrack => "quadrant block crack",
+            Self::VerticalBlock => "vertical block",
+            Self::HorizontalBlock => "horizontal block",
+            Self::TriangleCorners => "triangle corners",
+            Self::WhiteSquare => "white square",
+            Self::WhiteCircle => "white circle",
+            Self::BlackCircle => "black circle",
+            Self::Clock => "clock",
+            Self::MoonPhases => "moon phases",
+            Self::BrailleOne => "braille one",
+            Self::BrailleDouble => "braille double",
+            Self::BrailleSix => "braille six",
+            Self::BrailleSixDouble => "braille six double",
+            Self::BrailleEight => "braille eight",
+            Self::BrailleEightDouble => "braille eight double",
+            Self::OghamA => "ogham a",
+            Self::OghamB => "ogham b",
+            Self::OghamC => "ogham c",
+            Self::Parenthesis => "parenthesis",
+            Self::Canadian => "canadian",
+            Self::Custom(_) => "custom",
+        }
+    }
+
+    /// Returns the symbols the spinner cycles through.
+    pub fn frames(&self) -> &'static [&'static str] {
+        match self {
+            Self::Ascii => &["|", "/", "-", "\\"],
+            Self::BoxDrawing => &["│", "╱", "─", "╲"],
+            Self::Arrow => &["↑", "↗", "→", "↘", "↓", "↙", "←", "↖"],
+            Self::DoubleArrow => &["⇑", "⇗", "⇒", "⇘", "⇓", "⇙", "⇐", "⇖"],
+            Self::QuadrantBlock => &["▝", "▗", "▖", "▘"],
+            Self::QuadrantBlockCrack => &["▙", "▛", "▜", "▟"],
+            Self::VerticalBlock => &["▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"],
+            Self::HorizontalBlock => &["▏", "▎", "▍", "▌", "▋", "▊", "▉", "█"],
+            Self::TriangleCorners => &["◢", "◣", "◤", "◥"],
+            Self::WhiteSquare => &["◳", "◲", "◱", "◰"],
+            Self::WhiteCircle => &["◷", "◶", "◵", "◴"],
+            Self::BlackCircle => &["◑", "◒", "◐", "◓"],
+            Self::Clock => &[
+                "🕛", "🕧", "🕐", "🕜", "🕑", "🕝", "🕒", "🕞", "🕓", "🕟",
+                "🕔", "🕠", "🕕", "🕡", "🕖", "🕢", "🕗", "🕣", "🕘", "🕤",
+                "🕙", "🕥", "🕚", "🕦",
+            ],
+            Self::MoonPhases => &["🌑", "🌒", "🌓", "🌕", "🌖"],
+            Self::BrailleOne => &["⠈", "⠐", "⠠", "⠄", "⠂", "⠁"],
+            Self::BrailleDouble => &["⠘", "⠰", "⠤", "⠆", "⠃", "⠉"],
+            Self::BrailleSix => &["⠷", "⠯", "⠟", "⠻", "⠽", "⠾"],
+            Self::BrailleSixDouble => &["⠷", "⠯", "⠟", "⠻", "⠽", "⠾"],
+            Self::BrailleEight => &["⣷", "⣯", "⣟", "⡿", "⢿", "⣻", "⣽", "⣾"],
+            Self::BrailleEightDouble => {
+                &["⣧", "⣏", "⡟", "⠿", "⢻", "⣹", "⣼", "⣶"]
+            }
+            Self::OghamA => &[" ", "ᚐ", "ᚑ", "ᚒ", "ᚓ", "ᚔ"],
+            Self::OghamB => &[" ", "ᚁ", "ᚂ", "ᚃ", "ᚄ", "ᚅ"],
+            Self::OghamC => &[" ", "ᚆ", "ᚇ", "ᚈ", "ᚉ", "ᚊ"],
+            Self::Parenthesis => &["⎛", "⎜", "⎝", "⎞", "⎟", "⎠"],
+            Self::Canadian => &["ᔐ", "ᯇ", "ᔑ", "ᯇ"],
+            Self::Custom([]) => &[" "],
+            Self::Custom(frames) => frames,
+        }
+    }
+
+    /// Returns the number of symbols the spinner cycles
+    /// through.
+    pub fn frame_count(&self) -> usize {
+        self.frames().len()
+    }
+
+    /// Returns the interval between the symbols the spinner
+    /// looks smooth with.
+    pub fn recommended_interval(&self) -> Duration {
+        let millis = match self {
+            Self::Ascii | Self::BoxDrawing => 130,
+            Self::Arrow | Self::DoubleArrow => 100,
+            Self::QuadrantBlock
+            | Self::QuadrantBlockCrack
+            | Self::TriangleCorners
+            | Self::WhiteSquare
+            | Self::WhiteCircle
+            | Self::BlackCircle => 120,
+            Self::VerticalBlock | Self::HorizontalBlock => 80,
+            Self::Clock => 100,
+            Self::MoonPhases => 150,
+            Self::BrailleOne
+            | Self::BrailleDouble
+            | Self::BrailleSix
+            | Self::BrailleSixDouble
+            | Self::BrailleEight
+            | Self::BrailleEightDouble => 80,
+            Self::OghamA | Self::OghamB | Self::OghamC => 100,
+            Self::Parenthesis => 100,
+            Self::Canadian => 150,
+            Self::Custom(_) => 100,
+        };
+
+        Duration::from_millis(millis)
+    }
+}
+
+/// Symbol that [`SmallSpinnerWidget`] starts its cycle with.
+///
+/// Default variant is [`SmallSpinnerStart::First`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SmallSpinnerStart {
+    /// The cycle starts with its first symbol.
+    #[default]
+    First,
+
+    /// The cycle starts with a random symbol, so that
+    /// multiple spinners of the same type don't spin in
+    /// lockstep.
+    Random,
+
+    /// The cycle starts with the symbol with provided index.
+    /// The index wraps around the length of the cycle.
+    Index(usize),
+}
+
+/// Horizontal position of [`SmallSpinnerWidget`] within its
+/// area. Converts from [`Alignment`].
+///
+/// Default variant is [`SmallSpinnerAlignment::Left`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SmallSpinnerAlignment {
+    #[default]
+    Left,
+
+    /// The spinner is placed in the middle of the area,
+    /// rounding down.
+    Center,
+
+    Right,
+
+    /// The spinner is placed provided number of cells to the
+    /// right of the left edge of the area, but no further than
+    /// its right edge.
+    Offset(u16),
+}
+
+impl From<Alignment> for SmallSpinnerAlignment {
+    fn from(value: Alignment) -> Self {
+        match value {
+            Alignment::Left => Self::Left,
+            Alignment::Center => Self::Center,
+            Alignment::Right => Self::Right,
+        }
+    }
+}
+
+/// Vertical position of [`SmallSpinnerWidget`] within an area
+/// taller than a single row.
+///
+/// Default variant is [`SmallSpinnerVerticalAlignment::Top`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SmallSpinnerVerticalAlignment {
+    #[default]
+    Top,
+
+    /// The spinner is placed in the middle of the area,
+    /// rounding down.
+    Center,
+
+    Bottom,
 }
 
 /// A styling configuration for [`SmallSpinnerWidget`].
@@ -102,7 +314,7 @@ pub enum SmallSpinnerType {
 ///     style::Color,
 ///     layout::Alignment,
 /// };
-/// use ratatui_small_spinner::{
+/// use caponata_small_spinner::{
 ///     SmallSpinnerType,
 ///     SmallSpinnerStyleBuilder,
 /// };
@@ -113,10 +325,11 @@ pub enum SmallSpinnerType {
 ///     .with_alignment(Alignment::Center)
 ///     .with_foreground_color(Color::White)
 ///     .with_background_color(Color::Black)
+///     .with_show_elapsed(true)
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Builder)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Builder)]
 #[builder(setter(prefix = "with", into))]
 pub struct SmallSpinnerStyle {
     #[builder(default, setter(name = "with_type"))]
@@ -126,11 +339,38 @@ pub struct SmallSpinnerStyle {
     pub(crate) interval: Duration,
 
     #[builder(default)]
-    pub(crate) alignment: Alignment,
+    pub(crate) alignment: SmallSpinnerAlignment,
+
+    #[builder(default)]
+    pub(crate) vertical_alignment: SmallSpinnerVerticalAlignment,
+
+    /// Foreground color of the spinner. `None` leaves the
+    /// foreground color of the cell untouched.
+    #[builder(default)]
+    pub(crate) foreground_color: Option<Color>,
 
+    /// Background color of the spinner. `None` leaves the
+    /// background color of the cell untouched.
     #[builder(default)]
-    pub(crate) foreground_color: Color,
+    pub(crate) background_color: Option<Color>,
 
     #[builder(default)]
-    pub(crate) background_color: Color,
+    pub(crate) start: SmallSpinnerStart,
+
+    /// Whether the background of the cell the spinner is
+    /// rendered into is left untouched instead of being
+    /// overwritten with the background color.
+    #[builder(default)]
+    pub(crate) respect_existing_background: bool,
+
+    /// Whether the time elapsed since the spinner was first
+    /// rendered is displayed after it, e.g. `⠙ 00:42`. Useful
+    /// for indicators of long-running tasks.
+    #[builder(default)]
+    pub(crate) show_elapsed: bool,
+
+    /// Block rendered around the spinner. The spinner is
+    /// aligned within the inner area of the block.
+    #[builder(default, setter(strip_option))]
+    pub(crate) block: Option<Block<'static>>,
 }
@@ -1,4 +1,7 @@
-use super::SmallSpinnerType;
+use super::{
+    SmallSpinnerStart,
+    SmallSpinnerType,
+};
 
 /// A struct that cycles through a sequence of symbols used for
 /// rendering spinners.
@@ -8,7 +11,7 @@ use super::SmallSpinnerType;
 /// allows advancing to the next one in the sequence.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct SymbolCycle {
-    symbols: Vec<&'static str>,
+    symbols: &'static [&'static str],
     current_index: usize,
 }
 
@@ -20,90 +23,8 @@ impl Default for SymbolCycle {
 
 impl SymbolCycle {
     pub fn new(spinner_type: SmallSpinnerType) -> Self {
-        let symbols = match spinner_type {
-            SmallSpinnerType::Ascii => {
-                vec!["|", "/", "-", "\\"]
-            }
-            SmallSpinnerType::BoxDrawing => {
-                vec!["│", "╱", "─", "╲"]
-            }
-            SmallSpinnerType::Arrow => {
-                vec!["↑", "↗", "→", "↘", "↓", "↙", "←", "↖"]
-            }
-            SmallSpinnerType::DoubleArrow => {
-                vec!["⇑", "⇗", "⇒", "⇘", "⇓", "⇙", "⇐", "⇖"]
-            }
-            SmallSpinnerType::QuadrantBlock => {
-                vec!["▝", "▗", "▖", "▘"]
-            }
-            SmallSpinnerType::QuadrantBlockCrack => {
-                vec!["▙", "▛", "▜", "▟"]
-            }
-            SmallSpinnerType::VerticalBlock => {
-                vec!["▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"]
-            }
-            SmallSpinnerType::HorizontalBlock => {
-                vec!["▏", "▎", "▍", "▌", "▋", "▊", "▉", "█"]
-            }
-            SmallSpinnerType::TriangleCorners => {
-                vec!["◢", "◣", "◤", "◥"]
-            }
-            SmallSpinnerType::WhiteSquare => {
-                vec!["◳", "◲", "◱", "◰"]
-            }
-            SmallSpinnerType::WhiteCircle => {
-                vec!["◷", "◶", "◵", "◴"]
-            }
-            SmallSpinnerType::BlackCircle => {
-                vec!["◑", "◒", "◐", "◓"]
-            }
-            SmallSpinnerType::Clock => {
-                vec![
-                    "🕛", "🕧", "🕐", "🕜", "🕑", "🕝", "🕒", "🕞", "🕓",
-                    "🕟", "🕔", "🕠", "🕕", "🕡", "🕖", "🕢", "🕗", "🕣",
-                    "🕘", "🕤", "🕙", "🕥", "🕚", "🕦",
-                ]
-            }
-            SmallSpinnerType::MoonPhases => {
-                vec!["🌑", "🌒", "🌓", "🌕", "🌖"]
-            }
-            SmallSpinnerType::BrailleOne => {
-                vec!["⠈", "⠐", "⠠", "⠄", "⠂", "⠁"]
-            }
-            SmallSpinnerType::BrailleDouble => {
-                vec!["⠘", "⠰", "⠤", "⠆", "⠃", "⠉"]
-            }
-            SmallSpinnerType::BrailleSix => {
-                vec!["⠷", "⠯", "⠟", "⠻", "⠽", "⠾"]
-            }
-            SmallSpinnerType::BrailleSixDouble => {
-                vec!["⠷", "⠯", "⠟", "⠻", "⠽", "⠾"]
-            }
-            SmallSpinnerType::BrailleEight => {
-                vec!["⣷", "⣯", "⣟", "⡿", "⢿", "⣻", "⣽", "⣾"]
-            }
-            SmallSpinnerType::BrailleEightDouble => {
-                vec!["⣧", "⣏", "⡟", "⠿", "⢻", "⣹", "⣼", "⣶"]
-            }
-            SmallSpinnerType::OghamA => {
-                vec![" ", "ᚐ", "ᚑ", "ᚒ", "ᚓ", "ᚔ"]
-            }
-            SmallSpinnerType::OghamB => {
-                vec![" ", "ᚁ", "ᚂ", "ᚃ", "ᚄ", "ᚅ"]
-            }
-            SmallSpinnerType::OghamC => {
-                vec![" ", "ᚆ", "ᚇ", "ᚈ", "ᚉ", "ᚊ"]
-            }
-            SmallSpinnerType::Parenthesis => {
-                vec!["⎛", "⎜", "⎝", "⎞", "⎟", "⎠"]
-            }
-            SmallSpinnerType::Canadian => {
-                vec!["ᔐ", "ᯇ", "ᔑ", "ᯇ"]
-            }
-        };
-
         Self {
-            symbols,
+            symbols: spinner_type.frames(),
             current_index: 0,
         }
     }
@@ -123,8 +44,21 @@ impl SymbolCycle {
         self.symbols[self.current_index]
     }
 
-    /// Resets the cycle to the first symbol.
-    pub fn reset(&mut self) {
-        self.current_index = 0;
+    /// Replaces the symbols of the cycle with the symbols of
+    /// provided spinner type. The current position is kept,
+    /// wrapping around if the new cycle is shorter.
+    pub fn set_type(&mut self, spinner_type: SmallSpinnerType) {
+        self.symbols = spinner_type.frames();
+        self.current_index %= self.symbols.len();
+    }
+
+    /// Moves the cycle to the symbol specified by provided
+    /// start.
+    pub fn reset(&mut self, start: SmallSpinnerStart) {
+        self.current_index = match start {
+            SmallSpinnerStart::First => 0,
+            SmallSpinnerStart::Random => fastrand::usize(..self.symbols.len()),
+            SmallSpinnerStart::Index(index) => index % self.symbols.len(),
+        };
     }
 }
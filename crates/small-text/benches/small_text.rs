@@ -0,0 +1,97 @@
+//! Creating a text and generating an animation frame should take less than 1 ms
+//! per frame for texts of up to 1000 symbols.
+
+use std::{
+    hint::black_box,
+    time::Duration,
+};
+
+use caponata_common::AnimationGroup;
+use caponata_small_text::{
+    Animation,
+    AnimationRepeatMode,
+    AnimationStepBuilder,
+    AnimationStyleBuilder,
+    AnimationTarget,
+    SmallTextStyleBuilder,
+    SmallTextWidget,
+    Target,
+};
+use criterion::{
+    BenchmarkId,
+    Criterion,
+    criterion_group,
+    criterion_main,
+};
+use ratatui::style::Color;
+
+const TEXT_LENGTHS: [usize; 3] = [10, 100, 1000];
+
+fn create_symbols(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("create_symbols");
+    for length in TEXT_LENGTHS {
+        let text = "a".repeat(length);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(length),
+            &text,
+            |bencher, text| {
+                bencher.iter(|| {
+                    let text_style = SmallTextStyleBuilder::default()
+                        .with_text(text)
+                        .for_target(Target::Every(2))
+                        .set_foreground_color(Color::Red)
+                        .then()
+                        .build();
+                    black_box(SmallTextWidget::new(text_style))
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+fn next_frame(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("animation_next_frame");
+    for length in TEXT_LENGTHS {
+        let text = "a".repeat(length);
+        let text_style =
+            SmallTextStyleBuilder::default().with_text(&text).build();
+        let symbols = SmallTextWidget::new(text_style).symbols().clone();
+
+        let steps = [Color::Red, Color::Blue]
+            .into_iter()
+            .map(|color| {
+                AnimationStepBuilder::default()
+                    .with_duration(Duration::from_millis(10))
+                    .for_target(AnimationTarget::Every(2))
+                    .update_foreground_color(color)
+                    .then()
+                    .for_target(AnimationTarget::Untouched)
+                    .update_background_color(color)
+                    .then()
+                    .build()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_repeat_mode(AnimationRepeatMode::Infinite)
+            .with_steps(steps)
+            .build()
+            .unwrap();
+
+        let animation_group = AnimationGroup::new();
+        let mut animation = Animation::new(animation_style, symbols);
+        animation.set_clock(animation_group.clock());
+
+        group.bench_function(BenchmarkId::from_parameter(length), |bencher| {
+            bencher.iter(|| {
+                animation_group.advance(Duration::from_millis(5));
+                black_box(animation.next_frame())
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, create_symbols, next_frame);
+criterion_main!(benches);
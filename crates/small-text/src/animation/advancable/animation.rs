@@ -1,7 +1,10 @@
+use std::sync::Arc;
+
 use crate::{
     AnimationAdvanceMode,
     AnimationRepeatMode,
     AnimationStep,
+    AnimationStyle,
 };
 
 use super::{
@@ -17,19 +20,19 @@ pub(crate) enum AdvancableAnimation {
 
 impl AdvancableAnimation {
     pub fn new(
-        steps: Vec<AnimationStep>,
+        style: Arc<AnimationStyle>,
         repeat_mode: AnimationRepeatMode,
         advance_mode: AnimationAdvanceMode,
     ) -> Self {
         match advance_mode {
             AnimationAdvanceMode::Manual => {
                 let animation =
-                    ManuallyAdvancableAnimation::new(steps, repeat_mode);
+                    ManuallyAdvancableAnimation::new(style, repeat_mode);
                 Self::Manually(animation)
             }
             AnimationAdvanceMode::Auto => {
                 let animation =
-                    AutomaticallyAdvancableAnimation::new(steps, repeat_mode);
+                    AutomaticallyAdvancableAnimation::new(style, repeat_mode);
                 Self::Automatically(animation)
             }
         }
@@ -44,6 +47,40 @@ impl AdvancableAnimation {
         }
     }
 
+    /// Returns the index of the current step if the iteration
+    /// limit is not reached; otherwise returns `None`.
+    pub fn current_index(&self) -> Option<usize> {
+        match self {
+            Self::Manually(animation) => animation.current_index(),
+            Self::Automatically(animation) => animation.current_index(),
+        }
+    }
+
+    /// Returns the index of the current iteration, starting
+    /// from zero.
+    pub fn current_iteration(&self) -> u32 {
+        match self {
+            Self::Manually(animation) => animation.current_iteration(),
+            Self::Automatically(animation) => animation.current_iteration(),
+        }
+    }
+
+    pub fn steps(&self) -> &[AnimationStep] {
+        match self {
+            Self::Manually(animation) => animation.steps(),
+            Self::Automatically(animation) => animation.steps(),
+        }
+    }
+
+    /// Moves the animation to the first step of the first
+    /// iteration.
+    pub fn reset(&mut self) {
+        match self {
+            Self::Manually(animation) => animation.reset(),
+            Self::Automatically(animation) => animation.reset(),
+        }
+    }
+
     /// Advances the animation and returns the current step
     /// if the iteration limit is not reached. If the animation
     /// is manually advancable, the `advance` method must be
@@ -55,6 +92,25 @@ impl AdvancableAnimation {
         }
     }
 
+    /// Advances the animation like the `next_step` method,
+    /// but without waiting for the `advance` method to be
+    /// called if the animation is manually advancable.
+    pub fn skip_step(&mut self) -> Option<AnimationStep> {
+        match self {
+            Self::Manually(animation) => animation.skip_step(),
+            Self::Automatically(animation) => animation.next_step(),
+        }
+    }
+
+    /// Replaces the current branch step with provided steps of
+    /// the branch.
+    pub fn enter_branch(&mut self, steps: Vec<AnimationStep>) {
+        match self {
+            Self::Manually(animation) => animation.enter_branch(steps),
+            Self::Automatically(animation) => animation.enter_branch(steps),
+        }
+    }
+
     /// If the animation is manually advancable, marks ir ready
     /// to advance on the next call to the `next_step` method.
     pub fn advance(&mut self) {
@@ -1,6 +1,9 @@
+use std::sync::Arc;
+
 use crate::{
     AnimationRepeatMode,
     AnimationStep,
+    AnimationStyle,
     animation::RepeatableAnimation,
 };
 
@@ -12,11 +15,11 @@ pub(crate) struct ManuallyAdvancableAnimation {
 
 impl ManuallyAdvancableAnimation {
     pub fn new(
-        steps: Vec<AnimationStep>,
+        style: Arc<AnimationStyle>,
         repeat_mode: AnimationRepeatMode,
     ) -> Self {
         let repeatable_animation =
-            RepeatableAnimation::new(steps, repeat_mode);
+            RepeatableAnimation::new(style, repeat_mode);
 
         Self {
             repeatable_animation,
@@ -29,6 +32,27 @@ impl ManuallyAdvancableAnimation {
         self.repeatable_animation.current_step()
     }
 
+    /// Returns the index of the current step if the iteration
+    /// limit is not reached; otherwise returns `None`.
+    pub fn current_index(&self) -> Option<usize> {
+        self.repeatable_animation.current_index()
+    }
+
+    pub fn current_iteration(&self) -> u32 {
+        self.repeatable_animation.current_iteration()
+    }
+
+    pub fn steps(&self) -> &[AnimationStep] {
+        self.repeatable_animation.steps()
+    }
+
+    /// Moves the animation to the first step of the first
+    /// iteration and discards the pending advance.
+    pub fn reset(&mut self) {
+        self.repeatable_animation.reset();
+        self.is_advanced = false;
+    }
+
     /// Advances the animation and returns the current step
     /// if the iteration limit is not reached and the `advance`
     /// method was called beforehand; otherwise returns `None`.
@@ -45,4 +69,17 @@ impl ManuallyAdvancableAnimation {
     pub fn advance(&mut self) {
         self.is_advanced = true;
     }
+
+    /// Advances the animation regardless of the pending
+    /// advance and returns the current step if the iteration
+    /// limit is not reached; otherwise returns `None`.
+    pub fn skip_step(&mut self) -> Option<AnimationStep> {
+        self.repeatable_animation.next_step()
+    }
+
+    /// Replaces the current branch step with provided steps of
+    /// the branch.
+    pub fn enter_branch(&mut self, steps: Vec<AnimationStep>) {
+        self.repeatable_animation.enter_branch(steps);
+    }
 }
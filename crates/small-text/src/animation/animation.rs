@@ -1,20 +1,47 @@
 use std::{
-    collections::HashMap,
-    time::Instant,
+    collections::{
+        BTreeSet,
+        HashMap,
+    },
+    sync::Arc,
+    time::Duration,
 };
 
-use ratatui::style::Modifier;
+use caponata_common::{
+    Animated,
+    EventQueue,
+    Instant,
+    SharedClock,
+    color_to_hsl,
+    hsl_to_color,
+    to_rgb,
+};
+use ratatui::{
+    style::{
+        Color,
+        Modifier,
+    },
+    text::Line,
+};
 
 use super::{
     AdvancableAnimation,
     AnimationAction,
     AnimationEvent,
+    AnimationRepeatMode,
+    AnimationRestoreMode,
+    AnimationSpring,
     AnimationStep,
     AnimationStyle,
     AnimationTarget,
+    AnimationTranslateMode,
+    TargetError,
     animation_target_sorter,
 };
-use crate::Symbol;
+use crate::{
+    Symbol,
+    symbols_to_line,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum SymbolState {
@@ -72,6 +99,15 @@ pub struct AnimationFrame {
     pub symbols: HashMap<u16, Symbol>,
 }
 
+impl AnimationFrame {
+    /// Returns the symbols of the frame as a line, so the frame
+    /// can be embedded into other widgets. Adjacent symbols
+    /// with the same style share a span.
+    pub fn to_line(&self) -> Line<'static> {
+        symbols_to_line(&self.symbols)
+    }
+}
+
 /// Provides a high-level API of working with animations
 /// for [`SmallTextWidget`] with full control over
 /// behavior.
@@ -90,7 +126,8 @@ pub struct AnimationFrame {
 /// };
 ///
 /// use ratatui::style::{Color, Modifier};
-/// use ratatui_small_text::{
+/// use caponata_common::AnimationGroup;
+/// use caponata_small_text::{
 ///     Symbol,
 ///     AnimationTarget,
 ///     AnimationAdvanceMode,
@@ -112,7 +149,8 @@ pub struct AnimationFrame {
 ///     .update_background_color(Color::Blue)
 ///     .remove_all_modifiers()
 ///     .then()
-///     .build();
+///     .build()
+///     .unwrap();
 /// let second_step = AnimationStepBuilder::default()
 ///     .with_duration(Duration::from_millis(100))
 ///     .for_target(AnimationTarget::Range(0, 2))
@@ -125,7 +163,8 @@ pub struct AnimationFrame {
 ///     .update_background_color(Color::Green)
 ///     .remove_all_modifiers()
 ///     .then()
-///     .build();
+///     .build()
+///     .unwrap();
 /// let animation_style = AnimationStyleBuilder::default()
 ///     .with_advance_mode(AnimationAdvanceMode::Auto)
 ///     .with_repeat_mode(AnimationRepeatMode::Finite(1))
@@ -138,7 +177,9 @@ pub struct AnimationFrame {
 ///     (1, Symbol::default()),
 ///     (2, Symbol::default()),
 /// ]);
+/// let group = AnimationGroup::new();
 /// let mut animation = Animation::new(animation_style, symbols);
+/// animation.set_clock(group.clock());
 ///
 /// // Returns next frame of the animation.
 /// let first_frame = animation.next_frame().unwrap();
@@ -156,31 +197,77 @@ pub struct AnimationFrame {
 ///
 /// // Resume the animation.
 /// animation.unpause();
+/// group.advance(Duration::from_millis(100));
 ///
 /// // Returns a new frame since animation resumed.
 /// let third_frame = animation.next_frame().unwrap();
 /// assert_ne!(second_frame, third_frame);
 ///
 /// // Returns None when animation reaches the end.
+/// group.advance(Duration::from_millis(100));
 /// let fourth_frame = animation.next_frame();
 /// assert_eq!(fourth_frame, None);
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Animation {
     advancable_animation: AdvancableAnimation,
+    restore_mode: AnimationRestoreMode,
+    start_delay: Duration,
+    initial_symbols: HashMap<u16, Symbol>,
     symbol_states: HashMap<u16, SymbolState>,
-    is_paused: bool,
-    last_step_retrieved_at: Option<Instant>,
-    last_event: Option<AnimationEvent>,
+
+    /// Symbol states before the current step was first
+    /// processed. Steps are processed on every frame, and each
+    /// time they start from these states, so their actions
+    /// are applied once per step rather than once per frame.
+    step_base_states: Option<HashMap<u16, SymbolState>>,
+    clock: SharedClock,
+
+    /// Time the current step was first displayed at. Steps
+    /// of automatically advanced animations start exactly when
+    /// the previous ones end, even if the frame is generated
+    /// later.
+    step_started_at: Option<Instant>,
+    paused_at: Option<Instant>,
+
+    /// Minimal time between generated frames, derived from the
+    /// frame rate limit of the style.
+    min_frame_interval: Option<Duration>,
+    last_frame_at: Option<Instant>,
+    events: EventQueue<AnimationEvent>,
 }
 
 impl Animation {
-    pub fn new(style: AnimationStyle, symbols: HashMap<u16, Symbol>) -> Self {
-        let advancable_animation = AdvancableAnimation::new(
-            style.steps,
-            style.repeat_mode,
-            style.advance_mode,
-        );
+    /// Creates the animation from provided style. The style
+    /// can be shared between animations through an [`Arc`], so
+    /// its steps are not cloned.
+    pub fn new(
+        style: impl Into<Arc<AnimationStyle>>,
+        symbols: HashMap<u16, Symbol>,
+    ) -> Self {
+        let style = style.into();
+        let (repeat_mode, restore_mode) =
+            (style.repeat_mode, style.restore_mode);
+
+        Self::with_modes(style, symbols, repeat_mode, restore_mode)
+    }
+
+    /// Creates the animation from provided style, overriding
+    /// its repeat and restore modes.
+    pub(crate) fn with_modes(
+        style: Arc<AnimationStyle>,
+        symbols: HashMap<u16, Symbol>,
+        repeat_mode: AnimationRepeatMode,
+        restore_mode: AnimationRestoreMode,
+    ) -> Self {
+        let start_delay = style.start_delay;
+        let advance_mode = style.advance_mode;
+        let min_frame_interval = style
+            .max_fps
+            .filter(|max_fps| *max_fps > 0)
+            .map(|max_fps| Duration::from_secs(1) / max_fps as u32);
+        let advancable_animation =
+            AdvancableAnimation::new(style, repeat_mode, advance_mode);
         let symbol_states: HashMap<u16, SymbolState> = symbols
             .iter()
             .map(|(x, symbol)| (*x, SymbolState::Initial(*symbol)))
@@ -188,90 +275,510 @@ impl Animation {
 
         Self {
             advancable_animation,
+            restore_mode,
+            start_delay,
+            initial_symbols: symbols,
             symbol_states,
-            is_paused: false,
-            last_step_retrieved_at: None,
-            last_event: None,
+            step_base_states: None,
+            clock: SharedClock::default(),
+            step_started_at: None,
+            paused_at: None,
+            min_frame_interval,
+            last_frame_at: None,
+            events: EventQueue::new(),
         }
     }
 
+    /// Creates the animation like [`Animation::new`], but
+    /// returns [`TargetError`] listing every position selected
+    /// by [`AnimationTarget::Single`] and
+    /// [`AnimationTarget::Range`] targets that doesn't exist
+    /// in provided symbols.
+    pub fn try_new(
+        style: impl Into<Arc<AnimationStyle>>,
+        symbols: HashMap<u16, Symbol>,
+    ) -> Result<Self, TargetError> {
+        let style = style.into();
+        let coordinates =
+            style.out_of_bounds_coordinates(|x| symbols.contains_key(&x));
+        if !coordinates.is_empty() {
+            return Err(TargetError { coordinates });
+        }
+
+        Ok(Self::new(style, symbols))
+    }
+
+    /// Replaces the clock used to measure time. Animations
+    /// sharing the clock of an [`AnimationGroup`] advance from
+    /// a single timeline.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+    }
+
+    /// Returns the most recent event and discards the others.
+    /// Use [`Animation::drain_events`] to receive all of them.
     pub fn take_last_event(&mut self) -> Option<AnimationEvent> {
-        self.last_event.take()
+        self.events.take_latest()
     }
 
+    /// Returns the events produced since they were last taken,
+    /// from the oldest to the most recent one.
+    pub fn drain_events(&mut self) -> impl Iterator<Item = AnimationEvent> {
+        self.events.drain()
+    }
+
+    /// Returns the frame of the animation at the current time,
+    /// or `None` if the animation has ended.
+    ///
+    /// Automatically advanced animations stay time-accurate
+    /// when frames are requested less often than their steps
+    /// change: steps whose time has already passed are skipped,
+    /// but their actions are still applied. At most one
+    /// iteration is caught up per frame.
     pub fn next_frame(&mut self) -> Option<AnimationFrame> {
-        let now = Instant::now();
+        let now = self.clock.now();
 
-        let step = if self.is_paused {
-            self.advancable_animation.current_step()
-        } else if self.last_step_retrieved_at.is_none() {
-            self.last_step_retrieved_at = Some(now);
+        let step = if self.is_delayed(now) {
+            return self.make_frame().into();
+        } else if self.is_paused() {
             self.advancable_animation.current_step()
+        } else if self.step_started_at.is_none() {
+            self.step_started_at = Some(now + self.start_delay);
+            let step = self.enter_current_step();
+            if self.start_delay.is_zero() {
+                step
+            } else {
+                return self.make_frame().into();
+            }
+        } else if self.is_frame_limited(now)
+            && self.current_step_index().is_some()
+        {
+            return self.make_frame().into();
         } else {
-            let last_step_retrieved_at = self.last_step_retrieved_at?;
-            self.last_step_retrieved_at = Some(now);
-            self.last_event = Some(AnimationEvent::FrameGenerated);
-            self.next_step(now, last_step_retrieved_at)
+            self.events.push(AnimationEvent::FrameGenerated);
+            self.next_step(now)
         };
 
         if let Some(step) = step {
-            self.process_step(step);
+            self.process_step(step, self.elapsed_in_step());
+            self.last_frame_at = Some(now);
             self.make_frame().into()
         } else {
-            self.last_event = Some(AnimationEvent::Ended);
+            self.events.push(AnimationEvent::Ended);
             None
         }
     }
 
+    pub fn restore_mode(&self) -> AnimationRestoreMode {
+        self.restore_mode
+    }
+
+    /// Returns the symbols the animation was created with.
+    pub fn initial_symbols(&self) -> &HashMap<u16, Symbol> {
+        &self.initial_symbols
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    /// Pauses the animation if it is not already paused;
+    /// otherwise has no effect. The time spent on pause is not
+    /// counted towards the duration of the current step.
     pub fn pause(&mut self) {
-        self.is_paused = true;
+        if !self.is_paused() {
+            self.paused_at = Some(self.clock.now());
+        }
     }
 
     pub fn unpause(&mut self) {
-        self.is_paused = false;
+        if let Some(paused_at) = self.paused_at.take()
+            && let Some(step_started_at) = self.step_started_at.as_mut()
+        {
+            *step_started_at +=
+                self.clock.now().saturating_duration_since(paused_at);
+        }
     }
 
     pub fn advance(&mut self) {
         self.advancable_animation.advance();
     }
 
-    fn next_step(
-        &mut self,
-        now: Instant,
-        last_step_retrieved_at: Instant,
-    ) -> Option<AnimationStep> {
+    /// Returns the index of the current step, or `None` if
+    /// the animation has ended.
+    pub fn current_step_index(&self) -> Option<usize> {
+        self.advancable_animation.current_index()
+    }
+
+    pub fn step_count(&self) -> usize {
+        self.advancable_animation.steps().len()
+    }
+
+    /// Returns the time the current step has been displayed
+    /// for, excluding the time spent on pause. Returns zero if
+    /// no frame has been generated yet.
+    pub fn elapsed_in_step(&self) -> Duration {
+        let Some(step_started_at) = self.step_started_at else {
+            return Duration::ZERO;
+        };
+        let now = self.paused_at.unwrap_or_else(|| self.clock.now());
+
+        now.saturating_duration_since(step_started_at)
+    }
+
+    /// Returns the duration of a single iteration of the
+    /// animation, which is the sum of its step durations.
+    pub fn total_duration(&self) -> Duration {
+        self.advancable_animation
+            .steps()
+            .iter()
+            .map(|step| step.duration)
+            .sum()
+    }
+
+    /// Returns the progress of the current iteration of the
+    /// animation in the `0.0..=1.0` range. Returns `1.0` if
+    /// the animation has ended.
+    pub fn progress(&self) -> f32 {
+        let Some(current_step_index) = self.current_step_index() else {
+            return 1.0;
+        };
+        let steps = self.advancable_animation.steps();
+
+        let total_duration = self.total_duration();
+        if total_duration.is_zero() {
+            return current_step_index as f32 / steps.len() as f32;
+        }
+
+        let current_step_duration = steps[current_step_index].duration;
+        let passed_duration: Duration = steps[..current_step_index]
+            .iter()
+            .map(|step| step.duration)
+            .sum::<Duration>()
+            + self.elapsed_in_step().min(current_step_duration);
+
+        passed_duration.div_duration_f32(total_duration)
+    }
+
+    /// Moves the animation to the step with provided index
+    /// within the current iteration, applying the actions of
+    /// all intermediate steps to the symbols. Seeking
+    /// backwards restarts the animation from the first
+    /// iteration. Indexes beyond the last step are clamped.
+    pub fn seek_to_step(&mut self, index: usize) {
+        let index = index.min(self.step_count().saturating_sub(1));
+
+        let is_seeking_backwards = self
+            .current_step_index()
+            .is_none_or(|current_index| current_index > index);
+        if is_seeking_backwards {
+            self.rewind();
+        }
+        if is_seeking_backwards || self.step_started_at.is_none() {
+            self.enter_current_step();
+        }
+
+        while let Some(current_index) = self.current_step_index()
+            && current_index < index
+        {
+            if let Some(step) = self.advancable_animation.current_step() {
+                let duration = step.duration;
+                self.process_step(step, duration);
+            }
+            self.advancable_animation.advance();
+            let next_step = self
+                .next_advancable_step()
+                .and_then(|next_step| self.enter_step(next_step));
+            if next_step.is_none() {
+                break;
+            }
+        }
+
+        self.step_started_at =
+            Some(self.paused_at.unwrap_or_else(|| self.clock.now()));
+    }
+
+    /// Moves the animation to provided offset from the
+    /// beginning of the current iteration, like
+    /// [`Animation::seek_to_step`]. Offsets beyond the total
+    /// duration are clamped to the end of the last step.
+    pub fn seek(&mut self, offset: Duration) {
+        let steps = self.advancable_animation.steps();
+
+        let mut index = 0;
+        let mut offset_in_step = offset;
+        while index + 1 < steps.len()
+            && offset_in_step >= steps[index].duration
+        {
+            offset_in_step -= steps[index].duration;
+            index += 1;
+        }
+        let offset_in_step = steps
+            .get(index)
+            .map_or(Duration::ZERO, |step| offset_in_step.min(step.duration));
+
+        self.seek_to_step(index);
+        self.step_started_at = self
+            .step_started_at
+            .and_then(|step_started_at| {
+                step_started_at.checked_sub(offset_in_step)
+            })
+            .or(self.step_started_at);
+    }
+
+    /// Returns the time left until the next call to
+    /// `next_frame` advances the animation. Returns `None` if
+    /// the animation is paused, has ended or is advanced
+    /// manually.
+    pub fn time_until_next_frame(&self) -> Option<Duration> {
+        if self.is_paused()
+            || matches!(
+                self.advancable_animation,
+                AdvancableAnimation::Manually(_)
+            )
+        {
+            return None;
+        }
         let current_step = self.advancable_animation.current_step()?;
 
-        let enough_time_passed = now.duration_since(last_step_retrieved_at)
-            >= current_step.duration;
+        let Some(step_started_at) = self.step_started_at else {
+            return Some(Duration::ZERO);
+        };
+        let now = self.clock.now();
+        if self.is_delayed(now) {
+            return Some(step_started_at.duration_since(now));
+        }
+
+        let mut until_step_end =
+            current_step.duration.saturating_sub(self.elapsed_in_step());
+        if current_step.spring.is_some() {
+            until_step_end =
+                until_step_end.min(AnimationSpring::FRAME_INTERVAL);
+        }
+        Some(until_step_end.max(self.time_until_frame_allowed(now)))
+    }
+
+    /// Returns whether the first step is not displayed yet
+    /// because of the start delay.
+    fn is_delayed(&self, now: Instant) -> bool {
+        self.step_started_at
+            .is_some_and(|step_started_at| now < step_started_at)
+    }
+
+    /// Returns the time left until the frame rate limit allows
+    /// generating a new frame.
+    fn time_until_frame_allowed(&self, now: Instant) -> Duration {
+        match (self.min_frame_interval, self.last_frame_at) {
+            (Some(min_frame_interval), Some(last_frame_at)) => {
+                min_frame_interval.saturating_sub(
+                    now.saturating_duration_since(last_frame_at),
+                )
+            }
+            _ => Duration::ZERO,
+        }
+    }
+
+    fn is_frame_limited(&self, now: Instant) -> bool {
+        !self.time_until_frame_allowed(now).is_zero()
+    }
+
+    /// Moves the animation to the first step and restores the
+    /// initial symbols.
+    fn rewind(&mut self) {
+        self.advancable_animation.reset();
+        self.step_base_states = None;
+        self.symbol_states = self
+            .initial_symbols
+            .iter()
+            .map(|(x, symbol)| (*x, SymbolState::Initial(*symbol)))
+            .collect();
+    }
+
+    fn next_step(&mut self, now: Instant) -> Option<AnimationStep> {
+        let current_step = self.advancable_animation.current_step()?;
+
+        let enough_time_passed =
+            self.elapsed_in_step() >= current_step.duration;
         let next_step = if enough_time_passed {
-            self.advancable_animation.next_step()
+            self.next_advancable_step()
         } else {
             return current_step.into();
         };
 
-        if next_step.is_some() {
-            next_step
-        } else {
-            current_step.into()
+        let Some(next_step) = next_step else {
+            return self.advancable_animation.current_step();
+        };
+        let next_step = self.enter_step(next_step)?;
+        match self.advancable_animation {
+            AdvancableAnimation::Manually(_) => {
+                self.step_started_at = Some(now);
+                Some(next_step)
+            }
+            AdvancableAnimation::Automatically(_) => {
+                self.catch_up(current_step.duration, next_step, now)
+            }
         }
     }
 
-    fn process_step(&mut self, step: AnimationStep) {
-        let mut step_states: HashMap<u16, StepSymbolState> = self
-            .symbol_states
-            .clone()
-            .into_iter()
-            .map(|(x, state)| (x, state.into()))
+    /// Starts provided step when the previous step with provided
+    /// duration ended. Steps whose time has already passed are
+    /// processed and skipped, up to a full iteration; the rest
+    /// of the lag is dropped.
+    fn catch_up(
+        &mut self,
+        previous_step_duration: Duration,
+        mut step: AnimationStep,
+        now: Instant,
+    ) -> Option<AnimationStep> {
+        let mut step_started_at = self.step_started_at.unwrap_or(now);
+        let mut previous_step_duration = previous_step_duration;
+
+        for _ in 0..self.step_count() {
+            // Zero-duration steps starting right now are still
+            // displayed for a single frame.
+            step_started_at += previous_step_duration;
+            let elapsed = now.saturating_duration_since(step_started_at);
+            if elapsed < step.duration || elapsed.is_zero() {
+                self.step_started_at = Some(step_started_at);
+                return Some(step);
+            }
+
+            previous_step_duration = step.duration;
+            self.process_step(step, previous_step_duration);
+            let next_step = self
+                .next_advancable_step()
+                .and_then(|next_step| self.enter_step(next_step));
+            match next_step {
+                Some(next_step) => step = next_step,
+                None => {
+                    self.step_started_at = Some(step_started_at);
+                    return self.advancable_animation.current_step();
+                }
+            }
+        }
+
+        self.step_started_at = Some(now);
+        Some(step)
+    }
+
+    /// Advances the animation like
+    /// [`AdvancableAnimation::next_step`], so that the next
+    /// processed step starts from the current symbol states.
+    fn next_advancable_step(&mut self) -> Option<AnimationStep> {
+        let step = self.advancable_animation.next_step()?;
+        self.step_base_states = None;
+
+        Some(step)
+    }
+
+    /// Evaluates the condition and the branch of provided step
+    /// the animation has just reached, skipping the steps
+    /// whose conditions fail and entering the selected
+    /// branches. Returns the step to display, or `None` if the
+    /// animation has ended.
+    ///
+    /// If a full iteration of steps is skipped at once, the
+    /// step reached after it is displayed regardless of its
+    /// condition, so the animation doesn't stall.
+    fn enter_step(
+        &mut self,
+        mut step: AnimationStep,
+    ) -> Option<AnimationStep> {
+        let mut left_step_count = 0;
+        while left_step_count <= self.step_count() {
+            let iteration = self.advancable_animation.current_iteration();
+            let is_condition_met =
+                step.condition.as_ref().is_none_or(|condition| {
+                    condition.call((self.step_states(), iteration))
+                });
+
+            if is_condition_met {
+                let Some(branch) = step.branch.as_ref() else {
+                    return Some(step);
+                };
+                let steps = branch.select(self.step_states(), iteration);
+                if !steps.is_empty() {
+                    self.advancable_animation.enter_branch(steps);
+                    step = self.advancable_animation.current_step()?;
+                    continue;
+                }
+            }
+
+            let index = self.current_step_index();
+            step = self.advancable_animation.skip_step()?;
+            if self.current_step_index() != index
+                || self.advancable_animation.current_iteration() != iteration
+            {
+                left_step_count += 1;
+            }
+        }
+
+        Some(step)
+    }
+
+    /// Enters the current step like [`Animation::enter_step`].
+    fn enter_current_step(&mut self) -> Option<AnimationStep> {
+        let step = self.advancable_animation.current_step()?;
+        self.enter_step(step)
+    }
+
+    fn step_states(&self) -> HashMap<u16, StepSymbolState> {
+        self.symbol_states
+            .iter()
+            .map(|(x, state)| (*x, (*state).into()))
+            .collect()
+    }
+
+    /// Applies provided step to the symbol states, as it is
+    /// displayed after provided time in the step.
+    fn process_step(&mut self, step: AnimationStep, elapsed: Duration) {
+        let base_states = self
+            .step_base_states
+            .get_or_insert_with(|| self.symbol_states.clone());
+        let mut step_states: HashMap<u16, StepSymbolState> = base_states
+            .iter()
+            .map(|(x, state)| (*x, (*state).into()))
             .collect();
 
         let mut actions: Vec<(AnimationTarget, Vec<AnimationAction>)> =
             step.actions.into_iter().collect();
         actions.sort_by(|a, b| animation_target_sorter(&a.0, &b.0));
 
+        let mut has_out_of_bounds_targets = false;
         for (target, actions) in actions {
             let x_coords = self.resolve_target(target, &step_states);
-            self.execute_actions(x_coords, &mut step_states, actions);
+            has_out_of_bounds_targets |= !self.execute_actions(
+                x_coords,
+                &mut step_states,
+                actions,
+                step.translate_mode,
+            );
+        }
+        if step.shift != 0 {
+            let translations =
+                step_states.keys().map(|x| (*x, step.shift)).collect();
+            translate_symbols(
+                translations,
+                &mut step_states,
+                step.translate_mode,
+            );
+        }
+        if has_out_of_bounds_targets {
+            if self.events.latest() == Some(&AnimationEvent::FrameGenerated) {
+                self.events.pop_latest();
+            }
+            self.events.push(AnimationEvent::TargetOutOfBounds);
+        }
+
+        if let Some((spring, callback)) = step.spring {
+            // The final frame of the step lands exactly on the
+            // target, even if the spring hasn't fully settled.
+            let value = match elapsed >= step.duration {
+                true => spring.to,
+                false => spring.value_at(elapsed),
+            };
+            let symbols = callback.call((value, step_states.clone()));
+            merge_symbols_with_step_states(symbols, &mut step_states);
         }
 
         if let Some(on_before_finish) = step.on_before_finish {
@@ -303,12 +810,17 @@ impl Animation {
         target: AnimationTarget,
         step_states: &HashMap<u16, StepSymbolState>,
     ) -> Vec<u16> {
-        let mut step_states_as_vec: Vec<(u16, StepSymbolState)> = step_states
-            .clone()
-            .iter()
-            .map(|(x, state)| (*x, *state))
-            .collect();
-        step_states_as_vec.sort_by(|a, b| a.0.cmp(&b.0));
+        // Returns the sorted positions of the symbols whose
+        // states match provided predicate.
+        let filter_sorted = |predicate: fn(StepSymbolState) -> bool| {
+            let mut x_coords: Vec<u16> = step_states
+                .iter()
+                .filter(|(_, state)| predicate(**state))
+                .map(|(x, _)| *x)
+                .collect();
+            x_coords.sort_unstable();
+            x_coords
+        };
 
         match target {
             AnimationTarget::Single(x) => vec![x],
@@ -340,42 +852,121 @@ impl Animation {
                 .filter(|(i, _)| *i as u16 % n + offset != 0)
                 .map(|(_, (x, _))| (*x).into())
                 .collect(),
-            AnimationTarget::Untouched => step_states_as_vec
-                .iter()
-                .filter(|(_, state)| is_symbol_untouched(*state))
-                .map(|(x, _)| x)
-                .copied()
-                .collect(),
-            AnimationTarget::UntouchedThisStep => step_states_as_vec
-                .iter()
-                .filter(|(_, state)| is_symbol_untouched_this_step(*state))
-                .map(|(x, _)| x)
-                .copied()
-                .collect(),
+            AnimationTarget::NonWhitespace => {
+                let mut x_coords: Vec<u16> = self
+                    .initial_symbols
+                    .iter()
+                    .filter(|(_, symbol)| !symbol.value.is_whitespace())
+                    .map(|(x, _)| *x)
+                    .collect();
+                x_coords.sort_unstable();
+                x_coords
+            }
+            AnimationTarget::Words => {
+                words(&self.initial_symbols).into_iter().flatten().collect()
+            }
+            AnimationTarget::NthWord(n) => words(&self.initial_symbols)
+                .into_iter()
+                .nth(n as usize)
+                .unwrap_or_default(),
+            AnimationTarget::Untouched => filter_sorted(is_symbol_untouched),
+            AnimationTarget::UntouchedThisStep => {
+                filter_sorted(is_symbol_untouched_this_step)
+            }
+            AnimationTarget::Union(first, second) => {
+                let second = self.resolve_target(*second, step_states);
+                self.resolve_target(*first, step_states)
+                    .into_iter()
+                    .chain(second)
+                    .collect::<BTreeSet<u16>>()
+                    .into_iter()
+                    .collect()
+            }
+            AnimationTarget::Intersection(first, second) => {
+                let second = self.resolve_target(*second, step_states);
+                self.resolve_target(*first, step_states)
+                    .into_iter()
+                    .filter(|x| second.contains(x))
+                    .collect::<BTreeSet<u16>>()
+                    .into_iter()
+                    .collect()
+            }
+            AnimationTarget::Difference(first, second) => {
+                let second = self.resolve_target(*second, step_states);
+                self.resolve_target(*first, step_states)
+                    .into_iter()
+                    .filter(|x| !second.contains(x))
+                    .collect::<BTreeSet<u16>>()
+                    .into_iter()
+                    .collect()
+            }
         }
     }
 
+    /// Applies actions to the symbols at provided positions,
+    /// skipping positions that don't exist. Returns `false` if
+    /// some positions, including the ones referenced by
+    /// actions, were skipped.
     fn execute_actions(
         &self,
         x_coords: Vec<u16>,
         step_states: &mut HashMap<u16, StepSymbolState>,
         actions: Vec<AnimationAction>,
-    ) {
+        translate_mode: AnimationTranslateMode,
+    ) -> bool {
+        let previous_states = step_states.clone();
+        let previous_symbol =
+            |x: u16| previous_states.get(&x).map(StepSymbolState::symbol);
+
+        let mut are_all_in_bounds = true;
+        let mut swapped_styles: Vec<(u16, Symbol)> = Vec::new();
+        let mut translations: Vec<(u16, i16)> = Vec::new();
         for x in x_coords {
             let step_state = if let Some(state) = step_states.get_mut(&x) {
                 state
             } else {
+                are_all_in_bounds = false;
                 continue;
             };
 
             let mut symbol = step_state.symbol();
             for action in actions.iter() {
-                self.execute_action(&mut symbol, *action);
+                match *action {
+                    AnimationAction::CopyStyleFrom(source_x) => {
+                        match previous_symbol(source_x) {
+                            Some(source) => copy_style(&mut symbol, source),
+                            None => are_all_in_bounds = false,
+                        }
+                    }
+                    AnimationAction::SwapWith(other_x) => {
+                        match previous_symbol(other_x) {
+                            Some(other) => {
+                                swapped_styles
+                                    .push((other_x, step_state.symbol()));
+                                copy_style(&mut symbol, other);
+                            }
+                            None => are_all_in_bounds = false,
+                        }
+                    }
+                    AnimationAction::TranslateBy(offset) => {
+                        translations.push((x, offset));
+                    }
+                    action => self.execute_action(&mut symbol, action),
+                }
             }
 
             let new_step_state = StepSymbolState::Styled(symbol);
             step_states.insert(x, new_step_state);
         }
+
+        for (x, source) in swapped_styles {
+            let mut symbol = step_states[&x].symbol();
+            copy_style(&mut symbol, source);
+            step_states.insert(x, StepSymbolState::Styled(symbol));
+        }
+        translate_symbols(translations, step_states, translate_mode);
+
+        are_all_in_bounds
     }
 
     fn execute_action(&self, symbol: &mut Symbol, action: AnimationAction) {
@@ -384,10 +975,10 @@ impl Animation {
                 symbol.value = character;
             }
             AnimationAction::UpdateForegroundColor(color) => {
-                symbol.foreground_color = color;
+                symbol.foreground_color = Some(color);
             }
             AnimationAction::UpdateBackgroundColor(color) => {
-                symbol.background_color = color;
+                symbol.background_color = Some(color);
             }
             AnimationAction::AddModifier(modifier) => {
                 symbol.modifier = symbol.modifier.union(modifier);
@@ -398,10 +989,54 @@ impl Animation {
             AnimationAction::RemoveAllModifiers => {
                 symbol.modifier = Modifier::empty();
             }
+            AnimationAction::LightenForeground(amount) => {
+                symbol.foreground_color =
+                    symbol.foreground_color.map(|color| {
+                        adjust_hsl(color, |hue, saturation, lightness| {
+                            (hue, saturation, lightness + amount as f64)
+                        })
+                    });
+            }
+            AnimationAction::DarkenBackground(amount) => {
+                symbol.background_color =
+                    symbol.background_color.map(|color| {
+                        adjust_hsl(color, |hue, saturation, lightness| {
+                            (hue, saturation, lightness - amount as f64)
+                        })
+                    });
+            }
+            AnimationAction::ShiftHue(degrees) => {
+                let shift_hue = |color| {
+                    adjust_hsl(color, |hue, saturation, lightness| {
+                        (hue + degrees as f64, saturation, lightness)
+                    })
+                };
+                symbol.foreground_color =
+                    symbol.foreground_color.map(shift_hue);
+                symbol.background_color =
+                    symbol.background_color.map(shift_hue);
+            }
+            AnimationAction::InvertColors => {
+                symbol.foreground_color =
+                    symbol.foreground_color.map(invert_color);
+                symbol.background_color =
+                    symbol.background_color.map(invert_color);
+            }
+            // Actions across positions are executed by
+            // `execute_actions`, since they need other symbols.
+            AnimationAction::CopyStyleFrom(_)
+            | AnimationAction::SwapWith(_)
+            | AnimationAction::TranslateBy(_) => {}
         }
     }
 }
 
+impl Animated for Animation {
+    fn time_until_next_frame(&self) -> Option<Duration> {
+        Animation::time_until_next_frame(self)
+    }
+}
+
 fn is_symbol_untouched(state: StepSymbolState) -> bool {
     matches!(state, StepSymbolState::Untouched(_))
 }
@@ -413,6 +1048,115 @@ fn is_symbol_untouched_this_step(state: StepSymbolState) -> bool {
     )
 }
 
+/// Moves the symbols at provided positions by provided
+/// offsets, leaving blank symbols in their places. Offsets
+/// count symbols rather than coordinates, so gaps between
+/// positions are skipped.
+fn translate_symbols(
+    translations: Vec<(u16, i16)>,
+    step_states: &mut HashMap<u16, StepSymbolState>,
+    translate_mode: AnimationTranslateMode,
+) {
+    if translations.is_empty() {
+        return;
+    }
+
+    let mut x_coords: Vec<u16> = step_states.keys().copied().collect();
+    x_coords.sort_unstable();
+    let symbol_count = x_coords.len() as i64;
+
+    let mut moved_symbols: Vec<(u16, Symbol)> = Vec::new();
+    for (x, offset) in translations {
+        let Ok(index) = x_coords.binary_search(&x) else {
+            continue;
+        };
+        let symbol = step_states[&x].symbol();
+        step_states.insert(
+            x,
+            StepSymbolState::Styled(Symbol {
+                value: ' ',
+                ..Symbol::default()
+            }),
+        );
+
+        let new_index = index as i64 + offset as i64;
+        let new_index = match translate_mode {
+            AnimationTranslateMode::Clip => {
+                (0..symbol_count).contains(&new_index).then_some(new_index)
+            }
+            AnimationTranslateMode::Wrap => {
+                Some(new_index.rem_euclid(symbol_count))
+            }
+        };
+        if let Some(new_index) = new_index {
+            moved_symbols.push((x_coords[new_index as usize], symbol));
+        }
+    }
+
+    for (x, symbol) in moved_symbols {
+        step_states.insert(x, StepSymbolState::Styled(symbol));
+    }
+}
+
+/// Replaces the colors and the modifier of provided symbol
+/// with the ones of the source symbol.
+fn copy_style(symbol: &mut Symbol, source: Symbol) {
+    symbol.foreground_color = source.foreground_color;
+    symbol.background_color = source.background_color;
+    symbol.modifier = source.modifier;
+}
+
+/// Returns provided color with its hue, saturation and
+/// lightness changed by provided function. Returns
+/// [`Color::Reset`] as is.
+fn adjust_hsl(
+    color: Color,
+    adjust: impl Fn(f64, f64, f64) -> (f64, f64, f64),
+) -> Color {
+    match color_to_hsl(color) {
+        Some((hue, saturation, lightness)) => {
+            let (hue, saturation, lightness) =
+                adjust(hue, saturation, lightness);
+            hsl_to_color(hue, saturation, lightness)
+        }
+        None => color,
+    }
+}
+
+/// Returns the RGB complement of provided color. Returns
+/// [`Color::Reset`] as is.
+fn invert_color(color: Color) -> Color {
+    match to_rgb(color) {
+        Some((r, g, b)) => Color::Rgb(255 - r, 255 - g, 255 - b),
+        None => color,
+    }
+}
+
+/// Returns the positions of the symbols of each word in
+/// provided symbols, where a word is a run of alphanumeric
+/// characters.
+fn words(symbols: &HashMap<u16, Symbol>) -> Vec<Vec<u16>> {
+    let mut x_coords: Vec<u16> = symbols.keys().copied().collect();
+    x_coords.sort_unstable();
+
+    let mut words: Vec<Vec<u16>> = Vec::new();
+    let mut is_in_word = false;
+    for x in x_coords {
+        if !symbols[&x].value.is_alphanumeric() {
+            is_in_word = false;
+            continue;
+        }
+
+        match words.last_mut() {
+            Some(word) if is_in_word => word.push(x),
+            _ => words.push(vec![x]),
+        }
+        is_in_word = true;
+    }
+
+    words
+}
+
 fn merge_symbols_with_step_states(
     symbols: HashMap<u16, Symbol>,
     step_states: &mut HashMap<u16, StepSymbolState>,
@@ -421,3 +1165,753 @@ fn merge_symbols_with_step_states(
         step_states.insert(virtual_x, StepSymbolState::Styled(symbol));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        time::Duration,
+    };
+
+    use caponata_common::{
+        AnimationGroup,
+        Callable,
+    };
+    use ratatui::style::{
+        Color,
+        Modifier,
+    };
+
+    use super::{
+        Animation,
+        AnimationFrame,
+    };
+    use crate::{
+        AnimationAction,
+        AnimationAdvanceMode,
+        AnimationBranch,
+        AnimationEvent,
+        AnimationRepeatMode,
+        AnimationSpring,
+        AnimationStepBuilder,
+        AnimationStyleBuilder,
+        AnimationTarget,
+        AnimationTranslateMode,
+        StepSymbolState,
+        Symbol,
+        TargetError,
+    };
+
+    #[test]
+    fn out_of_bounds_targets() {
+        let step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_millis(100))
+            .for_target(AnimationTarget::Range(1, 3))
+            .update_foreground_color(Color::Red)
+            .then()
+            .for_target(AnimationTarget::Single(50))
+            .update_foreground_color(Color::Red)
+            .then()
+            .build()
+            .unwrap();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_steps(vec![step])
+            .build()
+            .unwrap();
+        let symbols = HashMap::from([
+            (0, Symbol::default()),
+            (1, Symbol::default()),
+            (2, Symbol::default()),
+        ]);
+
+        let result =
+            Animation::try_new(animation_style.clone(), symbols.clone());
+        assert_eq!(
+            result,
+            Err(TargetError {
+                coordinates: vec![(0, 3), (0, 50)],
+            }),
+        );
+
+        let mut animation = Animation::new(animation_style, symbols);
+        let frame = animation.next_frame().unwrap();
+        assert_eq!(frame.symbols.len(), 3);
+        assert_eq!(frame.symbols[&0].foreground_color, None);
+        assert_eq!(frame.symbols[&2].foreground_color, Some(Color::Red));
+        assert_eq!(
+            animation.take_last_event(),
+            Some(AnimationEvent::TargetOutOfBounds),
+        );
+    }
+
+    #[test]
+    fn timeline_inspection() {
+        let steps = vec![
+            AnimationStepBuilder::default()
+                .with_duration(Duration::from_millis(100))
+                .build()
+                .unwrap(),
+            AnimationStepBuilder::default()
+                .with_duration(Duration::from_millis(300))
+                .build()
+                .unwrap(),
+        ];
+        let animation_style = AnimationStyleBuilder::default()
+            .with_advance_mode(AnimationAdvanceMode::Manual)
+            .with_steps(steps)
+            .build()
+            .unwrap();
+        let animation = Animation::new(animation_style, HashMap::new());
+
+        assert_eq!(animation.step_count(), 2);
+        assert_eq!(animation.current_step_index(), Some(0));
+        assert_eq!(animation.total_duration(), Duration::from_millis(400));
+        assert_eq!(animation.elapsed_in_step(), Duration::ZERO);
+        assert_eq!(animation.progress(), 0.0);
+
+        let step = AnimationStepBuilder::default()
+            .for_target(AnimationTarget::Untouched)
+            .add_modifier(Modifier::BOLD)
+            .then()
+            .build()
+            .unwrap();
+        let steps = vec![step; 2];
+        let animation_style = AnimationStyleBuilder::default()
+            .with_advance_mode(AnimationAdvanceMode::Manual)
+            .with_repeat_mode(AnimationRepeatMode::Finite(1))
+            .with_steps(steps)
+            .build()
+            .unwrap();
+        let mut animation = Animation::new(animation_style, HashMap::new());
+
+        animation.next_frame();
+        assert_eq!(animation.progress(), 0.0);
+
+        animation.advance();
+        animation.next_frame();
+        assert_eq!(animation.current_step_index(), Some(1));
+        assert_eq!(animation.progress(), 0.5);
+
+        animation.advance();
+        assert_eq!(animation.next_frame(), None);
+        assert_eq!(animation.current_step_index(), None);
+        assert_eq!(animation.progress(), 1.0);
+    }
+
+    #[test]
+    fn seeking() {
+        let steps = [Color::Red, Color::Green, Color::Blue]
+            .into_iter()
+            .enumerate()
+            .map(|(x, color)| {
+                AnimationStepBuilder::default()
+                    .with_duration(Duration::from_secs(10))
+                    .for_target(AnimationTarget::Single(x as u16))
+                    .update_foreground_color(color)
+                    .then()
+                    .build()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_steps(steps)
+            .build()
+            .unwrap();
+        let symbols = HashMap::from([
+            (0, Symbol::default()),
+            (1, Symbol::default()),
+            (2, Symbol::default()),
+        ]);
+        let mut animation = Animation::new(animation_style, symbols);
+
+        animation.seek_to_step(2);
+        let frame = animation.next_frame().unwrap();
+        assert_eq!(animation.current_step_index(), Some(2));
+        assert_eq!(frame.symbols[&0].foreground_color, Some(Color::Red));
+        assert_eq!(frame.symbols[&1].foreground_color, Some(Color::Green));
+        assert_eq!(frame.symbols[&2].foreground_color, Some(Color::Blue));
+
+        animation.seek(Duration::from_secs(15));
+        let frame = animation.next_frame().unwrap();
+        assert_eq!(animation.current_step_index(), Some(1));
+        assert!(animation.elapsed_in_step() >= Duration::from_secs(5));
+        assert_eq!(frame.symbols[&0].foreground_color, Some(Color::Red));
+        assert_eq!(frame.symbols[&1].foreground_color, Some(Color::Green));
+        assert_eq!(frame.symbols[&2].foreground_color, None);
+    }
+
+    #[test]
+    fn frame_to_line() {
+        let red_symbol = |value| Symbol {
+            value,
+            foreground_color: Some(Color::Red),
+            ..Symbol::default()
+        };
+        let frame = AnimationFrame {
+            symbols: HashMap::from([
+                (2, red_symbol('c')),
+                (0, red_symbol('a')),
+                (1, red_symbol('b')),
+            ]),
+        };
+
+        let line = frame.to_line();
+        assert_eq!(line.spans.len(), 1);
+        assert_eq!(line.spans[0].content, "abc");
+        assert_eq!(line.spans[0].style.fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn grouped_animations() {
+        let steps = vec![
+            AnimationStepBuilder::default()
+                .with_duration(Duration::from_millis(100))
+                .build()
+                .unwrap();
+            3
+        ];
+        let animation_style = AnimationStyleBuilder::default()
+            .with_steps(steps)
+            .build()
+            .unwrap();
+        let group = AnimationGroup::new();
+
+        let mut animations = [
+            Animation::new(animation_style.clone(), HashMap::new()),
+            Animation::new(animation_style, HashMap::new()),
+        ];
+        for animation in animations.iter_mut() {
+            animation.set_clock(group.clock());
+            animation.next_frame();
+        }
+
+        for expected_step_index in [0, 1, 1, 2, 2, 0] {
+            group.advance(Duration::from_millis(50));
+            for animation in animations.iter_mut() {
+                animation.next_frame();
+                assert_eq!(
+                    animation.current_step_index(),
+                    Some(expected_step_index),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn start_delay() {
+        let step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_millis(100))
+            .for_target(AnimationTarget::Single(0))
+            .update_foreground_color(Color::Red)
+            .then()
+            .build()
+            .unwrap();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_start_delay(Duration::from_millis(300))
+            .with_steps(vec![step])
+            .build()
+            .unwrap();
+        let symbols = HashMap::from([(0, Symbol::default())]);
+        let group = AnimationGroup::new();
+        let mut animation = Animation::new(animation_style, symbols);
+        animation.set_clock(group.clock());
+
+        let frame = animation.next_frame().unwrap();
+        assert_eq!(frame.symbols[&0].foreground_color, None);
+        assert_eq!(
+            animation.time_until_next_frame(),
+            Some(Duration::from_millis(300)),
+        );
+
+        group.advance(Duration::from_millis(300));
+        let frame = animation.next_frame().unwrap();
+        assert_eq!(frame.symbols[&0].foreground_color, Some(Color::Red));
+    }
+
+    #[test]
+    fn slow_render_loop_catches_up() {
+        let steps: Vec<_> = ['a', 'b', 'c']
+            .into_iter()
+            .enumerate()
+            .map(|(x, value)| {
+                AnimationStepBuilder::default()
+                    .with_duration(Duration::from_millis(100))
+                    .for_target(AnimationTarget::Single(x as u16))
+                    .update_character(value)
+                    .then()
+                    .build()
+                    .unwrap()
+            })
+            .collect();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_steps(steps)
+            .build()
+            .unwrap();
+        let symbols = (0..3)
+            .map(|x| {
+                let symbol = Symbol {
+                    value: '-',
+                    ..Symbol::default()
+                };
+                (x, symbol)
+            })
+            .collect();
+        let group = AnimationGroup::new();
+        let mut animation = Animation::new(animation_style, symbols);
+        animation.set_clock(group.clock());
+
+        let text = |frame: AnimationFrame| -> String {
+            (0..3).map(|x| frame.symbols[&x].value).collect()
+        };
+        assert_eq!(text(animation.next_frame().unwrap()), "a--");
+
+        // The second step is skipped, but its actions are still
+        // applied.
+        group.advance(Duration::from_millis(250));
+        assert_eq!(text(animation.next_frame().unwrap()), "abc");
+        assert_eq!(animation.current_step_index(), Some(2));
+        assert_eq!(animation.elapsed_in_step(), Duration::from_millis(50));
+
+        group.advance(Duration::from_millis(50));
+        animation.next_frame();
+        assert_eq!(animation.current_step_index(), Some(0));
+    }
+
+    #[test]
+    fn frame_rate_limit() {
+        let step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_millis(40))
+            .for_target(AnimationTarget::Single(0))
+            .update_foreground_color(Color::Red)
+            .then()
+            .build()
+            .unwrap();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_steps(vec![step; 3])
+            .with_max_fps(10u16)
+            .build()
+            .unwrap();
+        let group = AnimationGroup::new();
+        let mut animation = Animation::new(
+            animation_style,
+            HashMap::from([(0, Symbol::default())]),
+        );
+        animation.set_clock(group.clock());
+
+        animation.next_frame();
+        group.advance(Duration::from_millis(50));
+        animation.next_frame();
+        assert_eq!(animation.current_step_index(), Some(0));
+        assert_eq!(
+            animation.time_until_next_frame(),
+            Some(Duration::from_millis(50)),
+        );
+
+        group.advance(Duration::from_millis(50));
+        animation.next_frame();
+        assert_eq!(animation.current_step_index(), Some(2));
+        assert_eq!(
+            animation.take_last_event(),
+            Some(AnimationEvent::FrameGenerated)
+        );
+    }
+
+    #[test]
+    fn seeded_step_randomization() {
+        let steps: Vec<_> = ['a', 'b', 'c', 'd']
+            .into_iter()
+            .map(|value| {
+                AnimationStepBuilder::default()
+                    .with_duration(Duration::from_millis(100))
+                    .for_target(AnimationTarget::Single(0))
+                    .update_character(value)
+                    .then()
+                    .build()
+                    .unwrap()
+            })
+            .collect();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_steps(steps)
+            .with_duration_jitter(20)
+            .with_shuffle_steps(true)
+            .with_seed(7u64)
+            .build()
+            .unwrap();
+
+        let play = || -> Vec<(char, Duration)> {
+            let group = AnimationGroup::new();
+            let mut animation = Animation::new(
+                animation_style.clone(),
+                HashMap::from([(0, Symbol::default())]),
+            );
+            animation.set_clock(group.clock());
+
+            (0..8)
+                .map(|_| {
+                    let frame = animation.next_frame().unwrap();
+                    let duration = animation.time_until_next_frame().unwrap();
+                    group.advance(duration);
+                    (frame.symbols[&0].value, duration)
+                })
+                .collect()
+        };
+        let played_steps = play();
+        assert_eq!(played_steps, play());
+
+        // Each iteration plays every step once.
+        for iteration in played_steps.chunks(4) {
+            let mut values: Vec<char> =
+                iteration.iter().map(|(value, _)| *value).collect();
+            values.sort();
+            assert_eq!(values, ['a', 'b', 'c', 'd']);
+        }
+        assert!(played_steps.iter().all(|(_, duration)| {
+            (80..=120).contains(&duration.as_millis())
+        }));
+    }
+
+    #[test]
+    fn conditional_steps_and_branches() {
+        let step = |value| {
+            AnimationStepBuilder::default()
+                .with_duration(Duration::from_millis(100))
+                .for_target(AnimationTarget::Single(0))
+                .update_character(value)
+                .then()
+        };
+        let is_last_iteration =
+            || Callable::from_fn(|(_, iteration)| iteration == 1);
+        let branch = AnimationBranch::new(
+            is_last_iteration(),
+            vec![step('z').build().unwrap()],
+            vec![step('y').build().unwrap(), step('x').build().unwrap()],
+        );
+        let animation_style = AnimationStyleBuilder::default()
+            .with_repeat_mode(AnimationRepeatMode::Finite(2))
+            .with_steps(vec![
+                step('a').build().unwrap(),
+                step('b')
+                    .with_condition(is_last_iteration())
+                    .build()
+                    .unwrap(),
+                branch.into(),
+            ])
+            .build()
+            .unwrap();
+        let group = AnimationGroup::new();
+        let mut animation = Animation::new(
+            animation_style,
+            HashMap::from([(0, Symbol::default())]),
+        );
+        animation.set_clock(group.clock());
+
+        let mut values = String::new();
+        while let Some(frame) = animation.next_frame() {
+            values.push(frame.symbols[&0].value);
+            group.advance(Duration::from_millis(100));
+        }
+        assert_eq!(values, "ayxabz");
+    }
+
+    #[test]
+    fn word_targets() {
+        let step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_millis(100))
+            .for_target(AnimationTarget::NonWhitespace)
+            .update_foreground_color(Color::Red)
+            .then()
+            .for_target(AnimationTarget::Words)
+            .add_modifier(Modifier::BOLD)
+            .then()
+            .for_target(AnimationTarget::NthWord(1))
+            .update_character('_')
+            .then()
+            .build()
+            .unwrap();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_steps(vec![step])
+            .build()
+            .unwrap();
+        let symbols = "hi, yo  x"
+            .chars()
+            .enumerate()
+            .map(|(x, value)| {
+                let symbol = Symbol {
+                    value,
+                    ..Symbol::default()
+                };
+                (x as u16, symbol)
+            })
+            .collect();
+        let mut animation = Animation::new(animation_style, symbols);
+
+        let frame = animation.next_frame().unwrap();
+        let text: String = (0..9).map(|x| frame.symbols[&x].value).collect();
+        assert_eq!(text, "hi, __  x");
+
+        let is_red =
+            |x| frame.symbols[&x].foreground_color == Some(Color::Red);
+        let is_bold = |x| frame.symbols[&x].modifier == Modifier::BOLD;
+        assert!([0, 1, 2, 4, 5, 8].into_iter().all(is_red));
+        assert!(![3, 6, 7].into_iter().any(is_red));
+        assert!([0, 1, 4, 5, 8].into_iter().all(is_bold));
+        assert!(!is_bold(2));
+    }
+
+    #[test]
+    fn target_set_operations() {
+        let range = AnimationTarget::Range;
+        let step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_millis(100))
+            .for_target(range(0, 1).union(range(6, 7)))
+            .update_foreground_color(Color::Red)
+            .then()
+            .for_target(range(0, 5).intersection(range(3, 9)))
+            .update_background_color(Color::Blue)
+            .then()
+            .for_target(range(0, 9).difference(range(2, 7)))
+            .update_character('x')
+            .then()
+            .build()
+            .unwrap();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_steps(vec![step])
+            .build()
+            .unwrap();
+        let symbols = (0..10)
+            .map(|x| {
+                let symbol = Symbol {
+                    value: '-',
+                    ..Symbol::default()
+                };
+                (x, symbol)
+            })
+            .collect();
+        let mut animation = Animation::new(animation_style, symbols);
+
+        let frame = animation.next_frame().unwrap();
+        let selected = |is_selected: fn(&Symbol) -> bool| -> Vec<u16> {
+            let mut x_coords: Vec<u16> = frame
+                .symbols
+                .iter()
+                .filter(|(_, symbol)| is_selected(symbol))
+                .map(|(x, _)| *x)
+                .collect();
+            x_coords.sort();
+            x_coords
+        };
+        assert_eq!(
+            selected(|symbol| symbol.foreground_color == Some(Color::Red)),
+            [0, 1, 6, 7],
+        );
+        assert_eq!(
+            selected(|symbol| symbol.background_color == Some(Color::Blue)),
+            [3, 4, 5],
+        );
+        assert_eq!(selected(|symbol| symbol.value == 'x'), [0, 1, 8, 9]);
+    }
+
+    #[test]
+    fn relative_color_actions() {
+        let step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_millis(100))
+            .for_target(AnimationTarget::Single(0))
+            .do_action(AnimationAction::LightenForeground(0.25))
+            .do_action(AnimationAction::DarkenBackground(0.25))
+            .then()
+            .for_target(AnimationTarget::Single(1))
+            .do_action(AnimationAction::ShiftHue(120.0))
+            .then()
+            .for_target(AnimationTarget::Single(2))
+            .do_action(AnimationAction::InvertColors)
+            .then()
+            .build()
+            .unwrap();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_steps(vec![step])
+            .build()
+            .unwrap();
+        let symbol = Symbol {
+            value: 'a',
+            foreground_color: Some(Color::Rgb(255, 0, 0)),
+            background_color: Some(Color::Rgb(0, 0, 255)),
+            ..Symbol::default()
+        };
+        let symbols = HashMap::from([
+            (0, symbol),
+            (1, symbol),
+            (2, symbol),
+            (3, Symbol::default()),
+        ]);
+        let group = AnimationGroup::new();
+        let mut animation = Animation::new(animation_style, symbols);
+        animation.set_clock(group.clock());
+
+        let frame = animation.next_frame().unwrap();
+        let colors = |x| {
+            let symbol = frame.symbols[&x];
+            (symbol.foreground_color, symbol.background_color)
+        };
+        assert_eq!(
+            colors(0),
+            (Some(Color::Rgb(255, 128, 128)), Some(Color::Rgb(0, 0, 128))),
+        );
+        assert_eq!(
+            colors(1),
+            (Some(Color::Rgb(0, 255, 0)), Some(Color::Rgb(255, 0, 0))),
+        );
+        assert_eq!(
+            colors(2),
+            (Some(Color::Rgb(0, 255, 255)), Some(Color::Rgb(255, 255, 0))),
+        );
+        assert_eq!(colors(3), (None, None));
+
+        // Steps are applied once, however many frames they span.
+        assert_eq!(animation.next_frame().as_ref(), Some(&frame));
+    }
+
+    #[test]
+    fn style_actions_across_positions() {
+        let step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_millis(100))
+            .for_target(AnimationTarget::Range(1, 2))
+            .do_action(AnimationAction::CopyStyleFrom(0))
+            .then()
+            .for_target(AnimationTarget::Single(0))
+            .do_action(AnimationAction::SwapWith(3))
+            .then()
+            .build()
+            .unwrap();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_steps(vec![step])
+            .build()
+            .unwrap();
+        let symbol = |value, color| Symbol {
+            value,
+            foreground_color: Some(color),
+            ..Symbol::default()
+        };
+        let symbols = HashMap::from([
+            (0, symbol('a', Color::Red)),
+            (1, symbol('b', Color::Green)),
+            (2, symbol('c', Color::Green)),
+            (3, symbol('d', Color::Blue)),
+        ]);
+        let mut animation = Animation::new(animation_style, symbols);
+
+        // Ranges are applied after single targets, so the range
+        // copies the swapped style.
+        let frame = animation.next_frame().unwrap();
+        assert_eq!(
+            (0..4).map(|x| frame.symbols[&x]).collect::<Vec<_>>(),
+            [
+                symbol('a', Color::Blue),
+                symbol('b', Color::Blue),
+                symbol('c', Color::Blue),
+                symbol('d', Color::Red),
+            ],
+        );
+    }
+
+    #[test]
+    fn translated_symbols() {
+        let first_step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_millis(100))
+            .for_target(AnimationTarget::Single(0))
+            .do_action(AnimationAction::TranslateBy(2))
+            .then()
+            .build()
+            .unwrap();
+        let second_step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_millis(100))
+            .with_shift(1)
+            .with_translate_mode(AnimationTranslateMode::Wrap)
+            .build()
+            .unwrap();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_repeat_mode(AnimationRepeatMode::Finite(1))
+            .with_steps(vec![first_step, second_step])
+            .build()
+            .unwrap();
+        let symbols = "abcd"
+            .chars()
+            .enumerate()
+            .map(|(x, value)| {
+                let symbol = Symbol {
+                    value,
+                    ..Symbol::default()
+                };
+                (x as u16, symbol)
+            })
+            .collect();
+        let group = AnimationGroup::new();
+        let mut animation = Animation::new(animation_style, symbols);
+        animation.set_clock(group.clock());
+
+        let text = |frame: AnimationFrame| -> String {
+            (0..4).map(|x| frame.symbols[&x].value).collect()
+        };
+        assert_eq!(text(animation.next_frame().unwrap()), " bad");
+        group.advance(Duration::from_millis(100));
+        assert_eq!(text(animation.next_frame().unwrap()), "d ba");
+        assert_eq!(text(animation.next_frame().unwrap()), "d ba");
+    }
+
+    #[test]
+    fn spring_driven_step() {
+        let spring = AnimationSpring::new(0.0, 3.0)
+            .with_stiffness(200.0)
+            .with_damping(5.0);
+        let move_marker =
+            |(value, states): (f64, HashMap<u16, StepSymbolState>)| {
+                let x = (value.round() as u16).min(4);
+                let mut marker = states[&x].symbol();
+                marker.value = '#';
+                HashMap::from([(x, marker)])
+            };
+        let step = AnimationStepBuilder::default()
+            .with_spring(spring, Callable::from_fn(move_marker))
+            .build()
+            .unwrap();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_repeat_mode(AnimationRepeatMode::Finite(1))
+            .with_steps(vec![step])
+            .build()
+            .unwrap();
+        let symbols = (0..5)
+            .map(|x| {
+                let symbol = Symbol {
+                    value: '.',
+                    ..Symbol::default()
+                };
+                (x, symbol)
+            })
+            .collect();
+        let group = AnimationGroup::new();
+        let mut animation = Animation::new(animation_style, symbols);
+        animation.set_clock(group.clock());
+
+        let text = |frame: AnimationFrame| -> String {
+            (0..5).map(|x| frame.symbols[&x].value).collect()
+        };
+        let settle_duration = animation.total_duration();
+        assert_eq!(settle_duration, spring.settle_duration());
+        assert_eq!(text(animation.next_frame().unwrap()), "#....");
+
+        // The spring overshoots the target.
+        group.advance(Duration::from_millis(220));
+        assert_eq!(text(animation.next_frame().unwrap()), "....#");
+        assert_eq!(
+            animation.time_until_next_frame(),
+            Some(AnimationSpring::FRAME_INTERVAL),
+        );
+
+        let millisecond = Duration::from_millis(1);
+        group.advance(
+            settle_duration - Duration::from_millis(220) - millisecond,
+        );
+        assert_eq!(text(animation.next_frame().unwrap()), "...#.");
+        group.advance(millisecond);
+        assert_eq!(animation.next_frame(), None);
+    }
+}
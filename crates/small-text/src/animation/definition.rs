@@ -0,0 +1,192 @@
+use std::{
+    collections::BTreeMap,
+    time::Duration,
+};
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::{
+    AnimationDslError,
+    AnimationRepeatMode,
+    AnimationStep,
+    AnimationStepBuilder,
+    AnimationStyle,
+    AnimationStyleBuilder,
+    parse_action_text,
+    parse_target_text,
+};
+
+/// A list of animation steps that can be loaded from an asset
+/// file in any format supported by serde, e.g. TOML or JSON.
+///
+/// Targets and actions are written in the syntax accepted by
+/// [`parse_animation_style`](crate::parse_animation_style).
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_small_text::{
+///     AnimationDefinition,
+///     AnimationStepDefinition,
+///     AnimationStyle,
+/// };
+///
+/// let definition = AnimationDefinition {
+///     repeat: Some(1),
+///     steps: vec![AnimationStepDefinition {
+///         duration: 100,
+///         actions: [(
+///             "0..2".to_string(),
+///             vec!["fg=white".to_string(), "+BOLD".to_string()],
+///         )]
+///         .into(),
+///     }],
+///     ..AnimationDefinition::default()
+/// };
+///
+/// let animation_style: AnimationStyle =
+///     "repeat once step 100ms { 0..2: fg=white +BOLD }".parse().unwrap();
+/// assert_eq!(definition.to_style(), Ok(animation_style));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnimationDefinition {
+    /// Number of times the animation is played. The animation
+    /// repeats indefinitely if `None`.
+    pub repeat: Option<u16>,
+
+    /// Time to wait before displaying the first step in
+    /// milliseconds.
+    pub delay: u64,
+
+    /// Maximum number of frames generated per second.
+    pub max_fps: Option<u16>,
+
+    pub steps: Vec<AnimationStepDefinition>,
+}
+
+/// A single step of an [`AnimationDefinition`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnimationStepDefinition {
+    /// Duration of the step in milliseconds.
+    pub duration: u64,
+
+    /// A map of targets to the actions applied to them.
+    pub actions: BTreeMap<String, Vec<String>>,
+}
+
+impl AnimationDefinition {
+    pub fn to_style(&self) -> Result<AnimationStyle, AnimationDslError> {
+        let repeat_mode = match self.repeat {
+            Some(count) => AnimationRepeatMode::Finite(count),
+            None => AnimationRepeatMode::Infinite,
+        };
+        let steps = self
+            .steps
+            .iter()
+            .map(AnimationStepDefinition::to_step)
+            .collect::<Result<Vec<AnimationStep>, _>>()?;
+
+        let mut style_builder = AnimationStyleBuilder::default();
+        style_builder
+            .with_repeat_mode(repeat_mode)
+            .with_start_delay(Duration::from_millis(self.delay))
+            .with_steps(steps);
+        if let Some(max_fps) = self.max_fps {
+            style_builder.with_max_fps(max_fps);
+        }
+
+        Ok(style_builder.build()?)
+    }
+}
+
+#[cfg(feature = "json")]
+impl AnimationDefinition {
+    /// Parses a definition from JSON, e.g.
+    /// `{"steps": [{"duration": 100, "actions": {"0": ["+BOLD"]}}]}`.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+impl AnimationStepDefinition {
+    pub fn to_step(&self) -> Result<AnimationStep, AnimationDslError> {
+        let mut step_builder = AnimationStepBuilder::default()
+            .with_duration(Duration::from_millis(self.duration));
+
+        for (target, actions) in self.actions.iter() {
+            let mut accumulator =
+                step_builder.for_target(parse_target_text(target)?);
+            for action in actions {
+                accumulator =
+                    accumulator.do_action(parse_action_text(action)?);
+            }
+            step_builder = accumulator.then();
+        }
+
+        Ok(step_builder.build()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AnimationDefinition;
+    use crate::{
+        AnimationDslError,
+        AnimationStyle,
+    };
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn animation_definition_from_json() {
+        let json = r#"{
+            "delay": 50,
+            "steps": [
+                {
+                    "duration": 100,
+                    "actions": {
+                        "0..2": ["fg=white", "bg=green", "+BOLD"]
+                    }
+                },
+                {
+                    "duration": 100,
+                    "actions": {
+                        "every(2)": ["fg=gray"]
+                    }
+                }
+            ]
+        }"#;
+        let definition = AnimationDefinition::from_json(json).unwrap();
+
+        let animation_style: AnimationStyle = "
+            delay 50ms
+            step 100ms { 0..2: fg=white bg=green +BOLD }
+            step 100ms { every(2): fg=gray }
+        "
+        .parse()
+        .unwrap();
+        assert_eq!(definition.to_style(), Ok(animation_style));
+    }
+
+    #[test]
+    fn invalid_animation_definition() {
+        let mut definition = AnimationDefinition::default();
+        definition.steps.push(Default::default());
+        definition.steps[0].duration = 100;
+        definition.steps[0]
+            .actions
+            .insert("0".to_string(), vec!["+SHINY".to_string()]);
+
+        assert_eq!(
+            definition.to_style(),
+            Err(AnimationDslError::InvalidModifier {
+                position: 0,
+                value: "SHINY".to_string(),
+            }),
+        );
+    }
+}
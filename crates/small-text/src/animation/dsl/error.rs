@@ -0,0 +1,116 @@
+use std::{
+    error::Error,
+    fmt,
+};
+
+use crate::AnimationStyleError;
+
+/// An error returned when an animation definition written in
+/// the DSL accepted by [`parse_animation_style`] can't be
+/// parsed. Positions are byte offsets in the source.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AnimationDslError {
+    /// A token doesn't fit the grammar. Includes the position
+    /// and the text of the token along with a description of
+    /// what was expected instead.
+    UnexpectedToken {
+        position: usize,
+        token: String,
+        expected: &'static str,
+    },
+
+    /// The source ended while more tokens were expected.
+    UnexpectedEnd { expected: &'static str },
+
+    /// A duration is not a non-negative number followed by
+    /// `ms` or `s`.
+    InvalidDuration { position: usize, value: String },
+
+    /// A number is not a valid count, symbol position or
+    /// amount.
+    InvalidNumber { position: usize, value: String },
+
+    /// A target selects no positions or is not recognized.
+    InvalidTarget { position: usize, value: String },
+
+    /// An action is not recognized.
+    InvalidAction { position: usize, value: String },
+
+    /// A color can't be parsed by ratatui.
+    InvalidColor { position: usize, value: String },
+
+    /// A modifier is not one of the ratatui modifier names.
+    InvalidModifier { position: usize, value: String },
+
+    /// The definition is parsed, but the resulting animation
+    /// style is misconfigured.
+    InvalidStyle(AnimationStyleError),
+}
+
+impl fmt::Display for AnimationDslError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedToken {
+                position,
+                token,
+                expected,
+            } => write!(
+                formatter,
+                "expected {}, found `{}` at position {}",
+                expected, token, position,
+            ),
+            Self::UnexpectedEnd { expected } => {
+                write!(formatter, "expected {}, found end of input", expected)
+            }
+            Self::InvalidDuration { position, value } => write!(
+                formatter,
+                "invalid duration `{}` at position {}",
+                value, position,
+            ),
+            Self::InvalidNumber { position, value } => write!(
+                formatter,
+                "invalid number `{}` at position {}",
+                value, position,
+            ),
+            Self::InvalidTarget { position, value } => write!(
+                formatter,
+                "invalid target `{}` at position {}",
+                value, position,
+            ),
+            Self::InvalidAction { position, value } => write!(
+                formatter,
+                "invalid action `{}` at position {}",
+                value, position,
+            ),
+            Self::InvalidColor { position, value } => write!(
+                formatter,
+                "invalid color `{}` at position {}",
+                value, position,
+            ),
+            Self::InvalidModifier { position, value } => write!(
+                formatter,
+                "invalid modifier `{}` at position {}",
+                value, position,
+            ),
+            Self::InvalidStyle(error) => {
+                write!(formatter, "invalid animation style: {}", error)
+            }
+        }
+    }
+}
+
+impl Error for AnimationDslError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidStyle(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<AnimationStyleError> for AnimationDslError {
+    fn from(value: AnimationStyleError) -> Self {
+        Self::InvalidStyle(value)
+    }
+}
@@ -0,0 +1,5 @@
+mod error;
+mod parser;
+
+pub use error::*;
+pub use parser::*;
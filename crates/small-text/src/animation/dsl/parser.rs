@@ -0,0 +1,775 @@
+use std::{
+    str::FromStr,
+    time::Duration,
+};
+
+use ratatui::style::{
+    Color,
+    Modifier,
+};
+
+use super::AnimationDslError;
+use crate::{
+    AnimationAction,
+    AnimationAdvanceMode,
+    AnimationRepeatMode,
+    AnimationRestoreMode,
+    AnimationStep,
+    AnimationStepBuilder,
+    AnimationStyle,
+    AnimationStyleBuilder,
+    AnimationTarget,
+};
+
+/// Builds an [`AnimationStyle`] from a compact text
+/// definition, so animations can be stored in configuration
+/// files or tweaked at runtime.
+///
+/// A definition is a sequence of statements:
+///
+/// * `step <duration> { <target>: <action>... }` - adds a step
+///   lasting provided duration (`100ms`, `1.5s`). Clauses of a
+///   step may be separated with `;`.
+/// * `repeat infinite`, `repeat once`, `repeat <count>`
+/// * `advance auto`, `advance manual`
+/// * `restore keep`, `restore initial`, `restore static`
+/// * `delay <duration>` - delay before the first step.
+/// * `max_fps <count>` - maximum number of frames per second.
+///
+/// Targets are `<x>`, `<start>..<end>` (exclusive),
+/// `<start>..=<end>` (inclusive), `every(<n>)`,
+/// `every(<n>, <from>)`, `except_every(<n>)`,
+/// `except_every(<n>, <from>)`, `non_whitespace`, `words`,
+/// `word(<n>)`, `untouched` and `untouched_this_step`.
+///
+/// Actions are `fg=<color>`, `bg=<color>`, `char=<symbol>`
+/// (quote the symbol with `'` if it is a space or one of
+/// `{};:`), `+<MODIFIER>`, `-<MODIFIER>`, `-ALL`,
+/// `lighten=<amount>`, `darken_bg=<amount>`, `hue=<degrees>`,
+/// `invert`, `copy_from=<x>`, `swap=<x>` and `move=<offset>`. Colors accept any format supported by
+/// ratatui, modifiers are ratatui modifier names and may be
+/// combined with `|`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::style::{Color, Modifier};
+/// use caponata_small_text::{
+///     AnimationStepBuilder,
+///     AnimationStyleBuilder,
+///     AnimationTarget,
+///     parse_animation_style,
+/// };
+///
+/// let animation_style = parse_animation_style(
+///     "step 100ms { 0..2: fg=white bg=green +BOLD } \
+///      step 100ms { every(2): fg=gray }",
+/// )
+/// .unwrap();
+///
+/// let first_step = AnimationStepBuilder::default()
+///     .with_duration(Duration::from_millis(100))
+///     .for_target(AnimationTarget::Range(0, 1))
+///     .update_foreground_color(Color::White)
+///     .update_background_color(Color::Green)
+///     .add_modifier(Modifier::BOLD)
+///     .then()
+///     .build()
+///     .unwrap();
+/// let second_step = AnimationStepBuilder::default()
+///     .with_duration(Duration::from_millis(100))
+///     .for_target(AnimationTarget::Every(2))
+///     .update_foreground_color(Color::Gray)
+///     .then()
+///     .build()
+///     .unwrap();
+/// let expected_style = AnimationStyleBuilder::default()
+///     .with_steps(vec![first_step, second_step])
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(animation_style, expected_style);
+/// ```
+pub fn parse_animation_style(
+    source: &str,
+) -> Result<AnimationStyle, AnimationDslError> {
+    Parser::new(source).parse()
+}
+
+impl FromStr for AnimationStyle {
+    type Err = AnimationDslError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_animation_style(s)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Word,
+    OpenBrace,
+    CloseBrace,
+    Colon,
+    Semicolon,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+
+    /// Byte offset of the token in the source.
+    position: usize,
+}
+
+impl<'a> Token<'a> {
+    fn unexpected(&self, expected: &'static str) -> AnimationDslError {
+        AnimationDslError::UnexpectedToken {
+            position: self.position,
+            token: self.text.to_string(),
+            expected,
+        }
+    }
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    index: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            tokens: tokenize(source),
+            index: 0,
+        }
+    }
+
+    fn parse(mut self) -> Result<AnimationStyle, AnimationDslError> {
+        let mut style_builder = AnimationStyleBuilder::default();
+        let mut steps = Vec::new();
+
+        while let Some(token) = self.next() {
+            if token.kind != TokenKind::Word {
+                return Err(token.unexpected(STATEMENT));
+            }
+            match token.text {
+                "step" => steps.push(self.parse_step()?),
+                "repeat" => {
+                    let token = self.expect_word("repeat mode")?;
+                    let repeat_mode = match token.text {
+                        "infinite" => AnimationRepeatMode::Infinite,
+                        "once" => AnimationRepeatMode::Finite(1),
+                        _ => AnimationRepeatMode::Finite(parse_number(
+                            token.text,
+                            token.position,
+                        )?),
+                    };
+                    style_builder.with_repeat_mode(repeat_mode);
+                }
+                "advance" => {
+                    let token = self.expect_word("advance mode")?;
+                    let advance_mode = match token.text {
+                        "auto" => AnimationAdvanceMode::Auto,
+                        "manual" => AnimationAdvanceMode::Manual,
+                        _ => {
+                            return Err(token.unexpected("`auto` or `manual`"));
+                        }
+                    };
+                    style_builder.with_advance_mode(advance_mode);
+                }
+                "restore" => {
+                    let token = self.expect_word("restore mode")?;
+                    let restore_mode = match token.text {
+                        "keep" => AnimationRestoreMode::KeepLastFrame,
+                        "initial" => AnimationRestoreMode::RestoreInitial,
+                        "static" => AnimationRestoreMode::RestoreStatic,
+                        _ => {
+                            return Err(token
+                                .unexpected("`keep`, `initial` or `static`"));
+                        }
+                    };
+                    style_builder.with_restore_mode(restore_mode);
+                }
+                "delay" => {
+                    let token = self.expect_word("duration")?;
+                    style_builder.with_start_delay(parse_duration(&token)?);
+                }
+                "max_fps" => {
+                    let token = self.expect_word("frame rate")?;
+                    style_builder.with_max_fps(parse_number(
+                        token.text,
+                        token.position,
+                    )?);
+                }
+                _ => return Err(token.unexpected(STATEMENT)),
+            }
+        }
+
+        Ok(style_builder.with_steps(steps).build()?)
+    }
+
+    fn parse_step(&mut self) -> Result<AnimationStep, AnimationDslError> {
+        let token = self.expect_word("duration")?;
+        let mut step_builder = AnimationStepBuilder::default()
+            .with_duration(parse_duration(&token)?);
+        self.expect(TokenKind::OpenBrace, "`{`")?;
+
+        loop {
+            let token =
+                self.next().ok_or(AnimationDslError::UnexpectedEnd {
+                    expected: "target or `}`",
+                })?;
+            match token.kind {
+                TokenKind::CloseBrace => break,
+                TokenKind::Semicolon => continue,
+                TokenKind::Word => {
+                    let target = parse_target(&token)?;
+                    self.expect(TokenKind::Colon, "`:`")?;
+
+                    let mut accumulator = step_builder.for_target(target);
+                    let mut action_count = 0;
+                    while let Some(token) = self.peek()
+                        && token.kind == TokenKind::Word
+                        && !self.is_clause_ahead()
+                    {
+                        self.index += 1;
+                        accumulator =
+                            accumulator.do_action(parse_action(&token)?);
+                        action_count += 1;
+                    }
+                    if action_count == 0 {
+                        return Err(match self.peek() {
+                            Some(token) => token.unexpected("action"),
+                            None => AnimationDslError::UnexpectedEnd {
+                                expected: "action",
+                            },
+                        });
+                    }
+                    step_builder = accumulator.then();
+                }
+                _ => return Err(token.unexpected("target or `}`")),
+            }
+        }
+
+        Ok(step_builder.build()?)
+    }
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.peek()?;
+        self.index += 1;
+        Some(token)
+    }
+
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.index).copied()
+    }
+
+    /// Returns boolean flag indicating whether the next word
+    /// starts a new clause, i.e. is a target followed by `:`.
+    fn is_clause_ahead(&self) -> bool {
+        self.tokens
+            .get(self.index + 1)
+            .is_some_and(|token| token.kind == TokenKind::Colon)
+    }
+
+    fn expect(
+        &mut self,
+        kind: TokenKind,
+        expected: &'static str,
+    ) -> Result<Token<'a>, AnimationDslError> {
+        match self.next() {
+            Some(token) if token.kind == kind => Ok(token),
+            Some(token) => Err(token.unexpected(expected)),
+            None => Err(AnimationDslError::UnexpectedEnd { expected }),
+        }
+    }
+
+    fn expect_word(
+        &mut self,
+        expected: &'static str,
+    ) -> Result<Token<'a>, AnimationDslError> {
+        self.expect(TokenKind::Word, expected)
+    }
+}
+
+const STATEMENT: &str =
+    "`step`, `repeat`, `advance`, `restore`, `delay` or `max_fps`";
+
+/// Splits provided source into tokens. Words end at
+/// whitespace or punctuation, except inside parentheses and
+/// single quotes.
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+
+    while let Some((position, c)) = chars.next() {
+        let kind = match c {
+            '{' => TokenKind::OpenBrace,
+            '}' => TokenKind::CloseBrace,
+            ':' => TokenKind::Colon,
+            ';' => TokenKind::Semicolon,
+            c if c.is_whitespace() => continue,
+            _ => TokenKind::Word,
+        };
+        if kind != TokenKind::Word {
+            let text = &source[position..position + c.len_utf8()];
+            tokens.push(Token {
+                kind,
+                text,
+                position,
+            });
+            continue;
+        }
+
+        let mut paren_depth = usize::from(c == '(');
+        let mut is_quoted = c == '\'';
+        let mut end = position + c.len_utf8();
+        while let Some(&(next_position, next)) = chars.peek() {
+            let is_boundary =
+                next.is_whitespace() || matches!(next, '{' | '}' | ':' | ';');
+            if is_boundary && paren_depth == 0 && !is_quoted {
+                break;
+            }
+            match next {
+                '\'' => is_quoted = !is_quoted,
+                '(' if !is_quoted => paren_depth += 1,
+                ')' if !is_quoted => {
+                    paren_depth = paren_depth.saturating_sub(1)
+                }
+                _ => {}
+            }
+            end = next_position + next.len_utf8();
+            chars.next();
+        }
+
+        tokens.push(Token {
+            kind,
+            text: &source[position..end],
+            position,
+        });
+    }
+
+    tokens
+}
+
+fn parse_number(
+    text: &str,
+    position: usize,
+) -> Result<u16, AnimationDslError> {
+    text.trim()
+        .parse()
+        .map_err(|_| AnimationDslError::InvalidNumber {
+            position,
+            value: text.to_string(),
+        })
+}
+
+fn parse_float(text: &str, position: usize) -> Result<f32, AnimationDslError> {
+    text.parse::<f32>()
+        .ok()
+        .filter(|value| value.is_finite())
+        .ok_or_else(|| AnimationDslError::InvalidNumber {
+            position,
+            value: text.to_string(),
+        })
+}
+
+fn parse_duration(token: &Token) -> Result<Duration, AnimationDslError> {
+    let (number, scale) = match token.text.strip_suffix("ms") {
+        Some(number) => (number, 0.001),
+        None => match token.text.strip_suffix('s') {
+            Some(number) => (number, 1.0),
+            None => ("", 0.0),
+        },
+    };
+
+    number
+        .parse::<f64>()
+        .ok()
+        .and_then(|number| Duration::try_from_secs_f64(number * scale).ok())
+        .ok_or_else(|| AnimationDslError::InvalidDuration {
+            position: token.position,
+            value: token.text.to_string(),
+        })
+}
+
+#[cfg(feature = "serde")]
+/// Parses a single target written in the syntax accepted by
+/// [`parse_animation_style`].
+pub(crate) fn parse_target_text(
+    text: &str,
+) -> Result<AnimationTarget, AnimationDslError> {
+    parse_target(&Token {
+        kind: TokenKind::Word,
+        text: text.trim(),
+        position: 0,
+    })
+}
+
+#[cfg(feature = "serde")]
+/// Parses a single action written in the syntax accepted by
+/// [`parse_animation_style`].
+pub(crate) fn parse_action_text(
+    text: &str,
+) -> Result<AnimationAction, AnimationDslError> {
+    parse_action(&Token {
+        kind: TokenKind::Word,
+        text: text.trim(),
+        position: 0,
+    })
+}
+
+fn parse_target(token: &Token) -> Result<AnimationTarget, AnimationDslError> {
+    let invalid_target = || AnimationDslError::InvalidTarget {
+        position: token.position,
+        value: token.text.to_string(),
+    };
+    let number = |text: &str| parse_number(text, token.position);
+
+    match token.text {
+        "non_whitespace" => return Ok(AnimationTarget::NonWhitespace),
+        "words" => return Ok(AnimationTarget::Words),
+        "untouched" => return Ok(AnimationTarget::Untouched),
+        "untouched_this_step" => {
+            return Ok(AnimationTarget::UntouchedThisStep);
+        }
+        _ => {}
+    }
+
+    let function_call = token
+        .text
+        .strip_suffix(')')
+        .and_then(|text| text.split_once('('));
+    if let Some((name, arguments)) = function_call {
+        let arguments = arguments
+            .split(',')
+            .map(number)
+            .collect::<Result<Vec<u16>, _>>()?;
+
+        return match (name, arguments.as_slice()) {
+            ("word", &[n]) => Ok(AnimationTarget::NthWord(n)),
+            (_, [0, ..]) => Err(invalid_target()),
+            ("every", &[n]) => Ok(AnimationTarget::Every(n)),
+            ("every", &[n, from]) => Ok(AnimationTarget::EveryFrom(n, from)),
+            ("except_every", &[n]) => Ok(AnimationTarget::ExceptEvery(n)),
+            ("except_every", &[n, from]) => {
+                Ok(AnimationTarget::ExceptEveryFrom(n, from))
+            }
+            _ => Err(invalid_target()),
+        };
+    }
+
+    if let Some((start, end)) = token.text.split_once("..") {
+        let start = number(start)?;
+        let end = match end.strip_prefix('=') {
+            Some(end) => number(end)?,
+            None => number(end)?.checked_sub(1).ok_or_else(invalid_target)?,
+        };
+        if start > end {
+            return Err(invalid_target());
+        }
+
+        return Ok(AnimationTarget::Range(start, end));
+    }
+
+    Ok(AnimationTarget::Single(number(token.text)?))
+}
+
+fn parse_action(token: &Token) -> Result<AnimationAction, AnimationDslError> {
+    let text = token.text;
+    let invalid_action = || AnimationDslError::InvalidAction {
+        position: token.position,
+        value: text.to_string(),
+    };
+
+    if let Some(color) = text.strip_prefix("fg=") {
+        return Ok(AnimationAction::UpdateForegroundColor(parse_color(
+            color,
+            token.position,
+        )?));
+    }
+    if let Some(color) = text.strip_prefix("bg=") {
+        return Ok(AnimationAction::UpdateBackgroundColor(parse_color(
+            color,
+            token.position,
+        )?));
+    }
+    if let Some(amount) = text.strip_prefix("lighten=") {
+        return Ok(AnimationAction::LightenForeground(parse_float(
+            amount,
+            token.position,
+        )?));
+    }
+    if let Some(amount) = text.strip_prefix("darken_bg=") {
+        return Ok(AnimationAction::DarkenBackground(parse_float(
+            amount,
+            token.position,
+        )?));
+    }
+    if let Some(degrees) = text.strip_prefix("hue=") {
+        return Ok(AnimationAction::ShiftHue(parse_float(
+            degrees,
+            token.position,
+        )?));
+    }
+    if text == "invert" {
+        return Ok(AnimationAction::InvertColors);
+    }
+    if let Some(x) = text.strip_prefix("copy_from=") {
+        return Ok(AnimationAction::CopyStyleFrom(parse_number(
+            x,
+            token.position,
+        )?));
+    }
+    if let Some(offset) = text.strip_prefix("move=") {
+        let offset =
+            offset
+                .parse()
+                .map_err(|_| AnimationDslError::InvalidNumber {
+                    position: token.position,
+                    value: offset.to_string(),
+                })?;
+        return Ok(AnimationAction::TranslateBy(offset));
+    }
+    if let Some(x) = text.strip_prefix("swap=") {
+        return Ok(AnimationAction::SwapWith(parse_number(
+            x,
+            token.position,
+        )?));
+    }
+    if let Some(symbol) = text.strip_prefix("char=") {
+        let symbol = match symbol.strip_prefix('\'') {
+            Some(quoted) => {
+                quoted.strip_suffix('\'').ok_or_else(invalid_action)?
+            }
+            None => symbol,
+        };
+        let mut chars = symbol.chars();
+
+        return match (chars.next(), chars.next()) {
+            (Some(character), None) => {
+                Ok(AnimationAction::UpdateCharacter(character))
+            }
+            _ => Err(invalid_action()),
+        };
+    }
+    if let Some(modifier) = text.strip_prefix('+') {
+        return Ok(AnimationAction::AddModifier(parse_modifier(
+            modifier,
+            token.position,
+        )?));
+    }
+    if let Some(modifier) = text.strip_prefix('-') {
+        if modifier.eq_ignore_ascii_case("all") {
+            return Ok(AnimationAction::RemoveAllModifiers);
+        }
+        return Ok(AnimationAction::RemoveModifier(parse_modifier(
+            modifier,
+            token.position,
+        )?));
+    }
+
+    Err(invalid_action())
+}
+
+fn parse_color(
+    text: &str,
+    position: usize,
+) -> Result<Color, AnimationDslError> {
+    Color::from_str(text).map_err(|_| AnimationDslError::InvalidColor {
+        position,
+        value: text.to_string(),
+    })
+}
+
+fn parse_modifier(
+    text: &str,
+    position: usize,
+) -> Result<Modifier, AnimationDslError> {
+    text.split('|')
+        .try_fold(Modifier::empty(), |modifier, name| {
+            Modifier::from_name(&name.trim().to_ascii_uppercase())
+                .map(|named_modifier| modifier | named_modifier)
+                .ok_or_else(|| AnimationDslError::InvalidModifier {
+                    position,
+                    value: text.to_string(),
+                })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use ratatui::style::{
+        Color,
+        Modifier,
+    };
+
+    use super::parse_animation_style;
+    use crate::{
+        AnimationAction,
+        AnimationAdvanceMode,
+        AnimationDslError,
+        AnimationRepeatMode,
+        AnimationRestoreMode,
+        AnimationStepBuilder,
+        AnimationStyle,
+        AnimationStyleBuilder,
+        AnimationStyleError,
+        AnimationTarget,
+    };
+
+    #[test]
+    fn parse_statements() {
+        let animation_style: AnimationStyle = "
+            repeat 3
+            advance manual
+            restore static
+            delay 1.5s
+            max_fps 30
+            step 250ms {
+                0..=2: char=' ' fg=#ff0000 -ALL;
+                except_every(2, 1): +bold|italic -underlined
+                untouched_this_step: bg=dark-gray
+            }
+        "
+        .parse()
+        .unwrap();
+
+        let step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_millis(250))
+            .for_target(AnimationTarget::Range(0, 2))
+            .update_character(' ')
+            .update_foreground_color(Color::Rgb(255, 0, 0))
+            .remove_all_modifiers()
+            .then()
+            .for_target(AnimationTarget::ExceptEveryFrom(2, 1))
+            .add_modifier(Modifier::BOLD | Modifier::ITALIC)
+            .remove_modifier(Modifier::UNDERLINED)
+            .then()
+            .for_target(AnimationTarget::UntouchedThisStep)
+            .update_background_color(Color::DarkGray)
+            .then()
+            .build()
+            .unwrap();
+        let expected_style = AnimationStyleBuilder::default()
+            .with_repeat_mode(AnimationRepeatMode::Finite(3))
+            .with_advance_mode(AnimationAdvanceMode::Manual)
+            .with_restore_mode(AnimationRestoreMode::RestoreStatic)
+            .with_start_delay(Duration::from_millis(1500))
+            .with_max_fps(30u16)
+            .with_steps(vec![step])
+            .build()
+            .unwrap();
+
+        assert_eq!(animation_style, expected_style);
+    }
+
+    #[test]
+    fn parse_word_targets() {
+        let animation_style: AnimationStyle =
+            "step 1s { non_whitespace: fg=red; words: +bold; word(0): -ALL }"
+                .parse()
+                .unwrap();
+
+        let step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_secs(1))
+            .for_target(AnimationTarget::NonWhitespace)
+            .update_foreground_color(Color::Red)
+            .then()
+            .for_target(AnimationTarget::Words)
+            .add_modifier(Modifier::BOLD)
+            .then()
+            .for_target(AnimationTarget::NthWord(0))
+            .remove_all_modifiers()
+            .then()
+            .build()
+            .unwrap();
+        assert_eq!(animation_style.steps, vec![step]);
+    }
+
+    #[test]
+    fn parse_relative_color_actions() {
+        let animation_style: AnimationStyle =
+            "step 1s { 0: lighten=0.2 darken_bg=-0.1 hue=90 invert swap=2 move=-3 }"
+                .parse()
+                .unwrap();
+
+        let step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_secs(1))
+            .for_target(AnimationTarget::Single(0))
+            .do_action(AnimationAction::LightenForeground(0.2))
+            .do_action(AnimationAction::DarkenBackground(-0.1))
+            .do_action(AnimationAction::ShiftHue(90.0))
+            .do_action(AnimationAction::InvertColors)
+            .do_action(AnimationAction::SwapWith(2))
+            .do_action(AnimationAction::TranslateBy(-3))
+            .then()
+            .build()
+            .unwrap();
+        assert_eq!(animation_style.steps, vec![step]);
+        assert_eq!(
+            parse_animation_style("step 1s { 0: hue=NaN }"),
+            Err(AnimationDslError::InvalidNumber {
+                position: 13,
+                value: "NaN".to_string(),
+            }),
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_animation_style("step 100ms { 0: fg=blue"),
+            Err(AnimationDslError::UnexpectedEnd {
+                expected: "target or `}`"
+            }),
+        );
+        assert_eq!(
+            parse_animation_style("step 100 { 0: fg=blue }"),
+            Err(AnimationDslError::InvalidDuration {
+                position: 5,
+                value: "100".to_string(),
+            }),
+        );
+        assert_eq!(
+            parse_animation_style("step 1s { 2..2: fg=blue }"),
+            Err(AnimationDslError::InvalidTarget {
+                position: 10,
+                value: "2..2".to_string(),
+            }),
+        );
+        assert_eq!(
+            parse_animation_style("step 1s { 0: fg=blurple }"),
+            Err(AnimationDslError::InvalidColor {
+                position: 13,
+                value: "blurple".to_string(),
+            }),
+        );
+        assert_eq!(
+            parse_animation_style("step 1s { 0: +SHINY }"),
+            Err(AnimationDslError::InvalidModifier {
+                position: 13,
+                value: "SHINY".to_string(),
+            }),
+        );
+        assert_eq!(
+            parse_animation_style("step 1s { 0: }"),
+            Err(AnimationDslError::UnexpectedToken {
+                position: 13,
+                token: "}".to_string(),
+                expected: "action",
+            }),
+        );
+        assert_eq!(
+            parse_animation_style("advance manual"),
+            Err(AnimationDslError::InvalidStyle(
+                AnimationStyleError::EmptySteps
+            )),
+        );
+    }
+}
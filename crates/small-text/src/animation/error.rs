@@ -0,0 +1,28 @@
+use std::{
+    error::Error,
+    fmt,
+};
+
+/// An error returned when [`AnimationTarget::Single`] or
+/// [`AnimationTarget::Range`] targets of the animation select
+/// positions that don't exist in the text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TargetError {
+    /// Indexes of the steps along with the invalid virtual x
+    /// coordinates selected by their targets, ordered by step
+    /// index and coordinate.
+    pub coordinates: Vec<(usize, u16)>,
+}
+
+impl fmt::Display for TargetError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "targets select positions outside of the text:")?;
+        for (step, x) in self.coordinates.iter() {
+            write!(formatter, " {} (step {})", x, step)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for TargetError {}
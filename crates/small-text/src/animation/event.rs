@@ -1,5 +1,25 @@
+use caponata_common::WidgetEvent;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AnimationEvent {
     FrameGenerated,
     Ended,
+
+    /// The current step has targets selecting positions that
+    /// don't exist in the text. Actions for these positions
+    /// are skipped. Replaces [`AnimationEvent::FrameGenerated`]
+    /// for the frame.
+    TargetOutOfBounds,
+}
+
+impl From<AnimationEvent> for WidgetEvent {
+    fn from(value: AnimationEvent) -> Self {
+        match value {
+            AnimationEvent::FrameGenerated
+            | AnimationEvent::TargetOutOfBounds => {
+                WidgetEvent::AnimationFrameGenerated
+            }
+            AnimationEvent::Ended => WidgetEvent::AnimationEnded,
+        }
+    }
 }
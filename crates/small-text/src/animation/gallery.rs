@@ -0,0 +1,476 @@
+#[cfg(feature = "crossterm")]
+use caponata_common::HandlesEvent;
+use caponata_common::{
+    AccessibleDescription,
+    Identifiable,
+    SharedClock,
+    WidgetIdentity,
+    describe_widget,
+};
+#[cfg(feature = "crossterm")]
+use crossterm::event::{
+    Event,
+    KeyCode,
+    KeyEventKind,
+    MouseButton,
+    MouseEventKind,
+};
+use derive_builder::Builder;
+#[cfg(feature = "crossterm")]
+use ratatui::layout::Position;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{
+        Modifier,
+        Style,
+    },
+    text::Line,
+    widgets::Widget,
+};
+
+use crate::{
+    AnimatedSmallTextWidget,
+    AnimationPresetRegistry,
+    SmallTextStyle,
+    TextContext,
+};
+
+/// A styling configuration for [`AnimationGalleryWidget`].
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_small_text::AnimationGalleryStyleBuilder;
+/// use ratatui::style::{
+///     Color,
+///     Style,
+/// };
+///
+/// let gallery_style = AnimationGalleryStyleBuilder::default()
+///     .with_label_style(Style::default().fg(Color::Gray))
+///     .with_selected_label_style(Style::default().fg(Color::Yellow))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Builder)]
+#[builder(setter(prefix = "with", into))]
+pub struct AnimationGalleryStyle {
+    #[builder(default)]
+    label_style: Style,
+
+    #[builder(default = "Style::default().add_modifier(Modifier::REVERSED)")]
+    selected_label_style: Style,
+}
+
+impl Default for AnimationGalleryStyle {
+    fn default() -> Self {
+        AnimationGalleryStyleBuilder::default()
+            .build()
+            .expect("all fields have defaults")
+    }
+}
+
+/// An event produced by [`AnimationGalleryWidget`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AnimationGalleryEvent {
+    /// Another preset was selected, e.g. with arrow keys.
+    SelectionChanged(String),
+
+    /// The selected preset was chosen with `Enter` or a click.
+    Chosen(String),
+}
+
+/// A preset of the gallery along with the text playing it.
+#[derive(Debug, Clone)]
+struct GalleryEntry {
+    name: String,
+    text: AnimatedSmallTextWidget<()>,
+}
+
+/// A widget listing the presets of an
+/// [`AnimationPresetRegistry`], one per row, each labeled with
+/// its name and played on the same text, so users can pick
+/// one, e.g. as the style of notifications.
+///
+/// The selection is moved with the up and down arrow keys or
+/// the mouse wheel, and the selected preset is chosen with
+/// `Enter` or a click on its row.
+///
+/// Presets whose style is invalid for the text are left out.
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_small_text::{
+///     AnimationGalleryStyle,
+///     AnimationGalleryWidget,
+///     AnimationPresetRegistry,
+///     SmallTextStyleBuilder,
+/// };
+///
+/// let text_style =
+///     SmallTextStyleBuilder::default().with_text("New message").build();
+/// let mut gallery = AnimationGalleryWidget::new(
+///     &AnimationPresetRegistry::builtin(),
+///     &text_style,
+///     AnimationGalleryStyle::default(),
+/// );
+/// gallery.select("wave");
+/// assert_eq!(gallery.selected(), Some("wave"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnimationGalleryWidget {
+    entries: Vec<GalleryEntry>,
+    style: AnimationGalleryStyle,
+    selected_index: usize,
+
+    /// Index of the first displayed entry, so the selected one
+    /// stays visible in areas shorter than the gallery.
+    scroll_offset: usize,
+    identity: WidgetIdentity,
+}
+
+impl AnimationGalleryWidget {
+    pub fn new(
+        registry: &AnimationPresetRegistry,
+        text_style: &SmallTextStyle,
+        style: AnimationGalleryStyle,
+    ) -> Self {
+        let context = TextContext::new(text_style);
+        let entries = registry
+            .presets()
+            .filter_map(|preset| {
+                let animation_style = preset.into_style(&context).ok()?;
+                let mut text = AnimatedSmallTextWidget::new(
+                    text_style.clone(),
+                    [((), animation_style)].into(),
+                );
+                text.enable_animation(&());
+
+                Some(GalleryEntry {
+                    name: preset.name().to_string(),
+                    text,
+                })
+            })
+            .collect();
+
+        Self {
+            entries,
+            style,
+            selected_index: 0,
+            scroll_offset: 0,
+            identity: WidgetIdentity::default(),
+        }
+    }
+
+    /// Returns the name of the selected preset, or `None` if
+    /// the gallery is empty.
+    pub fn selected(&self) -> Option<&str> {
+        self.entries
+            .get(self.selected_index)
+            .map(|entry| entry.name.as_str())
+    }
+
+    /// Selects the preset with provided name. Returns `false`
+    /// if there is no such preset.
+    pub fn select(&mut self, name: &str) -> bool {
+        match self.entries.iter().position(|entry| entry.name == name) {
+            Some(index) => {
+                self.selected_index = index;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected_index + 1 < self.entries.len() {
+            self.selected_index += 1;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected_index = self.selected_index.saturating_sub(1);
+    }
+
+    /// Replaces the clock used by the animations of all
+    /// presets, like [`AnimatedSmallTextWidget::set_clock`].
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        for entry in self.entries.iter_mut() {
+            entry.text.set_clock(clock.clone());
+        }
+    }
+
+    /// Returns the width of the labels column, including the
+    /// space separating it from the texts.
+    fn label_width(&self) -> u16 {
+        let name_width = self
+            .entries
+            .iter()
+            .map(|entry| entry.name.chars().count())
+            .max()
+            .unwrap_or_default();
+
+        name_width as u16 + 1
+    }
+
+    /// Scrolls the gallery so the selected preset is displayed
+    /// in an area of provided height.
+    fn scroll_to_selected(&mut self, height: u16) {
+        let height = height.max(1) as usize;
+        if self.selected_index < self.scroll_offset {
+            self.scroll_offset = self.selected_index;
+        } else if self.selected_index >= self.scroll_offset + height {
+            self.scroll_offset = self.selected_index + 1 - height;
+        }
+    }
+
+    /// Returns the index of the preset displayed at provided
+    /// row of the screen.
+    #[cfg(feature = "crossterm")]
+    fn entry_at(&self, area: Rect, position: Position) -> Option<usize> {
+        if !area.contains(position) {
+            return None;
+        }
+        let index = self.scroll_offset + (position.y - area.y) as usize;
+
+        (index < self.entries.len()).then_some(index)
+    }
+
+    /// Handles a crossterm event, returning the name of the
+    /// newly selected or chosen preset.
+    #[cfg(feature = "crossterm")]
+    pub fn handle_crossterm_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<AnimationGalleryEvent> {
+        let previous_index = self.selected_index;
+
+        match event {
+            Event::Key(key_event)
+                if key_event.kind != KeyEventKind::Release =>
+            {
+                match key_event.code {
+                    KeyCode::Up => self.select_previous(),
+                    KeyCode::Down => self.select_next(),
+                    KeyCode::Home => self.selected_index = 0,
+                    KeyCode::End => {
+                        self.selected_index =
+                            self.entries.len().saturating_sub(1);
+                    }
+                    KeyCode::Enter => {
+                        return self.selected().map(|name| {
+                            AnimationGalleryEvent::Chosen(name.to_string())
+                        });
+                    }
+                    _ => return None,
+                }
+            }
+            Event::Mouse(mouse_event) => {
+                let position =
+                    Position::new(mouse_event.column, mouse_event.row);
+                if !area.contains(position) {
+                    return None;
+                }
+                match mouse_event.kind {
+                    MouseEventKind::ScrollUp => self.select_previous(),
+                    MouseEventKind::ScrollDown => self.select_next(),
+                    MouseEventKind::Down(MouseButton::Left) => {
+                        let index = self.entry_at(area, position)?;
+                        self.selected_index = index;
+                        return Some(AnimationGalleryEvent::Chosen(
+                            self.entries[index].name.clone(),
+                        ));
+                    }
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        }
+        self.scroll_to_selected(area.height);
+
+        (self.selected_index != previous_index).then(|| {
+            AnimationGalleryEvent::SelectionChanged(
+                self.entries[self.selected_index].name.clone(),
+            )
+        })
+    }
+}
+
+impl Widget for &mut AnimationGalleryWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.scroll_to_selected(area.height);
+
+        let label_width = self.label_width().min(area.width);
+        let selected_index = self.selected_index;
+        let style = self.style;
+
+        let rows = self
+            .entries
+            .iter_mut()
+            .enumerate()
+            .skip(self.scroll_offset)
+            .zip(area.rows());
+        for ((index, entry), row_area) in rows {
+            let label_style = match index == selected_index {
+                true => style.selected_label_style,
+                false => style.label_style,
+            };
+            let label_area = Rect {
+                width: label_width,
+                ..row_area
+            };
+            buf.set_style(label_area, label_style);
+            Line::from(entry.name.as_str()).render(label_area, buf);
+
+            let text_area = Rect {
+                x: row_area.x + label_width,
+                width: row_area.width - label_width,
+                ..row_area
+            };
+            entry.text.render(text_area, buf);
+        }
+        self.identity.record(area, &*self);
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl HandlesEvent for AnimationGalleryWidget {
+    type Event = AnimationGalleryEvent;
+
+    fn handle_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<AnimationGalleryEvent> {
+        self.handle_crossterm_event(event, area)
+    }
+}
+
+impl AccessibleDescription for AnimationGalleryWidget {
+    /// Describes the selected preset and its position in the
+    /// gallery, e.g. `"animation gallery 'wave', 2 of 9"`.
+    fn accessible_description(&self) -> String {
+        let position = match self.entries.is_empty() {
+            true => "empty".to_string(),
+            false => format!(
+                "{} of {}",
+                self.selected_index + 1,
+                self.entries.len(),
+            ),
+        };
+
+        describe_widget("animation gallery", self.selected(), [&*position])
+    }
+}
+
+impl Identifiable for AnimationGalleryWidget {
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use caponata_common::AccessibleDescription;
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        style::Modifier,
+        widgets::Widget,
+    };
+
+    use super::{
+        AnimationGalleryStyle,
+        AnimationGalleryWidget,
+    };
+    use crate::{
+        AnimationPresetRegistry,
+        SmallTextStyleBuilder,
+    };
+
+    fn gallery() -> AnimationGalleryWidget {
+        let text_style =
+            SmallTextStyleBuilder::default().with_text("abc").build();
+
+        AnimationGalleryWidget::new(
+            &AnimationPresetRegistry::builtin(),
+            &text_style,
+            AnimationGalleryStyle::default(),
+        )
+    }
+
+    #[test]
+    fn selected_preset_is_scrolled_into_view() {
+        let mut gallery = gallery();
+        assert_eq!(gallery.selected(), Some("ticker"));
+        assert!(gallery.select("sparkle"));
+        assert!(!gallery.select("unknown"));
+
+        let area = Rect::new(0, 0, 14, 2);
+        let mut buf = Buffer::empty(area);
+        gallery.render(area, &mut buf);
+
+        assert_eq!(buf[(0, 0)].symbol(), "g");
+        assert_eq!(buf[(0, 1)].symbol(), "s");
+        assert!(!buf[(0, 0)].modifier.contains(Modifier::REVERSED));
+        assert!(buf[(0, 1)].modifier.contains(Modifier::REVERSED));
+        assert_eq!(
+            gallery.accessible_description(),
+            "animation gallery 'sparkle', 8 of 9",
+        );
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn presets_are_selected_and_chosen_with_keys_and_mouse() {
+        use crossterm::event::{
+            Event,
+            KeyCode,
+            KeyEvent,
+            KeyModifiers,
+            MouseButton,
+            MouseEvent,
+            MouseEventKind,
+        };
+
+        use super::AnimationGalleryEvent;
+
+        let mut gallery = gallery();
+        let area = Rect::new(0, 0, 14, 4);
+        let key = |code| Event::Key(KeyEvent::from(code));
+
+        assert_eq!(
+            gallery.handle_crossterm_event(key(KeyCode::Up), area),
+            None
+        );
+        assert_eq!(
+            gallery.handle_crossterm_event(key(KeyCode::Down), area),
+            Some(AnimationGalleryEvent::SelectionChanged(
+                "scanner".to_string()
+            )),
+        );
+        assert_eq!(
+            gallery.handle_crossterm_event(key(KeyCode::Enter), area),
+            Some(AnimationGalleryEvent::Chosen("scanner".to_string())),
+        );
+
+        let click = Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 3,
+            row: 2,
+            modifiers: KeyModifiers::NONE,
+        });
+        assert_eq!(
+            gallery.handle_crossterm_event(click, area),
+            Some(AnimationGalleryEvent::Chosen("wave".to_string())),
+        );
+        assert_eq!(gallery.selected(), Some("wave"));
+    }
+}
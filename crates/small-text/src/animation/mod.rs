@@ -1,6 +1,12 @@
 mod advancable;
 mod animation;
+#[cfg(feature = "serde")]
+mod definition;
+#[cfg(feature = "dsl")]
+mod dsl;
+mod error;
 mod event;
+mod gallery;
 mod presets;
 mod repeatable;
 mod style;
@@ -8,7 +14,13 @@ mod text;
 
 use advancable::*;
 pub use animation::*;
+#[cfg(feature = "serde")]
+pub use definition::*;
+#[cfg(feature = "dsl")]
+pub use dsl::*;
+pub use error::*;
 pub use event::*;
+pub use gallery::*;
 pub use presets::*;
 use repeatable::*;
 pub use style::*;
@@ -0,0 +1,328 @@
+use std::time::Duration;
+
+use derive_builder::Builder;
+
+use crate::{
+    AnimationAdvanceMode,
+    AnimationRepeatMode,
+    AnimationStyle,
+    AnimationStyleError,
+    SmallTextStyle,
+    Symbol,
+};
+
+use super::{
+    DEFAULT_STEP_DURATION,
+    HighlightPhase,
+    impl_animation_preset,
+    staggered_steps,
+};
+
+/// Specifies how a jumping symbol is drawn within its cell.
+///
+/// Default variant is [`BounceGlyphs::Superscript`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BounceGlyphs {
+    /// The symbol is replaced with its superscript form at
+    /// the top of the jump and with its subscript form when
+    /// landing. Characters without such forms stay unchanged
+    /// in the corresponding phase.
+    #[default]
+    Superscript,
+
+    /// The symbol is replaced with the upper half block at the
+    /// top of the jump and with the lower half block when
+    /// landing, so the text reads as a row of bouncing bars.
+    HalfBlocks,
+}
+
+/// A styling configuration for the bounce animation, in which
+/// the symbols of the text jump one after another while the
+/// text stays a single row tall.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use caponata_small_text::{
+///     AnimationStyle,
+///     BounceAnimationStyleBuilder,
+///     BounceGlyphs,
+///     SmallTextStyleBuilder,
+/// };
+///
+/// let text_style =
+///     SmallTextStyleBuilder::default().with_text("hooray").build();
+/// let animation_style: AnimationStyle =
+///     BounceAnimationStyleBuilder::default()
+///         .with_text_style(&text_style)
+///         .with_duration(Duration::from_millis(200))
+///         .with_stagger(Duration::from_millis(100))
+///         .with_glyphs(BounceGlyphs::Superscript)
+///         .build()
+///         .unwrap()
+///         .try_into()
+///         .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(prefix = "with", into, strip_option))]
+pub struct BounceAnimationStyle<'a> {
+    text_style: &'a SmallTextStyle<'a>,
+
+    /// Duration of a single jump. The first half of it is
+    /// spent at the top, the second half landing.
+    #[builder(default = "DEFAULT_STEP_DURATION")]
+    duration: Duration,
+
+    /// Time between the jumps of neighbouring symbols. If it
+    /// is shorter than the duration, several symbols are in
+    /// the air at once. By default, the next symbol jumps once
+    /// the previous one has landed. Whitespace is skipped.
+    #[builder(default)]
+    stagger: Option<Duration>,
+
+    #[builder(default)]
+    glyphs: BounceGlyphs,
+
+    #[builder(default)]
+    advance_mode: AnimationAdvanceMode,
+
+    #[builder(default)]
+    repeat_mode: AnimationRepeatMode,
+
+    /// Time to wait before the first step, e.g. to start
+    /// animations of several texts one after another.
+    #[builder(default)]
+    start_delay: Duration,
+}
+
+impl<'a> TryFrom<BounceAnimationStyle<'a>> for AnimationStyle {
+    type Error = AnimationStyleError;
+
+    fn try_from(value: BounceAnimationStyle<'a>) -> Result<Self, Self::Error> {
+        let text_symbols = value.text_style.symbols();
+        // Whitespace has nothing to jump, so it is skipped
+        // instead of delaying the next symbol.
+        let positions: Vec<u16> = value
+            .text_style
+            .transformed_text()
+            .chars()
+            .enumerate()
+            .filter(|(_, character)| !character.is_whitespace())
+            .map(|(x, _)| x as u16)
+            .collect();
+
+        let glyphs = value.glyphs;
+        let jump = move |mut symbol: Symbol, phase| {
+            let is_top = phase == HighlightPhase::Head;
+            symbol.value = match (glyphs, is_top) {
+                (BounceGlyphs::Superscript, true) => {
+                    superscript(symbol.value).unwrap_or(symbol.value)
+                }
+                (BounceGlyphs::Superscript, false) => {
+                    subscript(symbol.value).unwrap_or(symbol.value)
+                }
+                (BounceGlyphs::HalfBlocks, true) => '▀',
+                (BounceGlyphs::HalfBlocks, false) => '▄',
+            };
+            symbol
+        };
+
+        let top_duration = value.duration / 2;
+        let steps = staggered_steps(
+            &positions,
+            text_symbols,
+            value.stagger.unwrap_or(value.duration),
+            top_duration,
+            value.duration - top_duration,
+            jump,
+        )?;
+
+        let mut animation_style =
+            AnimationStyle::new(value.repeat_mode, value.advance_mode, steps)?;
+        animation_style.start_delay = value.start_delay;
+
+        Ok(animation_style)
+    }
+}
+
+impl_animation_preset!(BounceAnimationStyleBuilder, "bounce");
+
+/// Returns the superscript form of provided character, if
+/// Unicode has one.
+fn superscript(character: char) -> Option<char> {
+    let superscript = match character {
+        '0' => '⁰',
+        '1' => '¹',
+        '2' => '²',
+        '3' => '³',
+        '4' => '⁴',
+        '5' => '⁵',
+        '6' => '⁶',
+        '7' => '⁷',
+        '8' => '⁸',
+        '9' => '⁹',
+        'a' => 'ᵃ',
+        'b' => 'ᵇ',
+        'c' => 'ᶜ',
+        'd' => 'ᵈ',
+        'e' => 'ᵉ',
+        'f' => 'ᶠ',
+        'g' => 'ᵍ',
+        'h' => 'ʰ',
+        'i' => 'ⁱ',
+        'j' => 'ʲ',
+        'k' => 'ᵏ',
+        'l' => 'ˡ',
+        'm' => 'ᵐ',
+        'n' => 'ⁿ',
+        'o' => 'ᵒ',
+        'p' => 'ᵖ',
+        'r' => 'ʳ',
+        's' => 'ˢ',
+        't' => 'ᵗ',
+        'u' => 'ᵘ',
+        'v' => 'ᵛ',
+        'w' => 'ʷ',
+        'x' => 'ˣ',
+        'y' => 'ʸ',
+        'z' => 'ᶻ',
+        'A' => 'ᴬ',
+        'B' => 'ᴮ',
+        'D' => 'ᴰ',
+        'E' => 'ᴱ',
+        'G' => 'ᴳ',
+        'H' => 'ᴴ',
+        'I' => 'ᴵ',
+        'J' => 'ᴶ',
+        'K' => 'ᴷ',
+        'L' => 'ᴸ',
+        'M' => 'ᴹ',
+        'N' => 'ᴺ',
+        'O' => 'ᴼ',
+        'P' => 'ᴾ',
+        'R' => 'ᴿ',
+        'T' => 'ᵀ',
+        'U' => 'ᵁ',
+        'V' => 'ⱽ',
+        'W' => 'ᵂ',
+        '+' => '⁺',
+        '-' => '⁻',
+        '=' => '⁼',
+        '(' => '⁽',
+        ')' => '⁾',
+        _ => return None,
+    };
+
+    Some(superscript)
+}
+
+/// Returns the subscript form of provided character, if
+/// Unicode has one.
+fn subscript(character: char) -> Option<char> {
+    let subscript = match character {
+        '0' => '₀',
+        '1' => '₁',
+        '2' => '₂',
+        '3' => '₃',
+        '4' => '₄',
+        '5' => '₅',
+        '6' => '₆',
+        '7' => '₇',
+        '8' => '₈',
+        '9' => '₉',
+        'a' => 'ₐ',
+        'e' => 'ₑ',
+        'h' => 'ₕ',
+        'i' => 'ᵢ',
+        'j' => 'ⱼ',
+        'k' => 'ₖ',
+        'l' => 'ₗ',
+        'm' => 'ₘ',
+        'n' => 'ₙ',
+        'o' => 'ₒ',
+        'p' => 'ₚ',
+        'r' => 'ᵣ',
+        's' => 'ₛ',
+        't' => 'ₜ',
+        'u' => 'ᵤ',
+        'v' => 'ᵥ',
+        'x' => 'ₓ',
+        '+' => '₊',
+        '-' => '₋',
+        '=' => '₌',
+        '(' => '₍',
+        ')' => '₎',
+        _ => return None,
+    };
+
+    Some(subscript)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        time::Duration,
+    };
+
+    use caponata_common::AnimationGroup;
+
+    use super::{
+        BounceAnimationStyleBuilder,
+        BounceGlyphs,
+    };
+    use crate::{
+        Animation,
+        AnimationRepeatMode,
+        AnimationStyle,
+        SmallTextStyleBuilder,
+        SmallTextWidget,
+        Symbol,
+    };
+
+    #[test]
+    fn symbols_jump_one_after_another() {
+        let text_style =
+            SmallTextStyleBuilder::default().with_text("ab c").build();
+        let text = |symbols: HashMap<u16, Symbol>| -> String {
+            (0..symbols.len() as u16)
+                .map(|x| symbols[&x].value)
+                .collect()
+        };
+
+        for (glyphs, expected) in [
+            (
+                BounceGlyphs::Superscript,
+                ["ᵃb c", "ₐb c", "aᵇ c", "ab c", "ab ᶜ"],
+            ),
+            (
+                BounceGlyphs::HalfBlocks,
+                ["▀b c", "▄b c", "a▀ c", "a▄ c", "ab ▀"],
+            ),
+        ] {
+            let animation_style: AnimationStyle =
+                BounceAnimationStyleBuilder::default()
+                    .with_text_style(&text_style)
+                    .with_duration(Duration::from_millis(100))
+                    .with_glyphs(glyphs)
+                    .with_repeat_mode(AnimationRepeatMode::Finite(1))
+                    .build()
+                    .unwrap()
+                    .try_into()
+                    .unwrap();
+            let symbols =
+                SmallTextWidget::new(text_style.clone()).symbols().clone();
+            let group = AnimationGroup::new();
+            let mut animation = Animation::new(animation_style, symbols);
+            animation.set_clock(group.clock());
+
+            for expected_text in expected {
+                let frame = animation.next_frame().unwrap();
+                assert_eq!(text(frame.symbols), expected_text);
+                group.advance(Duration::from_millis(50));
+            }
+        }
+    }
+}
@@ -0,0 +1,329 @@
+use std::time::Duration;
+
+use caponata_common::{
+    Easing,
+    interpolate_color,
+};
+use derive_builder::Builder;
+use ratatui::style::Color;
+
+use crate::{
+    AnimationAdvanceMode,
+    AnimationRepeatMode,
+    AnimationStep,
+    AnimationStepBuilder,
+    AnimationStyle,
+    AnimationStyleError,
+    AnimationTarget,
+    SmallTextStyle,
+    SmallTextWidget,
+};
+
+use super::{
+    DEFAULT_TOTAL_DURATION,
+    impl_animation_preset,
+};
+
+/// A styling configuration for the fade-in animation, which
+/// changes the foreground color of every symbol from its
+/// background color to the color of the text style.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use caponata_common::Easing;
+/// use caponata_small_text::{
+///     AnimationRepeatMode,
+///     AnimationStyle,
+///     FadeInAnimationStyleBuilder,
+///     SmallTextStyleBuilder,
+/// };
+///
+/// let text_style = SmallTextStyleBuilder::default()
+///     .with_text("Welcome")
+///     .build();
+/// let animation_style: AnimationStyle =
+///     FadeInAnimationStyleBuilder::default()
+///         .with_text_style(&text_style)
+///         .with_duration(Duration::from_millis(500))
+///         .with_easing(Easing::EaseOut)
+///         .with_repeat_mode(AnimationRepeatMode::Finite(1))
+///         .build()
+///         .unwrap()
+///         .try_into()
+///         .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Builder)]
+#[builder(setter(prefix = "with", into, strip_option))]
+pub struct FadeInAnimationStyle<'a> {
+    text_style: &'a SmallTextStyle<'a>,
+
+    /// The total duration of the fade.
+    #[builder(default = "DEFAULT_TOTAL_DURATION")]
+    duration: Duration,
+
+    #[builder(default)]
+    easing: Easing,
+
+    /// Number of intermediate colors.
+    #[builder(default = "10")]
+    step_count: u16,
+
+    /// Color the fade starts from. The background color of
+    /// every symbol is used by default, falling back to
+    /// [`Color::Black`] if the background color is
+    /// [`Color::Reset`].
+    #[builder(default)]
+    background_color: Option<Color>,
+
+    #[builder(default)]
+    advance_mode: AnimationAdvanceMode,
+
+    #[builder(default)]
+    repeat_mode: AnimationRepeatMode,
+}
+
+impl<'a> TryFrom<FadeInAnimationStyle<'a>> for AnimationStyle {
+    type Error = AnimationStyleError;
+
+    fn try_from(value: FadeInAnimationStyle<'a>) -> Result<Self, Self::Error> {
+        let steps = fade_steps(
+            value.text_style,
+            value.duration,
+            value.easing,
+            value.step_count,
+            value.background_color,
+            true,
+        )?;
+
+        AnimationStyle::new(value.repeat_mode, value.advance_mode, steps)
+    }
+}
+
+/// A styling configuration for the fade-out animation, which
+/// changes the foreground color of every symbol from the
+/// color of the text style to its background color.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use caponata_small_text::{
+///     AnimationRepeatMode,
+///     AnimationStyle,
+///     FadeOutAnimationStyleBuilder,
+///     SmallTextStyleBuilder,
+/// };
+///
+/// let text_style = SmallTextStyleBuilder::default()
+///     .with_text("Goodbye")
+///     .build();
+/// let animation_style: AnimationStyle =
+///     FadeOutAnimationStyleBuilder::default()
+///         .with_text_style(&text_style)
+///         .with_duration(Duration::from_millis(500))
+///         .with_repeat_mode(AnimationRepeatMode::Finite(1))
+///         .build()
+///         .unwrap()
+///         .try_into()
+///         .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Builder)]
+#[builder(setter(prefix = "with", into, strip_option))]
+pub struct FadeOutAnimationStyle<'a> {
+    text_style: &'a SmallTextStyle<'a>,
+
+    /// The total duration of the fade.
+    #[builder(default = "DEFAULT_TOTAL_DURATION")]
+    duration: Duration,
+
+    #[builder(default)]
+    easing: Easing,
+
+    /// Number of intermediate colors.
+    #[builder(default = "10")]
+    step_count: u16,
+
+    /// Color the fade ends with. The background color of
+    /// every symbol is used by default, falling back to
+    /// [`Color::Black`] if the background color is
+    /// [`Color::Reset`].
+    #[builder(default)]
+    background_color: Option<Color>,
+
+    #[builder(default)]
+    advance_mode: AnimationAdvanceMode,
+
+    #[builder(default)]
+    repeat_mode: AnimationRepeatMode,
+}
+
+impl<'a> TryFrom<FadeOutAnimationStyle<'a>> for AnimationStyle {
+    type Error = AnimationStyleError;
+
+    fn try_from(
+        value: FadeOutAnimationStyle<'a>,
+    ) -> Result<Self, Self::Error> {
+        let steps = fade_steps(
+            value.text_style,
+            value.duration,
+            value.easing,
+            value.step_count,
+            value.background_color,
+            false,
+        )?;
+
+        AnimationStyle::new(value.repeat_mode, value.advance_mode, steps)
+    }
+}
+
+impl_animation_preset!(
+    FadeInAnimationStyleBuilder,
+    "fade_in",
+    default = DEFAULT_TOTAL_DURATION
+);
+impl_animation_preset!(
+    FadeOutAnimationStyleBuilder,
+    "fade_out",
+    default = DEFAULT_TOTAL_DURATION
+);
+
+/// Returns steps interpolating the foreground color of every
+/// symbol between its background color and its own color.
+/// The last step displays the final colors, so it has zero
+/// duration.
+fn fade_steps(
+    text_style: &SmallTextStyle,
+    duration: Duration,
+    easing: Easing,
+    step_count: u16,
+    background_color: Option<Color>,
+    is_fading_in: bool,
+) -> Result<Vec<AnimationStep>, AnimationStyleError> {
+    let text = SmallTextWidget::new(text_style.clone());
+    let mut symbols: Vec<_> = text.symbols().iter().collect();
+    symbols.sort_by_key(|(x, _)| **x);
+
+    let step_count = step_count.max(1);
+    let step_duration = duration / step_count as u32;
+
+    let mut steps = Vec::new();
+    for step_index in 0..=step_count {
+        let linear_progress = step_index as f64 / step_count as f64;
+        let progress = if is_fading_in {
+            easing.apply(linear_progress)
+        } else {
+            1.0 - easing.apply(linear_progress)
+        };
+
+        let duration = if step_index == step_count {
+            Duration::ZERO
+        } else {
+            step_duration
+        };
+        let mut step_builder =
+            AnimationStepBuilder::default().with_duration(duration);
+        for (x, symbol) in symbols.iter() {
+            let from = match background_color.or(symbol.background_color) {
+                None | Some(Color::Reset) => Color::Black,
+                Some(color) => color,
+            };
+            let color = interpolate_color(
+                from,
+                symbol.foreground_color.unwrap_or_default(),
+                progress,
+            );
+            step_builder = step_builder
+                .for_target(AnimationTarget::Single(**x))
+                .update_foreground_color(color)
+                .then();
+        }
+        steps.push(step_builder.build()?);
+    }
+
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use caponata_common::AnimationGroup;
+    use ratatui::style::Color;
+
+    use super::{
+        FadeInAnimationStyleBuilder,
+        FadeOutAnimationStyleBuilder,
+    };
+    use crate::{
+        Animation,
+        AnimationRepeatMode,
+        AnimationStyle,
+        SmallTextStyleBuilder,
+        SmallTextWidget,
+        Target,
+    };
+
+    #[test]
+    fn fade_in_and_out() {
+        let text_style = SmallTextStyleBuilder::default()
+            .with_text("fade")
+            .for_target(Target::Untouched)
+            .set_foreground_color(Color::White)
+            .then()
+            .build();
+        let symbols =
+            SmallTextWidget::new(text_style.clone()).symbols().clone();
+        let group = AnimationGroup::new();
+
+        let fade_in: AnimationStyle = FadeInAnimationStyleBuilder::default()
+            .with_text_style(&text_style)
+            .with_duration(Duration::from_millis(200))
+            .with_step_count(2u16)
+            .with_repeat_mode(AnimationRepeatMode::Finite(1))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let mut animation = Animation::new(fade_in, symbols.clone());
+        animation.set_clock(group.clock());
+
+        let colors = [
+            Color::Rgb(0, 0, 0),
+            Color::Rgb(128, 128, 128),
+            Color::Rgb(255, 255, 255),
+        ];
+        for color in colors {
+            let frame = animation.next_frame().unwrap();
+            assert_eq!(frame.symbols[&0].foreground_color, Some(color));
+            group.advance(Duration::from_millis(100));
+        }
+
+        let fade_out: AnimationStyle = FadeOutAnimationStyleBuilder::default()
+            .with_text_style(&text_style)
+            .with_duration(Duration::from_millis(200))
+            .with_step_count(2u16)
+            .with_background_color(Color::Blue)
+            .with_repeat_mode(AnimationRepeatMode::Finite(1))
+            .build()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let mut animation = Animation::new(fade_out, symbols);
+        animation.set_clock(group.clock());
+
+        let colors = [
+            Color::Rgb(255, 255, 255),
+            Color::Rgb(128, 128, 192),
+            Color::Rgb(0, 0, 128),
+        ];
+        for color in colors {
+            let frame = animation.next_frame().unwrap();
+            assert_eq!(frame.symbols[&3].foreground_color, Some(color));
+            group.advance(Duration::from_millis(100));
+        }
+    }
+}
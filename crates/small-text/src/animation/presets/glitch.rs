@@ -0,0 +1,208 @@
+use std::time::Duration;
+
+use derive_builder::Builder;
+use fastrand::Rng;
+
+use crate::{
+    AnimationAdvanceMode,
+    AnimationRepeatMode,
+    AnimationStepBuilder,
+    AnimationStyle,
+    AnimationStyleError,
+    AnimationTarget,
+    SmallTextStyle,
+};
+
+use super::{
+    DEFAULT_TOTAL_DURATION,
+    impl_animation_preset,
+};
+
+/// A styling configuration for the glitch animation, which
+/// replaces random characters of the text with noise glyphs
+/// and gradually resolves them back to the real text.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use caponata_small_text::{
+///     AnimationRepeatMode,
+///     AnimationStyle,
+///     GlitchAnimationStyleBuilder,
+///     SmallTextStyleBuilder,
+/// };
+///
+/// let text_style = SmallTextStyleBuilder::default()
+///     .with_text("SYSTEM ONLINE")
+///     .build();
+/// let animation_style: AnimationStyle =
+///     GlitchAnimationStyleBuilder::default()
+///         .with_text_style(&text_style)
+///         .with_duration(Duration::from_millis(800))
+///         .with_intensity(0.8)
+///         .with_character_pool("01")
+///         .with_repeat_mode(AnimationRepeatMode::Finite(1))
+///         .build()
+///         .unwrap()
+///         .try_into()
+///         .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Builder)]
+#[builder(setter(prefix = "with", into, strip_option))]
+pub struct GlitchAnimationStyle<'a> {
+    text_style: &'a SmallTextStyle<'a>,
+
+    /// The total duration of the glitch, after which the text
+    /// is fully resolved.
+    #[builder(default = "DEFAULT_TOTAL_DURATION")]
+    duration: Duration,
+
+    /// Share of the characters replaced on the first step,
+    /// in the `0.0..=1.0` range. The share decreases on every
+    /// next step.
+    #[builder(default = "0.5")]
+    intensity: f64,
+
+    /// Characters used as noise glyphs.
+    #[builder(default = "\"!@#$%&*<>/\\\\|?~\"")]
+    character_pool: &'a str,
+
+    /// Number of steps replacing characters before the text
+    /// is resolved.
+    #[builder(default = "8")]
+    step_count: u16,
+
+    /// Seed of the random number generator selecting the
+    /// replaced characters and the noise glyphs. A random
+    /// seed is used by default.
+    #[builder(default)]
+    seed: Option<u64>,
+
+    #[builder(default)]
+    advance_mode: AnimationAdvanceMode,
+
+    #[builder(default)]
+    repeat_mode: AnimationRepeatMode,
+}
+
+impl<'a> TryFrom<GlitchAnimationStyle<'a>> for AnimationStyle {
+    type Error = AnimationStyleError;
+
+    fn try_from(value: GlitchAnimationStyle<'a>) -> Result<Self, Self::Error> {
+        let text_chars: Vec<char> =
+            value.text_style.transformed_text().chars().collect();
+        let glyphs: Vec<char> = value.character_pool.chars().collect();
+
+        let mut rng = match value.seed {
+            Some(seed) => Rng::with_seed(seed),
+            None => Rng::new(),
+        };
+        let step_count = value.step_count.max(1);
+        let step_duration = value.duration / step_count as u32;
+        let intensity = value.intensity.clamp(0.0, 1.0);
+
+        let mut steps = Vec::new();
+        for step_index in 0..=step_count {
+            // The last step resolves the whole text.
+            let step_intensity = intensity * (step_count - step_index) as f64
+                / step_count as f64;
+            let glitch_count =
+                (text_chars.len() as f64 * step_intensity).round() as usize;
+            let glitched_positions = if glyphs.is_empty() {
+                Vec::new()
+            } else {
+                rng.choose_multiple(0..text_chars.len() as u16, glitch_count)
+            };
+
+            let duration = if step_index == step_count {
+                Duration::ZERO
+            } else {
+                step_duration
+            };
+            let mut step_builder =
+                AnimationStepBuilder::default().with_duration(duration);
+            for (x, character) in text_chars.iter().enumerate() {
+                let x = x as u16;
+                let character = if glitched_positions.contains(&x) {
+                    glyphs[rng.usize(..glyphs.len())]
+                } else {
+                    *character
+                };
+                step_builder = step_builder
+                    .for_target(AnimationTarget::Single(x))
+                    .update_character(character)
+                    .then();
+            }
+            steps.push(step_builder.build()?);
+        }
+
+        AnimationStyle::new(value.repeat_mode, value.advance_mode, steps)
+    }
+}
+
+impl_animation_preset!(
+    GlitchAnimationStyleBuilder,
+    "glitch",
+    default = DEFAULT_TOTAL_DURATION
+);
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        time::Duration,
+    };
+
+    use caponata_common::AnimationGroup;
+
+    use super::GlitchAnimationStyleBuilder;
+    use crate::{
+        Animation,
+        AnimationRepeatMode,
+        AnimationStyle,
+        SmallTextStyleBuilder,
+        SmallTextWidget,
+        Symbol,
+    };
+
+    #[test]
+    fn glitch_resolves_text() {
+        let text_style =
+            SmallTextStyleBuilder::default().with_text("glitch").build();
+        let animation_style: AnimationStyle =
+            GlitchAnimationStyleBuilder::default()
+                .with_text_style(&text_style)
+                .with_duration(Duration::from_millis(400))
+                .with_intensity(1.0)
+                .with_character_pool("#")
+                .with_step_count(4u16)
+                .with_seed(1u64)
+                .with_repeat_mode(AnimationRepeatMode::Finite(1))
+                .build()
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let symbols = SmallTextWidget::new(text_style).symbols().clone();
+        let group = AnimationGroup::new();
+        let mut animation = Animation::new(animation_style, symbols);
+        animation.set_clock(group.clock());
+
+        let text = |symbols: HashMap<u16, Symbol>| -> String {
+            (0..symbols.len() as u16)
+                .map(|x| symbols[&x].value)
+                .collect()
+        };
+
+        let frame = animation.next_frame().unwrap();
+        assert_eq!(text(frame.symbols), "######");
+
+        let mut last_text = String::new();
+        for _ in 0..4 {
+            group.advance(Duration::from_millis(100));
+            last_text = text(animation.next_frame().unwrap().symbols);
+        }
+        assert_eq!(last_text, "glitch");
+    }
+}
@@ -0,0 +1,302 @@
+//! Golden frame sequences of the presets.
+//!
+//! Every preset is played with a manually advanced clock and
+//! its frames are dumped into a string, which is compared with
+//! the fixture committed under `presets/fixtures`, so changes
+//! of the animation engine can't silently change how presets
+//! look. Run the tests with `UPDATE_GOLDEN=1` to rewrite the
+//! fixtures after an intended change and review their diff.
+
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Write,
+    fs,
+    path::PathBuf,
+    time::Duration,
+};
+
+use caponata_common::testing::SnapshotRenderer;
+use ratatui::style::Color;
+
+use crate::{
+    AnimatedSmallTextWidget,
+    AnimationRepeatMode,
+    AnimationStyle,
+    BounceAnimationStyleBuilder,
+    FadeInAnimationStyleBuilder,
+    FadeOutAnimationStyleBuilder,
+    GlitchAnimationStyleBuilder,
+    RainbowAnimationStyleBuilder,
+    ScannerAnimationStyleBuilder,
+    ScannerEndMode,
+    SmallTextStyle,
+    SmallTextStyleBuilder,
+    SparkleAnimationStyleBuilder,
+    Target,
+    TickerAnimationDirection,
+    TickerAnimationStyleBuilder,
+    WaveAnimationStyleBuilder,
+};
+
+const FRAME_INTERVAL: Duration = Duration::from_millis(50);
+const FRAME_COUNT: usize = 12;
+
+/// Renders provided number of frames of the animation, one
+/// every provided interval, and returns their snapshots.
+fn dump_frames(
+    text_style: &SmallTextStyle,
+    animation_style: AnimationStyle,
+    interval: Duration,
+    frame_count: usize,
+) -> String {
+    let width = text_style.transformed_text().chars().count() as u16;
+    let renderer = SnapshotRenderer::new(width, 1);
+
+    let mut text = AnimatedSmallTextWidget::new(
+        text_style.clone(),
+        HashMap::from([((), animation_style)]),
+    );
+    text.set_clock(renderer.clock());
+    text.enable_animation(&());
+
+    let mut dump = String::new();
+    for frame in 0..frame_count {
+        let elapsed = interval * frame as u32;
+        writeln!(dump, "# frame {frame} at {}ms", elapsed.as_millis())
+            .expect("string is writable");
+        dump.push_str(&renderer.snapshot(&mut text));
+        renderer.advance(interval);
+    }
+
+    dump
+}
+
+/// Compares provided dump with the fixture of provided name,
+/// or overwrites the fixture if `UPDATE_GOLDEN` is set.
+fn assert_golden(name: &str, dump: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/animation/presets/fixtures")
+        .join(format!("{name}.txt"));
+
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&path, dump).expect("fixture is writable");
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!("fixture {path:?} is missing, run with UPDATE_GOLDEN=1")
+    });
+    assert_eq!(
+        dump, expected,
+        "frames of {name} differ from the fixture, run with \
+         UPDATE_GOLDEN=1 if the change is intended",
+    );
+}
+
+fn text_style() -> SmallTextStyle<'static> {
+    SmallTextStyleBuilder::default()
+        .with_text("caponata")
+        .for_target(Target::Untouched)
+        .set_foreground_color(Color::White)
+        .then()
+        .build()
+}
+
+fn assert_preset(name: &str, animation_style: AnimationStyle) {
+    let dump = dump_frames(
+        &text_style(),
+        animation_style,
+        FRAME_INTERVAL,
+        FRAME_COUNT,
+    );
+    assert_golden(name, &dump);
+}
+
+#[test]
+fn ticker() {
+    let animation_style = TickerAnimationStyleBuilder::default()
+        .with_duration(FRAME_INTERVAL)
+        .with_repeat_mode(AnimationRepeatMode::Infinite)
+        .build()
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_preset("ticker", animation_style);
+}
+
+#[test]
+fn ticker_with_gap_and_start_pause() {
+    let text_style = text_style();
+    let animation_style = TickerAnimationStyleBuilder::default()
+        .with_text_style(&text_style)
+        .with_direction(TickerAnimationDirection::Backward)
+        .with_duration(FRAME_INTERVAL)
+        .with_gap(" * ")
+        .with_start_pause(FRAME_INTERVAL)
+        .with_step_size(2u16)
+        .with_repeat_mode(AnimationRepeatMode::Infinite)
+        .build()
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_preset("ticker_with_gap_and_start_pause", animation_style);
+
+    assert!(
+        TickerAnimationStyleBuilder::default()
+            .with_gap(" * ")
+            .build()
+            .is_err()
+    );
+}
+
+#[test]
+fn scanner() {
+    let text_style = text_style();
+    let animation_style = ScannerAnimationStyleBuilder::default()
+        .with_text_style(&text_style)
+        .with_duration(FRAME_INTERVAL)
+        .with_foreground_color(Color::Yellow)
+        .with_repeat_mode(AnimationRepeatMode::Infinite)
+        .build()
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_preset("scanner", animation_style);
+}
+
+#[test]
+fn wrapping_scanner_with_trail() {
+    let text_style = text_style();
+    let animation_style = ScannerAnimationStyleBuilder::default()
+        .with_text_style(&text_style)
+        .with_duration(FRAME_INTERVAL)
+        .with_foreground_color(Color::Rgb(255, 0, 0))
+        .with_head_width(2u16)
+        .with_trail_length(3u16)
+        .with_end_mode(ScannerEndMode::Wrap)
+        .with_repeat_mode(AnimationRepeatMode::Infinite)
+        .build()
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_preset("wrapping_scanner_with_trail", animation_style);
+}
+
+#[test]
+fn staggered_scanner() {
+    let text_style = text_style();
+    let animation_style = ScannerAnimationStyleBuilder::default()
+        .with_text_style(&text_style)
+        .with_duration(FRAME_INTERVAL * 3)
+        .with_stagger(FRAME_INTERVAL)
+        .with_background_color(Color::Blue)
+        .with_repeat_mode(AnimationRepeatMode::Infinite)
+        .build()
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_preset("staggered_scanner", animation_style);
+}
+
+#[test]
+fn wave() {
+    let text_style = text_style();
+    let animation_style = WaveAnimationStyleBuilder::default()
+        .with_text_style(&text_style)
+        .with_duration(FRAME_INTERVAL)
+        .with_foreground_color(Color::Cyan)
+        .with_repeat_mode(AnimationRepeatMode::Infinite)
+        .build()
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_preset("wave", animation_style);
+}
+
+#[test]
+fn fade_in_and_out() {
+    let text_style = text_style();
+    let fade_in = FadeInAnimationStyleBuilder::default()
+        .with_text_style(&text_style)
+        .with_duration(FRAME_INTERVAL * 4)
+        .with_step_count(4u16)
+        .with_repeat_mode(AnimationRepeatMode::Finite(1))
+        .build()
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_preset("fade_in", fade_in);
+
+    let fade_out = FadeOutAnimationStyleBuilder::default()
+        .with_text_style(&text_style)
+        .with_duration(FRAME_INTERVAL * 4)
+        .with_step_count(4u16)
+        .with_repeat_mode(AnimationRepeatMode::Finite(1))
+        .build()
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_preset("fade_out", fade_out);
+}
+
+#[test]
+fn rainbow() {
+    let text_style = text_style();
+    let animation_style = RainbowAnimationStyleBuilder::default()
+        .with_text_style(&text_style)
+        .with_step_count(6u16)
+        .with_speed(1200.0)
+        .with_repeat_mode(AnimationRepeatMode::Infinite)
+        .build()
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_preset("rainbow", animation_style);
+}
+
+#[test]
+fn glitch() {
+    let text_style = text_style();
+    let animation_style = GlitchAnimationStyleBuilder::default()
+        .with_text_style(&text_style)
+        .with_duration(FRAME_INTERVAL * 4)
+        .with_step_count(4u16)
+        .with_seed(3u64)
+        .with_repeat_mode(AnimationRepeatMode::Infinite)
+        .build()
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_preset("glitch", animation_style);
+}
+
+#[test]
+fn sparkle() {
+    let text_style = text_style();
+    let animation_style = SparkleAnimationStyleBuilder::default()
+        .with_text_style(&text_style)
+        .with_duration(FRAME_INTERVAL * 4)
+        .with_step_count(4u16)
+        .with_seed(3u64)
+        .with_repeat_mode(AnimationRepeatMode::Infinite)
+        .build()
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_preset("sparkle", animation_style);
+}
+
+#[test]
+fn bounce() {
+    let text_style = text_style();
+    let animation_style = BounceAnimationStyleBuilder::default()
+        .with_text_style(&text_style)
+        .with_duration(FRAME_INTERVAL * 2)
+        .with_repeat_mode(AnimationRepeatMode::Infinite)
+        .build()
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_preset("bounce", animation_style);
+}
@@ -1,7 +1,25 @@
+mod bounce;
+mod fade;
+mod glitch;
+#[cfg(test)]
+mod golden;
+mod preset;
+mod rainbow;
+mod registry;
 mod scanner;
+mod sparkle;
+mod stagger;
 mod ticker;
 mod wave;
 
+pub use bounce::*;
+pub use fade::*;
+pub use glitch::*;
+pub use preset::*;
+pub use rainbow::*;
+pub use registry::*;
 pub use scanner::*;
+pub use sparkle::*;
+use stagger::*;
 pub use ticker::*;
 pub use wave::*;
@@ -0,0 +1,153 @@
+use std::time::Duration;
+
+use crate::{
+    AnimationAdvanceMode,
+    AnimationRepeatMode,
+    AnimationStyle,
+    AnimationStyleError,
+    SmallTextStyle,
+};
+
+/// Default duration of a single step of the presets driven by
+/// a step duration, e.g. the wave.
+pub(crate) const DEFAULT_STEP_DURATION: Duration = Duration::from_millis(100);
+
+/// Default total duration of the presets played once over a
+/// duration, e.g. the fades.
+pub(crate) const DEFAULT_TOTAL_DURATION: Duration = Duration::from_secs(1);
+
+/// The text an [`AnimationPreset`] is played on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextContext<'a> {
+    pub text_style: &'a SmallTextStyle<'a>,
+}
+
+impl<'a> TextContext<'a> {
+    pub fn new(text_style: &'a SmallTextStyle<'a>) -> Self {
+        Self { text_style }
+    }
+}
+
+impl<'a> From<&'a SmallTextStyle<'a>> for TextContext<'a> {
+    fn from(value: &'a SmallTextStyle<'a>) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Parameters shared by all presets, e.g. to display them in a
+/// settings menu.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AnimationPresetParams {
+    /// Duration of a single step of the preset. `None` if the
+    /// preset is not driven by a duration, e.g. the rainbow,
+    /// whose pace is set by its speed.
+    pub duration: Option<Duration>,
+
+    pub repeat_mode: AnimationRepeatMode,
+    pub advance_mode: AnimationAdvanceMode,
+}
+
+/// An animation that can be played on any text, so presets can
+/// be enumerated and applied uniformly, e.g. by
+/// [`AnimationPresetRegistry`].
+///
+/// The builders of the built-in presets implement this trait,
+/// with the text style taken from [`TextContext`] instead of
+/// the builder.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use caponata_small_text::{
+///     AnimationPreset,
+///     AnimationRepeatMode,
+///     SmallTextStyleBuilder,
+///     TextContext,
+///     WaveAnimationStyleBuilder,
+/// };
+///
+/// let mut preset = WaveAnimationStyleBuilder::default();
+/// preset
+///     .with_duration(Duration::from_millis(100))
+///     .with_repeat_mode(AnimationRepeatMode::Infinite);
+/// assert_eq!(preset.name(), "wave");
+///
+/// let text_style = SmallTextStyleBuilder::default().with_text("Hi").build();
+/// let animation_style = preset
+///     .into_style(&TextContext::new(&text_style))
+///     .unwrap();
+/// ```
+///
+/// [`AnimationPresetRegistry`]: crate::AnimationPresetRegistry
+pub trait AnimationPreset: Send + Sync {
+    /// Returns the name identifying the preset.
+    fn name(&self) -> &str;
+
+    /// Returns the parameters the preset is played with.
+    fn default_params(&self) -> AnimationPresetParams;
+
+    /// Returns the animation style of the preset for provided
+    /// text, or the error the [`AnimationStyleBuilder`] would
+    /// return for it.
+    ///
+    /// [`AnimationStyleBuilder`]: crate::AnimationStyleBuilder
+    // Presets are registered as trait objects, so they can't be
+    // consumed.
+    #[allow(clippy::wrong_self_convention)]
+    fn into_style(
+        &self,
+        context: &TextContext,
+    ) -> Result<AnimationStyle, AnimationStyleError>;
+}
+
+/// Implements [`AnimationPreset`] for the builder of a preset
+/// taking the text style, with provided name. The duration of
+/// the parameters is read from the builder, falling back to
+/// provided default or [`DEFAULT_STEP_DURATION`], unless a
+/// function returning it is provided.
+macro_rules! impl_animation_preset {
+    ($builder:ident, $name:literal) => {
+        impl_animation_preset!(
+            $builder,
+            $name,
+            default = crate::animation::presets::DEFAULT_STEP_DURATION
+        );
+    };
+    ($builder:ident, $name:literal, default = $default:expr) => {
+        impl_animation_preset!($builder, $name, |builder: &$builder| {
+            Some(builder.duration.unwrap_or($default))
+        });
+    };
+    ($builder:ident, $name:literal, $duration:expr) => {
+        impl crate::AnimationPreset for $builder<'static> {
+            fn name(&self) -> &str {
+                $name
+            }
+
+            fn default_params(&self) -> crate::AnimationPresetParams {
+                crate::AnimationPresetParams {
+                    duration: ($duration)(self),
+                    repeat_mode: self.repeat_mode.unwrap_or_default(),
+                    advance_mode: self.advance_mode.unwrap_or_default(),
+                }
+            }
+
+            fn into_style(
+                &self,
+                context: &crate::TextContext,
+            ) -> Result<crate::AnimationStyle, crate::AnimationStyleError>
+            {
+                let mut builder: $builder = self.clone();
+                builder
+                    .with_text_style(context.text_style)
+                    .build()
+                    .expect("text style is set")
+                    .try_into()
+            }
+        }
+    };
+}
+
+pub(crate) use impl_animation_preset;
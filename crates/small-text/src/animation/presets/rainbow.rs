@@ -0,0 +1,170 @@
+use std::time::Duration;
+
+use caponata_common::hsl_to_color;
+use derive_builder::Builder;
+
+use crate::{
+    AnimationAdvanceMode,
+    AnimationRepeatMode,
+    AnimationStepBuilder,
+    AnimationStyle,
+    AnimationStyleError,
+    AnimationTarget,
+    SmallTextStyle,
+};
+
+use super::impl_animation_preset;
+
+/// A styling configuration for the rainbow animation, which
+/// rotates the hue of the foreground color of every symbol.
+/// One repetition of the animation is a full turn of the
+/// color wheel.
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_small_text::{
+///     AnimationRepeatMode,
+///     AnimationStyle,
+///     RainbowAnimationStyleBuilder,
+///     SmallTextStyleBuilder,
+/// };
+///
+/// let text_style = SmallTextStyleBuilder::default()
+///     .with_text("Party time")
+///     .build();
+/// let animation_style: AnimationStyle =
+///     RainbowAnimationStyleBuilder::default()
+///         .with_text_style(&text_style)
+///         .with_speed(180.0)
+///         .with_phase_offset(20.0)
+///         .with_lightness(0.6)
+///         .with_repeat_mode(AnimationRepeatMode::Infinite)
+///         .build()
+///         .unwrap()
+///         .try_into()
+///         .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Builder)]
+#[builder(setter(prefix = "with", into, strip_option))]
+pub struct RainbowAnimationStyle<'a> {
+    text_style: &'a SmallTextStyle<'a>,
+
+    /// Saturation of the colors in the `0.0..=1.0` range.
+    #[builder(default = "1.0")]
+    saturation: f64,
+
+    /// Lightness of the colors in the `0.0..=1.0` range.
+    #[builder(default = "0.5")]
+    lightness: f64,
+
+    /// Difference between the hues of adjacent symbols in
+    /// degrees.
+    #[builder(default = "30.0")]
+    phase_offset: f64,
+
+    /// Rotation speed of the hues in degrees per second.
+    #[builder(default = "360.0")]
+    speed: f64,
+
+    /// Number of steps in a full turn of the color wheel.
+    #[builder(default = "36")]
+    step_count: u16,
+
+    #[builder(default)]
+    advance_mode: AnimationAdvanceMode,
+
+    #[builder(default)]
+    repeat_mode: AnimationRepeatMode,
+}
+
+impl<'a> TryFrom<RainbowAnimationStyle<'a>> for AnimationStyle {
+    type Error = AnimationStyleError;
+
+    fn try_from(
+        value: RainbowAnimationStyle<'a>,
+    ) -> Result<Self, Self::Error> {
+        let symbol_count =
+            value.text_style.transformed_text().chars().count() as u16;
+        let step_count = value.step_count.max(1);
+        let hue_step = 360.0 / step_count as f64;
+        let step_duration = if value.speed > 0.0 {
+            Duration::from_secs_f64(hue_step / value.speed)
+        } else {
+            Duration::ZERO
+        };
+
+        let steps = (0..step_count)
+            .map(|step_index| {
+                let mut step_builder = AnimationStepBuilder::default()
+                    .with_duration(step_duration);
+                for x in 0..symbol_count {
+                    let hue = step_index as f64 * hue_step
+                        + x as f64 * value.phase_offset;
+                    let color =
+                        hsl_to_color(hue, value.saturation, value.lightness);
+                    step_builder = step_builder
+                        .for_target(AnimationTarget::Single(x))
+                        .update_foreground_color(color)
+                        .then();
+                }
+                step_builder.build()
+            })
+            .collect::<Result<_, _>>()?;
+
+        AnimationStyle::new(value.repeat_mode, value.advance_mode, steps)
+    }
+}
+
+// The pace of the rainbow is set by its speed rather than by a
+// duration.
+impl_animation_preset!(RainbowAnimationStyleBuilder, "rainbow", |_| None);
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use caponata_common::AnimationGroup;
+    use ratatui::style::Color;
+
+    use super::RainbowAnimationStyleBuilder;
+    use crate::{
+        Animation,
+        AnimationStyle,
+        SmallTextStyleBuilder,
+        SmallTextWidget,
+    };
+
+    #[test]
+    fn rainbow_rotates_hues() {
+        let text_style =
+            SmallTextStyleBuilder::default().with_text("rgb").build();
+        let animation_style: AnimationStyle =
+            RainbowAnimationStyleBuilder::default()
+                .with_text_style(&text_style)
+                .with_phase_offset(120.0)
+                .with_speed(360.0)
+                .with_step_count(3u16)
+                .build()
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let symbols = SmallTextWidget::new(text_style).symbols().clone();
+        let group = AnimationGroup::new();
+        let mut animation = Animation::new(animation_style, symbols);
+        animation.set_clock(group.clock());
+
+        let red = Color::Rgb(255, 0, 0);
+        let green = Color::Rgb(0, 255, 0);
+        let blue = Color::Rgb(0, 0, 255);
+
+        let frame = animation.next_frame().unwrap();
+        let colors: Vec<Option<Color>> =
+            (0..3).map(|x| frame.symbols[&x].foreground_color).collect();
+        assert_eq!(colors, vec![Some(red), Some(green), Some(blue)]);
+
+        group.advance(Duration::from_secs_f64(1.0 / 3.0));
+        let frame = animation.next_frame().unwrap();
+        assert_eq!(frame.symbols[&0].foreground_color, Some(green));
+    }
+}
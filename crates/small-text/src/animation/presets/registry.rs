@@ -0,0 +1,318 @@
+use std::{
+    fmt,
+    sync::Arc,
+    time::Duration,
+};
+
+use ratatui::style::Color;
+
+use crate::{
+    AnimationPreset,
+    AnimationRepeatMode,
+    AnimationStyle,
+    AnimationStyleError,
+    BounceAnimationStyleBuilder,
+    FadeInAnimationStyleBuilder,
+    FadeOutAnimationStyleBuilder,
+    GlitchAnimationStyleBuilder,
+    RainbowAnimationStyleBuilder,
+    ScannerAnimationStyleBuilder,
+    SparkleAnimationStyleBuilder,
+    TextContext,
+    TickerAnimationStyleBuilder,
+    WaveAnimationStyleBuilder,
+};
+
+/// An ordered collection of [`AnimationPreset`]s identified by
+/// their names, so applications can list the available presets,
+/// e.g. in a settings menu, and add their own ones.
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_small_text::{
+///     AnimationPresetRegistry,
+///     SmallTextStyleBuilder,
+///     TextContext,
+/// };
+///
+/// let registry = AnimationPresetRegistry::builtin();
+/// assert!(registry.names().any(|name| name == "scanner"));
+///
+/// let text_style = SmallTextStyleBuilder::default().with_text("Hi").build();
+/// let animation_style =
+///     registry.style("scanner", &TextContext::new(&text_style));
+/// assert!(animation_style.is_some_and(|result| result.is_ok()));
+/// ```
+#[derive(Clone, Default)]
+pub struct AnimationPresetRegistry {
+    presets: Vec<Arc<dyn AnimationPreset>>,
+}
+
+impl AnimationPresetRegistry {
+    /// Returns an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a registry of all built-in presets, played
+    /// indefinitely with a step of 100 ms.
+    pub fn builtin() -> Self {
+        let duration = Duration::from_millis(100);
+        let repeat_mode = AnimationRepeatMode::Infinite;
+
+        let mut registry = Self::new();
+        registry.register(
+            TickerAnimationStyleBuilder::default()
+                .with_duration(duration)
+                .with_repeat_mode(repeat_mode)
+                .clone(),
+        );
+        registry.register(
+            ScannerAnimationStyleBuilder::default()
+                .with_duration(duration)
+                .with_foreground_color(Color::Yellow)
+                .with_repeat_mode(repeat_mode)
+                .clone(),
+        );
+        registry.register(
+            WaveAnimationStyleBuilder::default()
+                .with_duration(duration)
+                .with_foreground_color(Color::Cyan)
+                .with_repeat_mode(repeat_mode)
+                .clone(),
+        );
+        registry.register(
+            FadeInAnimationStyleBuilder::default()
+                .with_duration(duration * 10)
+                .with_repeat_mode(repeat_mode)
+                .clone(),
+        );
+        registry.register(
+            FadeOutAnimationStyleBuilder::default()
+                .with_duration(duration * 10)
+                .with_repeat_mode(repeat_mode)
+                .clone(),
+        );
+        registry.register(
+            RainbowAnimationStyleBuilder::default()
+                .with_repeat_mode(repeat_mode)
+                .clone(),
+        );
+        registry.register(
+            GlitchAnimationStyleBuilder::default()
+                .with_duration(duration * 8)
+                .with_repeat_mode(repeat_mode)
+                .clone(),
+        );
+        registry.register(
+            SparkleAnimationStyleBuilder::default()
+                .with_duration(duration * 16)
+                .with_repeat_mode(repeat_mode)
+                .clone(),
+        );
+        registry.register(
+            BounceAnimationStyleBuilder::default()
+                .with_duration(duration * 2)
+                .with_stagger(duration)
+                .with_repeat_mode(repeat_mode)
+                .clone(),
+        );
+
+        registry
+    }
+
+    /// Adds provided preset to the end of the registry, or
+    /// replaces the preset with the same name in place.
+    pub fn register(&mut self, preset: impl AnimationPreset + 'static) {
+        let preset: Arc<dyn AnimationPreset> = Arc::new(preset);
+        match self.position(preset.name()) {
+            Some(index) => self.presets[index] = preset,
+            None => self.presets.push(preset),
+        }
+    }
+
+    /// Removes the preset with provided name. Returns `false`
+    /// if there is no such preset.
+    pub fn unregister(&mut self, name: &str) -> bool {
+        match self.position(name) {
+            Some(index) => {
+                self.presets.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn AnimationPreset> {
+        self.position(name)
+            .map(|index| self.presets[index].as_ref())
+    }
+
+    /// Returns the presets in the order they were registered.
+    pub fn presets(&self) -> impl Iterator<Item = &dyn AnimationPreset> {
+        self.presets.iter().map(Arc::as_ref)
+    }
+
+    /// Returns the names of the presets in the order they were
+    /// registered.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.presets().map(AnimationPreset::name)
+    }
+
+    /// Returns the validated animation style of the preset with
+    /// provided name for provided text, or `None` if there is
+    /// no such preset. Targets selecting positions outside of
+    /// the text are reported too.
+    pub fn style(
+        &self,
+        name: &str,
+        context: &TextContext,
+    ) -> Option<Result<AnimationStyle, AnimationStyleError>> {
+        let width = context.text_style.transformed_text().chars().count();
+
+        self.get(name).map(|preset| {
+            let animation_style = preset.into_style(context)?;
+            animation_style.validate_targets(width as u16)?;
+            Ok(animation_style)
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.presets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.presets.is_empty()
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.presets.iter().position(|preset| preset.name() == name)
+    }
+}
+
+impl fmt::Debug for AnimationPresetRegistry {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.debug_list().entries(self.names()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use ratatui::style::Modifier;
+
+    use super::AnimationPresetRegistry;
+    use crate::{
+        AnimationAdvanceMode,
+        AnimationPreset,
+        AnimationPresetParams,
+        AnimationRepeatMode,
+        AnimationStepBuilder,
+        AnimationStyle,
+        AnimationStyleError,
+        AnimationTarget,
+        SmallTextStyleBuilder,
+        TextContext,
+        TickerAnimationStyleBuilder,
+        WaveAnimationStyleBuilder,
+    };
+
+    struct BlinkPreset;
+
+    impl AnimationPreset for BlinkPreset {
+        fn name(&self) -> &str {
+            "blink"
+        }
+
+        fn default_params(&self) -> AnimationPresetParams {
+            AnimationPresetParams::default()
+        }
+
+        fn into_style(
+            &self,
+            _: &TextContext,
+        ) -> Result<AnimationStyle, AnimationStyleError> {
+            let step = AnimationStepBuilder::default()
+                .with_duration(Duration::from_millis(100))
+                .for_target(AnimationTarget::Untouched)
+                .add_modifier(Modifier::SLOW_BLINK)
+                .then()
+                .build()?;
+            AnimationStyle::new(
+                AnimationRepeatMode::Infinite,
+                AnimationAdvanceMode::Auto,
+                vec![step],
+            )
+        }
+    }
+
+    #[test]
+    fn presets_are_registered_by_name() {
+        let mut registry = AnimationPresetRegistry::builtin();
+        assert_eq!(
+            registry.names().collect::<Vec<_>>(),
+            vec![
+                "ticker", "scanner", "wave", "fade_in", "fade_out", "rainbow",
+                "glitch", "sparkle", "bounce",
+            ],
+        );
+
+        let text_style = SmallTextStyleBuilder::default()
+            .with_text("caponata")
+            .build();
+        let context = TextContext::new(&text_style);
+        for name in registry.names() {
+            assert!(registry.style(name, &context).unwrap().is_ok());
+        }
+
+        registry.register(
+            TickerAnimationStyleBuilder::default()
+                .with_duration(Duration::from_millis(50))
+                .clone(),
+        );
+        assert_eq!(registry.len(), 9);
+        assert_eq!(
+            registry.get("ticker").unwrap().default_params(),
+            AnimationPresetParams {
+                duration: Some(Duration::from_millis(50)),
+                ..AnimationPresetParams::default()
+            },
+        );
+        assert_eq!(
+            registry.get("rainbow").unwrap().default_params(),
+            AnimationPresetParams {
+                duration: None,
+                repeat_mode: AnimationRepeatMode::Infinite,
+                ..AnimationPresetParams::default()
+            },
+        );
+
+        registry.register(BlinkPreset);
+        assert!(registry.style("blink", &context).is_some());
+        assert!(registry.unregister("blink"));
+        assert!(registry.style("blink", &context).is_none());
+    }
+
+    #[test]
+    fn presets_are_validated() {
+        let mut registry = AnimationPresetRegistry::new();
+        registry.register(WaveAnimationStyleBuilder::default());
+
+        let text_style =
+            SmallTextStyleBuilder::default().with_text("Hi").build();
+        let context = TextContext::new(&text_style);
+        assert!(registry.style("wave", &context).unwrap().is_ok());
+
+        registry.register(
+            WaveAnimationStyleBuilder::default()
+                .with_duration(Duration::ZERO)
+                .clone(),
+        );
+        assert_eq!(
+            registry.style("wave", &context),
+            Some(Err(AnimationStyleError::ZeroDurationWithAutoAdvance)),
+        );
+    }
+}
@@ -1,10 +1,12 @@
 use std::{
     collections::HashMap,
-    sync::Arc,
     time::Duration,
 };
 
-use caponata_common::Callable;
+use caponata_common::{
+    Callable,
+    interpolate_color,
+};
 use derive_builder::Builder;
 use ratatui::style::Color;
 
@@ -14,20 +16,41 @@ use crate::{
     AnimationStep,
     AnimationStepBuilder,
     AnimationStyle,
-    AnimationStyleBuilder,
+    AnimationStyleError,
     SmallTextStyle,
     StepSymbolState,
     Symbol,
-    SymbolStyleBuilder,
-    create_symbols,
 };
 
+use super::{
+    DEFAULT_STEP_DURATION,
+    impl_animation_preset,
+    staggered_steps,
+};
+
+/// Specifies where the highlight of the scanner animation goes
+/// once it reaches the end of the text.
+///
+/// Default variant is [`ScannerEndMode::Bounce`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScannerEndMode {
+    /// The highlight turns back and moves towards the
+    /// beginning of the text.
+    #[default]
+    Bounce,
+
+    /// The highlight reappears at the beginning of the text,
+    /// while the rest of it and its trail are still leaving
+    /// the end.
+    Wrap,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Builder)]
 #[builder(setter(prefix = "with", into, strip_option))]
 pub struct ScannerAnimationStyle<'a> {
     text_style: &'a SmallTextStyle<'a>,
 
-    #[builder(default)]
+    #[builder(default = "DEFAULT_STEP_DURATION")]
     duration: Duration,
 
     #[builder(default)]
@@ -36,157 +59,176 @@ pub struct ScannerAnimationStyle<'a> {
     #[builder(default)]
     background_color: Option<Color>,
 
+    /// Number of symbols highlighted at once. Zero is treated
+    /// as one.
+    #[builder(default = "1")]
+    head_width: u16,
+
+    /// Number of symbols behind the highlighted ones, whose
+    /// colors are interpolated from the highlight colors to
+    /// their own colors.
+    #[builder(default)]
+    trail_length: u16,
+
+    #[builder(default)]
+    end_mode: ScannerEndMode,
+
     #[builder(default)]
     advance_mode: AnimationAdvanceMode,
 
     #[builder(default)]
     repeat_mode: AnimationRepeatMode,
-}
-
-impl<'a> Into<AnimationStyle> for ScannerAnimationStyle<'a> {
-    fn into(self) -> AnimationStyle {
-        let mut steps: Vec<AnimationStep> = Vec::new();
 
-        let foreground_color = self.foreground_color;
-        let background_color = self.background_color;
+    /// Time to wait before the first step, e.g. to start
+    /// animations of several texts one after another.
+    #[builder(default)]
+    start_delay: Duration,
+
+    /// Time between the starts of the highlights of
+    /// neighbouring symbols. If it is shorter than the
+    /// duration, several symbols are highlighted at once.
+    /// By default, the next symbol is highlighted once the
+    /// previous one has lasted the duration. The head width,
+    /// the trail and the end mode are not used with the
+    /// stagger.
+    #[builder(default)]
+    stagger: Option<Duration>,
+}
 
-        let text_symbols = create_symbols(
-            self.text_style.text,
-            self.text_style.symbol_styles.clone(),
-        );
-        let text_char_count = self.text_style.text.chars().count() as u16;
+impl<'a> TryFrom<ScannerAnimationStyle<'a>> for AnimationStyle {
+    type Error = AnimationStyleError;
 
-        for x in 0..text_char_count {
-            let symbols = text_symbols.clone();
+    fn try_from(
+        value: ScannerAnimationStyle<'a>,
+    ) -> Result<Self, Self::Error> {
+        let mut steps: Vec<AnimationStep> = Vec::new();
 
-            let on_before_finish =
-                move |(step_states,): (HashMap<u16, StepSymbolState>,)| {
-                    if step_states.is_empty() {
-                        return HashMap::new();
-                    }
-                    let mut updated_symbols = HashMap::new();
+        let foreground_color = value.foreground_color;
+        let background_color = value.background_color;
+
+        let text_symbols = value.text_style.symbols();
+        let text_char_count =
+            value.text_style.transformed_text().chars().count() as u16;
+
+        if let Some(stagger) = value.stagger {
+            let positions: Vec<u16> = (0..text_char_count)
+                .chain((1..text_char_count.saturating_sub(1)).rev())
+                .collect();
+            let highlight = move |mut symbol: Symbol, _| {
+                symbol.foreground_color =
+                    foreground_color.or(symbol.foreground_color);
+                symbol.background_color =
+                    background_color.or(symbol.background_color);
+                symbol
+            };
+            let steps = staggered_steps(
+                &positions,
+                text_symbols,
+                stagger,
+                value.duration,
+                Duration::ZERO,
+                highlight,
+            )?;
+            return value.make_animation_style(steps);
+        }
 
-                    let current_symbol = if let Some(symbol) = symbols.get(&x)
-                    {
-                        symbol
-                    } else {
-                        return HashMap::new();
+        let head_width = value.head_width.max(1) as i32;
+        let trail_length = value.trail_length as i32;
+        let highlight_length =
+            (head_width + trail_length).min(text_char_count as i32);
+        let end_mode = value.end_mode;
+        let sweeps: Vec<(u16, i32)> = match end_mode {
+            ScannerEndMode::Bounce => (0..text_char_count)
+                .map(|x| (x, 1))
+                .chain(
+                    (1..text_char_count.saturating_sub(1))
+                        .rev()
+                        .map(|x| (x, -1)),
+                )
+                .collect(),
+            ScannerEndMode::Wrap => {
+                (0..text_char_count).map(|x| (x, 1)).collect()
+            }
+        };
+
+        for (head_x, direction) in sweeps {
+            // Weights of the highlight, from the head towards the
+            // end of the trail, which fades out evenly.
+            let weights: Vec<(u16, f64)> = (0..highlight_length)
+                .filter_map(|i| {
+                    let x = head_x as i32 - direction * i;
+                    let x = match end_mode {
+                        ScannerEndMode::Bounce => x,
+                        ScannerEndMode::Wrap => {
+                            x.rem_euclid(text_char_count as i32)
+                        }
                     };
-
-                    let scanned_symbol_foreground_color = foreground_color
-                        .unwrap_or(current_symbol.foreground_color);
-                    let scanned_symbol_background_color = background_color
-                        .unwrap_or(current_symbol.background_color);
-                    let scanned_symbol_style = SymbolStyleBuilder::default()
-                        .with_foreground_color(scanned_symbol_foreground_color)
-                        .with_background_color(scanned_symbol_background_color)
-                        .with_modifier(current_symbol.modifier)
-                        .build()
-                        .unwrap();
-
-                    let scanned_symbol = Symbol::new(
-                        current_symbol.value,
-                        scanned_symbol_style,
-                    );
-                    updated_symbols.insert(x, scanned_symbol);
-
-                    if x == 0 {
-                        return updated_symbols;
-                    }
-
-                    let old_scanned_symbol_x = x.saturating_sub(1);
-
-                    let old_scanned_symbol = if let Some(symbol) =
-                        symbols.get(&old_scanned_symbol_x)
-                    {
-                        symbol
-                    } else {
-                        return HashMap::new();
+                    let weight = match i < head_width {
+                        true => 1.0,
+                        false => {
+                            let trail_index = i - head_width + 1;
+                            1.0 - trail_index as f64
+                                / (trail_length + 1) as f64
+                        }
                     };
-                    updated_symbols
-                        .insert(old_scanned_symbol_x, *old_scanned_symbol);
-
-                    updated_symbols
-                };
+                    u16::try_from(x).ok().map(|x| (x, weight))
+                })
+                .collect();
 
-            let on_before_finish = Arc::new(on_before_finish);
-            let on_before_finish = Callable::new(on_before_finish);
-
-            let step = AnimationStepBuilder::default()
-                .with_duration(self.duration)
-                .with_before_finish_callback(on_before_finish)
-                .build();
-            steps.push(step);
-        }
-
-        for x in (1..text_char_count.saturating_sub(1)).rev() {
             let symbols = text_symbols.clone();
-
             let on_before_finish =
-                move |(step_states,): (HashMap<u16, StepSymbolState>,)| {
-                    if step_states.is_empty() {
-                        return HashMap::new();
+                move |(_,): (HashMap<u16, StepSymbolState>,)| {
+                    let mut updated_symbols = symbols.clone();
+                    for (x, weight) in weights.iter() {
+                        let Some(symbol) = updated_symbols.get_mut(x) else {
+                            continue;
+                        };
+                        let blend = |highlight_color: Option<Color>,
+                                     color: Option<Color>| {
+                            let Some(highlight_color) = highlight_color else {
+                                return color;
+                            };
+                            match *weight >= 1.0 {
+                                true => Some(highlight_color),
+                                false => Some(interpolate_color(
+                                    color.unwrap_or_default(),
+                                    highlight_color,
+                                    *weight,
+                                )),
+                            }
+                        };
+                        symbol.foreground_color =
+                            blend(foreground_color, symbol.foreground_color);
+                        symbol.background_color =
+                            blend(background_color, symbol.background_color);
                     }
-                    let mut updated_symbols = HashMap::new();
-
-                    let current_symbol = if let Some(symbol) = symbols.get(&x)
-                    {
-                        symbol
-                    } else {
-                        return HashMap::new();
-                    };
-
-                    let scanned_symbol_foreground_color = foreground_color
-                        .unwrap_or(current_symbol.foreground_color);
-                    let scanned_symbol_background_color = background_color
-                        .unwrap_or(current_symbol.background_color);
-                    let scanned_symbol_style = SymbolStyleBuilder::default()
-                        .with_foreground_color(scanned_symbol_foreground_color)
-                        .with_background_color(scanned_symbol_background_color)
-                        .with_modifier(current_symbol.modifier)
-                        .build()
-                        .unwrap();
-
-                    let scanned_symbol = Symbol::new(
-                        current_symbol.value,
-                        scanned_symbol_style,
-                    );
-                    updated_symbols.insert(x, scanned_symbol);
-
-                    if x == text_char_count.saturating_sub(1) {
-                        return updated_symbols;
-                    }
-
-                    let old_scanned_symbol_x = x.saturating_add(1);
-
-                    let old_scanned_symbol = if let Some(symbol) =
-                        symbols.get(&old_scanned_symbol_x)
-                    {
-                        symbol
-                    } else {
-                        return HashMap::new();
-                    };
-                    updated_symbols
-                        .insert(old_scanned_symbol_x, *old_scanned_symbol);
-
                     updated_symbols
                 };
 
-            let on_before_finish = Arc::new(on_before_finish);
-            let on_before_finish = Callable::new(on_before_finish);
-
             let step = AnimationStepBuilder::default()
-                .with_duration(self.duration)
-                .with_before_finish_callback(on_before_finish)
-                .build();
+                .with_duration(value.duration)
+                .with_before_finish_callback(Callable::from_fn(
+                    on_before_finish,
+                ))
+                .build()?;
             steps.push(step);
         }
 
-        AnimationStyleBuilder::default()
-            .with_advance_mode(self.advance_mode)
-            .with_repeat_mode(self.repeat_mode)
-            .with_steps(steps)
-            .build()
-            .unwrap()
+        value.make_animation_style(steps)
+    }
+}
+
+impl<'a> ScannerAnimationStyle<'a> {
+    fn make_animation_style(
+        &self,
+        steps: Vec<AnimationStep>,
+    ) -> Result<AnimationStyle, AnimationStyleError> {
+        let mut animation_style =
+            AnimationStyle::new(self.repeat_mode, self.advance_mode, steps)?;
+        animation_style.start_delay = self.start_delay;
+
+        Ok(animation_style)
     }
 }
+
+impl_animation_preset!(ScannerAnimationStyleBuilder, "scanner");
@@ -0,0 +1,229 @@
+use std::{
+    collections::HashMap,
+    time::Duration,
+};
+
+use caponata_common::Callable;
+use derive_builder::Builder;
+use fastrand::Rng;
+use ratatui::style::{
+    Color,
+    Modifier,
+};
+
+use crate::{
+    AnimationAdvanceMode,
+    AnimationRepeatMode,
+    AnimationStep,
+    AnimationStepBuilder,
+    AnimationStyle,
+    AnimationStyleError,
+    SmallTextStyle,
+    StepSymbolState,
+};
+
+use super::{
+    DEFAULT_STEP_DURATION,
+    impl_animation_preset,
+};
+
+/// A styling configuration for the sparkle animation, which
+/// briefly brightens randomly selected symbols.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::style::Color;
+/// use caponata_small_text::{
+///     AnimationStyle,
+///     SmallTextStyleBuilder,
+///     SparkleAnimationStyleBuilder,
+/// };
+///
+/// let text_style = SmallTextStyleBuilder::default()
+///     .with_text("Sparkling text")
+///     .build();
+/// let animation_style: AnimationStyle =
+///     SparkleAnimationStyleBuilder::default()
+///         .with_text_style(&text_style)
+///         .with_duration(Duration::from_millis(80))
+///         .with_density(0.2)
+///         .with_color(Color::LightYellow)
+///         .with_decay(2u16)
+///         .with_seed(42u64)
+///         .build()
+///         .unwrap()
+///         .try_into()
+///         .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Builder)]
+#[builder(setter(prefix = "with", into, strip_option))]
+pub struct SparkleAnimationStyle<'a> {
+    text_style: &'a SmallTextStyle<'a>,
+
+    #[builder(default = "DEFAULT_STEP_DURATION")]
+    duration: Duration,
+
+    /// Share of the symbols that sparkle on every step, in
+    /// the `0.0..=1.0` range.
+    #[builder(default = "0.1")]
+    density: f64,
+
+    /// Foreground color of the sparkling symbols.
+    #[builder(default = "Color::White")]
+    color: Color,
+
+    /// Number of steps a sparkle stays dimmed after it
+    /// appears before the symbol gets its original style.
+    #[builder(default = "1")]
+    decay: u16,
+
+    /// Number of steps in a single iteration of the
+    /// animation. Sparkles are selected for every step
+    /// beforehand, so the iteration repeats the same pattern.
+    #[builder(default = "16")]
+    step_count: u16,
+
+    /// Seed of the random number generator selecting the
+    /// sparkles. The same seed always produces the same
+    /// pattern. A random seed is used by default.
+    #[builder(default)]
+    seed: Option<u64>,
+
+    #[builder(default)]
+    advance_mode: AnimationAdvanceMode,
+
+    #[builder(default)]
+    repeat_mode: AnimationRepeatMode,
+}
+
+impl<'a> TryFrom<SparkleAnimationStyle<'a>> for AnimationStyle {
+    type Error = AnimationStyleError;
+
+    fn try_from(
+        value: SparkleAnimationStyle<'a>,
+    ) -> Result<Self, Self::Error> {
+        let text_symbols = value.text_style.symbols();
+        let text_char_count =
+            value.text_style.transformed_text().chars().count();
+
+        let mut rng = match value.seed {
+            Some(seed) => Rng::with_seed(seed),
+            None => Rng::new(),
+        };
+        let sparkle_count = (text_char_count as f64
+            * value.density.clamp(0.0, 1.0))
+        .round() as usize;
+        let sparkles: Vec<Vec<u16>> = (0..value.step_count.max(1))
+            .map(|_| {
+                rng.choose_multiple(0..text_char_count as u16, sparkle_count)
+            })
+            .collect();
+
+        let mut steps: Vec<AnimationStep> = Vec::new();
+        for step_index in 0..sparkles.len() {
+            // Sparkles of the previous steps are dimmed. Newer
+            // sparkles override older ones at the same position.
+            let mut sparkle_ages: HashMap<u16, u16> = HashMap::new();
+            for age in (0..=value.decay).rev() {
+                let sparkle_step_index = (step_index + sparkles.len()
+                    - age as usize % sparkles.len())
+                    % sparkles.len();
+                for x in sparkles[sparkle_step_index].iter() {
+                    sparkle_ages.insert(*x, age);
+                }
+            }
+
+            let symbols = text_symbols.clone();
+            let color = value.color;
+            let on_before_finish =
+                move |(_,): (HashMap<u16, StepSymbolState>,)| {
+                    symbols
+                        .iter()
+                        .map(|(x, symbol)| {
+                            let mut symbol = *symbol;
+                            if let Some(age) = sparkle_ages.get(x) {
+                                let modifier = if *age == 0 {
+                                    Modifier::BOLD
+                                } else {
+                                    Modifier::DIM
+                                };
+                                symbol.foreground_color = Some(color);
+                                symbol.modifier =
+                                    symbol.modifier.union(modifier);
+                            }
+                            (*x, symbol)
+                        })
+                        .collect()
+                };
+            let on_before_finish = Callable::from_fn(on_before_finish);
+
+            let step = AnimationStepBuilder::default()
+                .with_duration(value.duration)
+                .with_before_finish_callback(on_before_finish)
+                .build()?;
+            steps.push(step);
+        }
+
+        AnimationStyle::new(value.repeat_mode, value.advance_mode, steps)
+    }
+}
+
+impl_animation_preset!(SparkleAnimationStyleBuilder, "sparkle");
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use ratatui::style::Modifier;
+
+    use super::SparkleAnimationStyleBuilder;
+    use crate::{
+        AnimationStyle,
+        SmallTextStyleBuilder,
+    };
+
+    fn sparkling_positions(animation_style: &AnimationStyle) -> Vec<Vec<u16>> {
+        animation_style
+            .steps
+            .iter()
+            .map(|step| {
+                let on_before_finish = step.on_before_finish.as_ref().unwrap();
+                let mut positions: Vec<u16> = on_before_finish
+                    .call((HashMap::new(),))
+                    .into_iter()
+                    .filter(|(_, symbol)| symbol.modifier == Modifier::BOLD)
+                    .map(|(x, _)| x)
+                    .collect();
+                positions.sort();
+                positions
+            })
+            .collect()
+    }
+
+    #[test]
+    fn seeded_sparkles() {
+        let text_style = SmallTextStyleBuilder::default()
+            .with_text("0123456789")
+            .build();
+        let make_animation_style = || -> AnimationStyle {
+            SparkleAnimationStyleBuilder::default()
+                .with_text_style(&text_style)
+                .with_density(0.3)
+                .with_decay(0u16)
+                .with_step_count(4u16)
+                .with_seed(7u64)
+                .build()
+                .unwrap()
+                .try_into()
+                .unwrap()
+        };
+
+        let positions = sparkling_positions(&make_animation_style());
+        assert_eq!(positions.len(), 4);
+        assert!(positions.iter().all(|positions| positions.len() == 3));
+        assert_eq!(positions, sparkling_positions(&make_animation_style()));
+    }
+}
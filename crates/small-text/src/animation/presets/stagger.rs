@@ -0,0 +1,161 @@
+use std::{
+    collections::HashMap,
+    time::Duration,
+};
+
+use caponata_common::Callable;
+
+use crate::{
+    AnimationStep,
+    AnimationStepBuilder,
+    AnimationStyleError,
+    StepSymbolState,
+    Symbol,
+};
+
+/// A phase of the highlight of a single symbol in a staggered
+/// animation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum HighlightPhase {
+    Head,
+    Tail,
+}
+
+/// Returns steps highlighting symbols at provided positions
+/// one after another. The highlight of the n-th position
+/// starts `n * stagger` after the beginning of the iteration,
+/// lasts `head_duration` in the head phase and then
+/// `tail_duration` in the tail phase, so highlights of
+/// neighbouring positions overlap if the stagger is shorter
+/// than the highlight.
+pub(crate) fn staggered_steps<F>(
+    positions: &[u16],
+    symbols: HashMap<u16, Symbol>,
+    stagger: Duration,
+    head_duration: Duration,
+    tail_duration: Duration,
+    highlight: F,
+) -> Result<Vec<AnimationStep>, AnimationStyleError>
+where
+    F: Fn(Symbol, HighlightPhase) -> Symbol + Clone + Send + Sync + 'static,
+{
+    let highlights: Vec<(u16, Duration)> = positions
+        .iter()
+        .enumerate()
+        .map(|(i, x)| (*x, stagger * i as u32))
+        .collect();
+
+    let mut boundaries: Vec<Duration> = highlights
+        .iter()
+        .flat_map(|(_, start)| {
+            [
+                *start,
+                *start + head_duration,
+                *start + head_duration + tail_duration,
+            ]
+        })
+        .collect();
+    boundaries.push(Duration::ZERO);
+    boundaries.sort();
+    boundaries.dedup();
+
+    boundaries
+        .windows(2)
+        .map(|window| {
+            let (start, end) = (window[0], window[1]);
+
+            let mut phases: HashMap<u16, HighlightPhase> = HashMap::new();
+            for (x, highlight_start) in highlights.iter() {
+                let phase = if start < *highlight_start {
+                    continue;
+                } else if start < *highlight_start + head_duration {
+                    HighlightPhase::Head
+                } else if start
+                    < *highlight_start + head_duration + tail_duration
+                {
+                    HighlightPhase::Tail
+                } else {
+                    continue;
+                };
+                if phases.get(x) != Some(&HighlightPhase::Head) {
+                    phases.insert(*x, phase);
+                }
+            }
+
+            let symbols = symbols.clone();
+            let highlight = highlight.clone();
+            let on_before_finish =
+                move |(_,): (HashMap<u16, StepSymbolState>,)| {
+                    symbols
+                        .iter()
+                        .map(|(x, symbol)| match phases.get(x) {
+                            Some(phase) => (*x, highlight(*symbol, *phase)),
+                            None => (*x, *symbol),
+                        })
+                        .collect()
+                };
+            let on_before_finish = Callable::from_fn(on_before_finish);
+
+            AnimationStepBuilder::default()
+                .with_duration(end - start)
+                .with_before_finish_callback(on_before_finish)
+                .build()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        time::Duration,
+    };
+
+    use ratatui::style::Color;
+
+    use super::staggered_steps;
+    use crate::Symbol;
+
+    #[test]
+    fn overlapping_highlights() {
+        let symbols: HashMap<u16, Symbol> =
+            (0..3).map(|x| (x, Symbol::default())).collect();
+        let highlight = |mut symbol: Symbol, _| {
+            symbol.foreground_color = Some(Color::Red);
+            symbol
+        };
+        let steps = staggered_steps(
+            &[0, 1, 2],
+            symbols,
+            Duration::from_millis(50),
+            Duration::from_millis(100),
+            Duration::ZERO,
+            highlight,
+        )
+        .unwrap();
+
+        let highlighted_positions: Vec<Vec<u16>> = steps
+            .iter()
+            .map(|step| {
+                assert_eq!(step.duration, Duration::from_millis(50));
+
+                let on_before_finish = step.on_before_finish.as_ref().unwrap();
+                let mut positions: Vec<u16> = on_before_finish
+                    .call((HashMap::new(),))
+                    .into_iter()
+                    .filter(|(_, symbol)| {
+                        symbol.foreground_color == Some(Color::Red)
+                    })
+                    .map(|(x, _)| x)
+                    .collect();
+                positions.sort();
+                positions
+            })
+            .collect();
+
+        assert_eq!(
+            highlighted_positions,
+            vec![vec![0], vec![0, 1], vec![1, 2], vec![2]],
+        );
+    }
+}
@@ -1,6 +1,5 @@
 use std::{
     collections::HashMap,
-    sync::Arc,
     time::Duration,
 };
 
@@ -10,13 +9,21 @@ use derive_builder::Builder;
 use crate::{
     AnimationAdvanceMode,
     AnimationRepeatMode,
+    AnimationStep,
     AnimationStepBuilder,
     AnimationStyle,
-    AnimationStyleBuilder,
+    AnimationStyleError,
+    AnimationTranslateMode,
+    SmallTextStyle,
     StepSymbolState,
     Symbol,
 };
 
+use super::{
+    DEFAULT_STEP_DURATION,
+    impl_animation_preset,
+};
+
 /// Direction of the ticker animation movement.
 ///
 /// Default variant is [`TickerAnimationDirection::Forward`].
@@ -38,29 +45,61 @@ pub enum TickerAnimationDirection {
 ///     AnimationStyle,
 ///     AnimationAdvanceMode,
 ///     AnimationRepeatMode,
+///     SmallTextStyleBuilder,
 ///     TickerAnimationDirection,
 ///     TickerAnimationStyleBuilder,
 /// };
 ///
+/// let text_style =
+///     SmallTextStyleBuilder::default().with_text("Breaking news").build();
 /// let animation_style: AnimationStyle =
 ///     TickerAnimationStyleBuilder::default()
-///         .with_direction(TickerDirection::Forward)
+///         .with_text_style(&text_style)
+///         .with_direction(TickerAnimationDirection::Backward)
 ///         .with_duration(Duration::from_millis(100))
+///         .with_gap(" +++ ")
+///         .with_start_pause(Duration::from_secs(1))
 ///         .with_advance_mode(AnimationAdvanceMode::Auto)
 ///         .with_repeat_mode(AnimationRepeatMode::Infinite)
 ///         .build()
 ///         .unwrap()
-///         .into();
+///         .try_into()
+///         .unwrap();
 /// ```
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Builder)]
-#[builder(setter(prefix = "with", into))]
-pub struct TickerAnimationStyle {
+#[builder(
+    setter(prefix = "with", into),
+    build_fn(validate = "Self::validate")
+)]
+pub struct TickerAnimationStyle<'a> {
+    /// The style of the moved text. It is required for the gap
+    /// and the start pause, since they depend on the length of
+    /// the text.
+    #[builder(default, setter(strip_option))]
+    text_style: Option<&'a SmallTextStyle<'a>>,
+
     #[builder(default)]
     direction: TickerAnimationDirection,
 
-    #[builder(default)]
+    /// Duration of a single step.
+    #[builder(default = "DEFAULT_STEP_DURATION")]
     duration: Duration,
 
+    /// Symbols inserted between the end of the text and its
+    /// beginning once it wraps around, e.g. `" • "`.
+    #[builder(default)]
+    gap: &'a str,
+
+    /// Time the text stays at its start position, in addition
+    /// to the duration of the step, before every pass.
+    #[builder(default)]
+    start_pause: Duration,
+
+    /// Number of cells the text is moved by on every step.
+    /// Zero is treated as one.
+    #[builder(default = "1")]
+    step_size: u16,
+
     #[builder(default)]
     advance_mode: AnimationAdvanceMode,
 
@@ -68,50 +107,106 @@ pub struct TickerAnimationStyle {
     repeat_mode: AnimationRepeatMode,
 }
 
-impl Into<AnimationStyle> for TickerAnimationStyle {
-    fn into(self) -> AnimationStyle {
-        let on_before_finish =
-            move |(step_states,): (HashMap<u16, StepSymbolState>,)| {
-                if step_states.is_empty() {
-                    return HashMap::new();
-                }
-
-                let mut symbols: Vec<(u16, Symbol)> = step_states
-                    .into_iter()
-                    .map(|(x, state)| (x, state.symbol()))
-                    .collect();
-                symbols.sort_by(|a, b| a.0.cmp(&b.0));
-
-                if self.direction == TickerAnimationDirection::Forward {
-                    let last_symbol_index = symbols.iter().count() - 1;
-                    let last_symbol = symbols.remove(last_symbol_index);
-                    symbols.insert(0, last_symbol);
-                } else {
-                    let first_symbol = symbols.remove(0);
-                    symbols.push(first_symbol);
-                }
-
-                let mut updated_symbols: HashMap<u16, Symbol> = HashMap::new();
-                for (new_x, (_, symbol)) in symbols.iter().enumerate() {
-                    updated_symbols.insert(new_x as u16, *symbol);
-                }
-
-                updated_symbols
-            };
-
-        let on_before_finish = Arc::new(on_before_finish);
-        let on_before_finish = Callable::new(on_before_finish);
-
-        let step = AnimationStepBuilder::default()
-            .with_duration(self.duration)
-            .with_before_finish_callback(on_before_finish)
-            .build();
-
-        return AnimationStyleBuilder::default()
-            .with_advance_mode(self.advance_mode)
-            .with_repeat_mode(self.repeat_mode)
-            .with_steps(vec![step])
-            .build()
-            .unwrap();
+impl<'a> TickerAnimationStyleBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        let has_text_style = self.text_style.flatten().is_some();
+        let has_gap = self.gap.is_some_and(|gap| !gap.is_empty());
+        let has_start_pause =
+            self.start_pause.is_some_and(|pause| !pause.is_zero());
+
+        if !has_text_style && (has_gap || has_start_pause) {
+            return Err(
+                "`text_style` is required for `gap` and `start_pause`"
+                    .to_string(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<TickerAnimationStyle<'a>> for AnimationStyle {
+    type Error = AnimationStyleError;
+
+    fn try_from(value: TickerAnimationStyle<'a>) -> Result<Self, Self::Error> {
+        let step_size = value.step_size.max(1) as i16;
+        let shift = match value.direction {
+            TickerAnimationDirection::Forward => step_size,
+            TickerAnimationDirection::Backward => -step_size,
+        };
+
+        let text_style = match value.text_style {
+            Some(text_style)
+                if !value.gap.is_empty() || !value.start_pause.is_zero() =>
+            {
+                text_style
+            }
+            // Without the gap and the pause, wrapping the symbols
+            // of the text is enough.
+            _ => {
+                let step = AnimationStepBuilder::default()
+                    .with_duration(value.duration)
+                    .with_shift(shift)
+                    .with_translate_mode(AnimationTranslateMode::Wrap)
+                    .build()?;
+                return AnimationStyle::new(
+                    value.repeat_mode,
+                    value.advance_mode,
+                    vec![step],
+                );
+            }
+        };
+
+        let text_symbols = text_style.symbols();
+        let text_char_count = text_symbols.len() as u16;
+        let gap_symbols = value.gap.chars().map(|character| Symbol {
+            value: character,
+            ..Symbol::default()
+        });
+        let strip: Vec<Symbol> = (0..text_char_count)
+            .map(|x| text_symbols[&x])
+            .chain(gap_symbols)
+            .collect();
+        let strip_len = strip.len() as i64;
+
+        // The text is back at its start position once the moved
+        // distance is a multiple of the length of the strip.
+        let step_count = strip_len / gcd(strip_len, step_size as i64);
+        let steps: Vec<AnimationStep> = (0..step_count)
+            .map(|step_index| {
+                let offset = step_index * shift as i64;
+                let strip = strip.clone();
+                let move_text =
+                    move |(_,): (HashMap<u16, StepSymbolState>,)| {
+                        (0..text_char_count)
+                            .map(|x| {
+                                let index =
+                                    (x as i64 - offset).rem_euclid(strip_len);
+                                (x, strip[index as usize])
+                            })
+                            .collect::<HashMap<u16, Symbol>>()
+                    };
+
+                let duration = match step_index {
+                    0 => value.duration + value.start_pause,
+                    _ => value.duration,
+                };
+                AnimationStepBuilder::default()
+                    .with_duration(duration)
+                    .with_before_finish_callback(Callable::from_fn(move_text))
+                    .build()
+            })
+            .collect::<Result<_, _>>()?;
+
+        AnimationStyle::new(value.repeat_mode, value.advance_mode, steps)
+    }
+}
+
+impl_animation_preset!(TickerAnimationStyleBuilder, "ticker");
+
+fn gcd(a: i64, b: i64) -> i64 {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
     }
 }
@@ -1,6 +1,5 @@
 use std::{
     collections::HashMap,
-    sync::Arc,
     time::Duration,
 };
 
@@ -17,12 +16,18 @@ use crate::{
     AnimationStep,
     AnimationStepBuilder,
     AnimationStyle,
-    AnimationStyleBuilder,
+    AnimationStyleError,
     SmallTextStyle,
     StepSymbolState,
     Symbol,
     SymbolStyleBuilder,
-    create_symbols,
+};
+
+use super::{
+    DEFAULT_STEP_DURATION,
+    HighlightPhase,
+    impl_animation_preset,
+    staggered_steps,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Builder)]
@@ -30,7 +35,7 @@ use crate::{
 pub struct WaveAnimationStyle<'a> {
     text_style: &'a SmallTextStyle<'a>,
 
-    #[builder(default)]
+    #[builder(default = "DEFAULT_STEP_DURATION")]
     duration: Duration,
 
     #[builder(default)]
@@ -44,20 +49,56 @@ pub struct WaveAnimationStyle<'a> {
 
     #[builder(default)]
     repeat_mode: AnimationRepeatMode,
+
+    /// Time to wait before the first step, e.g. to start
+    /// animations of several texts one after another.
+    #[builder(default)]
+    start_delay: Duration,
+
+    /// Time between the starts of the highlights of
+    /// neighbouring symbols. If it is shorter than the
+    /// duration, several symbols are highlighted at once.
+    /// By default, the next symbol is highlighted once the
+    /// previous one has lasted the duration.
+    #[builder(default)]
+    stagger: Option<Duration>,
 }
 
-impl<'a> Into<AnimationStyle> for WaveAnimationStyle<'a> {
-    fn into(self) -> AnimationStyle {
-        let mut steps: Vec<AnimationStep> = Vec::new();
+impl<'a> TryFrom<WaveAnimationStyle<'a>> for AnimationStyle {
+    type Error = AnimationStyleError;
 
-        let foreground_color = self.foreground_color;
-        let background_color = self.background_color;
+    fn try_from(value: WaveAnimationStyle<'a>) -> Result<Self, Self::Error> {
+        let mut steps: Vec<AnimationStep> = Vec::new();
 
-        let text_symbols = create_symbols(
-            self.text_style.text,
-            self.text_style.symbol_styles.clone(),
-        );
-        let text_char_count = self.text_style.text.chars().count() as u16;
+        let foreground_color = value.foreground_color;
+        let background_color = value.background_color;
+
+        let text_symbols = value.text_style.symbols();
+        let text_char_count =
+            value.text_style.transformed_text().chars().count() as u16;
+
+        if let Some(stagger) = value.stagger {
+            let positions: Vec<u16> = (0..text_char_count).collect();
+            let highlight = move |mut symbol: Symbol, phase| {
+                symbol.foreground_color =
+                    foreground_color.or(symbol.foreground_color);
+                symbol.background_color =
+                    background_color.or(symbol.background_color);
+                if phase == HighlightPhase::Tail {
+                    symbol.modifier = symbol.modifier.union(Modifier::DIM);
+                }
+                symbol
+            };
+            let steps = staggered_steps(
+                &positions,
+                text_symbols,
+                stagger,
+                value.duration,
+                stagger,
+                highlight,
+            )?;
+            return value.make_animation_style(steps);
+        }
 
         for x in 0..text_char_count {
             let symbols = text_symbols.clone();
@@ -95,9 +136,9 @@ impl<'a> Into<AnimationStyle> for WaveAnimationStyle<'a> {
                         };
 
                     let head_symbol_foreground_color = foreground_color
-                        .unwrap_or(symbol_at_head_position.foreground_color);
+                        .or(symbol_at_head_position.foreground_color);
                     let head_symbol_background_color = background_color
-                        .unwrap_or(symbol_at_head_position.background_color);
+                        .or(symbol_at_head_position.background_color);
                     let head_symbol_style = SymbolStyleBuilder::default()
                         .with_foreground_color(head_symbol_foreground_color)
                         .with_background_color(head_symbol_background_color)
@@ -133,9 +174,9 @@ impl<'a> Into<AnimationStyle> for WaveAnimationStyle<'a> {
                         };
 
                     let tail_symbol_foreground_color = foreground_color
-                        .unwrap_or(symbol_at_tail_position.foreground_color);
+                        .or(symbol_at_tail_position.foreground_color);
                     let tail_symbol_background_color = background_color
-                        .unwrap_or(symbol_at_tail_position.background_color);
+                        .or(symbol_at_tail_position.background_color);
                     let tail_symbol_modifier =
                         symbol_at_tail_position.modifier.union(Modifier::DIM);
                     let tail_symbol_style = SymbolStyleBuilder::default()
@@ -154,21 +195,30 @@ impl<'a> Into<AnimationStyle> for WaveAnimationStyle<'a> {
                     updated_symbols
                 };
 
-            let on_before_finish = Arc::new(on_before_finish);
-            let on_before_finish = Callable::new(on_before_finish);
+            let on_before_finish = Callable::from_fn(on_before_finish);
 
             let step = AnimationStepBuilder::default()
-                .with_duration(self.duration)
+                .with_duration(value.duration)
                 .with_before_finish_callback(on_before_finish)
-                .build();
+                .build()?;
             steps.push(step);
         }
 
-        AnimationStyleBuilder::default()
-            .with_advance_mode(self.advance_mode)
-            .with_repeat_mode(self.repeat_mode)
-            .with_steps(steps)
-            .build()
-            .unwrap()
+        value.make_animation_style(steps)
     }
 }
+
+impl<'a> WaveAnimationStyle<'a> {
+    fn make_animation_style(
+        &self,
+        steps: Vec<AnimationStep>,
+    ) -> Result<AnimationStyle, AnimationStyleError> {
+        let mut animation_style =
+            AnimationStyle::new(self.repeat_mode, self.advance_mode, steps)?;
+        animation_style.start_delay = self.start_delay;
+
+        Ok(animation_style)
+    }
+}
+
+impl_animation_preset!(WaveAnimationStyleBuilder, "wave");
@@ -1,53 +1,166 @@
+use std::{
+    collections::VecDeque,
+    sync::Arc,
+};
+
 use crate::{
     AnimationRepeatMode,
     AnimationStep,
+    AnimationStyle,
 };
 
 use super::{
     FinitelyRepeatableAnimation,
     InfinitelyRepeatableAnimation,
+    StepRandomizer,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub(crate) enum RepeatableAnimation {
+pub(crate) struct RepeatableAnimation {
+    iterations: Iterations,
+
+    /// Draws the order and the durations of the steps if the
+    /// style enables duration jitter or step shuffling.
+    randomizer: Option<StepRandomizer>,
+
+    /// Remaining steps of the entered branches. The first one
+    /// is displayed in place of the current step.
+    branch_steps: VecDeque<AnimationStep>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Iterations {
     Finitely(FinitelyRepeatableAnimation),
     Infinitely(InfinitelyRepeatableAnimation),
 }
 
 impl<'a> RepeatableAnimation {
     pub fn new(
-        steps: Vec<AnimationStep>,
+        style: Arc<AnimationStyle>,
         repeat_mode: AnimationRepeatMode,
     ) -> Self {
-        match repeat_mode {
+        let randomizer = StepRandomizer::new(&style);
+        let iterations = match repeat_mode {
             AnimationRepeatMode::Finite(max_iteration) => {
                 let animation =
-                    FinitelyRepeatableAnimation::new(steps, max_iteration);
-                Self::Finitely(animation)
+                    FinitelyRepeatableAnimation::new(style, max_iteration);
+                Iterations::Finitely(animation)
             }
             AnimationRepeatMode::Infinite => {
-                let animation = InfinitelyRepeatableAnimation::new(steps);
-                Self::Infinitely(animation)
+                let animation = InfinitelyRepeatableAnimation::new(style);
+                Iterations::Infinitely(animation)
             }
+        };
+
+        Self {
+            iterations,
+            randomizer,
+            branch_steps: VecDeque::new(),
         }
     }
 
     /// Returns the current animation step if the iteration
     /// limit is not reached; otherwise returns `None`.
     pub fn current_step(&'a self) -> Option<AnimationStep> {
-        match self {
-            Self::Finitely(animation) => animation.current_step(),
-            Self::Infinitely(animation) => animation.current_step().into(),
+        let index = self.current_index()?;
+        match self.branch_steps.front() {
+            Some(step) => Some(step.clone()),
+            None => Some(self.step_at(index)),
+        }
+    }
+
+    /// Returns the position of the current step within the
+    /// current iteration if the iteration limit is not
+    /// reached; otherwise returns `None`.
+    pub fn current_index(&self) -> Option<usize> {
+        match &self.iterations {
+            Iterations::Finitely(animation) => animation.current_index(),
+            Iterations::Infinitely(animation) => {
+                animation.current_index().into()
+            }
+        }
+    }
+
+    /// Returns the index of the current iteration, starting
+    /// from zero.
+    pub fn current_iteration(&self) -> u32 {
+        match &self.iterations {
+            Iterations::Finitely(animation) => {
+                animation.current_iteration().into()
+            }
+            Iterations::Infinitely(animation) => animation.current_iteration(),
+        }
+    }
+
+    pub fn steps(&self) -> &[AnimationStep] {
+        match &self.iterations {
+            Iterations::Finitely(animation) => animation.steps(),
+            Iterations::Infinitely(animation) => animation.steps(),
         }
     }
 
+    /// Moves the animation to the first step of the first
+    /// iteration.
+    pub fn reset(&mut self) {
+        match &mut self.iterations {
+            Iterations::Finitely(animation) => animation.reset(),
+            Iterations::Infinitely(animation) => animation.reset(),
+        }
+        self.branch_steps.clear();
+        self.start_iteration();
+    }
+
     /// Advances the animation and returns the current step
     /// if the iteration limit is not reached; otherwise
     /// returns `None`.
     pub fn next_step(&'a mut self) -> Option<AnimationStep> {
-        match self {
-            Self::Finitely(animation) => animation.next_step(),
-            Self::Infinitely(animation) => animation.next_step().into(),
+        if self.branch_steps.pop_front().is_some()
+            && let Some(step) = self.branch_steps.front()
+        {
+            return Some(step.clone());
+        }
+
+        let previous_index = self.current_index();
+        match &mut self.iterations {
+            Iterations::Finitely(animation) => {
+                animation.next_step()?;
+            }
+            Iterations::Infinitely(animation) => {
+                animation.next_step();
+            }
+        }
+
+        let index = self.current_index()?;
+        if previous_index.is_some_and(|previous| index <= previous) {
+            self.start_iteration();
+        }
+
+        Some(self.step_at(index))
+    }
+
+    /// Replaces the current step, which must be a branch step,
+    /// with provided steps of the branch.
+    pub fn enter_branch(&mut self, steps: Vec<AnimationStep>) {
+        self.branch_steps.pop_front();
+        for step in steps.into_iter().rev() {
+            self.branch_steps.push_front(step);
+        }
+    }
+
+    fn step_at(&self, index: usize) -> AnimationStep {
+        match &self.randomizer {
+            Some(randomizer) => randomizer.step_at(index, self.steps()),
+            None => self.steps()[index].clone(),
+        }
+    }
+
+    fn start_iteration(&mut self) {
+        let steps = match &self.iterations {
+            Iterations::Finitely(animation) => animation.steps(),
+            Iterations::Infinitely(animation) => animation.steps(),
+        };
+        if let Some(randomizer) = &mut self.randomizer {
+            randomizer.start_iteration(steps);
         }
     }
 }
@@ -1,59 +1,89 @@
-use crate::AnimationStep;
+use std::sync::Arc;
+
+use crate::{
+    AnimationStep,
+    AnimationStyle,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct FinitelyRepeatableAnimation {
-    steps: Vec<AnimationStep>,
+    style: Arc<AnimationStyle>,
     current_index: usize,
     max_iteration: u16,
     current_iteration: u16,
+
+    /// Whether the animation was advanced past the last step
+    /// of the last iteration.
+    is_finished: bool,
 }
 
 impl FinitelyRepeatableAnimation {
-    pub fn new(steps: Vec<AnimationStep>, max_iteration: u16) -> Self {
+    pub fn new(style: Arc<AnimationStyle>, max_iteration: u16) -> Self {
         Self {
-            steps: steps,
+            style,
             current_index: 0,
             max_iteration: max_iteration.saturating_sub(1),
             current_iteration: 0,
+            is_finished: false,
         }
     }
 
-    /// Returns the current animation step if the iteration
+    /// Returns the index of the current step if the iteration
     /// limit is not reached; otherwise returns `None`.
-    pub fn current_step(&self) -> Option<AnimationStep> {
-        let iterations_limit_is_reached = self.current_index
-            == self.steps.len().saturating_sub(1)
-            && self.current_iteration == self.max_iteration;
-        if iterations_limit_is_reached {
+    pub fn current_index(&self) -> Option<usize> {
+        if self.is_finished {
             return None;
         }
 
-        self.steps.get(self.current_index).unwrap().clone().into()
+        Some(self.current_index)
+    }
+
+    pub fn current_iteration(&self) -> u16 {
+        self.current_iteration
+    }
+
+    pub fn steps(&self) -> &[AnimationStep] {
+        &self.style.steps
+    }
+
+    /// Moves the animation to the first step of the first
+    /// iteration.
+    pub fn reset(&mut self) {
+        self.current_index = 0;
+        self.current_iteration = 0;
+        self.is_finished = false;
     }
 
     /// Advances the animation and returns the current step
     /// if the iteration limit is not reached; otherwise
     /// returns `None`.
     pub fn next_step(&mut self) -> Option<AnimationStep> {
-        let iterations_limit_is_reached = match (
-            self.current_index == self.steps.len().saturating_sub(1),
+        if self.is_finished {
+            return None;
+        }
+
+        match (
+            self.current_index == self.style.steps.len().saturating_sub(1),
             self.current_iteration == self.max_iteration,
         ) {
             (false, false) | (false, true) => {
                 self.current_index += 1;
-                false
             }
             (true, false) => {
                 self.current_index = 0;
                 self.current_iteration += 1;
-                false
             }
-            (true, true) => true,
+            (true, true) => {
+                self.is_finished = true;
+                return None;
+            }
         };
-        if iterations_limit_is_reached {
-            return None;
-        }
 
-        self.steps.get(self.current_index).unwrap().clone().into()
+        self.style
+            .steps
+            .get(self.current_index)
+            .unwrap()
+            .clone()
+            .into()
     }
 }
@@ -1,33 +1,54 @@
-use crate::AnimationStep;
+use std::sync::Arc;
+
+use crate::{
+    AnimationStep,
+    AnimationStyle,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct InfinitelyRepeatableAnimation {
-    steps: Vec<AnimationStep>,
+    style: Arc<AnimationStyle>,
     current_index: usize,
+    current_iteration: u32,
 }
 
 impl InfinitelyRepeatableAnimation {
-    pub fn new(steps: Vec<AnimationStep>) -> Self {
+    pub fn new(style: Arc<AnimationStyle>) -> Self {
         Self {
-            steps: steps,
+            style,
             current_index: 0,
+            current_iteration: 0,
         }
     }
 
-    /// Returns the current animation step.
-    pub fn current_step(&self) -> AnimationStep {
-        self.steps.get(self.current_index).unwrap().clone()
+    pub fn current_index(&self) -> usize {
+        self.current_index
+    }
+
+    pub fn current_iteration(&self) -> u32 {
+        self.current_iteration
+    }
+
+    pub fn steps(&self) -> &[AnimationStep] {
+        &self.style.steps
+    }
+
+    /// Moves the animation to the first step.
+    pub fn reset(&mut self) {
+        self.current_index = 0;
+        self.current_iteration = 0;
     }
 
     /// Advances the animation and returns the current
     /// animation step.
     pub fn next_step(&mut self) -> AnimationStep {
-        if self.current_index != self.steps.len().saturating_sub(1) {
+        if self.current_index != self.style.steps.len().saturating_sub(1) {
             self.current_index += 1;
         } else {
             self.current_index = 0;
+            self.current_iteration = self.current_iteration.saturating_add(1);
         };
 
-        self.steps.get(self.current_index).unwrap().clone()
+        self.style.steps.get(self.current_index).unwrap().clone()
     }
 }
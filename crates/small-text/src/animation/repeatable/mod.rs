@@ -1,7 +1,9 @@
 mod animation;
 mod finitely;
 mod infinitely;
+mod randomizer;
 
 pub(crate) use animation::*;
 use finitely::*;
 use infinitely::*;
+use randomizer::*;
@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+use fastrand::Rng;
+
+use crate::{
+    AnimationStep,
+    AnimationStyle,
+};
+
+/// Draws the order and the durations of the steps for each
+/// iteration of an animation with duration jitter or step
+/// shuffling enabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct StepRandomizer {
+    rng: Rng,
+    duration_jitter: u8,
+    shuffle_steps: bool,
+
+    /// Indexes of the steps in the order they are played
+    /// during the current iteration.
+    order: Vec<usize>,
+
+    /// Durations of the steps during the current iteration,
+    /// indexed like the steps of the style.
+    durations: Vec<Duration>,
+}
+
+impl StepRandomizer {
+    /// Returns `None` if the style neither jitters durations
+    /// nor shuffles steps.
+    pub fn new(style: &AnimationStyle) -> Option<Self> {
+        if style.duration_jitter == 0 && !style.shuffle_steps {
+            return None;
+        }
+
+        let rng = match style.seed {
+            Some(seed) => Rng::with_seed(seed),
+            None => Rng::new(),
+        };
+        let mut randomizer = Self {
+            rng,
+            duration_jitter: style.duration_jitter.min(100),
+            shuffle_steps: style.shuffle_steps,
+            order: Vec::new(),
+            durations: Vec::new(),
+        };
+        randomizer.start_iteration(&style.steps);
+
+        Some(randomizer)
+    }
+
+    /// Draws a new order and new durations of provided steps
+    /// for the next iteration.
+    pub fn start_iteration(&mut self, steps: &[AnimationStep]) {
+        self.order = (0..steps.len()).collect();
+        if self.shuffle_steps {
+            self.rng.shuffle(&mut self.order);
+        }
+
+        let max_deviation = self.duration_jitter as f32 / 100.0;
+        self.durations = steps
+            .iter()
+            .map(|step| {
+                let deviation = max_deviation * (self.rng.f32() * 2.0 - 1.0);
+                step.duration.mul_f32(1.0 + deviation)
+            })
+            .collect();
+    }
+
+    /// Returns the step played at provided position of the
+    /// current iteration.
+    pub fn step_at(
+        &self,
+        position: usize,
+        steps: &[AnimationStep],
+    ) -> AnimationStep {
+        let index = self.order[position];
+        let mut step = steps[index].clone();
+        step.duration = self.durations[index];
+
+        step
+    }
+}
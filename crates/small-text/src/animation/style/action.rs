@@ -1,10 +1,22 @@
+use std::{
+    hash::{
+        Hash,
+        Hasher,
+    },
+    mem,
+};
+
 use ratatui::style::{
     Color,
     Modifier,
 };
 
 /// A single action in the text animation step.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Relative color adjustments are computed from the current
+/// colors of the symbol, so they work on any base theme. They
+/// have no effect on unset colors and [`Color::Reset`].
+#[derive(Debug, Clone, Copy)]
 pub enum AnimationAction {
     UpdateCharacter(char),
     UpdateForegroundColor(Color),
@@ -12,4 +24,92 @@ pub enum AnimationAction {
     AddModifier(Modifier),
     RemoveModifier(Modifier),
     RemoveAllModifiers,
+
+    /// Increases the lightness of the foreground color by
+    /// provided amount in the `0.0..=1.0` range. Negative
+    /// amounts darken the color.
+    LightenForeground(f32),
+
+    /// Decreases the lightness of the background color by
+    /// provided amount in the `0.0..=1.0` range. Negative
+    /// amounts lighten the color.
+    DarkenBackground(f32),
+
+    /// Rotates the hue of both colors by provided number of
+    /// degrees.
+    ShiftHue(f32),
+
+    /// Replaces both colors with their RGB complements.
+    InvertColors,
+
+    /// Copies the colors and the modifier of the symbol at
+    /// provided position. The style is taken as it was
+    /// before the actions of the current target were applied,
+    /// so the order of the selected positions doesn't matter.
+    CopyStyleFrom(u16),
+
+    /// Exchanges the colors and the modifier with the symbol
+    /// at provided position. The styles are taken as they
+    /// were before the actions of the current target were
+    /// applied, and the other symbol is updated after them.
+    SwapWith(u16),
+
+    /// Moves the symbol by provided number of positions along
+    /// the x axis, leaving a blank symbol in its place. The
+    /// symbol is moved after the other actions of the current
+    /// target are applied, and the edges of the text are
+    /// handled according to the [`AnimationTranslateMode`] of
+    /// the step.
+    ///
+    /// [`AnimationTranslateMode`]: crate::AnimationTranslateMode
+    TranslateBy(i16),
+}
+
+impl PartialEq for AnimationAction {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::UpdateCharacter(a), Self::UpdateCharacter(b)) => a == b,
+            (
+                Self::UpdateForegroundColor(a),
+                Self::UpdateForegroundColor(b),
+            )
+            | (
+                Self::UpdateBackgroundColor(a),
+                Self::UpdateBackgroundColor(b),
+            ) => a == b,
+            (Self::AddModifier(a), Self::AddModifier(b))
+            | (Self::RemoveModifier(a), Self::RemoveModifier(b)) => a == b,
+            (Self::LightenForeground(a), Self::LightenForeground(b))
+            | (Self::DarkenBackground(a), Self::DarkenBackground(b))
+            | (Self::ShiftHue(a), Self::ShiftHue(b)) => {
+                a.to_bits() == b.to_bits()
+            }
+            (Self::CopyStyleFrom(a), Self::CopyStyleFrom(b))
+            | (Self::SwapWith(a), Self::SwapWith(b)) => a == b,
+            (Self::TranslateBy(a), Self::TranslateBy(b)) => a == b,
+            _ => mem::discriminant(self) == mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for AnimationAction {}
+
+impl Hash for AnimationAction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            Self::UpdateCharacter(character) => character.hash(state),
+            Self::UpdateForegroundColor(color)
+            | Self::UpdateBackgroundColor(color) => color.hash(state),
+            Self::AddModifier(modifier) | Self::RemoveModifier(modifier) => {
+                modifier.hash(state)
+            }
+            Self::LightenForeground(amount)
+            | Self::DarkenBackground(amount)
+            | Self::ShiftHue(amount) => amount.to_bits().hash(state),
+            Self::CopyStyleFrom(x) | Self::SwapWith(x) => x.hash(state),
+            Self::TranslateBy(offset) => offset.hash(state),
+            Self::RemoveAllModifiers | Self::InvertColors => {}
+        }
+    }
 }
@@ -1,20 +1,32 @@
+use std::time::Duration;
+
 use derive_builder::Builder;
 
 use super::{
     AnimationAdvanceMode,
     AnimationRepeatMode,
+    AnimationRestoreMode,
     AnimationStep,
+    AnimationStyleError,
+    AnimationTarget,
 };
 
 /// A styling configuration for the animation.
 ///
+/// Both the builder and [`AnimationStyle::new`] return
+/// [`AnimationStyleError`] if the animation has no steps,
+/// advances automatically while all its steps have zero
+/// duration, has a step that does nothing, or has a step whose
+/// [`AnimationTarget::Single`] and [`AnimationTarget::Range`]
+/// targets overlap.
+///
 /// # Example
 ///
 /// ```rust
 /// use std::time::Duration;
 ///
 /// use ratatui::style::{Color, Modifier};
-/// use ratatui_small_text::{
+/// use caponata_small_text::{
 ///     AnimationTarget,
 ///     AnimationAction,
 ///     AnimationRepeatMode,
@@ -33,7 +45,8 @@ use super::{
 ///     .update_background_color(Color::White)
 ///     .add_modifier(Modifier::BOLD)
 ///     .then()
-///     .build();
+///     .build()
+///     .unwrap();
 /// let second_step = AnimationStepBuilder::default()
 ///     .with_duration(Duration::from_millis(100))
 ///     .for_target(AnimationTarget::Single(1))
@@ -45,7 +58,8 @@ use super::{
 ///     .update_background_color(Color::Red)
 ///     .add_modifier(Modifier::BOLD)
 ///     .then()
-///     .build();
+///     .build()
+///     .unwrap();
 /// let animation_style = AnimationStyleBuilder::default()
 ///     .with_repeat_mode(AnimationRepeatMode::Infinite)
 ///     .with_advance_mode(AnimationAdvanceMode::Auto)
@@ -54,7 +68,10 @@ use super::{
 ///     .unwrap();
 /// ```
 #[derive(Debug, Default, Clone, PartialEq, Eq, Builder)]
-#[builder(setter(prefix = "with", into))]
+#[builder(
+    setter(prefix = "with", into),
+    build_fn(validate = "Self::validate", error = "AnimationStyleError")
+)]
 pub struct AnimationStyle {
     #[builder(default)]
     pub(crate) repeat_mode: AnimationRepeatMode,
@@ -64,6 +81,40 @@ pub struct AnimationStyle {
 
     #[builder(default)]
     pub(crate) steps: Vec<AnimationStep>,
+
+    #[builder(default)]
+    pub(crate) restore_mode: AnimationRestoreMode,
+
+    /// Time to wait before displaying the first step. The
+    /// delay is not repeated on subsequent iterations.
+    #[builder(default)]
+    pub(crate) start_delay: Duration,
+
+    /// Maximum number of frames generated per second. Frames
+    /// requested sooner repeat the previous frame without
+    /// processing steps. Zero is treated as no limit.
+    #[builder(default, setter(strip_option))]
+    pub(crate) max_fps: Option<u16>,
+
+    /// Maximum deviation of step durations from their
+    /// configured values in percent, e.g. `20` for ±20%. New
+    /// durations are drawn on each iteration. Values above 100
+    /// are treated as 100. Progress and seeking are based on
+    /// the configured durations.
+    #[builder(default)]
+    pub(crate) duration_jitter: u8,
+
+    /// Whether the steps are played in a new random order on
+    /// each iteration.
+    #[builder(default)]
+    pub(crate) shuffle_steps: bool,
+
+    /// Seed of the random number generator used for duration
+    /// jitter and step shuffling, so that the same seed
+    /// always produces the same animation. A random seed is
+    /// used if `None`.
+    #[builder(default, setter(strip_option))]
+    pub(crate) seed: Option<u64>,
 }
 
 impl<'a> AnimationStyle {
@@ -71,11 +122,257 @@ impl<'a> AnimationStyle {
         repeat_mode: AnimationRepeatMode,
         advance_mode: AnimationAdvanceMode,
         steps: Vec<AnimationStep>,
-    ) -> Self {
-        Self {
+    ) -> Result<Self, AnimationStyleError> {
+        validate_steps(&steps, advance_mode)?;
+
+        Ok(Self {
             repeat_mode,
             advance_mode,
             steps,
+            restore_mode: AnimationRestoreMode::default(),
+            start_delay: Duration::ZERO,
+            max_fps: None,
+            duration_jitter: 0,
+            shuffle_steps: false,
+            seed: None,
+        })
+    }
+
+    /// Returns [`AnimationStyleError::OutOfBoundsTarget`] if a
+    /// [`AnimationTarget::Single`] or [`AnimationTarget::Range`]
+    /// target selects a position outside of the text with
+    /// provided width.
+    pub fn validate_targets(
+        &self,
+        width: u16,
+    ) -> Result<(), AnimationStyleError> {
+        let out_of_bounds_coordinates =
+            self.out_of_bounds_coordinates(|x| x < width);
+
+        match out_of_bounds_coordinates.first() {
+            Some(&(step, position)) => {
+                Err(AnimationStyleError::OutOfBoundsTarget { step, position })
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Returns step indexes along with positions selected by
+    /// [`AnimationTarget::Single`] and [`AnimationTarget::Range`]
+    /// targets that are not accepted by provided function,
+    /// ordered by step index and position.
+    pub(crate) fn out_of_bounds_coordinates(
+        &self,
+        is_in_bounds: impl Fn(u16) -> bool,
+    ) -> Vec<(usize, u16)> {
+        let mut coordinates = Vec::new();
+        for (step_index, step) in self.steps.iter().enumerate() {
+            let mut positions: Vec<u16> = step
+                .actions
+                .keys()
+                .filter_map(AnimationTarget::fixed_positions)
+                .flatten()
+                .filter(|x| !is_in_bounds(*x))
+                .collect();
+            positions.sort();
+            positions.dedup();
+
+            coordinates.extend(positions.into_iter().map(|x| (step_index, x)));
+        }
+
+        coordinates
+    }
+}
+
+impl AnimationStyleBuilder {
+    fn validate(&self) -> Result<(), AnimationStyleError> {
+        validate_steps(
+            self.steps.as_deref().unwrap_or_default(),
+            self.advance_mode.unwrap_or_default(),
+        )
+    }
+}
+
+/// Returns the first misconfiguration of provided steps played
+/// with provided advance mode. Both [`AnimationStyle::new`] and
+/// the builder go through this check.
+fn validate_steps(
+    steps: &[AnimationStep],
+    advance_mode: AnimationAdvanceMode,
+) -> Result<(), AnimationStyleError> {
+    if steps.is_empty() {
+        return Err(AnimationStyleError::EmptySteps);
+    }
+    if advance_mode == AnimationAdvanceMode::Auto
+        && steps.iter().all(AnimationStep::is_instant)
+    {
+        return Err(AnimationStyleError::ZeroDurationWithAutoAdvance);
+    }
+
+    for (step_index, step) in steps.iter().enumerate() {
+        if step.is_empty() {
+            return Err(AnimationStyleError::EmptyStep { step: step_index });
         }
+
+        let mut positions: Vec<u16> = step
+            .actions
+            .keys()
+            .filter_map(AnimationTarget::fixed_positions)
+            .flatten()
+            .collect();
+        positions.sort();
+
+        let overlapping_position = positions
+            .windows(2)
+            .find(|pair| pair[0] == pair[1])
+            .map(|pair| pair[0]);
+        if let Some(position) = overlapping_position {
+            return Err(AnimationStyleError::OverlappingExclusiveTargets {
+                step: step_index,
+                position,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        time::Duration,
+    };
+
+    use ratatui::style::Modifier;
+
+    use super::{
+        AnimationStyle,
+        AnimationStyleBuilder,
+    };
+    use crate::{
+        AnimationAdvanceMode,
+        AnimationRepeatMode,
+        AnimationStep,
+        AnimationStepBuilder,
+        AnimationStyleError,
+        AnimationTarget,
+    };
+
+    #[test]
+    fn invalid_animation_styles() {
+        let result = AnimationStyleBuilder::default().build();
+        assert_eq!(result, Err(AnimationStyleError::EmptySteps));
+
+        let step = AnimationStepBuilder::default()
+            .for_target(AnimationTarget::Untouched)
+            .add_modifier(Modifier::BOLD)
+            .then()
+            .build()
+            .unwrap();
+        let result = AnimationStyleBuilder::default()
+            .with_steps(vec![step])
+            .build();
+        assert_eq!(
+            result,
+            Err(AnimationStyleError::ZeroDurationWithAutoAdvance),
+        );
+
+        let result = AnimationStyleBuilder::default()
+            .with_advance_mode(AnimationAdvanceMode::Manual)
+            .with_steps(vec![AnimationStep::default()])
+            .build();
+        assert_eq!(result, Err(AnimationStyleError::EmptyStep { step: 0 }));
+
+        let step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_millis(100))
+            .for_target(AnimationTarget::Range(0, 3))
+            .add_modifier(Modifier::BOLD)
+            .then()
+            .for_target(AnimationTarget::Single(2))
+            .add_modifier(Modifier::ITALIC)
+            .then()
+            .build()
+            .unwrap();
+        let result = AnimationStyleBuilder::default()
+            .with_steps(vec![step])
+            .build();
+        assert_eq!(
+            result,
+            Err(AnimationStyleError::OverlappingExclusiveTargets {
+                step: 0,
+                position: 2,
+            }),
+        );
+    }
+
+    #[test]
+    fn empty_steps_are_rejected() {
+        let result = AnimationStepBuilder::default().build();
+        assert_eq!(result, Err(AnimationStyleError::EmptyActions));
+
+        let result = AnimationStep::new(HashMap::new(), None, Duration::ZERO);
+        assert_eq!(result, Err(AnimationStyleError::EmptyActions));
+
+        let instant_step = AnimationStepBuilder::default()
+            .for_target(AnimationTarget::Untouched)
+            .add_modifier(Modifier::BOLD)
+            .then()
+            .build()
+            .unwrap();
+        let result = AnimationStyleBuilder::default()
+            .with_advance_mode(AnimationAdvanceMode::Manual)
+            .with_steps(vec![instant_step.clone()])
+            .build();
+        assert!(result.is_ok());
+
+        let pause_step = AnimationStep::new(
+            HashMap::new(),
+            None,
+            Duration::from_millis(100),
+        )
+        .unwrap();
+        let result = AnimationStyleBuilder::default()
+            .with_steps(vec![pause_step.clone(), AnimationStep::default()])
+            .build();
+        assert_eq!(result, Err(AnimationStyleError::EmptyStep { step: 1 }));
+
+        let result = AnimationStyle::new(
+            AnimationRepeatMode::Infinite,
+            AnimationAdvanceMode::Auto,
+            vec![pause_step, instant_step, AnimationStep::default()],
+        );
+        assert_eq!(result, Err(AnimationStyleError::EmptyStep { step: 2 }));
+
+        let result = AnimationStyle::new(
+            AnimationRepeatMode::Infinite,
+            AnimationAdvanceMode::Auto,
+            Vec::new(),
+        );
+        assert_eq!(result, Err(AnimationStyleError::EmptySteps));
+    }
+
+    #[test]
+    fn out_of_bounds_targets() {
+        let step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_millis(100))
+            .for_target(AnimationTarget::Range(2, 5))
+            .add_modifier(Modifier::BOLD)
+            .then()
+            .build()
+            .unwrap();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_steps(vec![step])
+            .build()
+            .unwrap();
+
+        assert!(animation_style.validate_targets(6).is_ok());
+        assert_eq!(
+            animation_style.validate_targets(5),
+            Err(AnimationStyleError::OutOfBoundsTarget {
+                step: 0,
+                position: 5,
+            }),
+        );
     }
 }
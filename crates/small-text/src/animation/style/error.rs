@@ -0,0 +1,88 @@
+use std::{
+    error::Error,
+    fmt,
+};
+
+use derive_builder::UninitializedFieldError;
+
+/// An error returned when an [`AnimationStyle`] is
+/// misconfigured.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AnimationStyleError {
+    /// The animation has no steps.
+    EmptySteps,
+
+    /// The animation advances automatically, but none of its
+    /// steps has non-zero duration, so it would advance on
+    /// every `render` method call.
+    ZeroDurationWithAutoAdvance,
+
+    /// A step has no actions, callbacks or branch and zero
+    /// duration, so it does nothing. Includes the index of the
+    /// step.
+    EmptyStep { step: usize },
+
+    /// A step passed to [`AnimationStepBuilder::build`] or
+    /// [`AnimationStep::new`] has no actions, callbacks or
+    /// spring and zero duration, so it would do nothing.
+    ///
+    /// [`AnimationStepBuilder::build`]: crate::AnimationStepBuilder::build
+    /// [`AnimationStep::new`]: crate::AnimationStep::new
+    EmptyActions,
+
+    /// Several [`AnimationTarget::Single`] or
+    /// [`AnimationTarget::Range`] targets of the same step
+    /// select the same position. Includes the index of the
+    /// step and the position.
+    OverlappingExclusiveTargets { step: usize, position: u16 },
+
+    /// A target of the step selects a position outside of the
+    /// text. Includes the index of the step and the position.
+    OutOfBoundsTarget { step: usize, position: u16 },
+
+    /// A required field of the builder was not set.
+    UninitializedField(&'static str),
+}
+
+impl fmt::Display for AnimationStyleError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptySteps => {
+                write!(formatter, "animation has no steps")
+            }
+            Self::ZeroDurationWithAutoAdvance => write!(
+                formatter,
+                "automatically advanced animation has only zero-duration \
+                 steps",
+            ),
+            Self::EmptyStep { step } => {
+                write!(formatter, "step {} does nothing", step)
+            }
+            Self::EmptyActions => {
+                write!(formatter, "step has no actions and zero duration")
+            }
+            Self::OverlappingExclusiveTargets { step, position } => write!(
+                formatter,
+                "several targets of step {} select position {}",
+                step, position,
+            ),
+            Self::OutOfBoundsTarget { step, position } => write!(
+                formatter,
+                "target of step {} selects position {} outside of the text",
+                step, position,
+            ),
+            Self::UninitializedField(field) => {
+                write!(formatter, "field `{}` is not initialized", field)
+            }
+        }
+    }
+}
+
+impl Error for AnimationStyleError {}
+
+impl From<UninitializedFieldError> for AnimationStyleError {
+    fn from(value: UninitializedFieldError) -> Self {
+        Self::UninitializedField(value.field_name())
+    }
+}
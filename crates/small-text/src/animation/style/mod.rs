@@ -1,13 +1,21 @@
 mod action;
 mod advance_mode;
 mod animation;
+mod error;
 mod repeat_mode;
+mod restore_mode;
+mod spring;
 mod step;
 mod target;
+mod translate_mode;
 
 pub use action::*;
 pub use advance_mode::*;
 pub use animation::*;
+pub use error::*;
 pub use repeat_mode::*;
+pub use restore_mode::*;
+pub use spring::*;
 pub use step::*;
 pub use target::*;
+pub use translate_mode::*;
@@ -0,0 +1,20 @@
+/// Specifies what [`AnimatedSmallTextWidget`] displays once
+/// the animation ends.
+///
+/// Default variant is [`AnimationRestoreMode::KeepLastFrame`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnimationRestoreMode {
+    /// The last frame of the animation stays applied to the
+    /// symbols.
+    #[default]
+    KeepLastFrame,
+
+    /// The symbols are restored to the state they had when
+    /// the animation was created.
+    RestoreInitial,
+
+    /// The symbols are restored to the styles of the text
+    /// style of the widget, discarding frames of any previous
+    /// animations.
+    RestoreStatic,
+}
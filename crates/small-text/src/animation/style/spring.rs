@@ -0,0 +1,222 @@
+use std::{
+    collections::HashMap,
+    time::Duration,
+};
+
+use caponata_common::Callable;
+
+use crate::{
+    StepSymbolState,
+    Symbol,
+};
+
+/// A function called on every frame of a step driven by an
+/// [`AnimationSpring`]. The function receives the current
+/// value of the spring and a map of the symbol positions to
+/// their corresponding states, and should return a map of
+/// updated symbol positions to their corresponding updated
+/// symbols.
+pub type SpringCallback =
+    Callable<(f64, HashMap<u16, StepSymbolState>), HashMap<u16, Symbol>>;
+
+/// A damped spring moving a scalar value from one point to
+/// another, e.g. an offset of the text or the intensity of a
+/// color. Unlike duration-based steps, the motion can
+/// overshoot the target and oscillate around it before coming
+/// to rest.
+///
+/// The value is computed from the time elapsed in the step,
+/// so it stays accurate regardless of how often frames are
+/// generated.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use caponata_common::Callable;
+/// use caponata_small_text::{
+///     AnimationSpring,
+///     AnimationStepBuilder,
+/// };
+///
+/// let spring = AnimationSpring::new(0.0, 1.0)
+///     .with_stiffness(200.0)
+///     .with_damping(10.0);
+/// let step = AnimationStepBuilder::default()
+///     .with_spring(spring, Callable::from_fn(|(_value, _states)| {
+///         HashMap::new()
+///     }))
+///     .build()
+///     .unwrap();
+///
+/// let settled_value = spring.value_at(spring.settle_duration());
+/// assert!((settled_value - 1.0).abs() < 0.01);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AnimationSpring {
+    /// Value the spring starts at.
+    pub from: f64,
+
+    /// Value the spring comes to rest at.
+    pub to: f64,
+
+    /// Strength of the force pulling the value towards the
+    /// target. Default value is `170.0`.
+    pub stiffness: f64,
+
+    /// Strength of the force slowing the value down. Springs
+    /// without damping never come to rest, so they stop after
+    /// [`AnimationSpring::MAX_SETTLE_DURATION`]. Default value
+    /// is `26.0`.
+    pub damping: f64,
+
+    /// Default value is `1.0`.
+    pub mass: f64,
+
+    /// Velocity of the value at the start in units per second.
+    pub initial_velocity: f64,
+
+    /// The spring is considered at rest once both its distance
+    /// to the target and its velocity are below this value.
+    /// Default value is `0.01`.
+    pub rest_threshold: f64,
+}
+
+impl PartialEq for AnimationSpring {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits() == other.bits()
+    }
+}
+
+impl Eq for AnimationSpring {}
+
+impl AnimationSpring {
+    /// Maximum time a step driven by a spring lasts.
+    pub const MAX_SETTLE_DURATION: Duration = Duration::from_secs(10);
+
+    /// Interval between the frames of a step driven by a
+    /// spring, unless the frame rate is limited further by the
+    /// animation style.
+    pub const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+    /// Resolution used to find the moment the spring comes to
+    /// rest.
+    const SETTLE_RESOLUTION: Duration = Duration::from_millis(1);
+
+    pub fn new(from: f64, to: f64) -> Self {
+        Self {
+            from,
+            to,
+            stiffness: 170.0,
+            damping: 26.0,
+            mass: 1.0,
+            initial_velocity: 0.0,
+            rest_threshold: 0.01,
+        }
+    }
+
+    pub fn with_stiffness(mut self, stiffness: f64) -> Self {
+        self.stiffness = stiffness;
+        self
+    }
+
+    pub fn with_damping(mut self, damping: f64) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    pub fn with_mass(mut self, mass: f64) -> Self {
+        self.mass = mass;
+        self
+    }
+
+    pub fn with_initial_velocity(mut self, initial_velocity: f64) -> Self {
+        self.initial_velocity = initial_velocity;
+        self
+    }
+
+    pub fn with_rest_threshold(mut self, rest_threshold: f64) -> Self {
+        self.rest_threshold = rest_threshold;
+        self
+    }
+
+    /// Returns the value of the spring after provided time.
+    /// Springs with non-positive stiffness or mass are always
+    /// at the target.
+    pub fn value_at(&self, elapsed: Duration) -> f64 {
+        self.to + self.displacement_at(elapsed.as_secs_f64())
+    }
+
+    /// Returns the time it takes the spring to come to rest,
+    /// but no more than [`AnimationSpring::MAX_SETTLE_DURATION`].
+    pub fn settle_duration(&self) -> Duration {
+        let mut elapsed = Duration::ZERO;
+        while elapsed < Self::MAX_SETTLE_DURATION {
+            let time = elapsed.as_secs_f64();
+            let step = Self::SETTLE_RESOLUTION.as_secs_f64();
+            let velocity = (self.displacement_at(time + step)
+                - self.displacement_at(time))
+                / step;
+
+            if self.displacement_at(time).abs() < self.rest_threshold
+                && velocity.abs() < self.rest_threshold
+            {
+                return elapsed;
+            }
+            elapsed += Self::SETTLE_RESOLUTION;
+        }
+
+        Self::MAX_SETTLE_DURATION
+    }
+
+    /// Returns the distance from the target after provided
+    /// number of seconds, using the closed-form solution of
+    /// the damped harmonic oscillator.
+    fn displacement_at(&self, time: f64) -> f64 {
+        if self.stiffness <= 0.0 || self.mass <= 0.0 {
+            return 0.0;
+        }
+
+        let x0 = self.from - self.to;
+        let v0 = self.initial_velocity;
+        let natural_frequency = (self.stiffness / self.mass).sqrt();
+        let damping_ratio = self.damping.max(0.0)
+            / (2.0 * (self.stiffness * self.mass).sqrt());
+
+        if damping_ratio < 1.0 {
+            let damped_frequency =
+                natural_frequency * (1.0 - damping_ratio.powi(2)).sqrt();
+            let decay = (-damping_ratio * natural_frequency * time).exp();
+            let sin_factor = (v0 + damping_ratio * natural_frequency * x0)
+                / damped_frequency;
+
+            decay
+                * (x0 * (damped_frequency * time).cos()
+                    + sin_factor * (damped_frequency * time).sin())
+        } else if damping_ratio == 1.0 {
+            let decay = (-natural_frequency * time).exp();
+            decay * (x0 + (v0 + natural_frequency * x0) * time)
+        } else {
+            let root = (damping_ratio.powi(2) - 1.0).sqrt();
+            let r1 = -natural_frequency * (damping_ratio - root);
+            let r2 = -natural_frequency * (damping_ratio + root);
+            let c2 = (v0 - r1 * x0) / (r2 - r1);
+            let c1 = x0 - c2;
+
+            c1 * (r1 * time).exp() + c2 * (r2 * time).exp()
+        }
+    }
+
+    fn bits(self) -> [u64; 7] {
+        [
+            self.from.to_bits(),
+            self.to.to_bits(),
+            self.stiffness.to_bits(),
+            self.damping.to_bits(),
+            self.mass.to_bits(),
+            self.initial_velocity.to_bits(),
+            self.rest_threshold.to_bits(),
+        ]
+    }
+}
@@ -11,7 +11,11 @@ use ratatui::style::{
 
 use super::{
     AnimationAction,
+    AnimationSpring,
+    AnimationStyleError,
     AnimationTarget,
+    AnimationTranslateMode,
+    SpringCallback,
 };
 use crate::{
     StepSymbolState,
@@ -21,6 +25,12 @@ use crate::{
 pub type BeforeFinishCallback =
     Callable<(HashMap<u16, StepSymbolState>,), HashMap<u16, Symbol>>;
 
+/// A predicate deciding whether a step is played. The function
+/// receives a map of the symbol positions to their
+/// corresponding states and the index of the current
+/// iteration, starting from zero.
+pub type StepCondition = Callable<(HashMap<u16, StepSymbolState>, u32), bool>;
+
 /// A single step in the animation for [`SmallTextWidget`].
 ///
 /// # Example
@@ -29,7 +39,7 @@ pub type BeforeFinishCallback =
 /// use std::time::Duration;
 ///
 /// use ratatui::style::{Color, Modifier};
-/// use ratatui_small_text::{
+/// use caponata_small_text::{
 ///     AnimationTarget,
 ///     AnimationAction,
 ///     AnimationStep,
@@ -54,7 +64,8 @@ pub type BeforeFinishCallback =
 ///     .update_background_color(Color::Blue)
 ///     .remove_all_modifiers()
 ///     .then()
-///     .build();
+///     .build()
+///     .unwrap();
 /// ```
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct AnimationStep {
@@ -73,31 +84,192 @@ pub struct AnimationStep {
     /// time elapses, the animation advances to the next
     /// step.
     pub(crate) duration: Duration,
+
+    /// Condition that is checked when the animation reaches
+    /// this step. If it returns `false`, the step is skipped
+    /// without applying its actions or taking any time.
+    pub(crate) condition: Option<StepCondition>,
+
+    /// Branch that replaces this step with one of its
+    /// sub-sequences when the animation reaches it.
+    pub(crate) branch: Option<AnimationBranch>,
+
+    /// Number of positions all symbols are moved by along the
+    /// x axis once the actions of this step are applied.
+    pub(crate) shift: i16,
+
+    /// Specifies how symbols moved past the edges of the text
+    /// by the shift or [`AnimationAction::TranslateBy`] are
+    /// handled.
+    pub(crate) translate_mode: AnimationTranslateMode,
+
+    /// Spring driving the step along with the function
+    /// receiving its value on every frame. The step lasts
+    /// until the spring comes to rest.
+    pub(crate) spring: Option<(AnimationSpring, SpringCallback)>,
 }
 
 impl<'a> AnimationStep {
+    /// Returns [`AnimationStyleError::EmptyActions`] if the step
+    /// would do nothing.
     pub fn new(
         actions: HashMap<AnimationTarget, Vec<AnimationAction>>,
         on_before_finish: Option<BeforeFinishCallback>,
         duration: Duration,
-    ) -> Self {
+    ) -> Result<Self, AnimationStyleError> {
         Self {
             actions,
             on_before_finish,
             duration,
+            condition: None,
+            branch: None,
+            shift: 0,
+            translate_mode: AnimationTranslateMode::default(),
+            spring: None,
+        }
+        .validated()
+    }
+
+    /// Returns the step, or [`AnimationStyleError::EmptyActions`]
+    /// if it does nothing. Every constructor of a step with
+    /// actions goes through this check.
+    fn validated(self) -> Result<Self, AnimationStyleError> {
+        match self.is_empty() {
+            true => Err(AnimationStyleError::EmptyActions),
+            false => Ok(self),
+        }
+    }
+
+    /// Returns whether the step has no actions, callbacks or
+    /// branch, doesn't move the symbols and has zero duration,
+    /// so playing it changes nothing.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.actions.values().all(Vec::is_empty)
+            && self.on_before_finish.is_none()
+            && self.branch.is_none()
+            && self.spring.is_none()
+            && self.shift == 0
+            && self.duration.is_zero()
+    }
+
+    /// Returns whether the step and all steps it may branch
+    /// into have zero duration.
+    pub(crate) fn is_instant(&self) -> bool {
+        self.duration.is_zero()
+            && self.branch.as_ref().is_none_or(|branch| {
+                branch
+                    .then_steps
+                    .iter()
+                    .chain(branch.else_steps.iter())
+                    .all(AnimationStep::is_instant)
+            })
+    }
+}
+
+/// A step selecting between two sub-sequences of steps when
+/// the animation reaches it, e.g. to play a different effect
+/// on the final iteration.
+///
+/// The condition receives the same arguments as
+/// [`StepCondition`]. The selected steps are played in place
+/// of the branch step, which has no actions or duration of its
+/// own, so progress and seeking treat it as a zero-duration
+/// step. An empty sub-sequence skips the branch step.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use caponata_common::Callable;
+/// use ratatui::style::Color;
+/// use caponata_small_text::{
+///     AnimationBranch,
+///     AnimationStep,
+///     AnimationStepBuilder,
+///     AnimationTarget,
+/// };
+///
+/// let highlight = |color| {
+///     AnimationStepBuilder::default()
+///         .with_duration(Duration::from_millis(100))
+///         .for_target(AnimationTarget::Untouched)
+///         .update_foreground_color(color)
+///         .then()
+///         .build()
+///         .unwrap()
+/// };
+/// let is_final_iteration =
+///     Callable::from_fn(|(_, iteration)| iteration == 2);
+/// let branch_step: AnimationStep = AnimationBranch::new(
+///     is_final_iteration,
+///     vec![highlight(Color::Green)],
+///     vec![highlight(Color::Gray)],
+/// )
+/// .into();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnimationBranch {
+    pub(crate) condition: StepCondition,
+
+    /// Steps played if the condition returns `true`.
+    pub(crate) then_steps: Vec<AnimationStep>,
+
+    /// Steps played if the condition returns `false`.
+    pub(crate) else_steps: Vec<AnimationStep>,
+}
+
+impl AnimationBranch {
+    pub fn new(
+        condition: StepCondition,
+        then_steps: Vec<AnimationStep>,
+        else_steps: Vec<AnimationStep>,
+    ) -> Self {
+        Self {
+            condition,
+            then_steps,
+            else_steps,
+        }
+    }
+
+    /// Returns the steps selected by the condition for
+    /// provided symbol states and iteration.
+    pub(crate) fn select(
+        &self,
+        step_states: HashMap<u16, StepSymbolState>,
+        iteration: u32,
+    ) -> Vec<AnimationStep> {
+        if self.condition.call((step_states, iteration)) {
+            self.then_steps.clone()
+        } else {
+            self.else_steps.clone()
+        }
+    }
+}
+
+impl From<AnimationBranch> for AnimationStep {
+    fn from(value: AnimationBranch) -> Self {
+        Self {
+            branch: Some(value),
+            ..Self::default()
         }
     }
 }
 
 /// A builder for constructing an [`AnimationStep`].
 ///
+/// Building a step without actions, callbacks, shift or spring
+/// and with zero duration returns
+/// [`AnimationStyleError::EmptyActions`], since such a step
+/// would do nothing.
+///
 /// # Example
 ///
 /// ```rust
 /// use std::time::Duration;
 ///
 /// use ratatui::style::{Color, Modifier};
-/// use ratatui_small_text::{
+/// use caponata_small_text::{
 ///     AnimationTarget,
 ///     AnimationAction,
 ///     AnimationStepBuilder,
@@ -121,13 +293,18 @@ impl<'a> AnimationStep {
 ///     .update_background_color(Color::Blue)
 ///     .remove_all_modifiers()
 ///     .then()
-///     .build();
+///     .build()
+///     .unwrap();
 /// ```
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct AnimationStepBuilder {
     duration: Option<Duration>,
     actions: HashMap<AnimationTarget, Vec<AnimationAction>>,
     on_before_finish: Option<BeforeFinishCallback>,
+    condition: Option<StepCondition>,
+    shift: i16,
+    translate_mode: AnimationTranslateMode,
+    spring: Option<(AnimationSpring, SpringCallback)>,
 }
 
 impl<'a> AnimationStepBuilder {
@@ -144,6 +321,39 @@ impl<'a> AnimationStepBuilder {
         self
     }
 
+    pub fn with_condition(mut self, condition: StepCondition) -> Self {
+        self.condition = Some(condition);
+        self
+    }
+
+    pub fn with_shift(mut self, shift: i16) -> Self {
+        self.shift = shift;
+        self
+    }
+
+    pub fn with_translate_mode(
+        mut self,
+        translate_mode: AnimationTranslateMode,
+    ) -> Self {
+        self.translate_mode = translate_mode;
+        self
+    }
+
+    /// Drives the step with provided spring, calling the
+    /// function with the value of the spring on every frame
+    /// after the actions of the step are applied. The step
+    /// lasts until the spring comes to rest, overriding its
+    /// duration, and its final frame receives the target value
+    /// of the spring.
+    pub fn with_spring(
+        mut self,
+        spring: AnimationSpring,
+        callback: SpringCallback,
+    ) -> Self {
+        self.spring = Some((spring, callback));
+        self
+    }
+
     pub fn for_target(
         self,
         target: AnimationTarget,
@@ -155,12 +365,23 @@ impl<'a> AnimationStepBuilder {
         }
     }
 
-    pub fn build(self) -> AnimationStep {
+    pub fn build(self) -> Result<AnimationStep, AnimationStyleError> {
+        let duration = match &self.spring {
+            Some((spring, _)) => spring.settle_duration(),
+            None => self.duration.unwrap_or_default(),
+        };
+
         AnimationStep {
             actions: self.actions,
             on_before_finish: self.on_before_finish,
-            duration: self.duration.unwrap_or_default(),
+            duration,
+            condition: self.condition,
+            branch: None,
+            shift: self.shift,
+            translate_mode: self.translate_mode,
+            spring: self.spring,
         }
+        .validated()
     }
 }
 
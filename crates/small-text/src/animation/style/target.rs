@@ -1,6 +1,7 @@
 use std::{
     cmp::Ordering,
     collections::HashMap,
+    ops::RangeInclusive,
 };
 
 use caponata_common::Callable;
@@ -21,10 +22,17 @@ type AnimationTargetCustomCallable =
 /// 3. [`AnimationTarget::EveryFrom`]
 /// 4. [`AnimationTarget::ExceptEvery`]
 /// 5. [`AnimationTarget::ExceptEveryFrom`]
-/// 6. [`AnimationTarget::Range`]
-/// 7. [`AnimationTarget::Single`]
-/// 8. [`AnimationTarget::Untouched`]
-/// 9. [`AnimationTarget::UntouchedThisStep`]
+/// 6. [`AnimationTarget::NonWhitespace`]
+/// 7. [`AnimationTarget::Words`]
+/// 8. [`AnimationTarget::NthWord`]
+/// 9. [`AnimationTarget::Range`]
+/// 10. [`AnimationTarget::Single`]
+/// 11. [`AnimationTarget::Untouched`]
+/// 12. [`AnimationTarget::UntouchedThisStep`]
+///
+/// [`AnimationTarget::Union`], [`AnimationTarget::Intersection`]
+/// and [`AnimationTarget::Difference`] are applied along with
+/// their first operand.
 ///
 /// Default variant is [`AnimationTarget::Untouched`].
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
@@ -63,6 +71,23 @@ pub enum AnimationTarget {
     /// second represents the starting position.
     ExceptEveryFrom(u16, u16),
 
+    /// Positions of symbols whose characters in the
+    /// original text are not whitespace.
+    NonWhitespace,
+
+    /// Positions of symbols that belong to words of the
+    /// original text. A word is a run of alphanumeric
+    /// characters, so whitespace and punctuation are not
+    /// selected.
+    Words,
+
+    /// Positions of symbols of the n-th word of the
+    /// original text, starting from 0. Selects nothing if
+    /// the text has fewer words. See
+    /// [`AnimationTarget::Words`] for the definition of a
+    /// word.
+    NthWord(u16),
+
     /// Custom selection logic using a function.
     /// The function receives a hashmap of virtual
     /// x coordinates with corresponding symbol
@@ -78,22 +103,81 @@ pub enum AnimationTarget {
     /// Positions of symbols that were not affected
     /// by styling during the current animation step.
     UntouchedThisStep,
+
+    /// Positions selected by either of the targets.
+    Union(Box<AnimationTarget>, Box<AnimationTarget>),
+
+    /// Positions selected by both targets.
+    Intersection(Box<AnimationTarget>, Box<AnimationTarget>),
+
+    /// Positions selected by the first target, but not by
+    /// the second one.
+    Difference(Box<AnimationTarget>, Box<AnimationTarget>),
+}
+
+impl AnimationTarget {
+    /// Returns [`AnimationTarget::Union`] of this and provided
+    /// targets.
+    pub fn union(self, other: AnimationTarget) -> Self {
+        Self::Union(Box::new(self), Box::new(other))
+    }
+
+    /// Returns [`AnimationTarget::Intersection`] of this and
+    /// provided targets.
+    pub fn intersection(self, other: AnimationTarget) -> Self {
+        Self::Intersection(Box::new(self), Box::new(other))
+    }
+
+    /// Returns [`AnimationTarget::Difference`] of this and
+    /// provided targets, e.g. every second position except the
+    /// range `4..=7`:
+    ///
+    /// ```rust
+    /// use caponata_small_text::AnimationTarget;
+    ///
+    /// let target =
+    ///     AnimationTarget::Every(2).difference(AnimationTarget::Range(4, 7));
+    /// ```
+    pub fn difference(self, other: AnimationTarget) -> Self {
+        Self::Difference(Box::new(self), Box::new(other))
+    }
+
+    /// Returns the positions selected by
+    /// [`AnimationTarget::Single`] and
+    /// [`AnimationTarget::Range`] targets, which don't depend
+    /// on the text; otherwise returns `None`.
+    pub(crate) fn fixed_positions(&self) -> Option<RangeInclusive<u16>> {
+        match self {
+            Self::Single(x) => Some(*x..=*x),
+            Self::Range(start, end) => Some(*start..=*end),
+            _ => None,
+        }
+    }
 }
 
 pub(crate) fn animation_target_sorter(
     a: &AnimationTarget,
     b: &AnimationTarget,
 ) -> Ordering {
-    let priority = |item: &AnimationTarget| match item {
-        AnimationTarget::Custom(_) => 8,
-        AnimationTarget::Every(_) => 7,
-        AnimationTarget::EveryFrom(_, _) => 6,
-        AnimationTarget::ExceptEvery(_) => 5,
-        AnimationTarget::ExceptEveryFrom(_, _) => 4,
-        AnimationTarget::Range(_, _) => 3,
-        AnimationTarget::Single(_) => 2,
-        AnimationTarget::Untouched => 1,
-        AnimationTarget::UntouchedThisStep => 0,
-    };
-    priority(&a).cmp(&priority(&b))
+    fn priority(item: &AnimationTarget) -> u8 {
+        match item {
+            AnimationTarget::Custom(_) => 11,
+            AnimationTarget::Every(_) => 10,
+            AnimationTarget::EveryFrom(_, _) => 9,
+            AnimationTarget::ExceptEvery(_) => 8,
+            AnimationTarget::ExceptEveryFrom(_, _) => 7,
+            AnimationTarget::NonWhitespace => 6,
+            AnimationTarget::Words => 5,
+            AnimationTarget::NthWord(_) => 4,
+            AnimationTarget::Range(_, _) => 3,
+            AnimationTarget::Single(_) => 2,
+            AnimationTarget::Untouched => 1,
+            AnimationTarget::UntouchedThisStep => 0,
+            AnimationTarget::Union(first, _)
+            | AnimationTarget::Intersection(first, _)
+            | AnimationTarget::Difference(first, _) => priority(first),
+        }
+    }
+
+    priority(a).cmp(&priority(b))
 }
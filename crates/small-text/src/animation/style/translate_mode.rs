@@ -0,0 +1,15 @@
+/// Specifies what happens to symbols moved past the edges of
+/// the text by [`AnimationAction::TranslateBy`] or the shift of
+/// an [`AnimationStep`].
+///
+/// Default variant is [`AnimationTranslateMode::Clip`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnimationTranslateMode {
+    /// Symbols moved past the edges are discarded.
+    #[default]
+    Clip,
+
+    /// Symbols moved past an edge reappear at the opposite
+    /// one.
+    Wrap,
+}
@@ -2,28 +2,55 @@ use std::{
     collections::HashMap,
     fmt::Debug,
     hash::Hash,
+    sync::Arc,
+    time::Duration,
 };
 
+use caponata_common::{
+    AccessibleDescription,
+    Animated,
+    Callable,
+    Identifiable,
+    SharedClock,
+    Transition,
+    TransitionEvent,
+    TransitionState,
+    WidgetIdentity,
+    describe_widget,
+    render_adapted,
+};
 #[cfg(feature = "crossterm")]
 use crossterm::event::Event;
 use ratatui::{
     buffer::Buffer,
-    layout::Rect,
+    layout::{
+        Position,
+        Rect,
+    },
+    text::Line,
     widgets::Widget,
 };
 
 use super::{
     Animation,
     AnimationEvent,
+    AnimationRepeatMode,
+    AnimationRestoreMode,
     AnimationStyle,
+    TargetError,
 };
 #[cfg(feature = "crossterm")]
 use crate::InteractionEvent;
 use crate::{
     SmallTextStyle,
     SmallTextWidget,
+    Symbol,
+    symbols_to_string,
 };
 
+type FrameHookCallable =
+    Callable<(HashMap<u16, Symbol>,), HashMap<u16, Symbol>>;
+
 /// Provides a high-level API for working with animated
 /// [`SmallTextWidget`] without the need for manual
 /// animation control.
@@ -45,7 +72,7 @@ use crate::{
 /// };
 ///
 /// use ratatui::style::{Color, Modifier};
-/// use ratatui_small_text::{
+/// use caponata_small_text::{
 ///     Target,
 ///     AnimationTarget,
 ///     SymbolStyleBuilder,
@@ -69,7 +96,8 @@ use crate::{
 ///     .update_background_color(Color::Blue)
 ///     .remove_all_modifiers()
 ///     .then()
-///     .build();
+///     .build()
+///     .unwrap();
 /// let second_step = AnimationStepBuilder::default()
 ///     .with_duration(Duration::from_millis(100))
 ///     .for_target(AnimationTarget::Every(2))
@@ -82,7 +110,8 @@ use crate::{
 ///     .update_background_color(Color::Green)
 ///     .remove_all_modifiers()
 ///     .then()
-///     .build();
+///     .build()
+///     .unwrap();
 /// let animation_style = AnimationStyleBuilder::default()
 ///     .with_advance_mode(AnimationAdvanceMode::Auto)
 ///     .with_repeat_mode(AnimationRepeatMode::Finite(1))
@@ -119,8 +148,27 @@ where
     K: Debug + Hash + PartialEq + Eq,
 {
     text: SmallTextWidget,
-    animation_styles: HashMap<K, AnimationStyle>,
+
+    /// Symbols created from the text style, displayed when
+    /// no animation is applied.
+    static_symbols: HashMap<u16, Symbol>,
+    animation_styles: HashMap<K, Arc<AnimationStyle>>,
     active_animation: Option<Animation>,
+
+    /// Whether the symbols were already restored after the
+    /// active animation ended.
+    is_animation_ended: bool,
+    clock: SharedClock,
+
+    /// A function called with the symbols of every animation
+    /// frame before rendering. Returned symbols are rendered
+    /// instead.
+    frame_hook: Option<FrameHookCallable>,
+
+    /// Visibility of the widget and transitions played when it
+    /// is shown or hidden.
+    transition_state: TransitionState,
+    identity: WidgetIdentity,
 }
 
 impl<K> Widget for &mut AnimatedSmallTextWidget<K>
@@ -128,17 +176,67 @@ where
     K: Debug + Hash + PartialEq + Eq,
 {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        if let Some(animation) = self.active_animation.as_mut()
-            && let Some(frame) = animation.next_frame()
-        {
-            let text_symbols = self.text.mut_symbols();
+        self.advance();
 
-            for (x, new_symbol) in frame.symbols {
-                text_symbols.insert(x, new_symbol);
+        let text = &mut self.text;
+        let transition_state = &mut self.transition_state;
+        render_adapted(area, buf, |buf| {
+            transition_state
+                .render(area, buf, |area, buf| text.render(area, buf));
+        });
+        self.identity.record(area, &*self);
+    }
+}
+
+impl<K> AnimatedSmallTextWidget<K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    /// Applies the next frame of the active animation to the
+    /// symbols of the text, restoring them once the animation
+    /// ends.
+    fn advance(&mut self) {
+        let frame = self
+            .active_animation
+            .as_mut()
+            .map(|animation| animation.next_frame());
+
+        match frame {
+            Some(Some(frame)) => {
+                let frame_symbols = match &self.frame_hook {
+                    Some(frame_hook) => frame_hook.call((frame.symbols,)),
+                    None => frame.symbols,
+                };
+                let text_symbols = self.text.mut_symbols();
+
+                for (x, new_symbol) in frame_symbols {
+                    text_symbols.insert(x, new_symbol);
+                }
             }
+            Some(None) if !self.is_animation_ended => {
+                self.is_animation_ended = true;
+                self.restore_symbols();
+            }
+            _ => {}
         }
+    }
 
-        self.text.render(area, buf);
+    /// Advances the active animation the same way rendering
+    /// does and returns the resulting symbols as a line. Useful
+    /// to show animated text in a ratatui `Table` cell or
+    /// `List` item, which accept lines.
+    pub fn next_line(&mut self) -> Line<'static> {
+        self.advance();
+        self.text.to_line()
+    }
+
+    /// Advances the active animation the same way rendering
+    /// does and returns the resulting symbols by their virtual
+    /// x coordinates. Useful to display the animated symbols
+    /// with another widget, e.g. as large glyphs.
+    pub fn next_symbols(&mut self) -> &HashMap<u16, Symbol> {
+        self.advance();
+        self.text.symbols()
     }
 }
 
@@ -151,14 +249,94 @@ where
         animation_styles: HashMap<K, AnimationStyle>,
     ) -> Self {
         let text = SmallTextWidget::new(text_style);
+        let static_symbols = text.symbols().clone();
+        let animation_styles = animation_styles
+            .into_iter()
+            .map(|(key, style)| (key, Arc::new(style)))
+            .collect();
 
         Self {
             text,
+            static_symbols,
             animation_styles,
             active_animation: None,
+            is_animation_ended: false,
+            clock: SharedClock::default(),
+            frame_hook: None,
+            transition_state: TransitionState::default(),
+            identity: WidgetIdentity::default(),
         }
     }
 
+    /// Replaces the clock used by the animations, including
+    /// the currently active one. Widgets sharing the clock of
+    /// an [`AnimationGroup`] advance from a single timeline.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        if let Some(animation) = self.active_animation.as_mut() {
+            animation.set_clock(clock.clone());
+        }
+        #[cfg(feature = "spinner")]
+        self.text.set_clock(clock.clone());
+        self.transition_state.set_clock(clock.clone());
+        self.clock = clock;
+    }
+
+    /// Sets transitions played by [`Self::show`] and
+    /// [`Self::hide`]. The widget appears and disappears
+    /// instantly if a transition is `None`.
+    pub fn set_transitions(
+        &mut self,
+        enter: Option<Transition>,
+        exit: Option<Transition>,
+    ) {
+        self.transition_state.set_transitions(enter, exit);
+    }
+
+    /// Starts rendering the widget, playing its enter
+    /// transition.
+    pub fn show(&mut self) {
+        self.transition_state.show();
+    }
+
+    /// Plays the exit transition of the widget, after which it
+    /// is no longer rendered and ignores events.
+    pub fn hide(&mut self) {
+        self.transition_state.hide();
+    }
+
+    /// Shows or hides the widget instantly, without playing
+    /// transitions.
+    pub fn set_visible(&mut self, visible: bool) {
+        self.transition_state.set_visible(visible);
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.transition_state.is_visible()
+    }
+
+    /// Returns the events produced by the completed
+    /// transitions since they were last taken.
+    pub fn drain_transition_events(
+        &mut self,
+    ) -> impl Iterator<Item = TransitionEvent> {
+        self.transition_state.drain_events()
+    }
+
+    /// Sets a function called with the symbols of every frame
+    /// of the active animation after it is computed but before
+    /// it is rendered. Symbols returned by the function are
+    /// rendered instead, so it can override some of them, e.g.
+    /// with live data. Passing `None` removes the function.
+    pub fn set_frame_hook(&mut self, frame_hook: Option<FrameHookCallable>) {
+        self.frame_hook = frame_hook;
+    }
+
+    /// Returns the currently active animation, if any. Useful
+    /// for inspecting its timeline.
+    pub fn active_animation(&self) -> Option<&Animation> {
+        self.active_animation.as_ref()
+    }
+
     pub fn take_animation_event(&mut self) -> Option<AnimationEvent> {
         if let Some(animation) = &mut self.active_animation {
             animation.take_last_event()
@@ -167,12 +345,43 @@ where
         }
     }
 
+    /// Returns the events produced by the active animation
+    /// since they were last taken, from the oldest to the most
+    /// recent one.
+    pub fn drain_events(&mut self) -> impl Iterator<Item = AnimationEvent> {
+        self.active_animation
+            .iter_mut()
+            .flat_map(|animation| animation.drain_events())
+    }
+
+    /// Returns the number of cells the text occupies, like
+    /// [`SmallTextWidget::width`].
+    pub fn width(&self) -> u16 {
+        self.text.width()
+    }
+
+    /// Returns the virtual x coordinate and the symbol rendered
+    /// at provided position, like [`SmallTextWidget::hit_test`].
+    pub fn hit_test(
+        &self,
+        area: Rect,
+        position: Position,
+    ) -> Option<(u16, Symbol)> {
+        if !self.is_visible() {
+            return None;
+        }
+        self.text.hit_test(area, position)
+    }
+
     #[cfg(feature = "crossterm")]
     pub fn handle_crossterm_event(
         &mut self,
         event: Event,
         area: Rect,
     ) -> Option<InteractionEvent> {
+        if !self.is_visible() {
+            return None;
+        }
         self.text.handle_event(event, area)
     }
 
@@ -182,15 +391,59 @@ where
     pub fn enable_animation(&mut self, key: &K) {
         if let Some(style) = self.animation_styles.get(key) {
             let text_symbols = self.text.symbols().clone();
-            let animation = Animation::new(style.clone(), text_symbols);
+            let mut animation = Animation::new(style.clone(), text_symbols);
+            animation.set_clock(self.clock.clone());
+            self.active_animation = Some(animation);
+            self.is_animation_ended = false;
+        }
+    }
+
+    /// Plays the animation associated with the specified key
+    /// a single time, regardless of its repeat and restore
+    /// modes, if it exists. Once the animation ends, the static
+    /// styles of the text are restored and
+    /// [`AnimationEvent::Ended`] is emitted. Replaces any
+    /// currently active animation.
+    pub fn play_once(&mut self, key: &K) {
+        if let Some(style) = self.animation_styles.get(key) {
+            let text_symbols = self.text.symbols().clone();
+            let mut animation = Animation::with_modes(
+                style.clone(),
+                text_symbols,
+                AnimationRepeatMode::Finite(1),
+                AnimationRestoreMode::RestoreStatic,
+            );
+            animation.set_clock(self.clock.clone());
+            self.active_animation = Some(animation);
+            self.is_animation_ended = false;
+        }
+    }
+
+    /// Enables the animation associated with the specified key
+    /// like [`AnimatedSmallTextWidget::enable_animation`], but
+    /// returns [`TargetError`] and keeps the current animation
+    /// if the animation targets positions outside of the text.
+    pub fn try_enable_animation(
+        &mut self,
+        key: &K,
+    ) -> Result<(), TargetError> {
+        if let Some(style) = self.animation_styles.get(key) {
+            let text_symbols = self.text.symbols().clone();
+            let mut animation =
+                Animation::try_new(style.clone(), text_symbols)?;
+            animation.set_clock(self.clock.clone());
             self.active_animation = Some(animation);
+            self.is_animation_ended = false;
         }
+
+        Ok(())
     }
 
     /// Disables the currently active animation, if any;
     /// otherwise has no effect.
     pub fn disable_animation(&mut self) {
         self.active_animation = None;
+        self.is_animation_ended = false;
     }
 
     /// Pauses the currently active animation if it is not
@@ -205,10 +458,382 @@ where
         self.active_animation.as_mut().map(|a| a.unpause());
     }
 
+    /// Returns the time left until the active animation, the
+    /// running transition or a spinner of the text displays its
+    /// next frame, or `None` if none of them advances by
+    /// itself.
+    pub fn time_until_next_frame(&self) -> Option<Duration> {
+        let animation_time = self
+            .active_animation
+            .as_ref()
+            .and_then(|animation| animation.time_until_next_frame());
+        let transition_time = self.transition_state.time_until_next_frame();
+        #[cfg(feature = "spinner")]
+        let transition_time = transition_time
+            .into_iter()
+            .chain(self.text.time_until_next_frame());
+
+        animation_time.into_iter().chain(transition_time).min()
+    }
+
     /// Advances the currently active animation if its advance
     /// mode is [`AnimationAdvanceMode::Manual`]. Has no effect
     /// if no animation is active or if it's in automatic mode.
     pub fn advance_animation(&mut self) {
         self.active_animation.as_mut().map(|a| a.advance());
     }
+
+    /// Restores the symbols according to the restore mode of
+    /// the active animation.
+    fn restore_symbols(&mut self) {
+        let Some(animation) = self.active_animation.as_ref() else {
+            return;
+        };
+
+        let symbols = match animation.restore_mode() {
+            AnimationRestoreMode::KeepLastFrame => return,
+            AnimationRestoreMode::RestoreInitial => {
+                animation.initial_symbols().clone()
+            }
+            AnimationRestoreMode::RestoreStatic => self.static_symbols.clone(),
+        };
+        *self.text.mut_symbols() = symbols;
+    }
+}
+
+impl<K> Animated for AnimatedSmallTextWidget<K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn time_until_next_frame(&self) -> Option<Duration> {
+        AnimatedSmallTextWidget::time_until_next_frame(self)
+    }
+}
+
+impl<K> AccessibleDescription for AnimatedSmallTextWidget<K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    /// Describes the text without the animation applied, so
+    /// the frames of the animation are not read out.
+    fn accessible_description(&self) -> String {
+        let animation = match &self.active_animation {
+            Some(animation) if animation.is_paused() => "animation paused",
+            Some(_) if !self.is_animation_ended => "animated",
+            _ => "",
+        };
+        let visibility = if self.is_visible() { "" } else { "hidden" };
+
+        describe_widget(
+            "text",
+            Some(&symbols_to_string(&self.static_symbols)),
+            [animation, visibility],
+        )
+    }
+}
+
+impl<K> Identifiable for AnimatedSmallTextWidget<K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        time::Duration,
+    };
+
+    use caponata_common::{
+        AccessibleDescription,
+        AnimationGroup,
+        Callable,
+        Transition,
+        TransitionDirection,
+        TransitionEvent,
+        TransitionKind,
+    };
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        style::Color,
+        widgets::{
+            List,
+            ListItem,
+            Widget,
+        },
+    };
+
+    use super::AnimatedSmallTextWidget;
+    use crate::{
+        AnimationEvent,
+        AnimationRepeatMode,
+        AnimationRestoreMode,
+        AnimationStepBuilder,
+        AnimationStyleBuilder,
+        AnimationTarget,
+        SmallTextStyleBuilder,
+        Symbol,
+    };
+
+    #[test]
+    fn frame_hook() {
+        let step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_millis(100))
+            .for_target(AnimationTarget::Range(0, 2))
+            .update_foreground_color(Color::Red)
+            .then()
+            .build()
+            .unwrap();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_steps(vec![step])
+            .build()
+            .unwrap();
+        let text_style =
+            SmallTextStyleBuilder::default().with_text("abc").build();
+        let mut text = AnimatedSmallTextWidget::new(
+            text_style,
+            HashMap::from([((), animation_style)]),
+        );
+
+        let frame_hook = |(mut symbols,): (HashMap<u16, Symbol>,)| {
+            if let Some(symbol) = symbols.get_mut(&1) {
+                symbol.value = 'x';
+            }
+            symbols
+        };
+        text.set_frame_hook(Some(Callable::from_fn(frame_hook)));
+        text.enable_animation(&());
+
+        let area = Rect::new(0, 0, 3, 1);
+        let mut buf = Buffer::empty(area);
+        text.render(area, &mut buf);
+
+        let mut expected = Buffer::with_lines(vec!["axc"]);
+        expected.set_style(area, Color::Red);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn next_line_in_list_item() {
+        let step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_millis(100))
+            .for_target(AnimationTarget::Single(0))
+            .update_foreground_color(Color::Red)
+            .then()
+            .build()
+            .unwrap();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_steps(vec![step])
+            .build()
+            .unwrap();
+        let text_style =
+            SmallTextStyleBuilder::default().with_text("ok").build();
+        let mut text = AnimatedSmallTextWidget::new(
+            text_style,
+            HashMap::from([((), animation_style)]),
+        );
+        text.enable_animation(&());
+
+        let list = List::new([ListItem::new(text.next_line())]);
+        let area = Rect::new(0, 0, 2, 1);
+        let mut buf = Buffer::empty(area);
+        Widget::render(list, area, &mut buf);
+
+        assert_eq!(buf[(0, 0)].symbol(), "o");
+        assert_eq!(buf[(0, 0)].fg, Color::Red);
+        assert_eq!(buf[(1, 0)].fg, Color::Reset);
+    }
+
+    #[test]
+    fn play_once() {
+        let step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_millis(100))
+            .for_target(AnimationTarget::Range(0, 2))
+            .update_foreground_color(Color::Red)
+            .then()
+            .build()
+            .unwrap();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_steps(vec![step])
+            .build()
+            .unwrap();
+        let text_style =
+            SmallTextStyleBuilder::default().with_text("abc").build();
+        let mut text = AnimatedSmallTextWidget::new(
+            text_style,
+            HashMap::from([((), animation_style)]),
+        );
+        let group = AnimationGroup::new();
+        text.set_clock(group.clock());
+        text.play_once(&());
+        assert_eq!(text.accessible_description(), "text 'abc', animated");
+
+        let area = Rect::new(0, 0, 3, 1);
+        let mut buf = Buffer::empty(area);
+        text.render(area, &mut buf);
+
+        let mut expected = Buffer::with_lines(vec!["abc"]);
+        expected.set_style(area, Color::Red);
+        assert_eq!(buf, expected);
+
+        group.advance(Duration::from_millis(100));
+        let mut buf = Buffer::empty(area);
+        text.render(area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec!["abc"]));
+        assert_eq!(text.take_animation_event(), Some(AnimationEvent::Ended));
+        assert_eq!(text.accessible_description(), "text 'abc'");
+    }
+
+    #[test]
+    fn events_are_drained_in_order() {
+        let step = AnimationStepBuilder::default()
+            .with_duration(Duration::from_millis(100))
+            .for_target(AnimationTarget::Range(0, 2))
+            .update_foreground_color(Color::Red)
+            .then()
+            .build()
+            .unwrap();
+        let animation_style = AnimationStyleBuilder::default()
+            .with_steps(vec![step])
+            .build()
+            .unwrap();
+        let text_style =
+            SmallTextStyleBuilder::default().with_text("abc").build();
+        let mut text = AnimatedSmallTextWidget::new(
+            text_style,
+            HashMap::from([((), animation_style)]),
+        );
+        let group = AnimationGroup::new();
+        text.set_clock(group.clock());
+        text.play_once(&());
+
+        let area = Rect::new(0, 0, 3, 1);
+        let mut buf = Buffer::empty(area);
+        text.render(area, &mut buf);
+        group.advance(Duration::from_millis(100));
+        text.render(area, &mut buf);
+
+        let events: Vec<AnimationEvent> = text.drain_events().collect();
+        assert_eq!(
+            events,
+            vec![AnimationEvent::FrameGenerated, AnimationEvent::Ended],
+        );
+        assert_eq!(text.take_animation_event(), None);
+    }
+
+    #[test]
+    fn restore_modes() {
+        let animation_styles = [
+            (AnimationRestoreMode::KeepLastFrame, Color::Red),
+            (AnimationRestoreMode::RestoreInitial, Color::Green),
+            (AnimationRestoreMode::RestoreStatic, Color::Blue),
+        ]
+        .map(|(restore_mode, color)| {
+            let step = AnimationStepBuilder::default()
+                .with_duration(Duration::from_millis(100))
+                .for_target(AnimationTarget::Range(0, 2))
+                .update_foreground_color(color)
+                .then()
+                .build()
+                .unwrap();
+            let animation_style = AnimationStyleBuilder::default()
+                .with_repeat_mode(AnimationRepeatMode::Finite(1))
+                .with_restore_mode(restore_mode)
+                .with_steps(vec![step])
+                .build()
+                .unwrap();
+            (restore_mode, animation_style)
+        });
+        let text_style =
+            SmallTextStyleBuilder::default().with_text("abc").build();
+        let mut text = AnimatedSmallTextWidget::new(
+            text_style,
+            HashMap::from(animation_styles),
+        );
+        let group = AnimationGroup::new();
+        text.set_clock(group.clock());
+
+        let area = Rect::new(0, 0, 3, 1);
+        let mut red_text = Buffer::with_lines(vec!["abc"]);
+        red_text.set_style(area, Color::Red);
+
+        for (restore_mode, expected) in [
+            (AnimationRestoreMode::KeepLastFrame, red_text.clone()),
+            (AnimationRestoreMode::RestoreInitial, red_text),
+            (
+                AnimationRestoreMode::RestoreStatic,
+                Buffer::with_lines(vec!["abc"]),
+            ),
+        ] {
+            text.enable_animation(&restore_mode);
+            text.render(area, &mut Buffer::empty(area));
+            group.advance(Duration::from_millis(100));
+            let mut buf = Buffer::empty(area);
+            text.render(area, &mut buf);
+
+            assert_eq!(buf, expected);
+        }
+    }
+
+    #[test]
+    fn enter_and_exit_transitions() {
+        let text_style =
+            SmallTextStyleBuilder::default().with_text("abcd").build();
+        let mut text =
+            AnimatedSmallTextWidget::<()>::new(text_style, HashMap::new());
+        let group = AnimationGroup::new();
+        text.set_clock(group.clock());
+
+        let wipe = Transition::new(
+            TransitionKind::Wipe(TransitionDirection::Right),
+            Duration::from_millis(100),
+        );
+        text.set_transitions(Some(wipe), Some(wipe));
+        text.set_visible(false);
+
+        let area = Rect::new(0, 0, 4, 1);
+        let render = |text: &mut AnimatedSmallTextWidget<()>| {
+            let mut buf = Buffer::empty(area);
+            text.render(area, &mut buf);
+            buf
+        };
+        assert_eq!(render(&mut text), Buffer::empty(area));
+
+        text.show();
+        group.advance(Duration::from_millis(50));
+        assert_eq!(render(&mut text), Buffer::with_lines(["ab  "]));
+        assert!(text.time_until_next_frame().is_some());
+
+        group.advance(Duration::from_millis(50));
+        assert_eq!(render(&mut text), Buffer::with_lines(["abcd"]));
+        assert_eq!(text.time_until_next_frame(), None);
+
+        text.hide();
+        group.advance(Duration::from_millis(100));
+        assert_eq!(render(&mut text), Buffer::empty(area));
+        assert!(!text.is_visible());
+        assert_eq!(
+            text.drain_transition_events().collect::<Vec<_>>(),
+            vec![TransitionEvent::Shown, TransitionEvent::Hidden],
+        );
+    }
+
+    #[test]
+    fn is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<AnimatedSmallTextWidget<()>>();
+        assert_send_sync::<crate::Animation>();
+        assert_send_sync::<crate::AnimationStyle>();
+    }
 }
@@ -1,5 +1,7 @@
 #![doc = include_str!("../README.md")]
 
+extern crate alloc;
+
 #[cfg(feature = "animation")]
 pub mod animation;
 pub mod text;
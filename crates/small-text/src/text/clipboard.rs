@@ -0,0 +1,90 @@
+use std::{
+    fmt,
+    sync::Arc,
+};
+
+/// A destination of the text copied from [`SmallTextWidget`],
+/// e.g. the system clipboard.
+///
+/// The crate doesn't depend on any clipboard library, so
+/// applications implement this trait with the one they use.
+/// Providers are shared between widgets that may live on
+/// different threads, so they must be [`Send`] and [`Sync`].
+///
+/// [`SmallTextWidget`]: crate::SmallTextWidget
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::{
+///     Arc,
+///     Mutex,
+/// };
+///
+/// use caponata_small_text::{
+///     ClipboardProvider,
+///     SharedClipboard,
+///     SmallTextWidget,
+/// };
+///
+/// #[derive(Default)]
+/// struct InMemoryClipboard(Mutex<String>);
+///
+/// impl ClipboardProvider for InMemoryClipboard {
+///     fn set_text(&self, text: &str) {
+///         *self.0.lock().unwrap() = text.to_owned();
+///     }
+/// }
+///
+/// let clipboard = Arc::new(InMemoryClipboard::default());
+/// let mut text = SmallTextWidget::plain("Hello");
+/// text.set_clipboard(SharedClipboard::new(clipboard.clone()));
+///
+/// text.set_selection(1..3);
+/// assert_eq!(text.copy_selection().as_deref(), Some("el"));
+/// assert_eq!(*clipboard.0.lock().unwrap(), "el");
+/// ```
+pub trait ClipboardProvider: Send + Sync {
+    /// Replaces the contents of the clipboard with provided
+    /// text. Failures are expected to be handled by the
+    /// provider, as the widget has no way to report them.
+    fn set_text(&self, text: &str);
+}
+
+/// A cheaply cloneable handle to a [`ClipboardProvider`].
+/// Clones of the handle share the same provider.
+pub struct SharedClipboard {
+    provider: Arc<dyn ClipboardProvider>,
+}
+
+impl fmt::Debug for SharedClipboard {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("SharedClipboard")
+    }
+}
+
+impl PartialEq for SharedClipboard {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.provider, &other.provider)
+    }
+}
+
+impl Eq for SharedClipboard {}
+
+impl Clone for SharedClipboard {
+    fn clone(&self) -> Self {
+        Self {
+            provider: self.provider.clone(),
+        }
+    }
+}
+
+impl SharedClipboard {
+    pub fn new(provider: Arc<dyn ClipboardProvider>) -> Self {
+        Self { provider }
+    }
+
+    pub fn set_text(&self, text: &str) {
+        self.provider.set_text(text);
+    }
+}
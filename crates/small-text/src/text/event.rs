@@ -1,3 +1,5 @@
+use caponata_common::WidgetEvent;
+
 use super::Symbol;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -7,4 +9,46 @@ pub enum InteractionEvent {
     Unhovered,
     Pressed(Symbol),
     Released(Symbol),
+
+    /// Triggered when a mouse button, pressed over a
+    /// [`SmallTextWidget`], is released outside of it.
+    ReleasedOutside,
+
+    /// Triggered when Enter is pressed in the cursor mode.
+    /// Includes the virtual x coordinate of the symbol under
+    /// the cursor.
+    Activated(u16, Symbol),
+
+    /// Triggered when the selected symbols change by dragging
+    /// the mouse. The selection is returned by
+    /// [`SmallTextWidget::selection`].
+    SelectionChanged,
+
+    /// Triggered when the selection is copied with Ctrl+C. The
+    /// copied text is pushed to the clipboard of the widget.
+    #[cfg(feature = "clipboard")]
+    SelectionCopied,
+}
+
+impl From<InteractionEvent> for WidgetEvent {
+    fn from(value: InteractionEvent) -> Self {
+        match value {
+            InteractionEvent::Hovered(_)
+            | InteractionEvent::HoveredSymbolChanged(_) => {
+                WidgetEvent::Hovered
+            }
+            InteractionEvent::Unhovered => WidgetEvent::Unhovered,
+            InteractionEvent::Pressed(_) => WidgetEvent::Pressed,
+            InteractionEvent::Released(_) => WidgetEvent::Released,
+            InteractionEvent::ReleasedOutside => WidgetEvent::ReleasedOutside,
+            InteractionEvent::Activated(x, _) => {
+                WidgetEvent::Selected(x.into())
+            }
+            InteractionEvent::SelectionChanged => {
+                WidgetEvent::SelectionChanged
+            }
+            #[cfg(feature = "clipboard")]
+            InteractionEvent::SelectionCopied => WidgetEvent::Copied,
+        }
+    }
 }
@@ -0,0 +1,223 @@
+use ratatui::style::{
+    Color,
+    Modifier,
+};
+
+use super::{
+    Markup,
+    style_ranges,
+};
+use crate::SymbolStyle;
+
+const ESCAPE: char = '\x1b';
+const BELL: char = '\x07';
+
+/// Parses text containing ANSI escape sequences, e.g. output
+/// captured from another CLI tool, into [`Markup`], so it can
+/// be displayed and animated like any other text.
+///
+/// SGR sequences (`ESC [ ... m`) set the colors and modifiers
+/// of the following symbols. Basic, bright, 256 and RGB colors
+/// are supported. Other escape sequences, like cursor movements
+/// or hyperlinks, are dropped, as are unsupported SGR codes.
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_small_text::{
+///     SmallTextWidget,
+///     parse_ansi,
+/// };
+/// use ratatui::style::{
+///     Color,
+///     Modifier,
+/// };
+///
+/// let markup = parse_ansi("\x1b[1;31merror\x1b[0m: not found");
+/// assert_eq!(markup.text(), "error: not found");
+///
+/// let text = SmallTextWidget::new(markup.text_style());
+/// assert_eq!(text.symbols()[&0].foreground_color, Some(Color::Red));
+/// assert_eq!(text.symbols()[&0].modifier, Modifier::BOLD);
+/// assert_eq!(text.symbols()[&5].foreground_color, None);
+/// ```
+pub fn parse_ansi(source: &str) -> Markup {
+    let mut text = String::new();
+    let mut styles: Vec<SymbolStyle> = Vec::new();
+    let mut current_style = SymbolStyle::default();
+
+    let mut chars = source.chars().peekable();
+    while let Some(character) = chars.next() {
+        if character != ESCAPE {
+            text.push(character);
+            styles.push(current_style);
+            continue;
+        }
+
+        match chars.next() {
+            Some('[') => {
+                let mut parameters = String::new();
+                let final_byte = chars.find(|character| {
+                    let is_final = ('\x40'..='\x7e').contains(character);
+                    if !is_final {
+                        parameters.push(*character);
+                    }
+                    is_final
+                });
+                if final_byte == Some('m') {
+                    apply_sgr(&parameters, &mut current_style);
+                }
+            }
+            // Operating system commands, e.g. hyperlinks, end
+            // with a bell or with `ESC \`.
+            Some(']') => {
+                while let Some(character) = chars.next() {
+                    if character == BELL {
+                        break;
+                    }
+                    if character == ESCAPE && chars.next_if_eq(&'\\').is_some()
+                    {
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Markup {
+        text,
+        symbol_styles: style_ranges(&styles),
+    }
+}
+
+/// Applies the codes of a SGR sequence, separated by `;`, on
+/// top of provided style. Empty codes are read as `0`, and
+/// codes that can't be parsed are skipped.
+fn apply_sgr(parameters: &str, style: &mut SymbolStyle) {
+    let mut codes = parameters.split([';', ':']).filter_map(|code| {
+        if code.is_empty() {
+            Some(0)
+        } else {
+            code.parse::<u8>().ok()
+        }
+    });
+
+    while let Some(code) = codes.next() {
+        match code {
+            0 => *style = SymbolStyle::default(),
+            1 => style.modifier |= Modifier::BOLD,
+            2 => style.modifier |= Modifier::DIM,
+            3 => style.modifier |= Modifier::ITALIC,
+            4 => style.modifier |= Modifier::UNDERLINED,
+            5 => style.modifier |= Modifier::SLOW_BLINK,
+            6 => style.modifier |= Modifier::RAPID_BLINK,
+            7 => style.modifier |= Modifier::REVERSED,
+            8 => style.modifier |= Modifier::HIDDEN,
+            9 => style.modifier |= Modifier::CROSSED_OUT,
+            22 => style.modifier -= Modifier::BOLD | Modifier::DIM,
+            23 => style.modifier -= Modifier::ITALIC,
+            24 => style.modifier -= Modifier::UNDERLINED,
+            25 => {
+                style.modifier -= Modifier::SLOW_BLINK | Modifier::RAPID_BLINK
+            }
+            27 => style.modifier -= Modifier::REVERSED,
+            28 => style.modifier -= Modifier::HIDDEN,
+            29 => style.modifier -= Modifier::CROSSED_OUT,
+            30..=37 => style.foreground_color = Some(basic_color(code - 30)),
+            38 => style.foreground_color = extended_color(&mut codes),
+            39 => style.foreground_color = None,
+            40..=47 => style.background_color = Some(basic_color(code - 40)),
+            48 => style.background_color = extended_color(&mut codes),
+            49 => style.background_color = None,
+            90..=97 => style.foreground_color = Some(bright_color(code - 90)),
+            100..=107 => {
+                style.background_color = Some(bright_color(code - 100))
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns the color of a `38` or `48` code, which is followed
+/// by either `5;<index>` or `2;<red>;<green>;<blue>`.
+fn extended_color(codes: &mut impl Iterator<Item = u8>) -> Option<Color> {
+    match codes.next()? {
+        5 => codes.next().map(Color::Indexed),
+        2 => Some(Color::Rgb(codes.next()?, codes.next()?, codes.next()?)),
+        _ => None,
+    }
+}
+
+fn basic_color(index: u8) -> Color {
+    match index {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        _ => Color::Gray,
+    }
+}
+
+fn bright_color(index: u8) -> Color {
+    match index {
+        0 => Color::DarkGray,
+        1 => Color::LightRed,
+        2 => Color::LightGreen,
+        3 => Color::LightYellow,
+        4 => Color::LightBlue,
+        5 => Color::LightMagenta,
+        6 => Color::LightCyan,
+        _ => Color::White,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::{
+        Color,
+        Modifier,
+    };
+
+    use super::parse_ansi;
+    use crate::SmallTextWidget;
+
+    #[test]
+    fn sgr_codes_are_applied_and_other_sequences_dropped() {
+        let markup = parse_ansi(
+            "\x1b[2K\x1b[1;38;5;208ma\x1b[22;48;2;1;2;3mb\x1b[39;49m\
+             \x1b]8;;https://example.com\x07c\x1b]8;;\x1b\\\x1b[0;94md",
+        );
+        assert_eq!(markup.text(), "abcd");
+
+        let text = SmallTextWidget::new(markup.text_style());
+        let symbols = text.symbols();
+        assert_eq!(symbols[&0].foreground_color, Some(Color::Indexed(208)));
+        assert_eq!(symbols[&0].modifier, Modifier::BOLD);
+        assert_eq!(symbols[&1].foreground_color, Some(Color::Indexed(208)));
+        assert_eq!(symbols[&1].background_color, Some(Color::Rgb(1, 2, 3)));
+        assert_eq!(symbols[&1].modifier, Modifier::empty());
+        assert_eq!(symbols[&2].foreground_color, None);
+        assert_eq!(symbols[&2].background_color, None);
+        assert_eq!(symbols[&3].foreground_color, Some(Color::LightBlue));
+    }
+
+    #[test]
+    fn out_of_range_codes_are_skipped() {
+        let markup =
+            parse_ansi("\x1b[1;31ma\x1b[300mb\x1b[38;2;300;0;0mc\x1b[mc");
+        assert_eq!(markup.text(), "abcc");
+
+        let text = SmallTextWidget::new(markup.text_style());
+        let symbols = text.symbols();
+        assert_eq!(symbols[&1].foreground_color, Some(Color::Red));
+        assert_eq!(symbols[&1].modifier, Modifier::BOLD);
+        assert_ne!(symbols[&2].foreground_color, Some(Color::Rgb(0, 0, 0)));
+        assert_eq!(symbols[&2].modifier, Modifier::BOLD);
+        assert_eq!(symbols[&3].foreground_color, None);
+        assert_eq!(symbols[&3].modifier, Modifier::empty());
+    }
+}
@@ -0,0 +1,64 @@
+use std::{
+    error::Error,
+    fmt,
+};
+
+/// An error returned when a markup string accepted by
+/// [`parse_markup`] can't be parsed. Positions are byte offsets
+/// in the source.
+///
+/// [`parse_markup`]: crate::parse_markup
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MarkupError {
+    /// A tag is not one of the supported ones.
+    UnknownTag { position: usize, tag: String },
+
+    /// A color of a `fg` or `bg` tag can't be parsed by
+    /// ratatui.
+    InvalidColor { position: usize, value: String },
+
+    /// A closing tag doesn't match the innermost open tag.
+    UnexpectedClosingTag { position: usize, tag: String },
+
+    /// A tag is still open at the end of the source.
+    UnclosedTag { position: usize, tag: String },
+
+    /// A `<` is not followed by a `>`. Use `\<` for a literal
+    /// `<`.
+    UnterminatedTag { position: usize },
+}
+
+impl fmt::Display for MarkupError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownTag { position, tag } => {
+                write!(
+                    formatter,
+                    "unknown tag `{}` at position {}",
+                    tag, position
+                )
+            }
+            Self::InvalidColor { position, value } => write!(
+                formatter,
+                "invalid color `{}` at position {}",
+                value, position,
+            ),
+            Self::UnexpectedClosingTag { position, tag } => write!(
+                formatter,
+                "unexpected closing tag `{}` at position {}",
+                tag, position,
+            ),
+            Self::UnclosedTag { position, tag } => write!(
+                formatter,
+                "tag `{}` at position {} is not closed",
+                tag, position,
+            ),
+            Self::UnterminatedTag { position } => {
+                write!(formatter, "unterminated tag at position {}", position)
+            }
+        }
+    }
+}
+
+impl Error for MarkupError {}
@@ -0,0 +1,7 @@
+mod ansi;
+mod error;
+mod parser;
+
+pub use ansi::*;
+pub use error::*;
+pub use parser::*;
@@ -0,0 +1,312 @@
+use std::{
+    collections::HashMap,
+    str::FromStr,
+};
+
+use ratatui::style::{
+    Color,
+    Modifier,
+};
+
+use super::MarkupError;
+use crate::{
+    SmallTextStyle,
+    SymbolStyle,
+    Target,
+};
+
+/// Text with styles parsed from a lightweight markup by
+/// [`parse_markup`] or from ANSI escape sequences by
+/// [`parse_ansi`]. The text owns the characters the markup
+/// consists of, so [`SmallTextStyle`] borrows it.
+///
+/// [`parse_ansi`]: crate::parse_ansi
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Markup {
+    pub(super) text: String,
+    pub(super) symbol_styles: HashMap<Target, SymbolStyle>,
+}
+
+impl Markup {
+    /// Returns the text without the tags.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the styles of the ranges of the symbols
+    /// enclosed in tags.
+    pub fn symbol_styles(&self) -> &HashMap<Target, SymbolStyle> {
+        &self.symbol_styles
+    }
+
+    /// Returns the style of the text, which can be passed to
+    /// [`crate::SmallTextWidget::new`] or presets.
+    pub fn text_style(&self) -> SmallTextStyle<'_> {
+        SmallTextStyle::new(&self.text, self.symbol_styles.clone())
+    }
+}
+
+impl FromStr for Markup {
+    type Err = MarkupError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_markup(s)
+    }
+}
+
+/// Parses text with inline tags into [`Markup`], so lines
+/// mixing several styles don't require manual bookkeeping of
+/// target ranges.
+///
+/// Supported tags are:
+///
+/// * `<b>`, `<i>`, `<u>`, `<s>`, `<dim>`, `<rev>` and
+///   `<blink>` - add the bold, italic, underlined, crossed
+///   out, dim, reversed and slow blink modifiers;
+/// * `<fg=<color>>`, `<bg=<color>>` - set the foreground and
+///   background colors. Colors accept any format supported by
+///   ratatui.
+///
+/// Tags may be nested and are closed with `</name>`, e.g.
+/// `</b>` or `</fg>`, or with `</>`, which closes the innermost
+/// tag. Use `\<` for a literal `<` and `\\` for a literal `\`.
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_small_text::{
+///     SmallTextWidget,
+///     parse_markup,
+/// };
+/// use ratatui::style::{
+///     Color,
+///     Modifier,
+/// };
+///
+/// let markup =
+///     parse_markup("Press <b>Enter</b> to <fg=red>delete</fg>").unwrap();
+/// assert_eq!(markup.text(), "Press Enter to delete");
+///
+/// let text = SmallTextWidget::new(markup.text_style());
+/// assert_eq!(text.symbols()[&6].modifier, Modifier::BOLD);
+/// assert_eq!(text.symbols()[&15].foreground_color, Some(Color::Red));
+/// ```
+pub fn parse_markup(source: &str) -> Result<Markup, MarkupError> {
+    let mut text = String::new();
+    let mut styles: Vec<SymbolStyle> = Vec::new();
+    let mut open_tags: Vec<OpenTag> = Vec::new();
+
+    let mut chars = source.char_indices().peekable();
+    while let Some((position, character)) = chars.next() {
+        let current_style = open_tags
+            .last()
+            .map_or(SymbolStyle::default(), |open_tag| open_tag.style);
+
+        let character = match character {
+            '\\' => chars.next().map_or('\\', |(_, escaped)| escaped),
+            '<' => {
+                let end = source[position..]
+                    .find('>')
+                    .map(|offset| position + offset)
+                    .ok_or(MarkupError::UnterminatedTag { position })?;
+                while chars.next_if(|(index, _)| *index <= end).is_some() {}
+
+                let tag = &source[position + 1..end];
+                match tag.strip_prefix('/') {
+                    Some(name) => {
+                        open_tags
+                            .pop()
+                            .filter(|open_tag| {
+                                name.is_empty() || open_tag.name == name.trim()
+                            })
+                            .ok_or_else(|| {
+                                MarkupError::UnexpectedClosingTag {
+                                    position,
+                                    tag: tag.to_string(),
+                                }
+                            })?;
+                    }
+                    None => open_tags.push(OpenTag::parse(
+                        tag,
+                        position,
+                        current_style,
+                    )?),
+                }
+                continue;
+            }
+            character => character,
+        };
+
+        text.push(character);
+        styles.push(current_style);
+    }
+
+    if let Some(open_tag) = open_tags.pop() {
+        return Err(MarkupError::UnclosedTag {
+            position: open_tag.position,
+            tag: open_tag.name.to_string(),
+        });
+    }
+
+    Ok(Markup {
+        text,
+        symbol_styles: style_ranges(&styles),
+    })
+}
+
+/// A tag that hasn't been closed yet, along with the style of
+/// the symbols it encloses.
+struct OpenTag<'a> {
+    name: &'a str,
+    position: usize,
+    style: SymbolStyle,
+}
+
+impl<'a> OpenTag<'a> {
+    /// Parses provided tag without the angle brackets, applying
+    /// it on top of provided style.
+    fn parse(
+        tag: &'a str,
+        position: usize,
+        mut style: SymbolStyle,
+    ) -> Result<Self, MarkupError> {
+        let (name, value) = match tag.split_once('=') {
+            Some((name, value)) => (name.trim(), Some(value.trim())),
+            None => (tag.trim(), None),
+        };
+        let parse_color = |value: &str| {
+            Color::from_str(value).map_err(|_| MarkupError::InvalidColor {
+                position,
+                value: value.to_string(),
+            })
+        };
+
+        match (name, value) {
+            ("fg", Some(value)) => {
+                style.foreground_color = Some(parse_color(value)?)
+            }
+            ("bg", Some(value)) => {
+                style.background_color = Some(parse_color(value)?)
+            }
+            (name, None) => {
+                let modifier = match name {
+                    "b" => Modifier::BOLD,
+                    "i" => Modifier::ITALIC,
+                    "u" => Modifier::UNDERLINED,
+                    "s" => Modifier::CROSSED_OUT,
+                    "dim" => Modifier::DIM,
+                    "rev" => Modifier::REVERSED,
+                    "blink" => Modifier::SLOW_BLINK,
+                    _ => {
+                        return Err(MarkupError::UnknownTag {
+                            position,
+                            tag: tag.to_string(),
+                        });
+                    }
+                };
+                style.modifier |= modifier;
+            }
+            _ => {
+                return Err(MarkupError::UnknownTag {
+                    position,
+                    tag: tag.to_string(),
+                });
+            }
+        }
+
+        Ok(Self {
+            name,
+            position,
+            style,
+        })
+    }
+}
+
+/// Groups adjacent symbols with the same style into ranges,
+/// skipping the symbols with the default style.
+pub(super) fn style_ranges(
+    styles: &[SymbolStyle],
+) -> HashMap<Target, SymbolStyle> {
+    let mut ranges = HashMap::new();
+
+    let mut start = 0;
+    while start < styles.len() {
+        let style = styles[start];
+        let end = (start..styles.len())
+            .find(|x| styles[*x] != style)
+            .unwrap_or(styles.len());
+
+        if style != SymbolStyle::default() {
+            ranges.insert(Target::Range(start as u16, end as u16 - 1), style);
+        }
+        start = end;
+    }
+
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::{
+        Color,
+        Modifier,
+    };
+
+    use super::parse_markup;
+    use crate::{
+        MarkupError,
+        SmallTextWidget,
+    };
+
+    #[test]
+    fn nested_tags_and_errors() {
+        let markup =
+            parse_markup(r"<fg=red>a<b>b</>\<</fg>c<bg=#102030>d</bg>")
+                .unwrap();
+        assert_eq!(markup.text(), "ab<cd");
+        assert_eq!(markup.symbol_styles().len(), 4);
+
+        let text = SmallTextWidget::new(markup.text_style());
+        let symbols = text.symbols();
+        assert_eq!(symbols[&0].foreground_color, Some(Color::Red));
+        assert_eq!(symbols[&0].modifier, Modifier::empty());
+        assert_eq!(symbols[&1].foreground_color, Some(Color::Red));
+        assert_eq!(symbols[&1].modifier, Modifier::BOLD);
+        assert_eq!(symbols[&2].foreground_color, Some(Color::Red));
+        assert_eq!(symbols[&3].foreground_color, None);
+        assert_eq!(symbols[&4].background_color, Some(Color::Rgb(16, 32, 48)));
+
+        assert_eq!(
+            parse_markup("a<b>b</i>"),
+            Err(MarkupError::UnexpectedClosingTag {
+                position: 5,
+                tag: "/i".to_string(),
+            }),
+        );
+        assert_eq!(
+            parse_markup("<u>a"),
+            Err(MarkupError::UnclosedTag {
+                position: 0,
+                tag: "u".to_string(),
+            }),
+        );
+        assert_eq!(
+            parse_markup("<x>a</x>"),
+            Err(MarkupError::UnknownTag {
+                position: 0,
+                tag: "x".to_string(),
+            }),
+        );
+        assert_eq!(
+            parse_markup("<fg=nope>a</fg>"),
+            Err(MarkupError::InvalidColor {
+                position: 0,
+                value: "nope".to_string(),
+            }),
+        );
+        assert_eq!(
+            parse_markup("a <b"),
+            Err(MarkupError::UnterminatedTag { position: 2 }),
+        );
+    }
+}
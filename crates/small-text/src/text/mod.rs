@@ -1,9 +1,17 @@
+#[cfg(feature = "clipboard")]
+mod clipboard;
 #[cfg(feature = "crossterm")]
 mod event;
+#[cfg(feature = "markup")]
+mod markup;
 mod style;
 mod text;
 
+#[cfg(feature = "clipboard")]
+pub use clipboard::*;
 #[cfg(feature = "crossterm")]
 pub use event::*;
+#[cfg(feature = "markup")]
+pub use markup::*;
 pub use style::*;
 pub use text::*;
@@ -0,0 +1,34 @@
+use ratatui::style::Color;
+
+/// An effect drawn around [`SmallTextWidget`] to make it stand
+/// out, e.g. for headlines. The effect is drawn only within
+/// the area of the widget, so the area should be larger than
+/// the text.
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_small_text::{
+///     SmallTextEffect,
+///     SmallTextStyleBuilder,
+/// };
+/// use ratatui::style::Color;
+///
+/// let text_style = SmallTextStyleBuilder::default()
+///     .with_text("Headline")
+///     .with_effect(SmallTextEffect::Shadow(Color::DarkGray))
+///     .build();
+/// ```
+///
+/// [`SmallTextWidget`]: crate::SmallTextWidget
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SmallTextEffect {
+    /// A dimmed copy of the text in provided color, shifted
+    /// one cell to the right and one cell down.
+    Shadow(Color),
+
+    /// The background of the cells adjacent to the text,
+    /// including the diagonal ones, is tinted with provided
+    /// color.
+    Outline(Color),
+}
@@ -1,7 +1,10 @@
+mod effect;
 mod symbol;
-mod target;
 mod text;
+mod transform;
 
+pub use caponata_common::Target;
+pub use effect::*;
 pub use symbol::*;
-pub use target::*;
 pub use text::*;
+pub use transform::*;
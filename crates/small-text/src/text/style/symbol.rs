@@ -1,9 +1,10 @@
-use std::hash::Hash;
+use core::hash::Hash;
 
 use derive_builder::Builder;
 use ratatui::style::{
     Color,
     Modifier,
+    Style,
 };
 
 /// A styling configuration for a single symbol.
@@ -12,7 +13,7 @@ use ratatui::style::{
 ///
 /// ```rust
 /// use ratatui::style::{Color, Modifier};
-/// use ratatui_small_text::SymbolStyleBuilder;
+/// use caponata_small_text::SymbolStyleBuilder;
 ///
 /// let symbol_style = SymbolStyleBuilder::default()
 ///     .with_foreground_color(Color::White)
@@ -24,11 +25,15 @@ use ratatui::style::{
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Builder)]
 #[builder(setter(prefix = "with", into))]
 pub struct SymbolStyle {
+    /// Foreground color of the symbol. `None` leaves the
+    /// foreground color of the cell untouched.
     #[builder(default)]
-    pub foreground_color: Color,
+    pub foreground_color: Option<Color>,
 
+    /// Background color of the symbol. `None` leaves the
+    /// background color of the cell untouched.
     #[builder(default)]
-    pub background_color: Color,
+    pub background_color: Option<Color>,
 
     #[builder(default)]
     pub modifier: Modifier,
@@ -36,14 +41,37 @@ pub struct SymbolStyle {
 
 impl SymbolStyle {
     pub fn new(
-        foreground_color: Color,
-        background_color: Color,
+        foreground_color: impl Into<Option<Color>>,
+        background_color: impl Into<Option<Color>>,
         modifier: Modifier,
     ) -> Self {
         Self {
-            foreground_color,
-            background_color,
+            foreground_color: foreground_color.into(),
+            background_color: background_color.into(),
             modifier,
         }
     }
 }
+
+/// Converts provided ratatui style into a symbol style. Unset
+/// colors stay unset and removed modifiers are dropped.
+impl From<Style> for SymbolStyle {
+    fn from(value: Style) -> Self {
+        Self {
+            foreground_color: value.fg,
+            background_color: value.bg,
+            modifier: value.add_modifier - value.sub_modifier,
+        }
+    }
+}
+
+impl From<SymbolStyle> for Style {
+    fn from(value: SymbolStyle) -> Self {
+        Self {
+            fg: value.foreground_color,
+            bg: value.background_color,
+            ..Style::default()
+        }
+        .add_modifier(value.modifier)
+    }
+}
@@ -1,13 +1,29 @@
-use std::collections::HashMap;
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+};
 
-use ratatui::style::{
-    Color,
-    Modifier,
+#[cfg(feature = "spinner")]
+use caponata_small_spinner::SmallSpinnerStyle;
+use ratatui::{
+    style::{
+        Color,
+        Modifier,
+    },
+    text::Span,
+    widgets::Block,
 };
 
 use super::{
+    SmallTextEffect,
     SymbolStyle,
     Target,
+    TextTransform,
+    transform_text,
+};
+use crate::{
+    Symbol,
+    create_symbols,
 };
 
 /// A styling configuration for [`SmallTextWidget`].
@@ -16,7 +32,7 @@ use super::{
 ///
 /// ```rust
 /// use ratatui::style::{Color, Modifier};
-/// use ratatui_small_text::{
+/// use caponata_small_text::{
 ///     Target,
 ///     SymbolStyleBuilder,
 ///     SmallTextStyleBuilder,
@@ -45,6 +61,47 @@ use super::{
 pub struct SmallTextStyle<'a> {
     pub(crate) text: &'a str,
     pub(crate) symbol_styles: HashMap<Target, SymbolStyle>,
+
+    /// Block rendered around the text. The text is rendered
+    /// within the inner area of the block.
+    pub(crate) block: Option<Block<'static>>,
+
+    /// Whether the background of the cells the text is
+    /// rendered into is left untouched instead of being
+    /// overwritten with the background colors of the symbols.
+    pub(crate) respect_existing_background: bool,
+
+    /// Effect drawn around the text.
+    pub(crate) effect: Option<SmallTextEffect>,
+
+    /// Number of filler cells inserted between adjacent
+    /// symbols. Targets and animations keep addressing the
+    /// symbols by their positions in the text.
+    pub(crate) letter_spacing: u16,
+
+    /// Symbol rendered in the filler cells. A space with the
+    /// default style is used by default.
+    pub(crate) letter_spacing_fill: Option<Symbol>,
+
+    /// Transformation applied to the text before its symbols
+    /// are created.
+    pub(crate) transform: Option<TextTransform>,
+
+    /// Style patched onto the symbol under the keyboard
+    /// cursor. Setting it enables the cursor mode, in which
+    /// arrow keys move the cursor between the symbols.
+    pub(crate) cursor_style: Option<SymbolStyle>,
+
+    /// Style patched onto the selected symbols. Setting it
+    /// enables selecting the symbols by dragging the mouse.
+    /// Selections set programmatically are displayed reversed
+    /// if it is not set.
+    pub(crate) selection_style: Option<SymbolStyle>,
+
+    /// Styles of the spinners rendered in place of the symbols
+    /// at their virtual x coordinates.
+    #[cfg(feature = "spinner")]
+    pub(crate) spinner_styles: HashMap<u16, SmallSpinnerStyle>,
 }
 
 impl<'a> SmallTextStyle<'a> {
@@ -55,8 +112,44 @@ impl<'a> SmallTextStyle<'a> {
         Self {
             text,
             symbol_styles,
+            block: None,
+            respect_existing_background: false,
+            effect: None,
+            letter_spacing: 0,
+            letter_spacing_fill: None,
+            transform: None,
+            cursor_style: None,
+            selection_style: None,
+            #[cfg(feature = "spinner")]
+            spinner_styles: HashMap::new(),
         }
     }
+
+    /// Returns the text with the transform applied.
+    pub(crate) fn transformed_text(&self) -> Cow<'a, str> {
+        transform_text(self.text, self.transform.as_ref())
+    }
+
+    /// Returns the symbols of the transformed text.
+    pub(crate) fn symbols(&self) -> HashMap<u16, Symbol> {
+        create_symbols(&self.transformed_text(), self.symbol_styles.clone())
+    }
+}
+
+impl<'a> From<&'a str> for SmallTextStyle<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::new(value, HashMap::new())
+    }
+}
+
+/// Converts provided span into a style applying the style of
+/// the span to every symbol.
+impl<'a> From<&'a Span<'_>> for SmallTextStyle<'a> {
+    fn from(value: &'a Span<'_>) -> Self {
+        let symbol_styles =
+            HashMap::from([(Target::Untouched, value.style.into())]);
+        Self::new(&value.content, symbol_styles)
+    }
 }
 
 /// A builder for constructing [`SmallTextStyle`].
@@ -65,7 +158,7 @@ impl<'a> SmallTextStyle<'a> {
 ///
 /// ```rust
 /// use ratatui::style::{Color, Modifier};
-/// use ratatui_small_text::{
+/// use caponata_small_text::{
 ///     Target,
 ///     SymbolStyleBuilder,
 ///     SmallTextStyleBuilder,
@@ -94,6 +187,16 @@ impl<'a> SmallTextStyle<'a> {
 pub struct SmallTextStyleBuilder<'a> {
     text: Option<&'a str>,
     symbol_styles: HashMap<Target, SymbolStyle>,
+    block: Option<Block<'static>>,
+    respect_existing_background: bool,
+    effect: Option<SmallTextEffect>,
+    letter_spacing: u16,
+    letter_spacing_fill: Option<Symbol>,
+    transform: Option<TextTransform>,
+    cursor_style: Option<SymbolStyle>,
+    selection_style: Option<SymbolStyle>,
+    #[cfg(feature = "spinner")]
+    spinner_styles: HashMap<u16, SmallSpinnerStyle>,
 }
 
 impl<'a> SmallTextStyleBuilder<'a> {
@@ -102,6 +205,57 @@ impl<'a> SmallTextStyleBuilder<'a> {
         self
     }
 
+    pub fn with_block(mut self, block: Block<'static>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn with_respect_existing_background(
+        mut self,
+        respect_existing_background: bool,
+    ) -> Self {
+        self.respect_existing_background = respect_existing_background;
+        self
+    }
+
+    pub fn with_effect(mut self, effect: SmallTextEffect) -> Self {
+        self.effect = Some(effect);
+        self
+    }
+
+    pub fn with_letter_spacing(mut self, letter_spacing: u16) -> Self {
+        self.letter_spacing = letter_spacing;
+        self
+    }
+
+    pub fn with_letter_spacing_fill(
+        mut self,
+        fill: char,
+        style: SymbolStyle,
+    ) -> Self {
+        self.letter_spacing_fill = Some(Symbol::new(fill, style));
+        self
+    }
+
+    pub fn with_transform(mut self, transform: TextTransform) -> Self {
+        self.transform = Some(transform);
+        self
+    }
+
+    /// Enables the cursor mode, highlighting the symbol under
+    /// the keyboard cursor with provided style.
+    pub fn with_cursor_style(mut self, style: SymbolStyle) -> Self {
+        self.cursor_style = Some(style);
+        self
+    }
+
+    /// Enables selecting the symbols by dragging the mouse,
+    /// highlighting the selected symbols with provided style.
+    pub fn with_selection_style(mut self, style: SymbolStyle) -> Self {
+        self.selection_style = Some(style);
+        self
+    }
+
     pub fn for_target(self, target: Target) -> SymbolStyleAssembler<'a> {
         SymbolStyleAssembler {
             target,
@@ -109,6 +263,8 @@ impl<'a> SmallTextStyleBuilder<'a> {
             background_color: None,
             foreground_color: None,
             modifier: None,
+            #[cfg(feature = "spinner")]
+            spinner_style: None,
         }
     }
 
@@ -116,6 +272,16 @@ impl<'a> SmallTextStyleBuilder<'a> {
         SmallTextStyle {
             text: self.text.unwrap_or_default(),
             symbol_styles: self.symbol_styles,
+            block: self.block,
+            respect_existing_background: self.respect_existing_background,
+            effect: self.effect,
+            letter_spacing: self.letter_spacing,
+            letter_spacing_fill: self.letter_spacing_fill,
+            transform: self.transform,
+            cursor_style: self.cursor_style,
+            selection_style: self.selection_style,
+            #[cfg(feature = "spinner")]
+            spinner_styles: self.spinner_styles,
         }
     }
 }
@@ -126,6 +292,8 @@ pub struct SymbolStyleAssembler<'a> {
     background_color: Option<Color>,
     foreground_color: Option<Color>,
     modifier: Option<Modifier>,
+    #[cfg(feature = "spinner")]
+    spinner_style: Option<SmallSpinnerStyle>,
 }
 
 impl<'a> SymbolStyleAssembler<'a> {
@@ -145,16 +313,43 @@ impl<'a> SymbolStyleAssembler<'a> {
     }
 
     pub fn set_style(mut self, style: SymbolStyle) -> Self {
-        self.background_color = Some(style.background_color);
-        self.foreground_color = Some(style.foreground_color);
+        self.background_color = style.background_color;
+        self.foreground_color = style.foreground_color;
         self.modifier = Some(style.modifier);
         self
     }
 
+    /// Renders a spinner of provided style in place of the
+    /// symbol, e.g. to display `⠙ Fetching results…` with a
+    /// single widget. The symbol style, if set, is applied to
+    /// the cell before the spinner is rendered. Only
+    /// [`Target::Single`] targets are supported; the spinner is
+    /// ignored for other targets.
+    #[cfg(feature = "spinner")]
+    pub fn set_spinner(mut self, spinner_style: SmallSpinnerStyle) -> Self {
+        self.spinner_style = Some(spinner_style);
+        self
+    }
+
     pub fn then(mut self) -> SmallTextStyleBuilder<'a> {
+        #[cfg(feature = "spinner")]
+        if let Some(spinner_style) = self.spinner_style.take() {
+            if let Target::Single(x) = self.target {
+                self.text_style_builder
+                    .spinner_styles
+                    .insert(x, spinner_style);
+            }
+            let has_symbol_style = self.foreground_color.is_some()
+                || self.background_color.is_some()
+                || self.modifier.is_some();
+            if !has_symbol_style {
+                return self.text_style_builder;
+            }
+        }
+
         let symbol_style = SymbolStyle::new(
-            self.foreground_color.unwrap_or_default(),
-            self.background_color.unwrap_or_default(),
+            self.foreground_color,
+            self.background_color,
             self.modifier.unwrap_or_default(),
         );
         self.text_style_builder
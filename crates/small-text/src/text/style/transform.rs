@@ -0,0 +1,78 @@
+use std::borrow::Cow;
+
+use caponata_common::Callable;
+
+/// A transformation applied to the text of [`SmallTextWidget`]
+/// before its symbols are created, so presentation concerns
+/// like the case of a title don't leak into application
+/// strings.
+///
+/// Targets address the symbols of the transformed text, which
+/// may differ in length from the original one, e.g. `ß` becomes
+/// `SS` in uppercase.
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_small_text::{
+///     SmallTextStyleBuilder,
+///     SmallTextWidget,
+///     TextTransform,
+/// };
+///
+/// let text_style = SmallTextStyleBuilder::default()
+///     .with_text("main menu")
+///     .with_transform(TextTransform::TitleCase)
+///     .build();
+/// let text = SmallTextWidget::new(text_style);
+/// assert_eq!(text.to_line().to_string(), "Main Menu");
+/// ```
+///
+/// [`SmallTextWidget`]: crate::SmallTextWidget
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TextTransform {
+    Uppercase,
+    Lowercase,
+
+    /// The first letter of every word is uppercased and the
+    /// rest are lowercased. Words are separated by whitespace.
+    TitleCase,
+
+    /// The text is replaced with the result of the function.
+    Custom(Callable<(String,), String>),
+}
+
+impl TextTransform {
+    pub fn apply(&self, text: &str) -> String {
+        match self {
+            Self::Uppercase => text.to_uppercase(),
+            Self::Lowercase => text.to_lowercase(),
+            Self::TitleCase => {
+                let mut is_word_start = true;
+                let mut transformed = String::with_capacity(text.len());
+                for character in text.chars() {
+                    if is_word_start {
+                        transformed.extend(character.to_uppercase());
+                    } else {
+                        transformed.extend(character.to_lowercase());
+                    }
+                    is_word_start = character.is_whitespace();
+                }
+                transformed
+            }
+            Self::Custom(function) => function.call((text.to_string(),)),
+        }
+    }
+}
+
+/// Returns provided text with provided transform applied, if
+/// any.
+pub(crate) fn transform_text<'a>(
+    text: &'a str,
+    transform: Option<&TextTransform>,
+) -> Cow<'a, str> {
+    match transform {
+        Some(transform) => Cow::Owned(transform.apply(text)),
+        None => Cow::Borrowed(text),
+    }
+}
@@ -1,42 +1,94 @@
+#[cfg(feature = "spinner")]
+use std::time::Duration;
 use std::{
     collections::{
         HashMap,
         HashSet,
     },
     fmt::Debug,
+    ops::Range,
 };
 
+use caponata_common::{
+    AccessibleDescription,
+    Identifiable,
+    WidgetIdentity,
+    describe_widget,
+    render_adapted,
+    resolve_target,
+    target_sorter,
+};
+#[cfg(feature = "spinner")]
+use caponata_common::{
+    Animated,
+    SharedClock,
+};
+#[cfg(feature = "crossterm")]
+use caponata_common::{
+    EventQueue,
+    Focusable,
+    HandlesEvent,
+};
+#[cfg(feature = "spinner")]
+use caponata_small_spinner::SmallSpinnerWidget;
+#[cfg(all(feature = "crossterm", feature = "clipboard"))]
+use crossterm::event::KeyModifiers;
 #[cfg(feature = "crossterm")]
 use crossterm::event::{
     Event,
+    KeyCode,
+    KeyEvent,
+    KeyEventKind,
     MouseButton,
     MouseEventKind,
 };
 use ratatui::{
     buffer::Buffer,
-    layout::Rect,
+    layout::{
+        Margin,
+        Position,
+        Rect,
+    },
     style::{
         Color,
         Modifier,
         Style,
     },
-    widgets::Widget,
+    text::{
+        Line,
+        Span,
+    },
+    widgets::{
+        Block,
+        Widget,
+    },
 };
 
 #[cfg(feature = "crossterm")]
 use super::InteractionEvent;
+#[cfg(feature = "clipboard")]
+use super::SharedClipboard;
 use super::{
+    SmallTextEffect,
     SmallTextStyle,
+    SmallTextStyleBuilder,
     SymbolStyle,
     Target,
-    target_sorter,
+    TextTransform,
+    transform_text,
 };
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct Symbol {
     pub value: char,
-    pub foreground_color: Color,
-    pub background_color: Color,
+
+    /// `None` leaves the foreground color of the cell
+    /// untouched.
+    pub foreground_color: Option<Color>,
+
+    /// `None` leaves the background color of the cell
+    /// untouched.
+    pub background_color: Option<Color>,
     pub modifier: Modifier,
 }
 
@@ -49,6 +101,16 @@ impl Symbol {
             modifier: style.modifier,
         }
     }
+
+    /// Returns the ratatui style the symbol is rendered with.
+    pub(crate) fn style(&self) -> Style {
+        SymbolStyle::new(
+            self.foreground_color,
+            self.background_color,
+            self.modifier,
+        )
+        .into()
+    }
 }
 
 /// A widget that displays one-character height text.
@@ -57,7 +119,7 @@ impl Symbol {
 ///
 /// ```rust
 /// use ratatui::style::{Color, Modifier};
-/// use ratatui_small_text::{
+/// use caponata_small_text::{
 ///     Target,
 ///     SymbolStyleBuilder,
 ///     SmallTextStyleBuilder,
@@ -86,27 +148,359 @@ impl Symbol {
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct SmallTextWidget {
     symbols: HashMap<u16, Symbol>,
+    block: Option<Block<'static>>,
+    respect_existing_background: bool,
+    effect: Option<SmallTextEffect>,
+    letter_spacing: u16,
+    letter_spacing_fill: Symbol,
+
+    /// Styles and transform the symbols are recreated with
+    /// when the text is replaced.
+    symbol_styles: HashMap<Target, SymbolStyle>,
+    transform: Option<TextTransform>,
+
+    /// Style patched onto the symbol under the keyboard
+    /// cursor, if the cursor mode is enabled.
+    cursor_style: Option<SymbolStyle>,
+
+    /// Virtual x coordinate of the symbol under the keyboard
+    /// cursor.
+    cursor: u16,
+
+    /// Style patched onto the selected symbols, if selecting
+    /// by dragging the mouse is enabled.
+    selection_style: Option<SymbolStyle>,
+
+    /// Virtual x coordinates of the selected symbols.
+    selection: Option<Range<u16>>,
+    identity: WidgetIdentity,
+
+    /// Clipboard the selected text is pushed to when copied.
+    #[cfg(feature = "clipboard")]
+    clipboard: Option<SharedClipboard>,
+
+    /// Spinners rendered in place of the symbols at their
+    /// virtual x coordinates.
+    #[cfg(feature = "spinner")]
+    spinners: HashMap<u16, SmallSpinnerWidget>,
 
     #[cfg(feature = "crossterm")]
     pressed_buttons: HashSet<MouseButton>,
     #[cfg(feature = "crossterm")]
     is_hovered: bool,
+    #[cfg(feature = "crossterm")]
+    is_focused: bool,
+
+    /// Virtual x coordinate of the symbol the selection
+    /// started from, while it is being dragged.
+    #[cfg(feature = "crossterm")]
+    selection_anchor: Option<u16>,
+
+    /// Events returned by [`SmallTextWidget::handle_event`] or
+    /// produced by keys delivered through [`Focusable`] that
+    /// have not been drained yet.
+    #[cfg(feature = "crossterm")]
+    events: EventQueue<InteractionEvent>,
 }
 
 impl Widget for &mut SmallTextWidget {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let available_width =
-            self.symbols.iter().count().min(area.width as usize) as u16;
+        render_adapted(area, buf, |buf| {
+            if let Some(block) = &self.block {
+                block.clone().render(area, buf);
+            }
+            let text_area = self.render_symbols(self.inner_area(area), buf);
+            self.render_effect(text_area, self.content_area(area), buf);
+        });
+        self.identity.record(area, &*self);
+    }
+}
+
+impl SmallTextWidget {
+    /// Renders the symbols and returns the area they occupy.
+    fn render_symbols(&mut self, area: Rect, buf: &mut Buffer) -> Rect {
+        if area.is_empty() {
+            return Rect::default();
+        }
 
-        let virtual_canvas: HashMap<u16, u16> = (0..0 + available_width)
-            .zip(area.x..area.x + available_width)
+        let available_width = self.occupied_width(area.width);
+        let virtual_canvas: HashMap<u16, u16> = (0..available_width)
+            .map(|real_x| area.x + real_x)
+            .filter_map(|real_x| {
+                self.virtual_x(real_x - area.x).map(|x| (x, real_x))
+            })
             .collect();
 
         self.apply_styles(area.y, buf, &virtual_canvas);
+        #[cfg(feature = "spinner")]
+        self.render_spinners(area.y, buf, &virtual_canvas);
+        self.render_letter_spacing(area, available_width, buf);
+
+        Rect::new(area.x, area.y, available_width, 1)
+    }
+
+    /// Renders the spinners over the symbols they replace, if
+    /// the symbols fit into the area.
+    #[cfg(feature = "spinner")]
+    fn render_spinners(
+        &mut self,
+        real_y: u16,
+        buf: &mut Buffer,
+        virtual_canvas: &HashMap<u16, u16>,
+    ) {
+        for (x, spinner) in self.spinners.iter_mut() {
+            if let Some(real_x) = virtual_canvas.get(x) {
+                spinner.render(Rect::new(*real_x, real_y, 1, 1), buf);
+            }
+        }
+    }
+
+    /// Renders the filler symbol into the cells between the
+    /// symbols within provided width.
+    fn render_letter_spacing(&self, area: Rect, width: u16, buf: &mut Buffer) {
+        if self.letter_spacing == 0 {
+            return;
+        }
+
+        let mut style = self.letter_spacing_fill.style();
+        if self.respect_existing_background {
+            style.bg = None;
+        }
+        for offset in 0..width {
+            if self.virtual_x(offset).is_none() {
+                buf[(area.x + offset, area.y)]
+                    .set_char(self.letter_spacing_fill.value)
+                    .set_style(style);
+            }
+        }
+    }
+
+    /// Returns the number of cells the symbols, separated by
+    /// the letter spacing, occupy within provided width. The
+    /// occupied cells never end with the letter spacing.
+    fn occupied_width(&self, width: u16) -> u16 {
+        let spacing = self.letter_spacing as u32;
+        let fitting_symbol_count = (width as u32 + spacing) / (spacing + 1);
+        let symbol_count = fitting_symbol_count.min(self.symbols.len() as u32);
+
+        (symbol_count * (spacing + 1)).saturating_sub(spacing) as u16
+    }
+
+    /// Returns the virtual x coordinate of the symbol rendered
+    /// at provided offset from the start of the text, or `None`
+    /// if the offset falls on the letter spacing.
+    fn virtual_x(&self, offset: u16) -> Option<u16> {
+        let step = self.letter_spacing + 1;
+        (offset % step == 0).then_some(offset / step)
+    }
+
+    /// Renders the effect around provided area occupied by the
+    /// symbols, clipped to provided area.
+    fn render_effect(&self, text_area: Rect, area: Rect, buf: &mut Buffer) {
+        if text_area.is_empty() {
+            return;
+        }
+
+        match self.effect {
+            Some(SmallTextEffect::Shadow(color)) => {
+                for offset in 0..text_area.width {
+                    let position = Position::new(
+                        text_area.x + offset + 1,
+                        text_area.y + 1,
+                    );
+                    let Some(symbol) = self
+                        .virtual_x(offset)
+                        .and_then(|x| self.symbols.get(&x))
+                    else {
+                        continue;
+                    };
+                    if !area.contains(position) || symbol.value.is_whitespace()
+                    {
+                        continue;
+                    }
+
+                    let cell =
+                        buf[position].set_char(symbol.value).set_fg(color);
+                    cell.modifier.insert(Modifier::DIM);
+                }
+            }
+            Some(SmallTextEffect::Outline(color)) => {
+                let outline_area = Rect::new(
+                    text_area.x.saturating_sub(1),
+                    text_area.y.saturating_sub(1),
+                    text_area.width + 2,
+                    text_area.height + 2,
+                )
+                .intersection(area);
+
+                for position in outline_area.positions() {
+                    if !text_area.contains(position) {
+                        buf[position].set_bg(color);
+                    }
+                }
+            }
+            None => {}
+        }
     }
 }
 
 impl SmallTextWidget {
+    /// Creates a widget displaying provided text with the
+    /// default style.
+    pub fn plain(text: &str) -> Self {
+        Self::new(SmallTextStyleBuilder::default().with_text(text).build())
+    }
+
+    /// Sets the foreground color of all the symbols.
+    pub fn fg(mut self, color: Color) -> Self {
+        for symbol in self.symbols.values_mut() {
+            symbol.foreground_color = Some(color);
+        }
+        self
+    }
+
+    /// Sets the background color of all the symbols.
+    pub fn bg(mut self, color: Color) -> Self {
+        for symbol in self.symbols.values_mut() {
+            symbol.background_color = Some(color);
+        }
+        self
+    }
+
+    /// Adds provided modifier to all the symbols.
+    pub fn modifier(mut self, modifier: Modifier) -> Self {
+        for symbol in self.symbols.values_mut() {
+            symbol.modifier |= modifier;
+        }
+        self
+    }
+
+    /// Returns the current symbols as a line, so the text can
+    /// be embedded into other widgets, such as `Paragraph` or
+    /// `Table` cells. Adjacent symbols with the same style
+    /// share a span.
+    pub fn to_line(&self) -> Line<'static> {
+        if self.letter_spacing == 0 {
+            return symbols_to_line(&self.symbols);
+        }
+
+        let width = self.occupied_width(u16::MAX);
+        let spaced_symbols = (0..width)
+            .filter_map(|offset| match self.virtual_x(offset) {
+                Some(x) => {
+                    self.symbols.get(&x).map(|symbol| (offset, *symbol))
+                }
+                None => Some((offset, self.letter_spacing_fill)),
+            })
+            .collect();
+        symbols_to_line(&spaced_symbols)
+    }
+
+    /// Replaces the text, recreating the symbols with the
+    /// symbol styles and the transform of the style the widget
+    /// was created with.
+    pub fn set_text(&mut self, text: &str) {
+        let text = transform_text(text, self.transform.as_ref());
+        self.symbols = create_symbols(&text, self.symbol_styles.clone());
+        self.set_cursor(self.cursor);
+        self.clear_selection();
+    }
+
+    /// Returns the virtual x coordinate of the symbol under the
+    /// keyboard cursor, or `None` if the cursor mode is
+    /// disabled or the text is empty.
+    pub fn cursor(&self) -> Option<u16> {
+        (self.cursor_style.is_some() && !self.symbols.is_empty())
+            .then_some(self.cursor)
+    }
+
+    /// Moves the keyboard cursor to the symbol with provided
+    /// virtual x coordinate, clamped to the last symbol. Has no
+    /// visible effect if the cursor mode is disabled.
+    pub fn set_cursor(&mut self, x: u16) {
+        let last_x = (self.symbols.len() as u16).saturating_sub(1);
+        self.cursor = x.min(last_x);
+    }
+
+    /// Returns the virtual x coordinates of the selected
+    /// symbols, or `None` if nothing is selected.
+    pub fn selection(&self) -> Option<Range<u16>> {
+        self.selection.clone()
+    }
+
+    /// Selects the symbols with virtual x coordinates within
+    /// provided range, clamped to the text. An empty range
+    /// clears the selection.
+    pub fn set_selection(&mut self, range: Range<u16>) {
+        let symbol_count = self.symbols.len() as u16;
+        let range = range.start.min(symbol_count)..range.end.min(symbol_count);
+        self.selection = (!range.is_empty()).then_some(range);
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.selection = None;
+    }
+
+    /// Replaces the clipboard the selected text is pushed to
+    /// by [`SmallTextWidget::copy_selection`].
+    #[cfg(feature = "clipboard")]
+    pub fn set_clipboard(&mut self, clipboard: SharedClipboard) {
+        self.clipboard = Some(clipboard);
+    }
+
+    /// Returns the text of the selected symbols, pushing it to
+    /// the clipboard if one is set, or `None` if nothing is
+    /// selected.
+    #[cfg(feature = "clipboard")]
+    pub fn copy_selection(&self) -> Option<String> {
+        let text = self
+            .selection
+            .clone()?
+            .filter_map(|x| self.symbols.get(&x))
+            .map(|symbol| symbol.value)
+            .collect::<String>();
+        if let Some(clipboard) = &self.clipboard {
+            clipboard.set_text(&text);
+        }
+        Some(text)
+    }
+
+    /// Returns the style patched onto the selected symbols,
+    /// reversing their colors if no selection style is set.
+    fn selection_symbol_style(&self) -> SymbolStyle {
+        self.selection_style.unwrap_or(SymbolStyle {
+            modifier: Modifier::REVERSED,
+            ..SymbolStyle::default()
+        })
+    }
+
+    /// Replaces the clock used by the spinners. Spinners sharing
+    /// the clock of an `AnimationGroup` switch their symbols in
+    /// the same frame.
+    #[cfg(feature = "spinner")]
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        for spinner in self.spinners.values_mut() {
+            spinner.set_clock(clock.clone());
+        }
+    }
+
+    /// Returns the time left until a spinner displays its next
+    /// symbol, or `None` if the text has no spinners.
+    #[cfg(feature = "spinner")]
+    pub fn time_until_next_frame(&self) -> Option<Duration> {
+        self.spinners
+            .values()
+            .map(SmallSpinnerWidget::time_until_next_frame)
+            .min()
+    }
+
+    /// Returns the number of cells the symbols, separated by
+    /// the letter spacing, occupy in an area wide enough for
+    /// all of them, excluding the block and the effect.
+    pub fn width(&self) -> u16 {
+        self.occupied_width(u16::MAX)
+    }
+
     pub fn symbols(&self) -> &HashMap<u16, Symbol> {
         &self.symbols
     }
@@ -115,6 +509,50 @@ impl SmallTextWidget {
         &mut self.symbols
     }
 
+    /// Returns the virtual x coordinate and the symbol rendered
+    /// at provided position if the text, rendered in provided
+    /// area, occupies it; otherwise returns `None`.
+    pub fn hit_test(
+        &self,
+        area: Rect,
+        position: Position,
+    ) -> Option<(u16, Symbol)> {
+        let area = self.inner_area(area);
+        let available_width = self.occupied_width(area.width);
+        if position.y != area.y
+            || !(area.x..area.x + available_width).contains(&position.x)
+        {
+            return None;
+        }
+
+        let virtual_x = self.virtual_x(position.x - area.x)?;
+        self.symbols
+            .get(&virtual_x)
+            .map(|symbol| (virtual_x, *symbol))
+    }
+
+    /// Returns the area the symbols are rendered in within
+    /// provided area, excluding the block if any. The outline
+    /// effect takes a cell on each side the area has room for.
+    fn inner_area(&self, area: Rect) -> Rect {
+        let area = self.content_area(area);
+        match self.effect {
+            Some(SmallTextEffect::Outline(_)) => area.inner(Margin::new(
+                u16::from(area.width >= 3),
+                u16::from(area.height >= 3),
+            )),
+            _ => area,
+        }
+    }
+
+    /// Returns provided area excluding the block if any.
+    fn content_area(&self, area: Rect) -> Rect {
+        match &self.block {
+            Some(block) => block.inner(area),
+            None => area,
+        }
+    }
+
     fn apply_styles(
         &mut self,
         real_y: u16,
@@ -128,14 +566,24 @@ impl SmallTextWidget {
                 continue;
             };
 
-            let ratatui_style = Style::default()
-                .fg(symbol.foreground_color)
-                .bg(symbol.background_color)
-                .add_modifier(symbol.modifier);
+            let mut style = symbol.style();
+            if self.respect_existing_background {
+                style.bg = None;
+            }
+            if let Some(selection) = &self.selection
+                && selection.contains(x)
+            {
+                style = style.patch(self.selection_symbol_style());
+            }
+            if let Some(cursor_style) = self.cursor_style
+                && *x == self.cursor
+            {
+                style = style.patch(cursor_style);
+            }
 
             buf[(*real_x, real_y)]
                 .set_char(symbol.value)
-                .set_style(ratatui_style);
+                .set_style(style);
         }
     }
 }
@@ -143,61 +591,229 @@ impl SmallTextWidget {
 #[cfg(not(feature = "crossterm"))]
 impl SmallTextWidget {
     pub fn new(style: SmallTextStyle) -> Self {
-        let symbols = create_symbols(style.text, style.symbol_styles);
-        Self { symbols }
+        let symbols = style.symbols();
+        Self {
+            block: style.block,
+            respect_existing_background: style.respect_existing_background,
+            effect: style.effect,
+            letter_spacing: style.letter_spacing,
+            letter_spacing_fill: style
+                .letter_spacing_fill
+                .unwrap_or(Symbol::new(' ', SymbolStyle::default())),
+            symbol_styles: style.symbol_styles,
+            transform: style.transform,
+            cursor_style: style.cursor_style,
+            selection_style: style.selection_style,
+            #[cfg(feature = "spinner")]
+            spinners: style
+                .spinner_styles
+                .into_iter()
+                .map(|(x, style)| (x, SmallSpinnerWidget::new(style)))
+                .collect(),
+            ..Self::from_symbols(symbols)
+        }
+    }
+
+    fn from_symbols(symbols: HashMap<u16, Symbol>) -> Self {
+        Self {
+            symbols,
+            block: None,
+            respect_existing_background: false,
+            effect: None,
+            letter_spacing: 0,
+            letter_spacing_fill: Symbol::new(' ', SymbolStyle::default()),
+            symbol_styles: HashMap::new(),
+            transform: None,
+            cursor_style: None,
+            cursor: 0,
+            selection_style: None,
+            selection: None,
+            identity: WidgetIdentity::default(),
+            #[cfg(feature = "clipboard")]
+            clipboard: None,
+            #[cfg(feature = "spinner")]
+            spinners: HashMap::new(),
+        }
     }
 }
 
 #[cfg(feature = "crossterm")]
 impl SmallTextWidget {
     pub fn new(style: SmallTextStyle) -> Self {
-        let symbols = create_symbols(style.text, style.symbol_styles);
+        let symbols = style.symbols();
+        Self {
+            block: style.block,
+            respect_existing_background: style.respect_existing_background,
+            effect: style.effect,
+            letter_spacing: style.letter_spacing,
+            letter_spacing_fill: style
+                .letter_spacing_fill
+                .unwrap_or(Symbol::new(' ', SymbolStyle::default())),
+            symbol_styles: style.symbol_styles,
+            transform: style.transform,
+            cursor_style: style.cursor_style,
+            selection_style: style.selection_style,
+            #[cfg(feature = "spinner")]
+            spinners: style
+                .spinner_styles
+                .into_iter()
+                .map(|(x, style)| (x, SmallSpinnerWidget::new(style)))
+                .collect(),
+            ..Self::from_symbols(symbols)
+        }
+    }
 
+    fn from_symbols(symbols: HashMap<u16, Symbol>) -> Self {
         Self {
             symbols,
+            block: None,
+            respect_existing_background: false,
+            effect: None,
+            letter_spacing: 0,
+            letter_spacing_fill: Symbol::new(' ', SymbolStyle::default()),
+            symbol_styles: HashMap::new(),
+            transform: None,
+            cursor_style: None,
+            cursor: 0,
+            selection_style: None,
+            selection: None,
+            identity: WidgetIdentity::default(),
+            #[cfg(feature = "clipboard")]
+            clipboard: None,
+            #[cfg(feature = "spinner")]
+            spinners: HashMap::new(),
             pressed_buttons: HashSet::new(),
             is_hovered: false,
+            is_focused: false,
+            selection_anchor: None,
+            events: EventQueue::new(),
         }
     }
 
+    /// Handles provided crossterm event as if the text was
+    /// rendered in provided area, returning the produced event
+    /// and keeping it for [`SmallTextWidget::drain_events`].
     pub fn handle_event(
         &mut self,
         event: Event,
         area: Rect,
     ) -> Option<InteractionEvent> {
-        let available_width =
-            self.symbols.iter().count().min(area.width as usize) as u16;
+        let interaction_event = self.handle_crossterm_event(event, area);
+        if let Some(interaction_event) = interaction_event {
+            self.events.push(interaction_event);
+        }
 
-        let virtual_canvas: HashMap<u16, u16> = (area.x
-            ..area.x + available_width)
-            .zip(0..0 + available_width)
-            .collect();
+        interaction_event
+    }
 
-        let mouse_event = if let Event::Mouse(mouse_event) = event {
-            mouse_event
-        } else {
-            return None;
+    /// Returns the events returned by
+    /// [`SmallTextWidget::handle_event`] or produced by keys
+    /// delivered through [`Focusable`] since they were last
+    /// drained, from the oldest to the most recent one.
+    pub fn drain_events(&mut self) -> impl Iterator<Item = InteractionEvent> {
+        self.events.drain()
+    }
+
+    fn handle_crossterm_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<InteractionEvent> {
+        let mouse_event = match event {
+            Event::Mouse(mouse_event) => mouse_event,
+            Event::Key(key_event) => return self.on_key(key_event),
+            _ => return None,
         };
 
-        let symbol =
-            if let Some(virtual_x) = virtual_canvas.get(&mouse_event.column) {
-                self.symbols.get(virtual_x).copied()
-            } else {
-                None
-            };
+        let position = Position::new(mouse_event.column, mouse_event.row);
+        let hit = self.hit_test(area, position);
+        let (x, symbol) = (hit.map(|(x, _)| x), hit.map(|(_, symbol)| symbol));
 
         match mouse_event.kind {
             MouseEventKind::Moved => self.on_mouse_moved(symbol),
+            // While a button pressed over the text is held, the
+            // cursor is tracked as if it was moved. Changes of
+            // the selection take precedence over hovering.
+            MouseEventKind::Drag(button)
+                if self.pressed_buttons.contains(&button) =>
+            {
+                let hover_event = self.on_mouse_moved(symbol);
+                self.on_selection_dragged(x, button).or(hover_event)
+            }
             MouseEventKind::Down(button) => {
+                if button == MouseButton::Left
+                    && self.selection_style.is_some()
+                {
+                    self.selection_anchor = x;
+                }
                 self.on_mouse_button_down(symbol, button)
             }
             MouseEventKind::Up(button) => {
+                if button == MouseButton::Left {
+                    self.selection_anchor = None;
+                }
                 self.on_mouse_button_up(symbol, button)
             }
             _ => None,
         }
     }
 
+    /// Selects the symbols from the one the drag started at to
+    /// provided one, inclusive. Dragging outside of the text
+    /// keeps the selection.
+    fn on_selection_dragged(
+        &mut self,
+        x: Option<u16>,
+        button: MouseButton,
+    ) -> Option<InteractionEvent> {
+        if button != MouseButton::Left {
+            return None;
+        }
+        let (anchor, x) = (self.selection_anchor?, x?);
+
+        let selection = anchor.min(x)..anchor.max(x) + 1;
+        if self.selection.as_ref() == Some(&selection) {
+            return None;
+        }
+        self.selection = Some(selection);
+        InteractionEvent::SelectionChanged.into()
+    }
+
+    /// Moves the keyboard cursor with the arrow, Home and End
+    /// keys, and activates the symbol under it with Enter. Has
+    /// no effect if the cursor mode is disabled.
+    ///
+    /// With the `clipboard` feature, Ctrl+C copies the
+    /// selection regardless of the cursor mode.
+    fn on_key(&mut self, key_event: KeyEvent) -> Option<InteractionEvent> {
+        if key_event.kind == KeyEventKind::Release {
+            return None;
+        }
+        #[cfg(feature = "clipboard")]
+        if key_event.code == KeyCode::Char('c')
+            && key_event.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            return self
+                .copy_selection()
+                .map(|_| InteractionEvent::SelectionCopied);
+        }
+
+        let cursor = self.cursor()?;
+
+        match key_event.code {
+            KeyCode::Left => self.set_cursor(cursor.saturating_sub(1)),
+            KeyCode::Right => self.set_cursor(cursor.saturating_add(1)),
+            KeyCode::Home => self.set_cursor(0),
+            KeyCode::End => self.set_cursor(u16::MAX),
+            KeyCode::Enter => {
+                let symbol = self.symbols.get(&cursor)?;
+                return InteractionEvent::Activated(cursor, *symbol).into();
+            }
+            _ => {}
+        }
+        None
+    }
+
     fn on_mouse_moved(
         &mut self,
         symbol: Option<Symbol>,
@@ -238,16 +854,193 @@ impl SmallTextWidget {
         symbol: Option<Symbol>,
         released_button: MouseButton,
     ) -> Option<InteractionEvent> {
-        if let Some(released_symbol) = symbol
-            && self.pressed_buttons.contains(&released_button)
-        {
-            self.pressed_buttons.remove(&released_button);
-            return InteractionEvent::Released(released_symbol).into();
+        if !self.pressed_buttons.remove(&released_button) {
+            return None;
+        }
+
+        match symbol {
+            Some(released_symbol) => {
+                InteractionEvent::Released(released_symbol).into()
+            }
+            None => InteractionEvent::ReleasedOutside.into(),
         }
-        None
     }
 }
 
+#[cfg(feature = "spinner")]
+impl Animated for SmallTextWidget {
+    fn time_until_next_frame(&self) -> Option<Duration> {
+        SmallTextWidget::time_until_next_frame(self)
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl HandlesEvent for SmallTextWidget {
+    type Event = InteractionEvent;
+
+    fn handle_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<InteractionEvent> {
+        SmallTextWidget::handle_event(self, event, area)
+    }
+}
+
+/// A focused text in the cursor mode moves the cursor and
+/// activates the symbol under it with the keys handled by
+/// [`SmallTextWidget::handle_event`]. The produced events are
+/// kept for [`SmallTextWidget::drain_events`].
+#[cfg(feature = "crossterm")]
+impl Focusable for SmallTextWidget {
+    fn set_focused(&mut self, is_focused: bool) {
+        self.is_focused = is_focused;
+    }
+
+    fn handle_key_event(&mut self, event: KeyEvent) -> bool {
+        let moves_cursor = self.cursor().is_some()
+            && event.kind != KeyEventKind::Release
+            && matches!(
+                event.code,
+                KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End
+            );
+
+        let interaction_event = self.on_key(event);
+        if let Some(interaction_event) = interaction_event {
+            self.events.push(interaction_event);
+        }
+        moves_cursor || interaction_event.is_some()
+    }
+
+    /// Returns the position of the symbol under the keyboard
+    /// cursor while the text is focused and the cursor mode is
+    /// enabled.
+    fn cursor_position(&self, area: Rect) -> Option<Position> {
+        let cursor = self.cursor().filter(|_| self.is_focused)?;
+        let area = self.inner_area(area);
+        let offset = cursor.checked_mul(self.letter_spacing + 1)?;
+
+        (area.height > 0 && offset < self.occupied_width(area.width))
+            .then(|| Position::new(area.x + offset, area.y))
+    }
+}
+
+impl AccessibleDescription for SmallTextWidget {
+    fn accessible_description(&self) -> String {
+        let cursor = self
+            .cursor()
+            .and_then(|x| self.symbols.get(&x))
+            .map(|symbol| format!("cursor on '{}'", symbol.value))
+            .unwrap_or_default();
+        let selection = self
+            .selection
+            .clone()
+            .map(|selection| {
+                let text: String = selection
+                    .filter_map(|x| self.symbols.get(&x))
+                    .map(|symbol| symbol.value)
+                    .collect();
+                format!("selected '{text}'")
+            })
+            .unwrap_or_default();
+        #[cfg(feature = "crossterm")]
+        let hovered = if self.is_hovered { "hovered" } else { "" };
+        #[cfg(not(feature = "crossterm"))]
+        let hovered = "";
+
+        describe_widget(
+            "text",
+            Some(&symbols_to_string(&self.symbols)),
+            [hovered, &cursor, &selection],
+        )
+    }
+}
+
+impl Identifiable for SmallTextWidget {
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+impl From<&str> for SmallTextWidget {
+    fn from(value: &str) -> Self {
+        Self::plain(value)
+    }
+}
+
+impl From<String> for SmallTextWidget {
+    fn from(value: String) -> Self {
+        Self::plain(&value)
+    }
+}
+
+impl From<Span<'_>> for SmallTextWidget {
+    fn from(value: Span<'_>) -> Self {
+        Line::from(value).into()
+    }
+}
+
+/// Converts provided line into a widget. Every symbol takes
+/// the style of its span patched onto the style of the line.
+/// The alignment of the line is ignored.
+impl From<Line<'_>> for SmallTextWidget {
+    fn from(value: Line<'_>) -> Self {
+        let symbols = value
+            .spans
+            .iter()
+            .flat_map(|span| {
+                let style = SymbolStyle::from(value.style.patch(span.style));
+                span.content
+                    .chars()
+                    .map(move |symbol_value| Symbol::new(symbol_value, style))
+            })
+            .enumerate()
+            .map(|(x, symbol)| (x as u16, symbol))
+            .collect();
+
+        Self::from_symbols(symbols)
+    }
+}
+
+/// Returns a line of provided symbols ordered by their x
+/// coordinates, merging adjacent symbols with the same style
+/// into a single span.
+pub(crate) fn symbols_to_line(
+    symbols: &HashMap<u16, Symbol>,
+) -> Line<'static> {
+    let mut symbols: Vec<(&u16, &Symbol)> = symbols.iter().collect();
+    symbols.sort_by_key(|(x, _)| **x);
+
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    for (_, symbol) in symbols {
+        match spans.last_mut() {
+            Some(span) if span.style == symbol.style() => {
+                span.content.to_mut().push(symbol.value);
+            }
+            _ => spans
+                .push(Span::styled(symbol.value.to_string(), symbol.style())),
+        }
+    }
+
+    Line::from(spans)
+}
+
+/// Returns the values of provided symbols ordered by their x
+/// coordinates.
+pub(crate) fn symbols_to_string(symbols: &HashMap<u16, Symbol>) -> String {
+    let mut symbols: Vec<(&u16, &Symbol)> = symbols.iter().collect();
+    symbols.sort_by_key(|(x, _)| **x);
+
+    symbols
+        .into_iter()
+        .map(|(_, symbol)| symbol.value)
+        .collect()
+}
+
 pub(crate) fn create_symbols(
     text: &str,
     symbol_styles: HashMap<Target, SymbolStyle>,
@@ -300,27 +1093,543 @@ pub(crate) fn create_symbols(
     resolved_symbols
 }
 
-/// Returns virtual x coordinates resolved from provided
-/// target. Panics if provided target is [`Target::Untouched`].
-fn resolve_target(
-    target: Target,
-    char_count: u16,
-) -> Box<dyn Iterator<Item = u16>> {
-    let all = 0..char_count;
-
-    match target {
-        Target::Single(x) => Box::new(std::iter::once(x)),
-        Target::Range(start, end) => Box::new(start..end),
-        Target::Every(n) => Box::new(all.step_by(n as usize)),
-        Target::EveryFrom(n, offset) => {
-            Box::new(all.skip(offset as usize).step_by(n as usize))
-        }
-        Target::ExceptEvery(n) => Box::new(all.filter(move |x| x % n != 0)),
-        Target::ExceptEveryFrom(n, offset) => Box::new(
-            all.skip(offset as usize)
-                .filter(move |x| x + offset % n != 0),
-        ),
-        Target::Custom(callable) => callable.call((Box::new(all),)),
-        Target::Untouched => Box::new(std::iter::empty()),
+#[cfg(test)]
+mod tests {
+    use caponata_common::{
+        AccessibleDescription,
+        Callable,
+    };
+    use ratatui::{
+        buffer::Buffer,
+        layout::{
+            Position,
+            Rect,
+        },
+        style::{
+            Color,
+            Modifier,
+            Style,
+        },
+        text::{
+            Line,
+            Span,
+        },
+        widgets::{
+            Block,
+            Widget,
+        },
+    };
+
+    use super::SmallTextWidget;
+    use crate::{
+        SmallTextEffect,
+        SmallTextStyleBuilder,
+        SymbolStyleBuilder,
+        Target,
+        TextTransform,
+    };
+
+    #[test]
+    fn hit_test_returns_symbol_under_position() {
+        let text_style =
+            SmallTextStyleBuilder::default().with_text("Hello").build();
+        let text = SmallTextWidget::new(text_style);
+        let area = Rect::new(2, 1, 4, 2);
+
+        let (x, symbol) = text.hit_test(area, Position::new(3, 1)).unwrap();
+        assert_eq!(x, 1);
+        assert_eq!(symbol.value, 'e');
+
+        assert_eq!(text.hit_test(area, Position::new(6, 1)), None);
+        assert_eq!(text.hit_test(area, Position::new(3, 2)), None);
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn release_outside_is_reported() {
+        use crossterm::event::{
+            Event,
+            KeyModifiers,
+            MouseButton,
+            MouseEvent,
+            MouseEventKind,
+        };
+
+        use crate::InteractionEvent;
+
+        let mouse_event = |kind, column| {
+            Event::Mouse(MouseEvent {
+                kind,
+                column,
+                row: 0,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        let text_style =
+            SmallTextStyleBuilder::default().with_text("Ok").build();
+        let mut text = SmallTextWidget::new(text_style);
+        let area = Rect::new(0, 0, 2, 1);
+
+        let down = MouseEventKind::Down(MouseButton::Left);
+        let up = MouseEventKind::Up(MouseButton::Left);
+        assert!(matches!(
+            text.handle_event(mouse_event(down, 0), area),
+            Some(InteractionEvent::Pressed(_)),
+        ));
+        assert_eq!(
+            text.handle_event(mouse_event(up, 5), area),
+            Some(InteractionEvent::ReleasedOutside),
+        );
+        assert_eq!(text.handle_event(mouse_event(up, 5), area), None);
+        assert!(matches!(
+            text.handle_event(mouse_event(down, 1), area),
+            Some(InteractionEvent::Pressed(_)),
+        ));
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn cursor_is_moved_with_keys() {
+        use crossterm::event::{
+            Event,
+            KeyCode,
+            KeyEvent,
+        };
+
+        use crate::InteractionEvent;
+
+        let key = |code| Event::Key(KeyEvent::from(code));
+        let cursor_style = SymbolStyleBuilder::default()
+            .with_background_color(Color::Yellow)
+            .build()
+            .unwrap();
+        let text_style = SmallTextStyleBuilder::default()
+            .with_text("abc")
+            .for_target(Target::Untouched)
+            .set_foreground_color(Color::Blue)
+            .then()
+            .with_cursor_style(cursor_style)
+            .build();
+        let mut text = SmallTextWidget::new(text_style);
+        let area = Rect::new(0, 0, 3, 1);
+        assert_eq!(text.cursor(), Some(0));
+
+        assert_eq!(text.handle_event(key(KeyCode::Left), area), None);
+        assert_eq!(text.handle_event(key(KeyCode::Right), area), None);
+        assert_eq!(text.cursor(), Some(1));
+        assert_eq!(text.handle_event(key(KeyCode::End), area), None);
+        text.handle_event(key(KeyCode::Right), area);
+        assert_eq!(text.cursor(), Some(2));
+
+        let mut buf = Buffer::empty(area);
+        text.render(area, &mut buf);
+        assert_eq!(buf[(2, 0)].bg, Color::Yellow);
+        assert_eq!(buf[(2, 0)].fg, Color::Blue);
+        assert_eq!(buf[(1, 0)].bg, Color::Reset);
+
+        let Some(InteractionEvent::Activated(x, symbol)) =
+            text.handle_event(key(KeyCode::Enter), area)
+        else {
+            panic!("expected the symbol under the cursor to be activated");
+        };
+        assert_eq!((x, symbol.value), (2, 'c'));
+
+        text.set_text("d");
+        assert_eq!(text.cursor(), Some(0));
+        let mut text = SmallTextWidget::plain("abc");
+        assert_eq!(text.cursor(), None);
+        assert_eq!(text.handle_event(key(KeyCode::Enter), area), None);
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn selection_is_changed_by_dragging() {
+        use crossterm::event::{
+            Event,
+            KeyModifiers,
+            MouseButton,
+            MouseEvent,
+            MouseEventKind,
+        };
+
+        use crate::InteractionEvent;
+
+        let mouse_event = |kind, column| {
+            Event::Mouse(MouseEvent {
+                kind,
+                column,
+                row: 0,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        let down = MouseEventKind::Down(MouseButton::Left);
+        let drag = MouseEventKind::Drag(MouseButton::Left);
+        let up = MouseEventKind::Up(MouseButton::Left);
+        let selection_style = SymbolStyleBuilder::default()
+            .with_background_color(Color::Blue)
+            .build()
+            .unwrap();
+        let text_style = SmallTextStyleBuilder::default()
+            .with_text("abcd")
+            .with_selection_style(selection_style)
+            .build();
+        let mut text = SmallTextWidget::new(text_style);
+        let area = Rect::new(0, 0, 4, 1);
+
+        text.handle_event(mouse_event(down, 2), area);
+        assert_eq!(
+            text.handle_event(mouse_event(drag, 0), area),
+            Some(InteractionEvent::SelectionChanged),
+        );
+        assert_eq!(text.selection(), Some(0..3));
+        assert_eq!(
+            text.handle_event(mouse_event(drag, 9), area),
+            Some(InteractionEvent::Unhovered),
+        );
+        assert_eq!(text.selection(), Some(0..3));
+        text.handle_event(mouse_event(up, 1), area);
+        assert_eq!(
+            text.handle_event(mouse_event(MouseEventKind::Moved, 3), area),
+            Some(InteractionEvent::Hovered(text.symbols()[&3])),
+        );
+
+        let mut buf = Buffer::empty(area);
+        text.render(area, &mut buf);
+        assert_eq!(buf[(2, 0)].bg, Color::Blue);
+        assert_eq!(buf[(3, 0)].bg, Color::Reset);
+
+        text.set_selection(3..10);
+        assert_eq!(text.selection(), Some(3..4));
+        text.set_selection(2..2);
+        assert_eq!(text.selection(), None);
+
+        let mut text = SmallTextWidget::plain("abcd");
+        text.handle_event(mouse_event(down, 0), area);
+        assert_eq!(
+            text.handle_event(mouse_event(drag, 2), area),
+            Some(InteractionEvent::Hovered(text.symbols()[&2])),
+        );
+        assert_eq!(text.selection(), None);
+        text.set_selection(1..2);
+        text.render(area, &mut buf);
+        assert!(buf[(1, 0)].modifier.contains(Modifier::REVERSED));
+    }
+
+    #[cfg(all(feature = "crossterm", feature = "clipboard"))]
+    #[test]
+    fn selection_is_copied_with_ctrl_c() {
+        use std::sync::{
+            Arc,
+            Mutex,
+        };
+
+        use crossterm::event::{
+            Event,
+            KeyCode,
+            KeyEvent,
+            KeyModifiers,
+        };
+
+        use crate::{
+            ClipboardProvider,
+            InteractionEvent,
+            SharedClipboard,
+        };
+
+        #[derive(Default)]
+        struct TestClipboard(Mutex<Vec<String>>);
+
+        impl ClipboardProvider for TestClipboard {
+            fn set_text(&self, text: &str) {
+                self.0.lock().unwrap().push(text.to_owned());
+            }
+        }
+
+        let ctrl_c = Event::Key(KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL,
+        ));
+        let area = Rect::new(0, 0, 5, 1);
+        let mut text = SmallTextWidget::plain("Hello");
+        assert_eq!(text.copy_selection(), None);
+        assert_eq!(text.handle_event(ctrl_c.clone(), area), None);
+
+        text.set_selection(1..4);
+        assert_eq!(text.copy_selection().as_deref(), Some("ell"));
+
+        let clipboard = Arc::new(TestClipboard::default());
+        text.set_clipboard(SharedClipboard::new(clipboard.clone()));
+        assert_eq!(
+            text.handle_event(ctrl_c, area),
+            Some(InteractionEvent::SelectionCopied),
+        );
+        assert_eq!(*clipboard.0.lock().unwrap(), ["ell"]);
+    }
+
+    #[test]
+    fn description_includes_cursor_and_selection() {
+        let text_style = SmallTextStyleBuilder::default()
+            .with_text("Hello")
+            .with_cursor_style(SymbolStyleBuilder::default().build().unwrap())
+            .build();
+        let mut text = SmallTextWidget::new(text_style);
+        text.set_cursor(4);
+        text.set_selection(1..3);
+        assert_eq!(
+            text.accessible_description(),
+            "text 'Hello', cursor on 'o', selected 'el'",
+        );
+
+        let text = SmallTextWidget::plain("");
+        assert_eq!(text.accessible_description(), "text ''");
+    }
+
+    #[test]
+    fn plain_text_with_fluent_style() {
+        let text = SmallTextWidget::plain("Hi")
+            .fg(Color::Red)
+            .bg(Color::Black)
+            .modifier(Modifier::BOLD)
+            .modifier(Modifier::ITALIC);
+
+        let symbol = text.symbols()[&1];
+        assert_eq!(symbol.value, 'i');
+        assert_eq!(symbol.foreground_color, Some(Color::Red));
+        assert_eq!(symbol.background_color, Some(Color::Black));
+        assert_eq!(symbol.modifier, Modifier::BOLD | Modifier::ITALIC);
+    }
+
+    #[test]
+    fn from_line_keeps_span_styles() {
+        let line = Line::from(vec![
+            Span::raw("a"),
+            Span::styled("bc", Style::new().fg(Color::Red)),
+        ])
+        .style(Style::new().bg(Color::Blue).add_modifier(Modifier::BOLD));
+        let text = SmallTextWidget::from(line);
+
+        let a = text.symbols()[&0];
+        assert_eq!(a.value, 'a');
+        assert_eq!(a.foreground_color, None);
+        assert_eq!(a.background_color, Some(Color::Blue));
+
+        let c = text.symbols()[&2];
+        assert_eq!(c.value, 'c');
+        assert_eq!(c.foreground_color, Some(Color::Red));
+        assert_eq!(c.background_color, Some(Color::Blue));
+        assert_eq!(c.modifier, Modifier::BOLD);
+
+        assert_eq!(
+            SmallTextWidget::from(String::from("abc")),
+            SmallTextWidget::from("abc"),
+        );
+    }
+
+    #[test]
+    fn to_line_merges_symbols_with_same_style() {
+        let text_style = SmallTextStyleBuilder::default()
+            .with_text("abcd")
+            .for_target(Target::Range(2, 3))
+            .set_foreground_color(Color::Red)
+            .then()
+            .build();
+        let text = SmallTextWidget::new(text_style);
+
+        assert_eq!(
+            text.to_line(),
+            Line::from(vec![
+                Span::raw("ab"),
+                Span::styled("cd", Style::default().fg(Color::Red)),
+            ]),
+        );
+    }
+
+    #[test]
+    fn text_inside_block() {
+        let text_style = SmallTextStyleBuilder::default()
+            .with_text("Hi")
+            .with_block(Block::bordered())
+            .build();
+        let mut text = SmallTextWidget::new(text_style);
+
+        let area = Rect::new(0, 0, 4, 3);
+        let mut buf = Buffer::empty(area);
+        text.render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["┌──┐", "│Hi│", "└──┘"]));
+
+        let (x, _) = text.hit_test(area, Position::new(2, 1)).unwrap();
+        assert_eq!(x, 1);
+        assert_eq!(text.hit_test(area, Position::new(0, 0)), None);
+    }
+
+    #[test]
+    fn letter_spacing_inserts_filler_cells() {
+        let fill_style = SymbolStyleBuilder::default()
+            .with_foreground_color(Color::DarkGray)
+            .build()
+            .unwrap();
+        let text_style = SmallTextStyleBuilder::default()
+            .with_text("abc")
+            .with_letter_spacing(2)
+            .with_letter_spacing_fill('.', fill_style)
+            .for_target(Target::Single(1))
+            .set_foreground_color(Color::Red)
+            .then()
+            .build();
+        let mut text = SmallTextWidget::new(text_style);
+
+        let area = Rect::new(0, 0, 8, 1);
+        let mut buf = Buffer::empty(area);
+        text.render(area, &mut buf);
+        let mut expected = Buffer::with_lines(vec!["a..b..c "]);
+        for x in [1, 2, 4, 5] {
+            expected[(x, 0)].set_fg(Color::DarkGray);
+        }
+        expected[(3, 0)].set_fg(Color::Red);
+        assert_eq!(buf, expected);
+
+        assert_eq!(text.hit_test(area, Position::new(3, 0)).unwrap().0, 1);
+        assert_eq!(text.hit_test(area, Position::new(4, 0)), None);
+        assert_eq!(text.hit_test(area, Position::new(7, 0)), None);
+        assert_eq!(text.to_line().to_string(), "a..b..c");
+
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buf = Buffer::empty(area);
+        text.render(area, &mut buf);
+        assert_eq!(buf[(3, 0)].symbol(), "b");
+        assert_eq!(buf[(4, 0)].symbol(), " ");
+    }
+
+    #[test]
+    fn transform_is_applied_before_styles_and_on_set_text() {
+        let text_style = SmallTextStyleBuilder::default()
+            .with_text("straße ok")
+            .with_transform(TextTransform::Uppercase)
+            .for_target(Target::Single(6))
+            .set_foreground_color(Color::Red)
+            .then()
+            .build();
+        let mut text = SmallTextWidget::new(text_style);
+        assert_eq!(text.to_line().to_string(), "STRASSE OK");
+        assert_eq!(text.symbols()[&6].foreground_color, Some(Color::Red));
+
+        text.set_text("new title");
+        assert_eq!(text.to_line().to_string(), "NEW TITLE");
+        assert_eq!(text.symbols()[&6].foreground_color, Some(Color::Red));
+
+        let reverse =
+            TextTransform::Custom(Callable::from_fn(|(text,): (String,)| {
+                text.chars().rev().collect()
+            }));
+        assert_eq!(reverse.apply("abc"), "cba");
+        assert_eq!(
+            TextTransform::TitleCase.apply("hELLO  wORLD"),
+            "Hello  World"
+        );
+    }
+
+    #[test]
+    fn shadow_and_outline_effects() {
+        let text_style = SmallTextStyleBuilder::default()
+            .with_text("Hi")
+            .with_effect(SmallTextEffect::Shadow(Color::DarkGray))
+            .build();
+        let mut text = SmallTextWidget::new(text_style);
+
+        let area = Rect::new(0, 0, 3, 2);
+        let mut buf = Buffer::empty(area);
+        text.render(area, &mut buf);
+        let mut expected = Buffer::with_lines(vec!["Hi ", " Hi"]);
+        expected.set_style(
+            Rect::new(1, 1, 2, 1),
+            Style::new().fg(Color::DarkGray).add_modifier(Modifier::DIM),
+        );
+        assert_eq!(buf, expected);
+
+        let text_style = SmallTextStyleBuilder::default()
+            .with_text("Hi")
+            .with_effect(SmallTextEffect::Outline(Color::Blue))
+            .build();
+        let mut text = SmallTextWidget::new(text_style);
+
+        let area = Rect::new(0, 0, 4, 3);
+        let mut buf = Buffer::empty(area);
+        text.render(area, &mut buf);
+        let mut expected = Buffer::with_lines(vec!["    ", " Hi ", "    "]);
+        expected.set_style(area, Style::new().bg(Color::Blue));
+        expected
+            .set_style(Rect::new(1, 1, 2, 1), Style::new().bg(Color::Reset));
+        assert_eq!(buf, expected);
+        assert_eq!(text.hit_test(area, Position::new(1, 1)).unwrap().0, 0);
+    }
+
+    #[test]
+    fn existing_background_is_respected() {
+        let text_style = SmallTextStyleBuilder::default()
+            .with_text("Hi")
+            .with_respect_existing_background(true)
+            .for_target(Target::Untouched)
+            .set_foreground_color(Color::White)
+            .set_background_color(Color::Red)
+            .then()
+            .build();
+        let mut text = SmallTextWidget::new(text_style);
+
+        let area = Rect::new(0, 0, 2, 1);
+        let mut buf = Buffer::empty(area);
+        buf.set_style(area, Style::new().bg(Color::Blue));
+        text.render(area, &mut buf);
+
+        assert_eq!(buf[(0, 0)].fg, Color::White);
+        assert_eq!(buf[(0, 0)].bg, Color::Blue);
+    }
+
+    #[cfg(feature = "spinner")]
+    #[test]
+    fn spinner_is_rendered_in_place_of_symbol() {
+        use std::time::Duration;
+
+        use caponata_common::AnimationGroup;
+        use caponata_small_spinner::{
+            SmallSpinnerStyleBuilder,
+            SmallSpinnerType,
+        };
+
+        let spinner_style = SmallSpinnerStyleBuilder::default()
+            .with_interval(Duration::from_millis(100))
+            .with_foreground_color(Color::Yellow)
+            .build()
+            .unwrap();
+        let text_style = SmallTextStyleBuilder::default()
+            .with_text("  Loading")
+            .for_target(Target::Single(0))
+            .set_spinner(spinner_style)
+            .then()
+            .build();
+        let group = AnimationGroup::new();
+        let mut text = SmallTextWidget::new(text_style);
+        text.set_clock(group.clock());
+        assert_eq!(text.time_until_next_frame(), Some(Duration::ZERO),);
+
+        let frames = SmallSpinnerType::default().frames();
+        let area = Rect::new(0, 0, 9, 1);
+        let mut buf = Buffer::empty(area);
+        text.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), frames[0]);
+        assert_eq!(buf[(0, 0)].fg, Color::Yellow);
+        assert_eq!(buf[(2, 0)].symbol(), "L");
+
+        group.advance(Duration::from_millis(100));
+        text.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), frames[1]);
+        assert_eq!(buf[(2, 0)].symbol(), "L");
+    }
+
+    #[test]
+    fn is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<SmallTextWidget>();
+        assert_send_sync::<crate::SmallTextStyle>();
     }
 }
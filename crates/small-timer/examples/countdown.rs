@@ -0,0 +1,112 @@
+use std::{
+    io,
+    time::Duration,
+};
+
+use caponata_small_text::SymbolStyleBuilder;
+use caponata_small_timer::{
+    SmallTimerEvent,
+    SmallTimerMode,
+    SmallTimerStyleBuilder,
+    SmallTimerThreshold,
+    SmallTimerWidget,
+};
+use crossterm::event::{
+    Event,
+    KeyCode,
+    poll,
+    read,
+};
+use ratatui::{
+    DefaultTerminal,
+    layout::Rect,
+    style::{
+        Color,
+        Modifier,
+    },
+};
+
+pub fn main() -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal);
+
+    ratatui::restore();
+    result
+}
+
+fn run(terminal: &mut DefaultTerminal) -> io::Result<()> {
+    let mut timer = make_timer();
+    timer.start();
+
+    let mut is_running = true;
+    while is_running {
+        terminal.draw(|frame| {
+            let area = frame.area();
+            let timer_area = Rect::new(area.x + 1, area.y + 1, 8, 1);
+            frame.render_widget(&mut timer, timer_area);
+        })?;
+
+        if let Some(SmallTimerEvent::Finished) = timer.take_last_event() {
+            timer.reset();
+            timer.start();
+        }
+        is_running = !handle_event(&mut timer)?;
+    }
+
+    Ok(())
+}
+
+fn make_timer() -> SmallTimerWidget {
+    let digit_style = SymbolStyleBuilder::default()
+        .with_foreground_color(Color::White)
+        .with_modifier(Modifier::BOLD)
+        .build()
+        .unwrap();
+    let separator_style = SymbolStyleBuilder::default()
+        .with_foreground_color(Color::DarkGray)
+        .build()
+        .unwrap();
+    let warning_style = SymbolStyleBuilder::default()
+        .with_foreground_color(Color::Red)
+        .with_modifier(Modifier::BOLD)
+        .build()
+        .unwrap();
+
+    let timer_style = SmallTimerStyleBuilder::default()
+        .with_mode(SmallTimerMode::Countdown(Duration::from_secs(20)))
+        .with_minutes_style(digit_style)
+        .with_seconds_style(digit_style)
+        .with_separator_style(separator_style)
+        .with_thresholds(vec![SmallTimerThreshold::new(
+            Duration::from_secs(10),
+            warning_style,
+        )])
+        .build()
+        .unwrap();
+    SmallTimerWidget::new(timer_style)
+}
+
+/// Handles a crossterm event and returns a flag indicating
+/// whether the application should be closed.
+fn handle_event(timer: &mut SmallTimerWidget) -> io::Result<bool> {
+    let timeout = Duration::from_millis(100);
+    if !poll(timeout)? {
+        return Ok(false);
+    }
+
+    match read()? {
+        Event::Key(key) => match key.code {
+            KeyCode::Char('q') => Ok(true),
+            KeyCode::Char(' ') => {
+                if timer.is_running() {
+                    timer.pause();
+                } else {
+                    timer.start();
+                }
+                Ok(false)
+            }
+            _ => Ok(false),
+        },
+        _ => Ok(false),
+    }
+}
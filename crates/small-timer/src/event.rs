@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SmallTimerEvent {
+    /// Triggered once when a countdown [`SmallTimerWidget`]
+    /// reaches zero.
+    Finished,
+}
@@ -0,0 +1,9 @@
+#![doc = include_str!("../README.md")]
+
+pub mod event;
+pub mod style;
+pub mod timer;
+
+pub use event::*;
+pub use style::*;
+pub use timer::*;
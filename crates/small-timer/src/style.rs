@@ -0,0 +1,153 @@
+use std::time::Duration;
+
+use caponata_small_text::SymbolStyle;
+use derive_builder::Builder;
+
+/// Specifies what [`SmallTimerWidget`] measures.
+///
+/// Default variant is [`SmallTimerMode::Stopwatch`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SmallTimerMode {
+    /// Displays the time elapsed since the timer was started.
+    #[default]
+    Stopwatch,
+
+    /// Displays the time remaining until the provided
+    /// duration elapses.
+    Countdown(Duration),
+}
+
+/// Specifies how [`SmallTimerWidget`] formats the displayed
+/// duration.
+///
+/// Default variant is [`SmallTimerFormat::Auto`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SmallTimerFormat {
+    /// "mm:ss". Minutes are not wrapped, so 75 minutes are
+    /// displayed as "75:00".
+    MinutesSeconds,
+
+    /// "hh:mm:ss".
+    HoursMinutesSeconds,
+
+    /// "hh:mm:ss" if the displayed duration is at least one
+    /// hour; otherwise "mm:ss".
+    #[default]
+    Auto,
+}
+
+/// A part of the text displayed by [`SmallTimerWidget`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SmallTimerSegment {
+    Hours,
+    Minutes,
+    Seconds,
+    Separator,
+}
+
+/// A style that overrides styles of the digit segments of
+/// [`SmallTimerWidget`] while the displayed duration is
+/// less than the provided one.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SmallTimerThreshold {
+    pub below: Duration,
+    pub style: SymbolStyle,
+}
+
+impl SmallTimerThreshold {
+    pub fn new(below: Duration, style: SymbolStyle) -> Self {
+        Self { below, style }
+    }
+}
+
+/// A styling configuration for [`SmallTimerWidget`].
+///
+/// If several thresholds match the displayed duration, the
+/// one with the smallest duration is applied.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::style::{Color, Modifier};
+/// use caponata_small_text::SymbolStyleBuilder;
+/// use caponata_small_timer::{
+///     SmallTimerFormat,
+///     SmallTimerMode,
+///     SmallTimerStyleBuilder,
+/// };
+///
+/// let digit_style = SymbolStyleBuilder::default()
+///     .with_foreground_color(Color::White)
+///     .with_modifier(Modifier::BOLD)
+///     .build()
+///     .unwrap();
+/// let separator_style = SymbolStyleBuilder::default()
+///     .with_foreground_color(Color::Gray)
+///     .build()
+///     .unwrap();
+/// let style = SmallTimerStyleBuilder::default()
+///     .with_mode(SmallTimerMode::Stopwatch)
+///     .with_format(SmallTimerFormat::HoursMinutesSeconds)
+///     .with_hours_style(digit_style)
+///     .with_minutes_style(digit_style)
+///     .with_seconds_style(digit_style)
+///     .with_separator_style(separator_style)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(prefix = "with", into))]
+pub struct SmallTimerStyle {
+    #[builder(default)]
+    pub(crate) mode: SmallTimerMode,
+
+    #[builder(default)]
+    pub(crate) format: SmallTimerFormat,
+
+    #[builder(default)]
+    pub(crate) hours_style: SymbolStyle,
+
+    #[builder(default)]
+    pub(crate) minutes_style: SymbolStyle,
+
+    #[builder(default)]
+    pub(crate) seconds_style: SymbolStyle,
+
+    #[builder(default)]
+    pub(crate) separator_style: SymbolStyle,
+
+    #[builder(default)]
+    pub(crate) thresholds: Vec<SmallTimerThreshold>,
+}
+
+impl SmallTimerStyle {
+    /// Returns the style of the provided segment for the
+    /// provided displayed duration.
+    pub fn segment_style(
+        &self,
+        segment: SmallTimerSegment,
+        displayed: Duration,
+    ) -> SymbolStyle {
+        if segment == SmallTimerSegment::Separator {
+            return self.separator_style;
+        }
+
+        let threshold = self
+            .thresholds
+            .iter()
+            .filter(|threshold| displayed < threshold.below)
+            .min_by_key(|threshold| threshold.below);
+        if let Some(threshold) = threshold {
+            return threshold.style;
+        }
+
+        match segment {
+            SmallTimerSegment::Hours => self.hours_style,
+            SmallTimerSegment::Minutes => self.minutes_style,
+            SmallTimerSegment::Seconds => self.seconds_style,
+            SmallTimerSegment::Separator => self.separator_style,
+        }
+    }
+}
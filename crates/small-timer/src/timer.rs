@@ -0,0 +1,431 @@
+use std::{
+    ops::Range,
+    time::Duration,
+};
+
+use caponata_common::{
+    AccessibleDescription,
+    Identifiable,
+    Instant,
+    SharedClock,
+    WidgetIdentity,
+    describe_widget,
+};
+use caponata_small_text::{
+    SmallTextStyle,
+    SmallTextStyleBuilder,
+    SmallTextWidget,
+    Target,
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::Widget,
+};
+
+use super::{
+    SmallTimerEvent,
+    SmallTimerFormat,
+    SmallTimerMode,
+    SmallTimerSegment,
+    SmallTimerStyle,
+};
+
+/// A widget that displays a countdown or a stopwatch as
+/// one-character height text.
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_small_timer::{
+///     SmallTimerFormat,
+///     SmallTimerMode,
+///     SmallTimerStyleBuilder,
+///     SmallTimerWidget,
+/// };
+///
+/// let timer_style = SmallTimerStyleBuilder::default()
+///     .with_mode(SmallTimerMode::Stopwatch)
+///     .with_format(SmallTimerFormat::HoursMinutesSeconds)
+///     .build()
+///     .unwrap();
+/// let mut timer = SmallTimerWidget::new(timer_style);
+///
+/// timer.start();
+/// assert!(timer.is_running());
+///
+/// timer.pause();
+/// assert!(!timer.is_running());
+/// assert_eq!(timer.text(), "00:00:00");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SmallTimerWidget {
+    style: SmallTimerStyle,
+    clock: SharedClock,
+
+    /// Time accumulated before the timer was last started.
+    accumulated: Duration,
+    started_at: Option<Instant>,
+    last_event: Option<SmallTimerEvent>,
+    identity: WidgetIdentity,
+}
+
+impl Widget for &mut SmallTimerWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.update();
+
+        let displayed = self.displayed();
+        let segments = self.segments(displayed);
+        let text: String =
+            segments.iter().map(|(_, value)| value.as_str()).collect();
+
+        let text_style = self.text_style(&text, &segments, displayed);
+        SmallTextWidget::new(text_style).render(area, buf);
+        self.identity.record(area, &*self);
+    }
+}
+
+impl SmallTimerWidget {
+    pub fn new(style: SmallTimerStyle) -> Self {
+        Self {
+            style,
+            clock: SharedClock::default(),
+            accumulated: Duration::ZERO,
+            started_at: None,
+            last_event: None,
+            identity: WidgetIdentity::default(),
+        }
+    }
+
+    /// Replaces the clock used to measure time. The time
+    /// accumulated so far is preserved.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        let was_running = self.is_running();
+        self.pause();
+        self.clock = clock;
+
+        if was_running {
+            self.start();
+        }
+    }
+
+    pub fn take_last_event(&mut self) -> Option<SmallTimerEvent> {
+        self.last_event.take()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.started_at.is_some()
+    }
+
+    /// Starts or resumes the timer if it is not running and,
+    /// in case of a countdown, has not finished yet;
+    /// otherwise has no effect.
+    pub fn start(&mut self) {
+        if self.is_running() || self.is_finished() {
+            return;
+        }
+        self.started_at = Some(self.clock.now());
+    }
+
+    /// Pauses the timer if it is running; otherwise has no
+    /// effect.
+    pub fn pause(&mut self) {
+        self.accumulated = self.elapsed();
+        self.started_at = None;
+    }
+
+    /// Resets the measured time to zero. The timer keeps
+    /// running if it was running.
+    pub fn reset(&mut self) {
+        self.accumulated = Duration::ZERO;
+        if self.is_running() {
+            self.started_at = Some(self.clock.now());
+        }
+    }
+
+    /// Returns the time measured by the timer. For a
+    /// countdown, the value never exceeds its duration.
+    pub fn elapsed(&self) -> Duration {
+        let running_for = self
+            .started_at
+            .map(|started_at| {
+                self.clock.now().saturating_duration_since(started_at)
+            })
+            .unwrap_or_default();
+        let elapsed = self.accumulated + running_for;
+
+        match self.style.mode {
+            SmallTimerMode::Stopwatch => elapsed,
+            SmallTimerMode::Countdown(duration) => elapsed.min(duration),
+        }
+    }
+
+    /// Returns the remaining time if the timer is a
+    /// countdown; otherwise returns `None`.
+    pub fn remaining(&self) -> Option<Duration> {
+        match self.style.mode {
+            SmallTimerMode::Stopwatch => None,
+            SmallTimerMode::Countdown(duration) => {
+                duration.saturating_sub(self.elapsed()).into()
+            }
+        }
+    }
+
+    /// Returns the duration displayed by the timer: the
+    /// remaining time for a countdown and the elapsed time
+    /// for a stopwatch.
+    pub fn displayed(&self) -> Duration {
+        self.remaining().unwrap_or_else(|| self.elapsed())
+    }
+
+    /// Stops a running countdown and emits
+    /// [`SmallTimerEvent::Finished`] if it has reached zero;
+    /// otherwise has no effect. Called on every `render`.
+    pub fn update(&mut self) {
+        if self.is_running() && self.is_finished() {
+            self.pause();
+            self.last_event = Some(SmallTimerEvent::Finished);
+        }
+    }
+
+    /// Returns the text currently displayed by the timer.
+    pub fn text(&self) -> String {
+        self.segments(self.displayed())
+            .into_iter()
+            .map(|(_, value)| value)
+            .collect()
+    }
+
+    fn is_finished(&self) -> bool {
+        self.remaining() == Some(Duration::ZERO)
+    }
+
+    /// Returns the segments of the displayed text in the
+    /// order they are displayed.
+    fn segments(
+        &self,
+        displayed: Duration,
+    ) -> Vec<(SmallTimerSegment, String)> {
+        // A countdown shows "00:01" until it actually reaches
+        // zero, so the seconds are rounded up.
+        let total_seconds = match self.style.mode {
+            SmallTimerMode::Stopwatch => displayed.as_secs(),
+            SmallTimerMode::Countdown(_) => {
+                displayed.as_secs() + (displayed.subsec_nanos() > 0) as u64
+            }
+        };
+        let (hours, minutes, seconds) = (
+            total_seconds / 3600,
+            total_seconds % 3600 / 60,
+            total_seconds % 60,
+        );
+
+        let show_hours = match self.style.format {
+            SmallTimerFormat::MinutesSeconds => false,
+            SmallTimerFormat::HoursMinutesSeconds => true,
+            SmallTimerFormat::Auto => hours > 0,
+        };
+
+        let mut segments = Vec::new();
+        if show_hours {
+            segments.push((SmallTimerSegment::Hours, format!("{:02}", hours)));
+            segments.push((SmallTimerSegment::Separator, ":".to_string()));
+            segments
+                .push((SmallTimerSegment::Minutes, format!("{:02}", minutes)));
+        } else {
+            let minutes = hours * 60 + minutes;
+            segments
+                .push((SmallTimerSegment::Minutes, format!("{:02}", minutes)));
+        }
+        segments.push((SmallTimerSegment::Separator, ":".to_string()));
+        segments.push((SmallTimerSegment::Seconds, format!("{:02}", seconds)));
+
+        segments
+    }
+
+    fn text_style<'a>(
+        &self,
+        text: &'a str,
+        segments: &[(SmallTimerSegment, String)],
+        displayed: Duration,
+    ) -> SmallTextStyle<'a> {
+        let mut ranges: Vec<(SmallTimerSegment, Range<u16>)> = Vec::new();
+        let mut start = 0;
+        for (segment, value) in segments {
+            let end = start + value.chars().count() as u16;
+            ranges.push((*segment, start..end));
+            start = end;
+        }
+
+        let mut text_style_builder =
+            SmallTextStyleBuilder::default().with_text(text);
+        for (segment, range) in ranges {
+            if range.is_empty() {
+                continue;
+            }
+
+            // The end of the target range is inclusive.
+            let style = self.style.segment_style(segment, displayed);
+            text_style_builder = text_style_builder
+                .for_target(Target::Range(range.start, range.end - 1))
+                .set_style(style)
+                .then();
+        }
+
+        text_style_builder.build()
+    }
+}
+
+impl AccessibleDescription for SmallTimerWidget {
+    fn accessible_description(&self) -> String {
+        let role = match self.style.mode {
+            SmallTimerMode::Stopwatch => "stopwatch",
+            SmallTimerMode::Countdown(_) => "countdown",
+        };
+        let state = match (self.is_running(), self.is_finished()) {
+            (true, _) => "running",
+            (false, true) => "finished",
+            (false, false) => "stopped",
+        };
+
+        describe_widget(role, Some(&self.text()), [state])
+    }
+}
+
+impl Identifiable for SmallTimerWidget {
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use caponata_common::AnimationGroup;
+    use caponata_small_text::SymbolStyleBuilder;
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        style::Color,
+        widgets::Widget,
+    };
+
+    use super::SmallTimerWidget;
+    use crate::{
+        SmallTimerEvent,
+        SmallTimerFormat,
+        SmallTimerMode,
+        SmallTimerStyleBuilder,
+        SmallTimerThreshold,
+    };
+
+    #[test]
+    fn countdown_is_rendered_with_thresholds() {
+        let warning_style = SymbolStyleBuilder::default()
+            .with_foreground_color(Color::Red)
+            .build()
+            .unwrap();
+        let style = SmallTimerStyleBuilder::default()
+            .with_mode(SmallTimerMode::Countdown(Duration::from_secs(90)))
+            .with_format(SmallTimerFormat::MinutesSeconds)
+            .with_thresholds(vec![SmallTimerThreshold::new(
+                Duration::from_secs(10),
+                warning_style,
+            )])
+            .build()
+            .unwrap();
+        let mut timer = SmallTimerWidget::new(style);
+        let group = AnimationGroup::new();
+        timer.set_clock(group.clock());
+        timer.start();
+
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buf = Buffer::empty(area);
+        timer.render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["01:30"]));
+
+        group.advance(Duration::from_secs(80));
+        timer.render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["00:10"]));
+        assert_eq!(buf[(4, 0)].fg, Color::Reset);
+
+        // The seconds are rounded up, while the thresholds apply
+        // to the exact remaining time.
+        group.advance(Duration::from_millis(500));
+        timer.render(area, &mut buf);
+        assert_eq!(timer.text(), "00:10");
+        assert_eq!(buf[(4, 0)].fg, Color::Red);
+    }
+
+    #[test]
+    fn segments_are_styled_separately() {
+        let symbol_style = |color| {
+            SymbolStyleBuilder::default()
+                .with_foreground_color(color)
+                .build()
+                .unwrap()
+        };
+        let style = SmallTimerStyleBuilder::default()
+            .with_mode(SmallTimerMode::Countdown(Duration::from_secs(90)))
+            .with_format(SmallTimerFormat::MinutesSeconds)
+            .with_minutes_style(symbol_style(Color::Red))
+            .with_separator_style(symbol_style(Color::Gray))
+            .with_seconds_style(symbol_style(Color::Blue))
+            .build()
+            .unwrap();
+        let mut timer = SmallTimerWidget::new(style);
+
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buf = Buffer::empty(area);
+        timer.render(area, &mut buf);
+        assert_eq!(timer.text(), "01:30");
+
+        let colors: Vec<Color> = (0..5).map(|x| buf[(x, 0)].fg).collect();
+        assert_eq!(
+            colors,
+            [
+                Color::Red,
+                Color::Red,
+                Color::Gray,
+                Color::Blue,
+                Color::Blue
+            ],
+        );
+    }
+
+    #[test]
+    fn countdown_finishes_at_deadline() {
+        let style = SmallTimerStyleBuilder::default()
+            .with_mode(SmallTimerMode::Countdown(Duration::from_secs(2)))
+            .build()
+            .unwrap();
+        let mut timer = SmallTimerWidget::new(style);
+        let group = AnimationGroup::new();
+        timer.set_clock(group.clock());
+        timer.start();
+
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buf = Buffer::empty(area);
+        group.advance(Duration::from_millis(1999));
+        timer.render(area, &mut buf);
+        assert_eq!(timer.take_last_event(), None);
+        assert_eq!(timer.text(), "00:01");
+
+        group.advance(Duration::from_millis(1));
+        timer.render(area, &mut buf);
+        assert_eq!(timer.take_last_event(), Some(SmallTimerEvent::Finished));
+        assert_eq!(buf, Buffer::with_lines(["00:00"]));
+        assert!(!timer.is_running());
+
+        group.advance(Duration::from_secs(1));
+        timer.render(area, &mut buf);
+        timer.start();
+        assert_eq!(timer.take_last_event(), None);
+        assert!(!timer.is_running());
+    }
+}
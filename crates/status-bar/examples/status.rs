@@ -0,0 +1,114 @@
+use std::{
+    io,
+    time::Duration,
+};
+
+use caponata_common::WidgetEvent;
+use caponata_line_composer::{
+    InlineBadge,
+    InlineElement,
+};
+use caponata_small_spinner::SmallSpinnerWidget;
+use caponata_small_text::{
+    SmallTextWidget,
+    SymbolStyleBuilder,
+};
+use caponata_status_bar::{
+    StatusBarEvent,
+    StatusBarGroup,
+    StatusBarStyleBuilder,
+    StatusBarWidget,
+};
+use crossterm::{
+    event::{
+        DisableMouseCapture,
+        EnableMouseCapture,
+        Event,
+        KeyCode,
+        poll,
+        read,
+    },
+    execute,
+};
+use ratatui::{
+    DefaultTerminal,
+    layout::Rect,
+    style::{
+        Color,
+        Modifier,
+    },
+};
+
+pub fn main() -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    execute!(io::stdout(), EnableMouseCapture)?;
+    let result = run(&mut terminal);
+
+    execute!(io::stdout(), DisableMouseCapture)?;
+    ratatui::restore();
+    result
+}
+
+fn run(terminal: &mut DefaultTerminal) -> io::Result<()> {
+    let separator_style = SymbolStyleBuilder::default()
+        .with_foreground_color(Color::DarkGray)
+        .build()
+        .unwrap();
+    let background_style = SymbolStyleBuilder::default()
+        .with_background_color(Color::Black)
+        .build()
+        .unwrap();
+    let badge_style = SymbolStyleBuilder::default()
+        .with_foreground_color(Color::Black)
+        .with_background_color(Color::Yellow)
+        .with_modifier(Modifier::BOLD)
+        .build()
+        .unwrap();
+    let style = StatusBarStyleBuilder::default()
+        .with_separator(" │ ")
+        .with_separator_style(separator_style)
+        .with_background_style(background_style)
+        .build()
+        .unwrap();
+
+    let mut status_bar: StatusBarWidget = StatusBarWidget::new(style)
+        .with_left(SmallTextWidget::plain("NORMAL").fg(Color::Green), 3)
+        .with_left(SmallTextWidget::plain("main"), 1)
+        .with_center(SmallSpinnerWidget::braille().fg(Color::Cyan), 0)
+        .with_center(SmallTextWidget::plain("indexing"), 0)
+        .with_right(SmallTextWidget::plain("q to quit"), 0)
+        .with_right(InlineBadge::new("3 new", badge_style), 2);
+
+    let clicked_badge = StatusBarEvent {
+        group: StatusBarGroup::Right,
+        index: 1,
+        event: WidgetEvent::Clicked,
+    };
+
+    loop {
+        let mut status_bar_area = Rect::default();
+        terminal.draw(|frame| {
+            let area = frame.area();
+            status_bar_area =
+                Rect::new(area.x, area.bottom() - 1, area.width, 1);
+            frame.render_widget(&mut status_bar, status_bar_area);
+        })?;
+
+        let timeout = Duration::from_millis(50);
+        if !poll(timeout)? {
+            continue;
+        }
+        let event = read()?;
+        if matches!(event, Event::Key(key) if key.code == KeyCode::Char('q')) {
+            return Ok(());
+        }
+
+        if status_bar.handle_event(event, status_bar_area)
+            == Some(clicked_badge)
+            && let Some(InlineElement::Text(text)) =
+                status_bar.segment_mut(StatusBarGroup::Center, 1)
+        {
+            text.set_text("badge clicked");
+        }
+    }
+}
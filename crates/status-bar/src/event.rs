@@ -0,0 +1,18 @@
+use caponata_common::WidgetEvent;
+
+use super::StatusBarGroup;
+
+/// An event produced by a segment of [`StatusBarWidget`].
+///
+/// [`StatusBarWidget`]: crate::StatusBarWidget
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StatusBarEvent {
+    /// Group of the segment that produced the event.
+    pub group: StatusBarGroup,
+
+    /// Index of the segment within its group, in the order
+    /// the segments were added.
+    pub index: usize,
+
+    pub event: WidgetEvent,
+}
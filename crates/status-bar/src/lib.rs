@@ -0,0 +1,11 @@
+#![doc = include_str!("../README.md")]
+
+#[cfg(feature = "crossterm")]
+pub mod event;
+pub mod status_bar;
+pub mod style;
+
+#[cfg(feature = "crossterm")]
+pub use event::*;
+pub use status_bar::*;
+pub use style::*;
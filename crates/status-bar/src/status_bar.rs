@@ -0,0 +1,686 @@
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    time::Duration,
+};
+
+use caponata_common::{
+    AccessibleDescription,
+    Animated,
+    Identifiable,
+    SharedClock,
+    WidgetIdentity,
+    describe_parts,
+};
+#[cfg(feature = "crossterm")]
+use caponata_common::{
+    EventQueue,
+    HandlesEvent,
+};
+use caponata_line_composer::InlineElement;
+#[cfg(feature = "crossterm")]
+use crossterm::event::Event;
+#[cfg(feature = "crossterm")]
+use ratatui::layout::Position;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    text::Span,
+    widgets::Widget,
+};
+
+#[cfg(feature = "crossterm")]
+use super::StatusBarEvent;
+use super::StatusBarStyle;
+
+/// A group of segments of [`StatusBarWidget`], determining
+/// where the segments are rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusBarGroup {
+    /// Segments aligned to the left edge of the bar.
+    Left,
+
+    /// Segments centered within the bar, or within the space
+    /// between the left and the right groups if the center of
+    /// the bar is taken.
+    Center,
+
+    /// Segments aligned to the right edge of the bar.
+    Right,
+}
+
+impl StatusBarGroup {
+    const ALL: [StatusBarGroup; 3] = [
+        StatusBarGroup::Left,
+        StatusBarGroup::Center,
+        StatusBarGroup::Right,
+    ];
+}
+
+/// An element along with its truncation priority.
+#[derive(Debug, Clone)]
+struct StatusSegment<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    element: InlineElement<'a, K>,
+
+    /// Segments with lower priority are hidden first when the
+    /// area is too narrow.
+    priority: u8,
+}
+
+/// An element of the row rendered by [`StatusBarWidget`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StatusBarItem {
+    /// A segment with provided index within provided group.
+    Segment(StatusBarGroup, usize),
+    Separator,
+}
+
+/// A widget that displays a single-line status bar made of
+/// three groups of [`InlineElement`]s: aligned to the left,
+/// centered and aligned to the right. Adjacent segments of the
+/// same group are divided by a separator, and the center
+/// group is kept at least a single cell away from the others.
+///
+/// Each segment has a priority. When the area is too narrow
+/// to display all the segments, the segments with the lowest
+/// priority are hidden first. Among segments with the same
+/// priority, the rightmost one is hidden first.
+///
+/// # Example
+///
+/// ```rust
+/// use caponata_small_text::SmallTextWidget;
+/// use caponata_status_bar::{
+///     StatusBarStyleBuilder,
+///     StatusBarWidget,
+/// };
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     widgets::Widget,
+/// };
+///
+/// let style = StatusBarStyleBuilder::default()
+///     .with_separator("|")
+///     .build()
+///     .unwrap();
+/// let mut status_bar: StatusBarWidget = StatusBarWidget::new(style)
+///     .with_left(SmallTextWidget::plain("NORMAL"), 2)
+///     .with_left(SmallTextWidget::plain("main"), 0)
+///     .with_right(SmallTextWidget::plain("42:7"), 1);
+///
+/// let area = Rect::new(0, 0, 16, 1);
+/// let mut buf = Buffer::empty(area);
+/// status_bar.render(area, &mut buf);
+/// assert_eq!(buf, Buffer::with_lines(["NORMAL|main 42:7"]));
+///
+/// let area = Rect::new(0, 0, 12, 1);
+/// let mut buf = Buffer::empty(area);
+/// status_bar.render(area, &mut buf);
+/// assert_eq!(buf, Buffer::with_lines(["NORMAL  42:7"]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct StatusBarWidget<'a, K = ()>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    style: StatusBarStyle<'a>,
+    left: Vec<StatusSegment<'a, K>>,
+    center: Vec<StatusSegment<'a, K>>,
+    right: Vec<StatusSegment<'a, K>>,
+
+    /// Events returned by [`StatusBarWidget::handle_event`]
+    /// that have not been drained yet.
+    #[cfg(feature = "crossterm")]
+    events: EventQueue<StatusBarEvent>,
+    identity: WidgetIdentity,
+}
+
+impl<'a, K> Default for StatusBarWidget<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn default() -> Self {
+        Self::new(StatusBarStyle::default())
+    }
+}
+
+impl<'a, K> Widget for &mut StatusBarWidget<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let row = Rect { height: 1, ..area };
+        let background_style: Style = self.style.background_style.into();
+        buf.set_style(row, background_style);
+
+        let separator_style: Style = self.style.separator_style.into();
+        for (item, item_area) in self.layout(row) {
+            match item {
+                StatusBarItem::Segment(group, index) => {
+                    self.segments_mut(group)[index]
+                        .element
+                        .render(item_area, buf);
+                }
+                StatusBarItem::Separator => {
+                    buf.set_stringn(
+                        item_area.x,
+                        item_area.y,
+                        self.style.separator,
+                        item_area.width as usize,
+                        separator_style,
+                    );
+                }
+            }
+        }
+        self.identity.record(area, &*self);
+    }
+}
+
+impl<'a, K> StatusBarWidget<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    pub fn new(style: StatusBarStyle<'a>) -> Self {
+        Self {
+            style,
+            left: Vec::new(),
+            center: Vec::new(),
+            right: Vec::new(),
+            #[cfg(feature = "crossterm")]
+            events: EventQueue::new(),
+            identity: WidgetIdentity::default(),
+        }
+    }
+
+    /// Adds provided element after the other segments of the
+    /// left group.
+    pub fn with_left(
+        mut self,
+        element: impl Into<InlineElement<'a, K>>,
+        priority: u8,
+    ) -> Self {
+        self.push(StatusBarGroup::Left, element, priority);
+        self
+    }
+
+    /// Adds provided element after the other segments of the
+    /// center group.
+    pub fn with_center(
+        mut self,
+        element: impl Into<InlineElement<'a, K>>,
+        priority: u8,
+    ) -> Self {
+        self.push(StatusBarGroup::Center, element, priority);
+        self
+    }
+
+    /// Adds provided element after the other segments of the
+    /// right group.
+    pub fn with_right(
+        mut self,
+        element: impl Into<InlineElement<'a, K>>,
+        priority: u8,
+    ) -> Self {
+        self.push(StatusBarGroup::Right, element, priority);
+        self
+    }
+
+    /// Adds provided element after the other segments of
+    /// provided group. Segments with lower priority are hidden
+    /// first when the area is too narrow.
+    pub fn push(
+        &mut self,
+        group: StatusBarGroup,
+        element: impl Into<InlineElement<'a, K>>,
+        priority: u8,
+    ) {
+        self.segments_mut(group).push(StatusSegment {
+            element: element.into(),
+            priority,
+        });
+    }
+
+    /// Returns the element of the segment with provided index
+    /// within provided group.
+    pub fn segment(
+        &self,
+        group: StatusBarGroup,
+        index: usize,
+    ) -> Option<&InlineElement<'a, K>> {
+        self.segments(group)
+            .get(index)
+            .map(|segment| &segment.element)
+    }
+
+    /// Returns the element of the segment with provided index
+    /// within provided group mutably, e.g. to replace the text
+    /// of a status.
+    pub fn segment_mut(
+        &mut self,
+        group: StatusBarGroup,
+        index: usize,
+    ) -> Option<&mut InlineElement<'a, K>> {
+        self.segments_mut(group)
+            .get_mut(index)
+            .map(|segment| &mut segment.element)
+    }
+
+    /// Replaces the clock used by the animated segments.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        for group in StatusBarGroup::ALL {
+            for segment in self.segments_mut(group) {
+                segment.element.set_clock(clock.clone());
+            }
+        }
+    }
+
+    /// Returns the time left until any of the segments
+    /// displays its next frame, or `None` if none of them
+    /// changes by itself.
+    pub fn time_until_next_frame(&self) -> Option<Duration> {
+        StatusBarGroup::ALL
+            .into_iter()
+            .flat_map(|group| self.segments(group))
+            .filter_map(|segment| segment.element.time_until_next_frame())
+            .min()
+    }
+
+    fn segments(&self, group: StatusBarGroup) -> &Vec<StatusSegment<'a, K>> {
+        match group {
+            StatusBarGroup::Left => &self.left,
+            StatusBarGroup::Center => &self.center,
+            StatusBarGroup::Right => &self.right,
+        }
+    }
+
+    fn segments_mut(
+        &mut self,
+        group: StatusBarGroup,
+    ) -> &mut Vec<StatusSegment<'a, K>> {
+        match group {
+            StatusBarGroup::Left => &mut self.left,
+            StatusBarGroup::Center => &mut self.center,
+            StatusBarGroup::Right => &mut self.right,
+        }
+    }
+
+    /// Returns the visible items along with the areas they
+    /// are rendered in within the first row of provided area.
+    fn layout(&self, area: Rect) -> Vec<(StatusBarItem, Rect)> {
+        let visible = self.visible_segments(area.width);
+        let separator_width = Span::raw(self.style.separator).width() as u16;
+        let group_segments = |group: StatusBarGroup| {
+            visible.iter().filter(move |(g, _)| *g == group)
+        };
+        let group_width = |group: StatusBarGroup| {
+            let segments = group_segments(group);
+            let separator_count = segments.clone().count().saturating_sub(1);
+            segments
+                .map(|(_, index)| self.segment_width(group, *index))
+                .sum::<u16>()
+                + separator_count as u16 * separator_width
+        };
+        let has_segments =
+            |group: StatusBarGroup| group_segments(group).next().is_some();
+
+        let left_width = group_width(StatusBarGroup::Left);
+        let center_width = group_width(StatusBarGroup::Center);
+        let right_width = group_width(StatusBarGroup::Right);
+
+        // Keep a gap of a single cell between the center group
+        // and its neighbours.
+        let has_center = has_segments(StatusBarGroup::Center);
+        let left_gap =
+            (has_center && has_segments(StatusBarGroup::Left)) as u16;
+        let right_gap =
+            (has_center && has_segments(StatusBarGroup::Right)) as u16;
+
+        let right_x = area.right() - right_width;
+        let center_x = (area.x + (area.width - center_width) / 2).clamp(
+            area.x + left_width + left_gap,
+            right_x - right_gap - center_width,
+        );
+
+        let mut layout = Vec::new();
+        for (group, mut x) in [
+            (StatusBarGroup::Left, area.x),
+            (StatusBarGroup::Center, center_x),
+            (StatusBarGroup::Right, right_x),
+        ] {
+            for (i, (_, index)) in group_segments(group).enumerate() {
+                if i > 0 {
+                    let separator_area =
+                        Rect::new(x, area.y, separator_width, 1);
+                    layout.push((StatusBarItem::Separator, separator_area));
+                    x += separator_width;
+                }
+                let width = self.segment_width(group, *index);
+                let segment_area = Rect::new(x, area.y, width, 1);
+                layout.push((
+                    StatusBarItem::Segment(group, *index),
+                    segment_area,
+                ));
+                x += width;
+            }
+        }
+
+        layout
+    }
+
+    /// Returns the segments that should be rendered to fit
+    /// into provided width, in the order they are displayed.
+    fn visible_segments(&self, width: u16) -> Vec<(StatusBarGroup, usize)> {
+        let mut visible: Vec<(StatusBarGroup, usize)> = StatusBarGroup::ALL
+            .into_iter()
+            .flat_map(|group| {
+                (0..self.segments(group).len())
+                    .map(move |index| (group, index))
+            })
+            .collect();
+
+        while self.segments_width(&visible) > width as usize {
+            let hidden_position = visible
+                .iter()
+                .enumerate()
+                .rev()
+                .min_by_key(|(_, (group, index))| {
+                    self.segments(*group)[*index].priority
+                })
+                .map(|(position, _)| position);
+            let Some(hidden_position) = hidden_position else {
+                break;
+            };
+            visible.remove(hidden_position);
+        }
+
+        visible
+    }
+
+    /// Returns the width taken by provided segments along with
+    /// the separators between the segments of the same group
+    /// and the gaps between the groups.
+    fn segments_width(&self, segments: &[(StatusBarGroup, usize)]) -> usize {
+        let separator_width = Span::raw(self.style.separator).width();
+        let (separator_count, gap_count) = segments.windows(2).fold(
+            (0, 0),
+            |(separator_count, gap_count), pair| {
+                if pair[0].0 == pair[1].0 {
+                    (separator_count + 1, gap_count)
+                } else {
+                    (separator_count, gap_count + 1)
+                }
+            },
+        );
+
+        segments
+            .iter()
+            .map(|(group, index)| self.segment_width(*group, *index) as usize)
+            .sum::<usize>()
+            + separator_count * separator_width
+            + gap_count
+    }
+
+    fn segment_width(&self, group: StatusBarGroup, index: usize) -> u16 {
+        self.segments(group)[index].element.width()
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl<'a, K> StatusBarWidget<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    /// Delivers provided mouse event to all the segments and
+    /// returns the event of the segment under the cursor, or of
+    /// the first segment producing one. Hidden segments receive
+    /// the event with an empty area, so they can notice the
+    /// cursor leaving them. All the produced events are kept
+    /// for [`StatusBarWidget::drain_events`].
+    pub fn handle_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<StatusBarEvent> {
+        let Event::Mouse(mouse_event) = event else {
+            return None;
+        };
+        let position = Position::new(mouse_event.column, mouse_event.row);
+        let area = Rect {
+            height: area.height.min(1),
+            ..area
+        };
+        let layout = self.layout(area);
+
+        let mut routed_event = None;
+        for group in StatusBarGroup::ALL {
+            for index in 0..self.segments(group).len() {
+                let segment_area = layout
+                    .iter()
+                    .find(|(item, _)| {
+                        *item == StatusBarItem::Segment(group, index)
+                    })
+                    .map_or(Rect::default(), |(_, segment_area)| {
+                        *segment_area
+                    });
+                let Some(event) = self.segments_mut(group)[index]
+                    .element
+                    .handle_event(event.clone(), segment_area)
+                else {
+                    continue;
+                };
+                let event = StatusBarEvent {
+                    group,
+                    index,
+                    event,
+                };
+                self.events.push(event);
+
+                if routed_event.is_none() || segment_area.contains(position) {
+                    routed_event = Some(event);
+                }
+            }
+        }
+
+        routed_event
+    }
+
+    /// Returns the events produced by the segments since they
+    /// were last drained, from the oldest to the most recent
+    /// one.
+    pub fn drain_events(
+        &mut self,
+    ) -> impl Iterator<Item = StatusBarEvent> + '_ {
+        self.events.drain()
+    }
+}
+
+impl<'a, K> Animated for StatusBarWidget<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn time_until_next_frame(&self) -> Option<Duration> {
+        StatusBarWidget::time_until_next_frame(self)
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl<'a, K> HandlesEvent for StatusBarWidget<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    type Event = StatusBarEvent;
+
+    fn handle_event(
+        &mut self,
+        event: Event,
+        area: Rect,
+    ) -> Option<StatusBarEvent> {
+        StatusBarWidget::handle_event(self, event, area)
+    }
+}
+
+impl<'a, K> AccessibleDescription for StatusBarWidget<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    /// Describes the segments of all the groups from left to
+    /// right, including the ones hidden for lack of space.
+    fn accessible_description(&self) -> String {
+        let parts = StatusBarGroup::ALL
+            .into_iter()
+            .flat_map(|group| self.segments(group))
+            .map(|segment| segment.element.accessible_description());
+
+        describe_parts("status bar", parts)
+    }
+}
+
+impl<'a, K> Identifiable for StatusBarWidget<'a, K>
+where
+    K: Debug + Hash + PartialEq + Eq,
+{
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use caponata_common::AccessibleDescription;
+    use caponata_line_composer::InlineBadge;
+    use caponata_small_text::{
+        SmallTextWidget,
+        SymbolStyleBuilder,
+    };
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{
+            Color,
+            Style,
+        },
+        widgets::Widget,
+    };
+
+    use super::StatusBarWidget;
+    use crate::StatusBarStyleBuilder;
+
+    fn status_bar() -> StatusBarWidget<'static> {
+        let separator_style = SymbolStyleBuilder::default()
+            .with_foreground_color(Color::DarkGray)
+            .build()
+            .unwrap();
+        let style = StatusBarStyleBuilder::default()
+            .with_separator("|")
+            .with_separator_style(separator_style)
+            .build()
+            .unwrap();
+
+        StatusBarWidget::new(style)
+            .with_left(SmallTextWidget::plain("ab"), 3)
+            .with_left(SmallTextWidget::plain("cd"), 1)
+            .with_center(SmallTextWidget::plain("mid"), 0)
+            .with_right(SmallTextWidget::plain("ef"), 1)
+            .with_right(InlineBadge::new("9", Default::default()), 2)
+    }
+
+    #[test]
+    fn groups_are_aligned_and_separated() {
+        let mut status_bar = status_bar();
+        let area = Rect::new(0, 0, 17, 1);
+        let mut buf = Buffer::empty(area);
+        status_bar.render(area, &mut buf);
+
+        let mut expected_buf = Buffer::with_lines(["ab|cd  mid ef| 9 "]);
+        let separator_style = Style::default().fg(Color::DarkGray);
+        expected_buf.set_style(Rect::new(2, 0, 1, 1), separator_style);
+        expected_buf.set_style(Rect::new(13, 0, 1, 1), separator_style);
+        assert_eq!(buf, expected_buf);
+    }
+
+    #[test]
+    fn segments_with_lowest_priority_are_hidden_first() {
+        let mut status_bar = status_bar();
+        let render = |status_bar: &mut StatusBarWidget, width| {
+            let area = Rect::new(0, 0, width, 1);
+            let mut buf = Buffer::empty(area);
+            status_bar.render(area, &mut buf);
+            buf.content
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+
+        assert_eq!(render(&mut status_bar, 14), "ab|cd   ef| 9 ");
+        assert_eq!(render(&mut status_bar, 10), "ab|cd   9 ");
+        assert_eq!(render(&mut status_bar, 6), "ab  9 ");
+        assert_eq!(render(&mut status_bar, 2), "ab");
+        assert_eq!(
+            status_bar.accessible_description(),
+            "status bar: text 'ab'; text 'cd'; text 'mid'; text 'ef'; badge '9'",
+        );
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn events_are_routed_to_segments() {
+        use caponata_common::WidgetEvent;
+        use crossterm::event::{
+            Event,
+            KeyModifiers,
+            MouseButton,
+            MouseEvent,
+            MouseEventKind,
+        };
+
+        use crate::{
+            StatusBarEvent,
+            StatusBarGroup,
+        };
+
+        let mouse = |kind, column| {
+            Event::Mouse(MouseEvent {
+                kind,
+                column,
+                row: 0,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        let mut status_bar = status_bar();
+        let area = Rect::new(0, 0, 17, 1);
+
+        assert_eq!(
+            status_bar.handle_event(mouse(MouseEventKind::Moved, 8), area),
+            Some(StatusBarEvent {
+                group: StatusBarGroup::Center,
+                index: 0,
+                event: WidgetEvent::Hovered,
+            }),
+        );
+        assert_eq!(
+            status_bar.handle_event(
+                mouse(MouseEventKind::Down(MouseButton::Left), 16),
+                area,
+            ),
+            Some(StatusBarEvent {
+                group: StatusBarGroup::Right,
+                index: 1,
+                event: WidgetEvent::Clicked,
+            }),
+        );
+        assert_eq!(status_bar.drain_events().count(), 2);
+    }
+}
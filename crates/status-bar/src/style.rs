@@ -0,0 +1,50 @@
+use caponata_small_text::SymbolStyle;
+use derive_builder::Builder;
+
+/// A styling configuration for [`StatusBarWidget`].
+///
+/// [`StatusBarWidget`]: crate::StatusBarWidget
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Color;
+/// use caponata_small_text::SymbolStyleBuilder;
+/// use caponata_status_bar::StatusBarStyleBuilder;
+///
+/// let separator_style = SymbolStyleBuilder::default()
+///     .with_foreground_color(Color::DarkGray)
+///     .build()
+///     .unwrap();
+/// let style = StatusBarStyleBuilder::default()
+///     .with_separator(" | ")
+///     .with_separator_style(separator_style)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Builder)]
+#[builder(setter(prefix = "with", into))]
+pub struct StatusBarStyle<'a> {
+    /// Text displayed between adjacent segments of the same
+    /// group.
+    #[builder(default = "\" │ \"")]
+    pub(crate) separator: &'a str,
+
+    #[builder(default)]
+    pub(crate) separator_style: SymbolStyle,
+
+    /// Style applied to the whole row before the segments are
+    /// rendered.
+    #[builder(default)]
+    pub(crate) background_style: SymbolStyle,
+}
+
+impl<'a> Default for StatusBarStyle<'a> {
+    fn default() -> Self {
+        Self {
+            separator: " │ ",
+            separator_style: SymbolStyle::default(),
+            background_style: SymbolStyle::default(),
+        }
+    }
+}
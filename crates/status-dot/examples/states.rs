@@ -0,0 +1,73 @@
+use std::{
+    io,
+    time::Duration,
+};
+
+use caponata_status_dot::{
+    StatusDotState,
+    StatusDotStyleBuilder,
+    StatusDotWidget,
+};
+use crossterm::event::{
+    Event,
+    KeyCode,
+    poll,
+    read,
+};
+use ratatui::{
+    DefaultTerminal,
+    layout::Rect,
+    style::Color,
+    text::Line,
+};
+
+pub fn main() -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal);
+
+    ratatui::restore();
+    result
+}
+
+fn run(terminal: &mut DefaultTerminal) -> io::Result<()> {
+    let style = StatusDotStyleBuilder::default()
+        .with_pulse_duration(Duration::from_millis(600))
+        .with_pulse_color(Color::White)
+        .build()
+        .unwrap();
+    let mut status_dot = StatusDotWidget::new(style);
+
+    loop {
+        terminal.draw(|frame| {
+            let area = frame.area();
+            frame.render_widget(&status_dot, Rect::new(area.x + 1, 1, 1, 1));
+            frame.render_widget(
+                Line::from(status_dot.state().name()),
+                Rect::new(area.x + 3, 1, 10, 1),
+            );
+            frame.render_widget(
+                Line::from("o/w/e/u to change the state, q to quit"),
+                Rect::new(area.x + 1, 3, 40, 1),
+            );
+        })?;
+
+        let timeout = status_dot
+            .time_until_next_frame()
+            .unwrap_or(Duration::from_millis(100));
+        if !poll(timeout)? {
+            continue;
+        }
+        let Event::Key(key) = read()? else {
+            continue;
+        };
+        let state = match key.code {
+            KeyCode::Char('o') => StatusDotState::Ok,
+            KeyCode::Char('w') => StatusDotState::Warn,
+            KeyCode::Char('e') => StatusDotState::Error,
+            KeyCode::Char('u') => StatusDotState::Unknown,
+            KeyCode::Char('q') => return Ok(()),
+            _ => continue,
+        };
+        status_dot.set_state(state);
+    }
+}
@@ -0,0 +1,7 @@
+#![doc = include_str!("../README.md")]
+
+pub mod status_dot;
+pub mod style;
+
+pub use status_dot::*;
+pub use style::*;
@@ -0,0 +1,313 @@
+use std::time::Duration;
+
+use caponata_common::{
+    AccessibleDescription,
+    Animated,
+    Identifiable,
+    Instant,
+    SharedClock,
+    WidgetIdentity,
+    describe_widget,
+    interpolate_color,
+    render_adapted,
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{
+        Color,
+        Style,
+    },
+    text::Span,
+    widgets::Widget,
+};
+
+use super::{
+    StatusDotState,
+    StatusDotStyle,
+};
+
+/// A one-cell widget that indicates a state, e.g. the health
+/// of a service, with a glyph and a color configured for each
+/// [`StatusDotState`].
+///
+/// When the state changes, the dot can pulse by changing its
+/// color to the pulse color of the style and back.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     style::Color,
+///     widgets::Widget,
+/// };
+/// use caponata_status_dot::{
+///     StatusDotState,
+///     StatusDotWidget,
+/// };
+///
+/// let mut status_dot = StatusDotWidget::default();
+/// status_dot.set_state(StatusDotState::Error);
+///
+/// let area = Rect::new(0, 0, 1, 1);
+/// let mut buf = Buffer::empty(area);
+/// status_dot.render(area, &mut buf);
+/// assert_eq!(buf[(0, 0)].symbol(), "●");
+/// assert_eq!(buf[(0, 0)].fg, Color::Red);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StatusDotWidget {
+    style: StatusDotStyle,
+    state: StatusDotState,
+
+    /// Time of the last state change. Used to animate the
+    /// pulse.
+    changed_at: Option<Instant>,
+    clock: SharedClock,
+    identity: WidgetIdentity,
+}
+
+impl Widget for &StatusDotWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.identity.record(area, self);
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+
+        render_adapted(area, buf, |buf| {
+            let symbol = self.style.symbol(self.state);
+            buf[(area.x, area.y)]
+                .set_char(symbol.glyph)
+                .set_style(self.span_style());
+        });
+    }
+}
+
+impl StatusDotWidget {
+    /// Interval between the frames of the pulse animation,
+    /// reported by [`Animated::time_until_next_frame`].
+    pub const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+    /// Creates a widget in the [`StatusDotState::Unknown`]
+    /// state.
+    pub fn new(style: StatusDotStyle) -> Self {
+        Self {
+            style,
+            state: StatusDotState::default(),
+            changed_at: None,
+            clock: SharedClock::default(),
+            identity: WidgetIdentity::default(),
+        }
+    }
+
+    pub fn state(&self) -> StatusDotState {
+        self.state
+    }
+
+    /// Displays provided state, starting the pulse if it
+    /// differs from the current one.
+    pub fn set_state(&mut self, state: StatusDotState) {
+        if state == self.state {
+            return;
+        }
+
+        self.state = state;
+        self.changed_at = Some(self.clock.now());
+    }
+
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+    }
+
+    /// Returns the current glyph as a styled span. Useful to
+    /// show the dot in a ratatui `Table` cell or `List` item,
+    /// which accept spans.
+    pub fn span(&self) -> Span<'static> {
+        let glyph = self.style.symbol(self.state).glyph;
+        Span::styled(glyph.to_string(), self.span_style())
+    }
+
+    /// Returns the time left until the next frame of the
+    /// pulse, or `None` if it has completed.
+    pub fn time_until_next_frame(&self) -> Option<Duration> {
+        let changed_at = self.changed_at?;
+        let elapsed = self.clock.now().saturating_duration_since(changed_at);
+        let time_left = self.style.pulse_duration?.checked_sub(elapsed)?;
+
+        (!time_left.is_zero()).then_some(time_left.min(Self::FRAME_INTERVAL))
+    }
+
+    fn span_style(&self) -> Style {
+        Style {
+            fg: Some(self.color()),
+            bg: self.style.background_color,
+            ..Style::default()
+        }
+    }
+
+    /// Returns the color of the dot, taking the pulse into
+    /// account.
+    fn color(&self) -> Color {
+        let color = self.style.symbol(self.state).color;
+        let (Some(changed_at), Some(duration)) =
+            (self.changed_at, self.style.pulse_duration)
+        else {
+            return color;
+        };
+        if duration.is_zero() {
+            return color;
+        }
+
+        let elapsed = self.clock.now().saturating_duration_since(changed_at);
+        let progress = elapsed.as_secs_f64() / duration.as_secs_f64();
+        if progress >= 1.0 {
+            return color;
+        }
+
+        // The color reaches the pulse color halfway through the
+        // pulse and returns to the color of the state by its
+        // end.
+        let intensity = 1.0 - (progress * 2.0 - 1.0).abs();
+        interpolate_color(
+            color,
+            self.style.pulse_color,
+            self.style.pulse_easing.apply(intensity),
+        )
+    }
+}
+
+impl Animated for StatusDotWidget {
+    fn time_until_next_frame(&self) -> Option<Duration> {
+        StatusDotWidget::time_until_next_frame(self)
+    }
+}
+
+impl AccessibleDescription for StatusDotWidget {
+    fn accessible_description(&self) -> String {
+        describe_widget("status", Some(self.state.name()), [])
+    }
+}
+
+impl Identifiable for StatusDotWidget {
+    fn identity(&self) -> &WidgetIdentity {
+        &self.identity
+    }
+
+    fn identity_mut(&mut self) -> &mut WidgetIdentity {
+        &mut self.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use caponata_common::{
+        AccessibleDescription,
+        AnimationGroup,
+        Identifiable,
+        WidgetRegistry,
+    };
+    use ratatui::{
+        buffer::Buffer,
+        layout::{
+            Position,
+            Rect,
+        },
+        style::Color,
+        widgets::Widget,
+    };
+
+    use super::StatusDotWidget;
+    use crate::{
+        StatusDotState,
+        StatusDotStyleBuilder,
+        StatusDotSymbol,
+    };
+
+    #[test]
+    fn dot_pulses_on_state_change() {
+        let style = StatusDotStyleBuilder::default()
+            .with_warn(StatusDotSymbol::new('▲', Color::Rgb(200, 200, 0)))
+            .with_pulse_duration(Duration::from_millis(100))
+            .with_pulse_color(Color::Rgb(0, 0, 200))
+            .build()
+            .unwrap();
+        let mut status_dot = StatusDotWidget::new(style);
+        let group = AnimationGroup::new();
+        status_dot.set_clock(group.clock());
+        assert_eq!(status_dot.state(), StatusDotState::Unknown);
+        assert_eq!(status_dot.time_until_next_frame(), None);
+
+        status_dot.set_state(StatusDotState::Warn);
+        group.advance(Duration::from_millis(50));
+        let area = Rect::new(0, 0, 2, 1);
+        let mut buf = Buffer::empty(area);
+        status_dot.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "▲");
+        assert_eq!(buf[(0, 0)].fg, Color::Rgb(0, 0, 200));
+        assert_eq!(buf[(1, 0)], Buffer::empty(area)[(1, 0)]);
+        assert_eq!(
+            status_dot.time_until_next_frame(),
+            Some(StatusDotWidget::FRAME_INTERVAL),
+        );
+
+        group.advance(Duration::from_millis(25));
+        status_dot.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].fg, Color::Rgb(100, 100, 100));
+
+        group.advance(Duration::from_millis(25));
+        status_dot.render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].fg, Color::Rgb(200, 200, 0));
+        assert_eq!(status_dot.time_until_next_frame(), None);
+
+        status_dot.set_state(StatusDotState::Warn);
+        assert_eq!(status_dot.time_until_next_frame(), None);
+    }
+
+    #[test]
+    fn dot_without_pulse_changes_instantly() {
+        let mut status_dot = StatusDotWidget::default();
+        status_dot.set_state(StatusDotState::Ok);
+        assert_eq!(status_dot.time_until_next_frame(), None);
+
+        let span = status_dot.span();
+        assert_eq!(span.content, "●");
+        assert_eq!(span.style.fg, Some(Color::Green));
+        assert_eq!(span.style.bg, None);
+        assert_eq!(status_dot.accessible_description(), "status 'ok'");
+    }
+
+    #[test]
+    fn dot_with_id_is_recorded_when_rendered() {
+        let registry = WidgetRegistry::new();
+        let mut status_dot = StatusDotWidget::default();
+        status_dot.set_id("connection");
+        status_dot.set_registry(registry.clone());
+        assert_eq!(status_dot.id(), Some("connection"));
+
+        let area = Rect::new(3, 1, 1, 1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        status_dot.render(area, &mut buf);
+        assert_eq!(registry.area("connection"), Some(area));
+        assert_eq!(
+            registry.snapshot("connection").as_deref(),
+            Some("status 'unknown'"),
+        );
+
+        status_dot.set_state(StatusDotState::Error);
+        status_dot.render(area, &mut buf);
+        assert_eq!(
+            registry.snapshot("connection").as_deref(),
+            Some("status 'error'"),
+        );
+        assert_eq!(
+            registry.id_at(Position::new(3, 1)).as_deref(),
+            Some("connection"),
+        );
+        assert_eq!(registry.id_at(Position::new(2, 1)), None);
+    }
+}
@@ -0,0 +1,138 @@
+use std::time::Duration;
+
+use caponata_common::Easing;
+use derive_builder::Builder;
+use ratatui::style::Color;
+
+/// A named state displayed by [`StatusDotWidget`].
+///
+/// Default variant is [`StatusDotState::Unknown`].
+///
+/// [`StatusDotWidget`]: crate::StatusDotWidget
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusDotState {
+    Ok,
+    Warn,
+    Error,
+
+    /// The state is not determined yet, e.g. a health check
+    /// hasn't finished.
+    #[default]
+    Unknown,
+}
+
+impl StatusDotState {
+    /// All states in the order they are declared.
+    pub const ALL: [Self; 4] =
+        [Self::Ok, Self::Warn, Self::Error, Self::Unknown];
+
+    /// Returns the human-readable name of the state, e.g.
+    /// `"warn"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Warn => "warn",
+            Self::Error => "error",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+/// A glyph and its color displayed for a [`StatusDotState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StatusDotSymbol {
+    pub glyph: char,
+    pub color: Color,
+}
+
+impl StatusDotSymbol {
+    pub fn new(glyph: char, color: Color) -> Self {
+        Self { glyph, color }
+    }
+}
+
+/// A styling configuration for [`StatusDotWidget`].
+///
+/// [`StatusDotWidget`]: crate::StatusDotWidget
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::style::Color;
+/// use caponata_common::Easing;
+/// use caponata_status_dot::{
+///     StatusDotStyleBuilder,
+///     StatusDotSymbol,
+/// };
+///
+/// let style = StatusDotStyleBuilder::default()
+///     .with_ok(StatusDotSymbol::new('✔', Color::Green))
+///     .with_error(StatusDotSymbol::new('✘', Color::Red))
+///     .with_pulse_duration(Duration::from_millis(500))
+///     .with_pulse_color(Color::White)
+///     .with_pulse_easing(Easing::EaseInOut)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Builder)]
+#[builder(setter(prefix = "with", into, strip_option))]
+pub struct StatusDotStyle {
+    #[builder(default = "StatusDotSymbol::new('●', Color::Green)")]
+    pub(crate) ok: StatusDotSymbol,
+
+    #[builder(default = "StatusDotSymbol::new('●', Color::Yellow)")]
+    pub(crate) warn: StatusDotSymbol,
+
+    #[builder(default = "StatusDotSymbol::new('●', Color::Red)")]
+    pub(crate) error: StatusDotSymbol,
+
+    #[builder(default = "StatusDotSymbol::new('○', Color::DarkGray)")]
+    pub(crate) unknown: StatusDotSymbol,
+
+    /// Background color of the dot. `None` leaves the
+    /// background color of the cell untouched.
+    #[builder(default)]
+    pub(crate) background_color: Option<Color>,
+
+    /// Duration of the pulse played when the state changes, in
+    /// which the color of the dot changes to the pulse color
+    /// and back. `None` disables the pulse.
+    #[builder(default)]
+    pub(crate) pulse_duration: Option<Duration>,
+
+    #[builder(default = "Color::White")]
+    pub(crate) pulse_color: Color,
+
+    #[builder(default)]
+    pub(crate) pulse_easing: Easing,
+}
+
+impl Default for StatusDotStyle {
+    fn default() -> Self {
+        Self {
+            ok: StatusDotSymbol::new('●', Color::Green),
+            warn: StatusDotSymbol::new('●', Color::Yellow),
+            error: StatusDotSymbol::new('●', Color::Red),
+            unknown: StatusDotSymbol::new('○', Color::DarkGray),
+            background_color: None,
+            pulse_duration: None,
+            pulse_color: Color::White,
+            pulse_easing: Easing::default(),
+        }
+    }
+}
+
+impl StatusDotStyle {
+    /// Returns the glyph and the color displayed for provided
+    /// state.
+    pub fn symbol(&self, state: StatusDotState) -> StatusDotSymbol {
+        match state {
+            StatusDotState::Ok => self.ok,
+            StatusDotState::Warn => self.warn,
+            StatusDotState::Error => self.error,
+            StatusDotState::Unknown => self.unknown,
+        }
+    }
+}
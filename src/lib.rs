@@ -9,3 +9,59 @@ pub use caponata_button as button;
 #[cfg(feature = "small-text-widget")]
 #[doc(inline)]
 pub use caponata_small_text as small_text;
+
+#[cfg(feature = "small-gauge-widget")]
+#[doc(inline)]
+pub use caponata_small_gauge as small_gauge;
+
+#[cfg(feature = "small-timer-widget")]
+#[doc(inline)]
+pub use caponata_small_timer as small_timer;
+
+#[cfg(feature = "odometer-widget")]
+#[doc(inline)]
+pub use caponata_odometer as odometer;
+
+#[cfg(feature = "breadcrumb-widget")]
+#[doc(inline)]
+pub use caponata_breadcrumb as breadcrumb;
+
+#[cfg(feature = "dropdown-widget")]
+#[doc(inline)]
+pub use caponata_dropdown as dropdown;
+
+#[cfg(feature = "dialog-widget")]
+#[doc(inline)]
+pub use caponata_dialog as dialog;
+
+#[cfg(feature = "slider-widget")]
+#[doc(inline)]
+pub use caponata_slider as slider;
+
+#[cfg(feature = "line-composer-widget")]
+#[doc(inline)]
+pub use caponata_line_composer as line_composer;
+
+#[cfg(feature = "status-bar-widget")]
+#[doc(inline)]
+pub use caponata_status_bar as status_bar;
+
+#[cfg(feature = "pagination-widget")]
+#[doc(inline)]
+pub use caponata_pagination as pagination;
+
+#[cfg(feature = "key-echo-widget")]
+#[doc(inline)]
+pub use caponata_key_echo as key_echo;
+
+#[cfg(feature = "banner-text-widget")]
+#[doc(inline)]
+pub use caponata_banner_text as banner_text;
+
+#[cfg(feature = "large-spinner-widget")]
+#[doc(inline)]
+pub use caponata_large_spinner as large_spinner;
+
+#[cfg(feature = "status-dot-widget")]
+#[doc(inline)]
+pub use caponata_status_dot as status_dot;